@@ -32,7 +32,6 @@ fn setup(spawner: ::embassy_executor::Spawner) {
     };
     let pong_id = pong_ref.id();
     let mut group = ChildGroupBuilder::new(GroupShutdown::WhenAnyDone);
-    let _sup_control_ref_0 = group.control_ref();
     let sup_notify_ref_0 = group.notify_ref();
     let ping_ctx = PingCtx::new(
         ping_id,
@@ -48,7 +47,10 @@ fn setup(spawner: ::embassy_executor::Spawner) {
         spawner,
         group,
         ping_task(ping_machine, ping_mbox, ping_id),
-        ChildPolicy::Restart { max: 1u32 as usize }
+        ChildPolicy::Restart {
+            max: 1u32 as usize,
+            backoff: Backoff::Immediate
+        }
     );
     ::bloxide_embassy::spawn_child!(
         spawner,
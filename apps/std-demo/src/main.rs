@@ -0,0 +1,68 @@
+// Copyright 2025 Bloxide, all rights reserved
+// Auto-generated by bloxide-codegen. Do not edit manually.
+use ::bloxide_core::lifecycle::LifecycleCommand;
+use ::bloxide_std::prelude::*;
+use ::ping_blox::prelude::*;
+use ::ping_pong_impl::PingBehavior;
+use ::pong_blox::prelude::*;
+::bloxide_std::actor_task_supervised!(ping_task, PingSpec < StdRuntime, PingBehavior >);
+::bloxide_std::actor_task_supervised!(pong_task, PongSpec<StdRuntime>);
+::bloxide_std::root_task!(
+    supervisor_task,
+    ::bloxide_supervisor::SupervisorSpec<StdRuntime>
+);
+fn main() {
+    tracing_log::LogTracer::init().ok();
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .try_init()
+        .ok();
+    let timer_ref = ::bloxide_std::spawn_timer!(8);
+    let ((ping_ref,), ping_mbox) = ::bloxide_std::channels! {
+        ping_pong_messages::PingPongMsg(16),
+    };
+    let ping_id = ping_ref.id();
+    let ((pong_ref,), pong_mbox) = ::bloxide_std::channels! {
+        ping_pong_messages::PingPongMsg(16),
+    };
+    let pong_id = pong_ref.id();
+    let mut group = ChildGroupBuilder::new(GroupShutdown::WhenAnyDone);
+    let sup_notify_ref_0 = group.notify_ref();
+    let ping_ctx = PingCtx::new(
+        ping_id,
+        pong_ref.clone(),
+        ping_ref.clone(),
+        timer_ref.clone(),
+        PingBehavior::default(),
+    );
+    let pong_ctx = PongCtx::new(pong_id, ping_ref.clone());
+    let ping_machine = ::bloxide_core::StateMachine::new(ping_ctx);
+    let pong_machine = ::bloxide_core::StateMachine::new(pong_ctx);
+    ::bloxide_std::spawn_child!(
+        group,
+        ping_task(ping_machine, ping_mbox, ping_id),
+        ChildPolicy::Restart {
+            max: 1u32 as usize,
+            backoff: Backoff::Immediate
+        }
+    );
+    ::bloxide_std::spawn_child!(
+        group,
+        pong_task(pong_machine, pong_mbox, pong_id),
+        ChildPolicy::Stop
+    );
+    let sup_id = ::bloxide_std::next_actor_id!();
+    let (children, sup_notify_rx, sup_control_rx) = group.finish();
+    let sup_ctx = ::bloxide_supervisor::SupervisorCtx::new(sup_id, children, sup_notify_ref_0);
+    let mut sup_machine = ::bloxide_core::StateMachine::<
+        ::bloxide_supervisor::SupervisorSpec<StdRuntime>,
+    >::new(sup_ctx);
+    sup_machine.dispatch(
+        ::bloxide_supervisor::SupervisorEvent::<StdRuntime>::Lifecycle(LifecycleCommand::Start),
+    );
+    supervisor_task(sup_machine, (sup_notify_rx, sup_control_rx));
+    println!("std-demo complete");
+}
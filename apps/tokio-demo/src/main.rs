@@ -14,7 +14,7 @@ use ::pong_blox::prelude::*;
     ::bloxide_supervisor::SupervisorSpec<TokioRuntime>
 );
 #[tokio::main]
-async fn main() {
+async fn main() -> ::std::process::ExitCode {
     tracing_log::LogTracer::init().ok();
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -23,7 +23,9 @@ async fn main() {
         )
         .try_init()
         .ok();
-    let timer_ref = ::bloxide_tokio::spawn_timer!(8);
+    let mut system =
+        ::bloxide_tokio::SystemHandle::new(::std::time::Duration::from_millis(5000u64));
+    let timer_ref = ::bloxide_tokio::spawn_timer!(8, system);
     let ((ping_ref,), ping_mbox) = ::bloxide_tokio::channels! {
         ping_pong_messages::PingPongMsg(16),
     };
@@ -33,7 +35,7 @@ async fn main() {
     };
     let pong_id = pong_ref.id();
     let mut group = ChildGroupBuilder::new(GroupShutdown::WhenAnyDone);
-    let _sup_control_ref_0 = group.control_ref();
+    let sup_control_ref_0 = group.control_ref();
     let sup_notify_ref_0 = group.notify_ref();
     let ping_ctx = PingCtx::new(
         ping_id,
@@ -45,16 +47,19 @@ async fn main() {
     let pong_ctx = PongCtx::new(pong_id, ping_ref.clone());
     let ping_machine = ::bloxide_core::StateMachine::new(ping_ctx);
     let pong_machine = ::bloxide_core::StateMachine::new(pong_ctx);
-    ::bloxide_tokio::spawn_child!(
+    system.track(::bloxide_tokio::spawn_child!(
         group,
         ping_task(ping_machine, ping_mbox, ping_id),
-        ChildPolicy::Restart { max: 1u32 as usize }
-    );
-    ::bloxide_tokio::spawn_child!(
+        ChildPolicy::Restart {
+            max: 1u32 as usize,
+            backoff: Backoff::Immediate
+        }
+    ));
+    system.track(::bloxide_tokio::spawn_child!(
         group,
         pong_task(pong_machine, pong_mbox, pong_id),
         ChildPolicy::Stop
-    );
+    ));
     let sup_id = ::bloxide_tokio::next_actor_id!();
     let (children, sup_notify_rx, sup_control_rx) = group.finish();
     let sup_ctx = ::bloxide_supervisor::SupervisorCtx::new(sup_id, children, sup_notify_ref_0);
@@ -64,6 +69,16 @@ async fn main() {
     sup_machine.dispatch(
         ::bloxide_supervisor::SupervisorEvent::<TokioRuntime>::Lifecycle(LifecycleCommand::Start),
     );
-    supervisor_task(sup_machine, (sup_notify_rx, sup_control_rx)).await;
+    system.add_root(
+        ::tokio::spawn(supervisor_task(
+            sup_machine,
+            (sup_notify_rx, sup_control_rx),
+        )),
+        sup_control_ref_0,
+        ::bloxide_supervisor::SupervisorControl::Shutdown,
+        ::bloxide_supervisor::SupervisorControl::EscalateShutdown,
+    );
+    let report = system.run().await;
     println!("tokio-demo complete");
+    report.exit_code()
 }
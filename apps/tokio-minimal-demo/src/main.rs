@@ -12,7 +12,7 @@ use ::counter_messages::Tick;
     ::bloxide_supervisor::SupervisorSpec<TokioRuntime>
 );
 #[tokio::main]
-async fn main() {
+async fn main() -> ::std::process::ExitCode {
     tracing_log::LogTracer::init().ok();
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -21,20 +21,22 @@ async fn main() {
         )
         .try_init()
         .ok();
+    let mut system =
+        ::bloxide_tokio::SystemHandle::new(::std::time::Duration::from_millis(5000u64));
     let ((counter_ref,), counter_mbox) = ::bloxide_tokio::channels! {
         counter_messages::CounterMsg(16),
     };
     let counter_id = counter_ref.id();
     let mut group = ChildGroupBuilder::new(GroupShutdown::WhenAnyDone);
-    let _sup_control_ref_0 = group.control_ref();
+    let sup_control_ref_0 = group.control_ref();
     let sup_notify_ref_0 = group.notify_ref();
     let counter_ctx = CounterCtx::new(counter_id, CounterBehavior::default());
     let counter_machine = ::bloxide_core::StateMachine::new(counter_ctx);
-    ::bloxide_tokio::spawn_child!(
+    system.track(::bloxide_tokio::spawn_child!(
         group,
         counter_task(counter_machine, counter_mbox, counter_id),
         ChildPolicy::Stop
-    );
+    ));
     let sup_id = ::bloxide_tokio::next_actor_id!();
     let (children, sup_notify_rx, sup_control_rx) = group.finish();
     let sup_ctx = ::bloxide_supervisor::SupervisorCtx::new(sup_id, children, sup_notify_ref_0);
@@ -46,6 +48,16 @@ async fn main() {
     );
     let _ = counter_ref.send(counter_id, CounterMsg::Tick(Tick)).await;
     let _ = counter_ref.send(counter_id, CounterMsg::Tick(Tick)).await;
-    supervisor_task(sup_machine, (sup_notify_rx, sup_control_rx)).await;
+    system.add_root(
+        ::tokio::spawn(supervisor_task(
+            sup_machine,
+            (sup_notify_rx, sup_control_rx),
+        )),
+        sup_control_ref_0,
+        ::bloxide_supervisor::SupervisorControl::Shutdown,
+        ::bloxide_supervisor::SupervisorControl::EscalateShutdown,
+    );
+    let report = system.run().await;
     println!("tokio-minimal-demo complete");
+    report.exit_code()
 }
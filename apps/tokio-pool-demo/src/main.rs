@@ -11,7 +11,7 @@ use ::pool_messages::SpawnWorker;
     ::bloxide_supervisor::SupervisorSpec<TokioRuntime>
 );
 #[tokio::main]
-async fn main() {
+async fn main() -> ::std::process::ExitCode {
     tracing_log::LogTracer::init().ok();
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -20,6 +20,8 @@ async fn main() {
         )
         .try_init()
         .ok();
+    let mut system =
+        ::bloxide_tokio::SystemHandle::new(::std::time::Duration::from_millis(5000u64));
     let ((pool_ref, spawn_reply_ref), pool_mbox) = ::bloxide_tokio::channels! {
         pool_messages::PoolMsg(16), pool_messages::SpawnedWorker < TokioRuntime > (16),
     };
@@ -36,11 +38,11 @@ async fn main() {
         spawn_reply_ref.clone(),
     );
     let pool_machine = ::bloxide_core::StateMachine::new(pool_ctx);
-    ::bloxide_tokio::spawn_child!(
+    system.track(::bloxide_tokio::spawn_child!(
         group,
         pool_task(pool_machine, pool_mbox, pool_id),
         ChildPolicy::Stop
-    );
+    ));
     let sup_id = ::bloxide_tokio::next_actor_id!();
     let (children, sup_notify_rx, sup_control_rx) = group.finish();
     let sup_ctx = ::bloxide_supervisor::SupervisorCtx::new(sup_id, children, sup_notify_ref_0);
@@ -59,6 +61,16 @@ async fn main() {
     let _ = pool_ref
         .send(pool_id, PoolMsg::SpawnWorker(SpawnWorker { task_id: 2 }))
         .await;
-    supervisor_task(sup_machine, (sup_notify_rx, sup_control_rx)).await;
+    system.add_root(
+        ::tokio::spawn(supervisor_task(
+            sup_machine,
+            (sup_notify_rx, sup_control_rx),
+        )),
+        sup_control_ref_0,
+        ::bloxide_supervisor::SupervisorControl::Shutdown,
+        ::bloxide_supervisor::SupervisorControl::EscalateShutdown,
+    );
+    let report = system.run().await;
     println!("tokio-pool-demo complete");
+    report.exit_code()
 }
@@ -47,7 +47,7 @@ where
     R: BloxRuntime,
     C: HasSelfRef<R, PingPongMsg> + HasTimerRef<R> + HasSelfId + HasCurrentTimer,
 {
-    let id = set_timer::<R, C, PingPongMsg>(
+    let id = set_timer::<R, C, PingPongMsg, _>(
         ctx,
         duration_ms,
         ctx.self_ref(),
@@ -63,7 +63,7 @@ where
     C: HasSelfId + HasTimerRef<R> + HasCurrentTimer,
 {
     if let Some(id) = ctx.current_timer() {
-        cancel_timer::<R, C>(ctx, id);
+        cancel_timer::<R, C, _>(ctx, id);
         ctx.set_current_timer(None);
     }
 }
@@ -6,7 +6,8 @@
 extern crate alloc;
 
 use bloxide_core::{accessor::HasSelfId, capability::BloxRuntime};
-use bloxide_peers::PeerCtrl;
+use bloxide_log::blox_log_warn;
+use bloxide_peers::{apply_peer_control, PeerCtrl, PeerSet};
 use pool_messages::{PeerResult, PoolMsg, WorkDone, WorkerMsg};
 
 use crate::traits::{HasCurrentTask, HasPeers, HasPoolRef};
@@ -39,32 +40,25 @@ where
     C: HasSelfId + HasCurrentTask + HasPeers<WorkerMsg, R>,
 {
     let from = ctx.self_id();
-    let result = ctx.result();
-    let n = ctx.peers().len();
-    for i in 0..n {
-        let peer_ref = ctx.peers()[i].clone();
-        let _ = peer_ref.try_send(
-            from,
-            WorkerMsg::PeerResult(PeerResult {
-                from_id: from,
-                result,
-            }),
-        );
-    }
+    let msg = WorkerMsg::PeerResult(PeerResult {
+        from_id: from,
+        result: ctx.result(),
+    });
+    ctx.peers().try_broadcast_with(from, msg, |peer_id, _| {
+        blox_log_warn!(from, "peer {} mailbox full, result not delivered", peer_id);
+    });
 }
 
 /// Apply a `PeerCtrl<WorkerMsg, R>` command to the context's peer collection.
 ///
-/// Handles both `AddPeer` and `RemovePeer` variants.
+/// Handles both `AddPeer` and `RemovePeer` variants; a peer that does not
+/// fit in a bounded collection is logged and ignored.
 pub fn apply_worker_control<R, C>(ctx: &mut C, ctrl: &PeerCtrl<WorkerMsg, R>)
 where
     R: BloxRuntime,
-    C: HasPeers<WorkerMsg, R>,
+    C: HasSelfId + HasPeers<WorkerMsg, R>,
 {
-    match ctrl {
-        PeerCtrl::AddPeer(add) => ctx.peers_mut().push(add.peer_ref.clone()),
-        PeerCtrl::RemovePeer(remove) => {
-            ctx.peers_mut().retain(|r| r.id() != remove.peer_id);
-        }
+    if let Err(e) = apply_peer_control(ctx, ctrl) {
+        blox_log_warn!(ctx.self_id(), "{}", e);
     }
 }
@@ -211,6 +211,28 @@ mod ping_tests {
         assert!(matches!(resumes[0], PingPongMsg::Resume(_)));
     }
 
+    #[test]
+    fn paused_timer_is_owned_by_ping_and_cancelled_on_reset() {
+        let mut h = PingHarness::new();
+        h.start();
+        h.drain_to_pong_rx();
+        for _ in 0..PAUSE_AT_ROUND {
+            h.send_pong();
+            h.drain_to_pong_rx();
+        }
+        assert_eq!(h.current_state(), MachineState::State(PingState::Paused));
+
+        let timer = h.ctx().behavior.current_timer.expect("Paused sets a timer");
+        assert_eq!(h.clock.pending_for(h.ping_id), [timer]);
+        assert_eq!(h.clock.next_deadline(), Some(PAUSE_DURATION_MS));
+
+        h.terminate();
+
+        assert!(h.clock.pending_for(h.ping_id).is_empty());
+        assert_eq!(h.clock.run_until_idle(), 0);
+        assert!(h.drain_to_ping_rx().is_empty());
+    }
+
     #[test]
     fn timer_fires_resume_transitions_to_active() {
         let mut h = PingHarness::new();
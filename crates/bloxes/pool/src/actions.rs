@@ -10,7 +10,7 @@ use crate::{PoolCtx, PoolEvent};
 #[cfg(feature = "dynamic")]
 use bloxide_peers::introduce_peers;
 #[cfg(feature = "dynamic")]
-use bloxide_spawn::spawn_child;
+use bloxide_spawn::{spawn_child, SpawnChildError};
 #[cfg(feature = "dynamic")]
 use bloxide_supervisor::SupervisorRegistrar;
 #[cfg(feature = "dynamic")]
@@ -39,13 +39,25 @@ pub fn handle_spawn_worker<R: BloxRuntime>(
             &ctx.notify_ref,
             ctx.self_id(),
         );
-        if result.is_err() {
-            bloxide_log::blox_log_warn!(
-                ctx.self_id(),
-                "spawn failed (supervisor control mailbox full), dropping task_id={}",
-                task_id
-            );
-            ctx.spawn_in_flight = false;
+        match result {
+            Ok(()) => {}
+            Err(SpawnChildError::Spawn(err)) => {
+                bloxide_log::blox_log_warn!(
+                    ctx.self_id(),
+                    "spawn failed ({}), dropping task_id={}",
+                    err,
+                    task_id
+                );
+                ctx.spawn_in_flight = false;
+            }
+            Err(SpawnChildError::Register(_)) => {
+                bloxide_log::blox_log_warn!(
+                    ctx.self_id(),
+                    "spawn failed (supervisor control mailbox full), dropping task_id={}",
+                    task_id
+                );
+                ctx.spawn_in_flight = false;
+            }
         }
     }
     ActionResult::Ok
@@ -154,13 +166,25 @@ pub fn handle_spawned_worker<R: BloxRuntime>(
                 &ctx.notify_ref,
                 ctx.self_id(),
             );
-            if result.is_err() {
-                bloxide_log::blox_log_warn!(
-                    ctx.self_id(),
-                    "spawn failed (supervisor control mailbox full), dropping queued task_id={}",
-                    next_task_id
-                );
-                ctx.spawn_in_flight = false;
+            match result {
+                Ok(()) => {}
+                Err(SpawnChildError::Spawn(err)) => {
+                    bloxide_log::blox_log_warn!(
+                        ctx.self_id(),
+                        "spawn failed ({}), dropping queued task_id={}",
+                        err,
+                        next_task_id
+                    );
+                    ctx.spawn_in_flight = false;
+                }
+                Err(SpawnChildError::Register(_)) => {
+                    bloxide_log::blox_log_warn!(
+                        ctx.self_id(),
+                        "spawn failed (supervisor control mailbox full), dropping queued task_id={}",
+                        next_task_id
+                    );
+                    ctx.spawn_in_flight = false;
+                }
             }
         }
     }
@@ -19,7 +19,7 @@ mod pool_tests {
         Envelope, MachineState, StateMachine,
     };
     use bloxide_peers::PeerCtrl;
-    use bloxide_spawn::{SpawnFn, SpawnOutput};
+    use bloxide_spawn::{SpawnError, SpawnFn, SpawnOutput};
     use bloxide_supervisor::SupervisorControl;
     use bloxide_test_runtime::TestRuntime;
     use pool_messages::{PoolMsg, SpawnRequest, SpawnWorker, SpawnedWorker, WorkDone, WorkerMsg};
@@ -30,7 +30,7 @@ mod pool_tests {
 
     struct PoolHarness {
         machine: StateMachine<PoolSpec<TestRuntime>>,
-        _control_rx: <TestRuntime as BloxRuntime>::Receiver<SupervisorControl<TestRuntime>>,
+        control_rx: <TestRuntime as BloxRuntime>::Receiver<SupervisorControl<TestRuntime>>,
         _spawn_reply_ref: ActorRef<SpawnedWorker<TestRuntime>, TestRuntime>,
     }
 
@@ -43,7 +43,7 @@ mod pool_tests {
     fn test_spawn_worker(
         req: SpawnRequest<TestRuntime>,
         _notify: ActorRef<ChildLifecycleEvent, TestRuntime>,
-    ) -> SpawnOutput<TestRuntime> {
+    ) -> Result<SpawnOutput<TestRuntime>, SpawnError> {
         match req {
             SpawnRequest::Worker {
                 task_id: _,
@@ -71,19 +71,31 @@ mod pool_tests {
                     },
                 );
 
-                SpawnOutput {
+                Ok(SpawnOutput {
                     child_id: worker_id,
                     lifecycle_ref,
                     abort_ref,
                     kill_handle: (),
                     policy: ChildPolicy::Stop,
-                }
+                })
             }
         }
     }
 
+    /// Spawn function for a runtime whose task slots are all in use.
+    fn exhausted_spawn_worker(
+        _req: SpawnRequest<TestRuntime>,
+        _notify: ActorRef<ChildLifecycleEvent, TestRuntime>,
+    ) -> Result<SpawnOutput<TestRuntime>, SpawnError> {
+        Err(SpawnError::NoTaskSlot)
+    }
+
     impl PoolHarness {
         fn new() -> Self {
+            Self::with_spawn_fn(test_spawn_worker)
+        }
+
+        fn with_spawn_fn(spawn_fn: SpawnFn<TestRuntime, SpawnRequest<TestRuntime>>) -> Self {
             let pool_id = TestRuntime::alloc_actor_id();
             let (pool_ref, _pool_rx) =
                 <TestRuntime as DynamicChannelCap>::channel::<PoolMsg>(pool_id, 32);
@@ -102,7 +114,6 @@ mod pool_tests {
                 SpawnedWorker<TestRuntime>,
             >(reply_id, 16);
 
-            let spawn_fn: SpawnFn<TestRuntime, SpawnRequest<TestRuntime>> = test_spawn_worker;
             let ctx = PoolCtx::new(
                 pool_id,
                 pool_ref.clone(),
@@ -115,7 +126,7 @@ mod pool_tests {
 
             PoolHarness {
                 machine,
-                _control_rx: control_rx,
+                control_rx,
                 _spawn_reply_ref: spawn_reply_ref.clone(),
             }
         }
@@ -358,6 +369,22 @@ mod pool_tests {
         );
     }
 
+    #[test]
+    fn exhausted_spawn_clears_in_flight_and_sends_nothing() {
+        let mut h = PoolHarness::with_spawn_fn(exhausted_spawn_worker);
+        h.start();
+        h.dispatch_spawn_worker(7);
+
+        assert!(
+            !h.machine.ctx().spawn_in_flight,
+            "a spawn that never started must not wait for a reply"
+        );
+        assert!(
+            h.control_rx.drain_payloads().is_empty(),
+            "nothing should be registered with the supervisor"
+        );
+    }
+
     // ── Spawn queue tests ────────────────────────────────────────────────────
 
     #[test]
@@ -2,7 +2,6 @@
 // Auto-generated by bloxide-codegen. Do not edit manually.
 use ::bloxide_core::{capability::BloxRuntime, messaging::ActorRef};
 use ::bloxide_macros::BloxCtx;
-use alloc::vec::Vec;
 #[allow(unused_imports)]
 use blox_ctx_current_task::{__delegate_HasCurrentTask, HasCurrentTask};
 #[allow(unused_imports)]
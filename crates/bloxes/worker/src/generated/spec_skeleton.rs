@@ -90,6 +90,6 @@ impl<R: BloxRuntime, B: HasPeers<WorkerMsg, R> + HasCurrentTask + 'static> Machi
     fn on_init_entry(ctx: &mut Self::Ctx) {
         ctx.set_task_id(0);
         ctx.set_result(0);
-        ctx.peers_mut().clear();
+        bloxide_peers::PeerSet::clear_peers(ctx.peers_mut());
     }
 }
@@ -5,16 +5,12 @@
 
 #[cfg(all(test, feature = "std"))]
 mod worker_tests {
-    extern crate alloc;
-    use alloc::vec::Vec;
-
     use blox_ctx_current_task::HasCurrentTask;
     use bloxide_core::lifecycle::LifecycleCommand;
     use bloxide_core::{
-        capability::DynamicChannelCap, messaging::ActorRef, spec::MachineSpec, Envelope,
-        MachineState, StateMachine,
+        capability::DynamicChannelCap, spec::MachineSpec, Envelope, MachineState, StateMachine,
     };
-    use bloxide_peers::{AddPeer, HasPeers, PeerCtrl};
+    use bloxide_peers::{ActorGroup, AddPeer, HasPeers, PeerCtrl};
     use bloxide_test_runtime::{TestReceiver, TestRuntime};
     use pool_messages::{DoWork, PeerResult, PoolMsg, WorkDone, WorkerMsg};
 
@@ -24,9 +20,11 @@ mod worker_tests {
     struct TestBehavior {
         task_id: u32,
         result: u32,
-        peers: Vec<ActorRef<WorkerMsg, TestRuntime>>,
+        peers: ActorGroup<WorkerMsg, TestRuntime, MAX_PEERS>,
     }
 
+    const MAX_PEERS: usize = 2;
+
     impl HasCurrentTask for TestBehavior {
         fn task_id(&self) -> u32 {
             self.task_id
@@ -43,10 +41,11 @@ mod worker_tests {
     }
 
     impl HasPeers<WorkerMsg, TestRuntime> for TestBehavior {
-        fn peers(&self) -> &[ActorRef<WorkerMsg, TestRuntime>] {
+        type Peers = ActorGroup<WorkerMsg, TestRuntime, MAX_PEERS>;
+        fn peers(&self) -> &Self::Peers {
             &self.peers
         }
-        fn peers_mut(&mut self) -> &mut Vec<ActorRef<WorkerMsg, TestRuntime>> {
+        fn peers_mut(&mut self) -> &mut Self::Peers {
             &mut self.peers
         }
     }
@@ -160,6 +159,22 @@ mod worker_tests {
         assert_eq!(h.peer_count(), 1);
     }
 
+    #[test]
+    fn add_peer_beyond_capacity_is_ignored() {
+        let mut h = WorkerHarness::new();
+        h.start();
+
+        for _ in 0..MAX_PEERS + 1 {
+            let (peer_ref, _peer_rx) = <TestRuntime as DynamicChannelCap>::channel::<WorkerMsg>(
+                TestRuntime::alloc_actor_id(),
+                16,
+            );
+            h.dispatch_add_peer(peer_ref);
+        }
+        assert_eq!(h.peer_count(), MAX_PEERS);
+        assert_eq!(h.current_state(), MachineState::State(WorkerState::Waiting));
+    }
+
     #[test]
     fn broadcast_sends_peer_result_to_all_peers() {
         let mut h = WorkerHarness::new();
@@ -0,0 +1,101 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Consistent Overhead Byte Stuffing.
+//!
+//! Encoded data never contains `0x00`, so a single zero byte delimits frames
+//! on the wire and a receiver can resynchronise after line noise by waiting
+//! for the next delimiter.
+
+use alloc::vec::Vec;
+
+/// Append the COBS encoding of `data` to `out` (without the trailing
+/// delimiter).
+pub fn encode(data: &[u8], out: &mut Vec<u8>) {
+    let mut code_index = out.len();
+    out.push(0);
+    let mut code: u8 = 1;
+    for &byte in data {
+        if byte == 0 {
+            out[code_index] = code;
+            code_index = out.len();
+            out.push(0);
+            code = 1;
+        } else {
+            out.push(byte);
+            code += 1;
+            if code == 0xff {
+                out[code_index] = code;
+                code_index = out.len();
+                out.push(0);
+                code = 1;
+            }
+        }
+    }
+    out[code_index] = code;
+}
+
+/// Decode one COBS block (without the delimiter). Returns `None` if the
+/// input is not valid COBS.
+pub fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut i = 0;
+    while i < data.len() {
+        let code = data[i] as usize;
+        if code == 0 {
+            return None;
+        }
+        let end = i + code;
+        if end > data.len() {
+            return None;
+        }
+        out.extend_from_slice(&data[i + 1..end]);
+        i = end;
+        if code != 0xff && i < data.len() {
+            out.push(0);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn round_trip(data: &[u8]) -> Vec<u8> {
+        let mut enc = Vec::new();
+        encode(data, &mut enc);
+        assert!(!enc.contains(&0), "encoded form must not contain zero");
+        decode(&enc).expect("valid cobs")
+    }
+
+    #[test]
+    fn known_vectors() {
+        let mut enc = Vec::new();
+        encode(&[0x11, 0x22, 0x00, 0x33], &mut enc);
+        assert_eq!(enc, vec![0x03, 0x11, 0x22, 0x02, 0x33]);
+
+        enc.clear();
+        encode(&[0x00], &mut enc);
+        assert_eq!(enc, vec![0x01, 0x01]);
+
+        enc.clear();
+        encode(&[], &mut enc);
+        assert_eq!(enc, vec![0x01]);
+    }
+
+    #[test]
+    fn round_trips() {
+        assert_eq!(round_trip(&[]), Vec::<u8>::new());
+        assert_eq!(round_trip(&[0, 0, 0]), vec![0, 0, 0]);
+        let long: Vec<u8> = (0..600u32).map(|i| (i % 255) as u8 + 1).collect();
+        assert_eq!(round_trip(&long), long);
+        let mixed: Vec<u8> = (0..600u32).map(|i| (i % 7) as u8).collect();
+        assert_eq!(round_trip(&mixed), mixed);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(decode(&[0x05, 0x11]), None);
+        assert_eq!(decode(&[0x00]), None);
+    }
+}
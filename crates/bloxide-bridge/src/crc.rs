@@ -0,0 +1,29 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! CRC-16/CCITT-FALSE (poly 0x1021, init 0xFFFF, no reflection).
+
+pub fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc: u16 = 0xffff;
+    for &byte in bytes {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        // Standard check input for CRC-16/CCITT-FALSE.
+        assert_eq!(crc16(b"123456789"), 0x29b1);
+        assert_eq!(crc16(&[]), 0xffff);
+    }
+}
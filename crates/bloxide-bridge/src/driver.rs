@@ -0,0 +1,402 @@
+// Copyright 2025 Bloxide, all rights reserved
+use alloc::vec::Vec;
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use bloxide_core::{
+    capability::BloxRuntime,
+    messaging::{ActorId, ActorRef, Envelope},
+};
+use bloxide_log::blox_log_warn;
+use futures_core::Stream;
+
+use crate::link::{Link, SendError};
+use crate::{BridgeClock, BridgeConfig, ByteReader, ByteWriter, FrameCodec, LinkDown, LinkEvent};
+
+/// Bytes requested from the reader per poll.
+const READ_CHUNK: usize = 64;
+
+/// Rounds of work done in one poll before yielding, so a chatty peer cannot
+/// starve the rest of the executor.
+const MAX_ROUNDS: usize = 16;
+
+/// Why [`Bridge::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeExit {
+    /// The reader reported end of stream.
+    ReaderClosed,
+    ReadError,
+    WriteError,
+    /// Every sender of the outbound stream was dropped.
+    OutboundClosed,
+}
+
+impl BridgeExit {
+    fn down_reason(self) -> LinkDown {
+        match self {
+            BridgeExit::ReaderClosed | BridgeExit::OutboundClosed => LinkDown::Closed,
+            BridgeExit::ReadError => LinkDown::ReadError,
+            BridgeExit::WriteError => LinkDown::WriteError,
+        }
+    }
+}
+
+/// Drives a [`Link`] over a byte transport. See the [crate docs](crate).
+pub struct Bridge<Rd, Wr, Clk> {
+    reader: Rd,
+    writer: Wr,
+    clock: Clk,
+    link: Link,
+    writing: Option<Vec<u8>>,
+    written: usize,
+}
+
+impl<Rd, Wr, Clk> Bridge<Rd, Wr, Clk>
+where
+    Rd: ByteReader,
+    Wr: ByteWriter,
+    Clk: BridgeClock,
+{
+    pub fn new(reader: Rd, writer: Wr, clock: Clk, config: BridgeConfig) -> Self {
+        Self {
+            reader,
+            writer,
+            clock,
+            link: Link::new(config),
+            writing: None,
+            written: 0,
+        }
+    }
+
+    /// Run the bridge until the transport or the outbound stream closes.
+    ///
+    /// Decoded inbound messages are delivered to `target` with `try_send`;
+    /// when its mailbox is full the frame is left unacknowledged and the peer
+    /// retransmits it later. Messages from `outbound` are sent one at a time,
+    /// each waiting for its ack. Link state changes go to `events`, including
+    /// a final `Down` when the bridge exits while the link was up.
+    ///
+    /// Messages that fail to encode or decode are logged and dropped.
+    pub async fn run<R, In, Out, C>(
+        mut self,
+        self_id: ActorId,
+        target: ActorRef<In, R>,
+        mut outbound: R::Stream<Out>,
+        events: ActorRef<LinkEvent, R>,
+        codec: C,
+    ) -> BridgeExit
+    where
+        R: BloxRuntime,
+        In: Send + 'static,
+        Out: Send + 'static,
+        C: FrameCodec<In> + FrameCodec<Out>,
+    {
+        let mut read_buf = [0u8; READ_CHUNK];
+        let exit = poll_fn(|cx| {
+            for _ in 0..MAX_ROUNDS {
+                let round =
+                    self.poll_round(cx, self_id, &mut read_buf, &target, &mut outbound, &codec);
+                self.flush_events(self_id, &events);
+                match round {
+                    Err(exit) => return Poll::Ready(exit),
+                    Ok(false) => return Poll::Pending,
+                    Ok(true) => {}
+                }
+            }
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+        .await;
+
+        self.link.mark_down(exit.down_reason());
+        self.flush_events(self_id, &events);
+        exit
+    }
+
+    /// One pass over every source. Returns whether anything happened; when
+    /// nothing did, every source that can wake the task has registered `cx`.
+    fn poll_round<R, In, Out, C>(
+        &mut self,
+        cx: &mut Context<'_>,
+        self_id: ActorId,
+        read_buf: &mut [u8],
+        target: &ActorRef<In, R>,
+        outbound: &mut R::Stream<Out>,
+        codec: &C,
+    ) -> Result<bool, BridgeExit>
+    where
+        R: BloxRuntime,
+        In: Send + 'static,
+        Out: Send + 'static,
+        C: FrameCodec<In> + FrameCodec<Out>,
+    {
+        let mut progress = self.poll_write(cx)?;
+
+        match self.reader.poll_read(cx, read_buf) {
+            Poll::Ready(Ok(0)) => return Err(BridgeExit::ReaderClosed),
+            Poll::Ready(Ok(n)) => {
+                progress = true;
+                self.link.receive(&read_buf[..n], |payload| {
+                    match FrameCodec::<In>::decode(codec, payload) {
+                        Ok(msg) => target.try_send(self_id, msg).is_ok(),
+                        Err(e) => {
+                            blox_log_warn!(self_id, "bridge: dropping inbound frame: {}", e);
+                            true
+                        }
+                    }
+                });
+            }
+            Poll::Ready(Err(e)) => {
+                blox_log_warn!(self_id, "bridge: read failed: {:?}", e);
+                return Err(BridgeExit::ReadError);
+            }
+            Poll::Pending => {}
+        }
+
+        if self.link.can_send() {
+            match Pin::new(&mut *outbound).poll_next(cx) {
+                Poll::Ready(Some(Envelope(_, msg))) => {
+                    progress = true;
+                    self.send(self_id, &msg, codec);
+                }
+                Poll::Ready(None) => return Err(BridgeExit::OutboundClosed),
+                Poll::Pending => {}
+            }
+        }
+
+        if let Some(deadline) = self.link.next_deadline() {
+            if self.clock.poll_sleep_until(cx, deadline).is_ready() {
+                progress = true;
+                self.link.handle_timeout(self.clock.now_ms());
+            }
+        }
+
+        Ok(progress)
+    }
+
+    fn send<Out, C: FrameCodec<Out>>(&mut self, self_id: ActorId, msg: &Out, codec: &C) {
+        let mut payload = Vec::new();
+        if let Err(e) = codec.encode(msg, &mut payload) {
+            blox_log_warn!(self_id, "bridge: dropping outbound message: {}", e);
+            return;
+        }
+        match self.link.send(&payload, self.clock.now_ms()) {
+            Ok(()) => {}
+            Err(SendError::TooLarge) => {
+                blox_log_warn!(
+                    self_id,
+                    "bridge: dropping outbound message of {} bytes",
+                    payload.len()
+                );
+            }
+            Err(SendError::Busy) => unreachable!("outbound is only polled when the link can send"),
+        }
+    }
+
+    /// Write queued frames until the writer stalls or the queue is empty.
+    fn poll_write(&mut self, cx: &mut Context<'_>) -> Result<bool, BridgeExit> {
+        let mut progress = false;
+        loop {
+            if self.writing.is_none() {
+                self.writing = self.link.pop_transmit();
+                self.written = 0;
+            }
+            let Some(frame) = self.writing.as_deref() else {
+                return Ok(progress);
+            };
+            match self.writer.poll_write(cx, &frame[self.written..]) {
+                Poll::Ready(Ok(0)) => return Err(BridgeExit::WriteError),
+                Poll::Ready(Ok(n)) => {
+                    progress = true;
+                    self.written += n;
+                    if self.written == frame.len() {
+                        self.writing = None;
+                    }
+                }
+                Poll::Ready(Err(_)) => return Err(BridgeExit::WriteError),
+                Poll::Pending => return Ok(progress),
+            }
+        }
+    }
+
+    fn flush_events<R: BloxRuntime>(&mut self, self_id: ActorId, events: &ActorRef<LinkEvent, R>) {
+        while let Some(event) = self.link.pop_event() {
+            if events.try_send(self_id, event).is_err() {
+                blox_log_warn!(self_id, "bridge: event mailbox full, dropped {:?}", event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{pipe, Line, ManualClock, PipeReader, PipeWriter};
+    use crate::BytesCodec;
+    use bloxide_core::capability::DynamicChannelCap;
+    use bloxide_test_runtime::{TestReceiver, TestRuntime};
+    use core::future::Future;
+    use core::task::Waker;
+    use std::boxed::Box;
+    use std::vec;
+
+    const CONFIG: BridgeConfig = BridgeConfig {
+        ack_timeout_ms: 100,
+        max_retries: 2,
+        max_payload: 64,
+    };
+
+    type Run = Pin<Box<dyn Future<Output = BridgeExit>>>;
+
+    /// The actor-facing side of one bridge, plus its running future.
+    struct End {
+        inbox: TestReceiver<Vec<u8>>,
+        events: TestReceiver<LinkEvent>,
+        outbound: ActorRef<Vec<u8>, TestRuntime>,
+        line: Line,
+        run: Option<Run>,
+        exit: Option<BridgeExit>,
+    }
+
+    impl End {
+        fn new(reader: PipeReader, writer: PipeWriter, clock: ManualClock) -> Self {
+            let id = TestRuntime::alloc_actor_id();
+            let (target, inbox) = TestRuntime::channel::<Vec<u8>>(id, 8);
+            let (events_ref, events) = TestRuntime::channel::<LinkEvent>(id, 8);
+            let (outbound, outbound_rx) = TestRuntime::channel::<Vec<u8>>(id, 8);
+            let line = writer.line();
+            let run = Bridge::new(reader, writer, clock, CONFIG).run(
+                id,
+                target,
+                TestRuntime::to_stream(outbound_rx),
+                events_ref,
+                BytesCodec,
+            );
+            Self {
+                inbox,
+                events,
+                outbound,
+                line,
+                run: Some(Box::pin(run)),
+                exit: None,
+            }
+        }
+
+        fn poll(&mut self) {
+            let Some(run) = self.run.as_mut() else {
+                return;
+            };
+            let mut cx = Context::from_waker(Waker::noop());
+            if let Poll::Ready(exit) = run.as_mut().poll(&mut cx) {
+                self.exit = Some(exit);
+                self.run = None;
+            }
+        }
+
+        fn send(&self, payload: &[u8]) {
+            self.outbound.try_send(0, payload.to_vec()).unwrap();
+        }
+    }
+
+    fn link_pair() -> (End, End, ManualClock) {
+        let clock = ManualClock::new();
+        let (a_tx, b_rx) = pipe();
+        let (b_tx, a_rx) = pipe();
+        let a = End::new(a_rx, a_tx, clock.clone());
+        let b = End::new(b_rx, b_tx, clock.clone());
+        (a, b, clock)
+    }
+
+    fn settle(a: &mut End, b: &mut End) {
+        for _ in 0..8 {
+            a.poll();
+            b.poll();
+        }
+    }
+
+    #[test]
+    fn message_crosses_link_and_raises_up() {
+        let (mut a, mut b, _clock) = link_pair();
+        a.send(b"ping");
+        settle(&mut a, &mut b);
+
+        assert_eq!(b.inbox.drain_payloads(), vec![b"ping".to_vec()]);
+        assert_eq!(a.events.drain_payloads(), vec![LinkEvent::Up]);
+        assert_eq!(b.events.drain_payloads(), vec![LinkEvent::Up]);
+
+        b.send(b"pong");
+        settle(&mut a, &mut b);
+        assert_eq!(a.inbox.drain_payloads(), vec![b"pong".to_vec()]);
+    }
+
+    #[test]
+    fn outbound_messages_are_sent_in_order() {
+        let (mut a, mut b, _clock) = link_pair();
+        for n in 0..5u8 {
+            a.send(&[n]);
+        }
+        settle(&mut a, &mut b);
+        let got: Vec<Vec<u8>> = b.inbox.drain_payloads();
+        assert_eq!(got, (0..5u8).map(|n| vec![n]).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn lost_frame_is_retransmitted_after_timeout() {
+        let (mut a, mut b, clock) = link_pair();
+        a.line.set_lossy(true);
+        a.send(b"late");
+        settle(&mut a, &mut b);
+        assert!(b.inbox.drain_payloads().is_empty());
+
+        a.line.set_lossy(false);
+        clock.advance(100);
+        settle(&mut a, &mut b);
+        assert_eq!(b.inbox.drain_payloads(), vec![b"late".to_vec()]);
+    }
+
+    #[test]
+    fn line_noise_is_ignored() {
+        let (mut a, mut b, _clock) = link_pair();
+        a.line.inject(&[0x55, 0xaa, 0x00, 0x03, 0x01, 0x00]);
+        a.send(b"clean");
+        settle(&mut a, &mut b);
+        assert_eq!(b.inbox.drain_payloads(), vec![b"clean".to_vec()]);
+    }
+
+    #[test]
+    fn missing_acks_report_link_down() {
+        let (mut a, mut b, clock) = link_pair();
+        a.send(b"1");
+        settle(&mut a, &mut b);
+        assert_eq!(a.events.drain_payloads(), vec![LinkEvent::Up]);
+
+        b.line.set_lossy(true);
+        a.send(b"2");
+        for _ in 0..3 {
+            settle(&mut a, &mut b);
+            clock.advance(100);
+        }
+        settle(&mut a, &mut b);
+        assert_eq!(
+            a.events.drain_payloads(),
+            vec![LinkEvent::Down(LinkDown::NoAck)]
+        );
+    }
+
+    #[test]
+    fn peer_closing_ends_the_bridge() {
+        let (mut a, mut b, _clock) = link_pair();
+        a.send(b"hi");
+        settle(&mut a, &mut b);
+        a.events.drain_payloads();
+
+        b.run = None; // drops b's writer, closing a's reader
+        a.poll();
+        assert_eq!(a.exit, Some(BridgeExit::ReaderClosed));
+        assert_eq!(
+            a.events.drain_payloads(),
+            vec![LinkEvent::Down(LinkDown::Closed)]
+        );
+    }
+}
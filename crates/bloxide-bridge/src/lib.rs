@@ -0,0 +1,204 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Framed byte-stream bridge for talking to bloxes over a UART-style link.
+//!
+//! A [`Bridge`] owns the two halves of a raw byte transport (a serial port,
+//! a USB CDC endpoint, an in-memory pipe in tests) and turns it into typed
+//! mailbox traffic:
+//!
+//! - inbound frames are checked, decoded with a [`FrameCodec`] and delivered
+//!   to a target `ActorRef`;
+//! - messages read from an outbound stream are encoded and written as frames;
+//! - every DATA frame is acknowledged and retransmitted on timeout, and a
+//!   [`LinkEvent`] is raised when the link comes up or goes down.
+//!
+//! Frames are COBS-encoded with a CRC-16 trailer and a `0x00` delimiter; see
+//! [`link`] for the wire format and the sans-IO protocol state machine.
+//!
+//! The transport and the clock are small poll-based traits
+//! ([`ByteReader`], [`ByteWriter`], [`BridgeClock`]) so the bridge stays
+//! runtime-agnostic and `no_std`; it only needs `alloc`. With the `std`
+//! feature, the `testing` module provides an in-memory pipe and a manual clock.
+//!
+//! ```ignore
+//! // Doc test ignored: needs a UART driver
+//! let bridge = Bridge::new(uart_rx, uart_tx, clock, BridgeConfig::default());
+//! let exit = bridge
+//!     .run(bridge_id, ping_ref, outbound_stream, link_events_ref, PostcardCodec::new())
+//!     .await;
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod cobs;
+mod crc;
+mod driver;
+pub mod link;
+#[cfg(any(test, feature = "std"))]
+pub mod testing;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::task::{Context, Poll};
+
+pub use driver::{Bridge, BridgeExit};
+pub use link::{Link, SendError};
+
+// ── Configuration and events ──────────────────────────────────────────────────
+
+/// Link protocol parameters. Both ends should agree on `max_payload`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeConfig {
+    /// How long to wait for an ack before retransmitting.
+    pub ack_timeout_ms: u32,
+    /// Retransmits before a frame is dropped and the link reported down.
+    pub max_retries: u8,
+    /// Largest encoded message accepted in either direction.
+    pub max_payload: usize,
+}
+
+impl Default for BridgeConfig {
+    fn default() -> Self {
+        Self {
+            ack_timeout_ms: 200,
+            max_retries: 3,
+            max_payload: 256,
+        }
+    }
+}
+
+/// Link state change, delivered to the bridge's event `ActorRef`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkEvent {
+    /// The first valid frame arrived from the peer (or the first since the
+    /// link last went down).
+    Up,
+    Down(LinkDown),
+}
+
+/// Why the link went down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkDown {
+    /// A frame exhausted its retransmits without an ack.
+    NoAck,
+    ReadError,
+    WriteError,
+    /// The transport or the outbound stream closed.
+    Closed,
+}
+
+// ── Transport traits ──────────────────────────────────────────────────────────
+
+/// Receive half of a byte transport.
+pub trait ByteReader {
+    type Error: fmt::Debug;
+
+    /// Read available bytes into `buf`. `Ok(0)` means the transport closed.
+    fn poll_read(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, Self::Error>>;
+}
+
+/// Send half of a byte transport.
+pub trait ByteWriter {
+    type Error: fmt::Debug;
+
+    /// Write a prefix of `buf`, returning how many bytes were accepted.
+    /// `Ok(0)` for a non-empty `buf` means the transport closed.
+    fn poll_write(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize, Self::Error>>;
+}
+
+/// Millisecond time source used for ack timeouts.
+pub trait BridgeClock {
+    fn now_ms(&self) -> u64;
+
+    /// Resolve once `now_ms() >= deadline_ms`, registering `cx`'s waker
+    /// otherwise.
+    fn poll_sleep_until(&mut self, cx: &mut Context<'_>, deadline_ms: u64) -> Poll<()>;
+}
+
+// ── Codecs ────────────────────────────────────────────────────────────────────
+
+/// Converts messages of type `M` to and from frame payloads.
+pub trait FrameCodec<M> {
+    /// Append the encoded form of `msg` to `buf`.
+    fn encode(&self, msg: &M, buf: &mut Vec<u8>) -> Result<(), CodecError>;
+
+    /// Decode one message from a complete frame payload.
+    fn decode(&self, bytes: &[u8]) -> Result<M, CodecError>;
+}
+
+/// A message could not be encoded or decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodecError;
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "frame codec error")
+    }
+}
+
+/// Pass-through codec for raw `Vec<u8>` messages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BytesCodec;
+
+impl FrameCodec<Vec<u8>> for BytesCodec {
+    fn encode(&self, msg: &Vec<u8>, buf: &mut Vec<u8>) -> Result<(), CodecError> {
+        buf.extend_from_slice(msg);
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>, CodecError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// `postcard` codec for any serde message, including enums generated with
+/// `serialize = "serde"`. One value decodes both directions of a link.
+#[cfg(feature = "postcard")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostcardCodec;
+
+#[cfg(feature = "postcard")]
+impl PostcardCodec {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<M> FrameCodec<M> for PostcardCodec
+where
+    M: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn encode(&self, msg: &M, buf: &mut Vec<u8>) -> Result<(), CodecError> {
+        let bytes = bloxide_messaging::wire::to_vec(msg).map_err(|_| CodecError)?;
+        buf.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<M, CodecError> {
+        bloxide_messaging::wire::from_bytes(bytes).map_err(|_| CodecError)
+    }
+}
+
+#[cfg(all(test, feature = "postcard"))]
+mod tests {
+    use super::*;
+    use ping_pong_messages::{Ping, PingPongMsg};
+
+    #[test]
+    fn postcard_codec_round_trips_generated_enum() {
+        let msg = PingPongMsg::Ping(Ping { round: 7 });
+        let mut buf = Vec::new();
+        PostcardCodec.encode(&msg, &mut buf).unwrap();
+        let back: PingPongMsg = PostcardCodec.decode(&buf).unwrap();
+        assert!(matches!(back, PingPongMsg::Ping(Ping { round: 7 })));
+        assert!(FrameCodec::<PingPongMsg>::decode(&PostcardCodec, &[0xff]).is_err());
+    }
+}
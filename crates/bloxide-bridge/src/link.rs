@@ -0,0 +1,455 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Sans-IO link protocol: framing, acknowledgement and retransmit.
+//!
+//! A frame on the wire is `COBS(kind | seq | payload | crc16) 0x00`, where
+//! `crc16` (big-endian) covers `kind | seq | payload`. `kind` is
+//! [`KIND_DATA`], [`KIND_ACK`] or [`KIND_RESET`].
+//!
+//! Each end opens a session with an empty RESET frame, sent when the link is
+//! created and again whenever it goes down. The receiver forgets which
+//! sequence number it delivered last, so a peer that rebooted and counts
+//! from 0 again is not mistaken for a duplicate. RESET is acked and
+//! retransmitted like DATA, and payloads wait until it is acked; a repeated
+//! RESET is harmless since nothing is delivered in between.
+//!
+//! Delivery is stop-and-wait: at most one frame is in flight. The
+//! receiver acknowledges every valid DATA frame it accepts (including
+//! duplicates, whose ack may have been lost) and delivers a payload only when
+//! its sequence number differs from the previously delivered one. A frame the
+//! consumer refuses (e.g. its mailbox is full) is not acked, so the sender's
+//! retransmit doubles as backpressure. The sender retransmits
+//! on `ack_timeout_ms` up to `max_retries` times, then drops the frame and
+//! reports the link down.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::{cobs, crc::crc16, BridgeConfig, LinkDown, LinkEvent};
+
+pub const KIND_DATA: u8 = 0;
+pub const KIND_ACK: u8 = 1;
+pub const KIND_RESET: u8 = 2;
+
+/// Bytes of framing around a payload before COBS: kind, seq and CRC.
+const OVERHEAD: usize = 4;
+
+/// Why [`Link::send`] refused a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendError {
+    /// A frame, or the session's RESET, is still awaiting its ack.
+    Busy,
+    /// The payload exceeds `BridgeConfig::max_payload`.
+    TooLarge,
+}
+
+struct InFlight {
+    seq: u8,
+    wire: Vec<u8>,
+    /// `None` until the first `handle_timeout` arms it, for a RESET queued
+    /// without a clock reading.
+    deadline_ms: Option<u64>,
+    retries: u8,
+    reset: bool,
+}
+
+/// Protocol state for one end of a link. Feed it bytes and time; drain the
+/// frames it wants written and the events it raised.
+pub struct Link {
+    config: BridgeConfig,
+    next_seq: u8,
+    in_flight: Option<InFlight>,
+    last_delivered: Option<u8>,
+    up: bool,
+    rx: Vec<u8>,
+    rx_overflow: bool,
+    tx: VecDeque<Vec<u8>>,
+    events: VecDeque<LinkEvent>,
+}
+
+impl Link {
+    /// Create a link; its RESET frame is queued at once.
+    pub fn new(config: BridgeConfig) -> Self {
+        let mut link = Self {
+            config,
+            next_seq: 0,
+            in_flight: None,
+            last_delivered: None,
+            up: false,
+            rx: Vec::new(),
+            rx_overflow: false,
+            tx: VecDeque::new(),
+            events: VecDeque::new(),
+        };
+        link.begin_session();
+        link
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.up
+    }
+
+    /// Whether [`send`](Self::send) would accept a payload now.
+    pub fn can_send(&self) -> bool {
+        self.in_flight.is_none()
+    }
+
+    /// Queue `payload` as the next DATA frame.
+    pub fn send(&mut self, payload: &[u8], now_ms: u64) -> Result<(), SendError> {
+        if self.in_flight.is_some() {
+            return Err(SendError::Busy);
+        }
+        if payload.len() > self.config.max_payload {
+            return Err(SendError::TooLarge);
+        }
+        let seq = self.next_seq;
+        let wire = encode_frame(KIND_DATA, seq, payload);
+        self.tx.push_back(wire.clone());
+        self.in_flight = Some(InFlight {
+            seq,
+            wire,
+            deadline_ms: Some(now_ms + u64::from(self.config.ack_timeout_ms)),
+            retries: 0,
+            reset: false,
+        });
+        Ok(())
+    }
+
+    /// Consume bytes read from the wire, calling `deliver` with the payload
+    /// of every new DATA frame. `deliver` returns `false` to refuse the
+    /// payload; it is then neither acked nor marked delivered.
+    pub fn receive(&mut self, bytes: &[u8], mut deliver: impl FnMut(&[u8]) -> bool) {
+        let max_encoded = max_encoded_len(self.config.max_payload);
+        for &byte in bytes {
+            if byte == 0 {
+                if !self.rx.is_empty() && !self.rx_overflow {
+                    let block = core::mem::take(&mut self.rx);
+                    self.handle_block(&block, &mut deliver);
+                    self.rx = block;
+                }
+                self.rx.clear();
+                self.rx_overflow = false;
+            } else if self.rx.len() < max_encoded {
+                self.rx.push(byte);
+            } else {
+                self.rx_overflow = true;
+            }
+        }
+    }
+
+    /// Retransmit or give up on the in-flight frame if its deadline passed.
+    pub fn handle_timeout(&mut self, now_ms: u64) {
+        let Some(in_flight) = self.in_flight.as_mut() else {
+            return;
+        };
+        let next_deadline_ms = now_ms + u64::from(self.config.ack_timeout_ms);
+        let Some(deadline_ms) = in_flight.deadline_ms else {
+            in_flight.deadline_ms = Some(next_deadline_ms);
+            return;
+        };
+        if now_ms < deadline_ms {
+            return;
+        }
+        if in_flight.retries < self.config.max_retries {
+            in_flight.retries += 1;
+            in_flight.deadline_ms = Some(next_deadline_ms);
+            self.tx.push_back(in_flight.wire.clone());
+        } else {
+            self.in_flight = None;
+            self.next_seq = self.next_seq.wrapping_add(1);
+            self.mark_down(LinkDown::NoAck);
+        }
+    }
+
+    /// Earliest time [`handle_timeout`](Self::handle_timeout) has work to do.
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.in_flight.as_ref().map(|f| f.deadline_ms.unwrap_or(0))
+    }
+
+    /// Next encoded frame (delimiter included) to write to the wire.
+    pub fn pop_transmit(&mut self) -> Option<Vec<u8>> {
+        self.tx.pop_front()
+    }
+
+    /// Next link state change.
+    pub fn pop_event(&mut self) -> Option<LinkEvent> {
+        self.events.pop_front()
+    }
+
+    /// Record that the transport itself failed or closed. Unless one is
+    /// already in flight, a RESET replaces any frame awaiting its ack.
+    pub fn mark_down(&mut self, reason: LinkDown) {
+        if self.up {
+            self.up = false;
+            self.events.push_back(LinkEvent::Down(reason));
+        }
+        if !self.in_flight.as_ref().is_some_and(|f| f.reset) {
+            self.begin_session();
+        }
+    }
+
+    /// Queue a RESET, armed by the next `handle_timeout`.
+    fn begin_session(&mut self) {
+        let seq = self.next_seq;
+        let wire = encode_frame(KIND_RESET, seq, &[]);
+        self.tx.push_back(wire.clone());
+        self.in_flight = Some(InFlight {
+            seq,
+            wire,
+            deadline_ms: None,
+            retries: 0,
+            reset: true,
+        });
+    }
+
+    fn mark_up(&mut self) {
+        if !self.up {
+            self.up = true;
+            self.events.push_back(LinkEvent::Up);
+        }
+    }
+
+    fn handle_block(&mut self, block: &[u8], deliver: &mut impl FnMut(&[u8]) -> bool) {
+        let Some(frame) = cobs::decode(block) else {
+            return;
+        };
+        if frame.len() < OVERHEAD {
+            return;
+        }
+        let (body, crc) = frame.split_at(frame.len() - 2);
+        if crc16(body) != u16::from_be_bytes([crc[0], crc[1]]) {
+            return;
+        }
+        let (kind, seq, payload) = (body[0], body[1], &body[2..]);
+        match kind {
+            KIND_DATA => {
+                self.mark_up();
+                if self.last_delivered != Some(seq) {
+                    if !deliver(payload) {
+                        return;
+                    }
+                    self.last_delivered = Some(seq);
+                }
+                self.tx.push_back(encode_frame(KIND_ACK, seq, &[]));
+            }
+            KIND_RESET => {
+                self.mark_up();
+                self.last_delivered = None;
+                self.tx.push_back(encode_frame(KIND_ACK, seq, &[]));
+            }
+            KIND_ACK => {
+                self.mark_up();
+                if self.in_flight.as_ref().is_some_and(|f| f.seq == seq) {
+                    self.in_flight = None;
+                    self.next_seq = self.next_seq.wrapping_add(1);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Build the wire form of one frame, delimiter included.
+pub fn encode_frame(kind: u8, seq: u8, payload: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(payload.len() + OVERHEAD);
+    body.push(kind);
+    body.push(seq);
+    body.extend_from_slice(payload);
+    let crc = crc16(&body);
+    body.extend_from_slice(&crc.to_be_bytes());
+
+    let mut wire = Vec::with_capacity(max_encoded_len(payload.len()) + 1);
+    cobs::encode(&body, &mut wire);
+    wire.push(0);
+    wire
+}
+
+fn max_encoded_len(payload: usize) -> usize {
+    let raw = payload + OVERHEAD;
+    raw + raw / 254 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn config() -> BridgeConfig {
+        BridgeConfig {
+            ack_timeout_ms: 100,
+            max_retries: 2,
+            max_payload: 64,
+        }
+    }
+
+    /// Move every queued frame from `from` into `to`, collecting deliveries.
+    fn pump(from: &mut Link, to: &mut Link) -> Vec<Vec<u8>> {
+        let mut delivered = Vec::new();
+        while let Some(frame) = from.pop_transmit() {
+            to.receive(&frame, |p| {
+                delivered.push(p.to_vec());
+                true
+            });
+        }
+        delivered
+    }
+
+    /// Two links that have exchanged and acked their RESETs.
+    fn linked() -> (Link, Link) {
+        let (mut a, mut b) = (Link::new(config()), Link::new(config()));
+        pump(&mut a, &mut b);
+        pump(&mut b, &mut a);
+        pump(&mut a, &mut b);
+        while a.pop_event().is_some() || b.pop_event().is_some() {}
+        (a, b)
+    }
+
+    #[test]
+    fn reset_handshake_raises_up_and_gates_sending() {
+        let (mut a, mut b) = (Link::new(config()), Link::new(config()));
+        assert!(!a.can_send());
+        assert_eq!(a.send(b"early", 0), Err(SendError::Busy));
+        assert_eq!(a.next_deadline(), Some(0));
+        a.handle_timeout(0);
+        assert_eq!(a.next_deadline(), Some(100));
+
+        assert!(pump(&mut a, &mut b).is_empty());
+        assert_eq!(b.pop_event(), Some(LinkEvent::Up));
+        pump(&mut b, &mut a);
+        assert_eq!(a.pop_event(), Some(LinkEvent::Up));
+        assert!(a.can_send());
+        assert_eq!(a.next_deadline(), None);
+    }
+
+    #[test]
+    fn data_is_delivered_and_acked() {
+        let (mut a, mut b) = linked();
+        a.send(b"hello", 0).unwrap();
+        assert!(!a.can_send());
+
+        assert_eq!(pump(&mut a, &mut b), vec![b"hello".to_vec()]);
+        assert!(pump(&mut b, &mut a).is_empty());
+        assert!(a.can_send());
+        assert_eq!(a.next_deadline(), None);
+    }
+
+    #[test]
+    fn rebooted_peer_is_not_taken_for_a_duplicate() {
+        let (mut a, mut b) = linked();
+        a.send(b"before", 0).unwrap();
+        assert_eq!(pump(&mut a, &mut b), vec![b"before".to_vec()]);
+        pump(&mut b, &mut a);
+
+        // The peer restarts and counts from 0 again: the sequence number
+        // of its first payload matches the one `b` delivered last.
+        let mut a = Link::new(config());
+        pump(&mut a, &mut b);
+        pump(&mut b, &mut a);
+        a.send(b"after", 0).unwrap();
+        assert_eq!(pump(&mut a, &mut b), vec![b"after".to_vec()]);
+    }
+
+    #[test]
+    fn lost_frame_is_retransmitted() {
+        let (mut a, mut b) = linked();
+        a.send(b"x", 0).unwrap();
+        a.pop_transmit(); // lost on the wire
+
+        a.handle_timeout(50);
+        assert!(a.pop_transmit().is_none(), "not yet due");
+        a.handle_timeout(100);
+        assert_eq!(pump(&mut a, &mut b), vec![b"x".to_vec()]);
+    }
+
+    #[test]
+    fn lost_ack_does_not_duplicate_delivery() {
+        let (mut a, mut b) = linked();
+        a.send(b"once", 0).unwrap();
+        assert_eq!(pump(&mut a, &mut b).len(), 1);
+        b.pop_transmit(); // ack lost
+
+        a.handle_timeout(100);
+        assert!(pump(&mut a, &mut b).is_empty(), "duplicate suppressed");
+        pump(&mut b, &mut a); // re-ack arrives
+        assert!(a.can_send());
+
+        a.send(b"twice", 200).unwrap();
+        assert_eq!(pump(&mut a, &mut b), vec![b"twice".to_vec()]);
+    }
+
+    #[test]
+    fn corrupted_frame_is_dropped() {
+        let (mut a, mut b) = linked();
+        a.send(b"abc", 0).unwrap();
+        let mut frame = a.pop_transmit().unwrap();
+        frame[2] ^= 0x40;
+        let mut delivered = 0;
+        b.receive(&frame, |_| {
+            delivered += 1;
+            true
+        });
+        assert_eq!(delivered, 0);
+        assert!(b.pop_transmit().is_none(), "no ack for a bad frame");
+    }
+
+    #[test]
+    fn receiver_resyncs_after_noise() {
+        let (mut a, mut b) = linked();
+        a.send(b"ok", 0).unwrap();
+        let frame = a.pop_transmit().unwrap();
+        let mut delivered = Vec::new();
+        for chunk in [&[0x13, 0x37, 0x00][..], &frame[..3], &frame[3..]] {
+            b.receive(chunk, |p| {
+                delivered.push(p.to_vec());
+                true
+            });
+        }
+        assert_eq!(delivered, vec![b"ok".to_vec()]);
+    }
+
+    #[test]
+    fn exhausted_retries_report_link_down() {
+        let (mut a, mut b) = linked();
+        a.send(b"1", 0).unwrap();
+        pump(&mut a, &mut b);
+        pump(&mut b, &mut a);
+
+        a.send(b"2", 0).unwrap();
+        for now in [100, 200, 300] {
+            a.handle_timeout(now);
+        }
+        // Initial send plus two retries, then give up and reset.
+        assert_eq!(core::iter::from_fn(|| a.pop_transmit()).count(), 4);
+        assert_eq!(a.pop_event(), Some(LinkEvent::Down(LinkDown::NoAck)));
+        assert!(!a.is_up());
+        assert!(!a.can_send());
+
+        // The RESET above was drained with the lost frames; its retransmit
+        // reopens the session.
+        a.handle_timeout(300);
+        a.handle_timeout(400);
+        pump(&mut a, &mut b);
+        pump(&mut b, &mut a);
+        assert!(a.can_send());
+    }
+
+    #[test]
+    fn refused_payload_is_not_acked_and_redelivered() {
+        let (mut a, mut b) = linked();
+        a.send(b"later", 0).unwrap();
+        let frame = a.pop_transmit().unwrap();
+        b.receive(&frame, |_| false);
+        assert!(b.pop_transmit().is_none(), "refused frames are not acked");
+
+        a.handle_timeout(100);
+        assert_eq!(pump(&mut a, &mut b), vec![b"later".to_vec()]);
+        assert!(b.pop_transmit().is_some(), "accepted retransmit is acked");
+    }
+
+    #[test]
+    fn oversized_payload_is_refused() {
+        let (mut a, _b) = linked();
+        assert_eq!(a.send(&[1u8; 65], 0), Err(SendError::TooLarge));
+        a.send(&[1u8; 64], 0).unwrap();
+        assert_eq!(a.send(&[1u8], 0), Err(SendError::Busy));
+    }
+}
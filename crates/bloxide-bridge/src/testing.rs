@@ -0,0 +1,184 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! In-memory transport and clock for exercising a [`Bridge`](crate::Bridge)
+//! without hardware.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::vec::Vec;
+
+use crate::{BridgeClock, ByteReader, ByteWriter};
+
+// ── pipe ──────────────────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct PipeState {
+    bytes: VecDeque<u8>,
+    closed: bool,
+    lossy: bool,
+    reader_waker: Option<Waker>,
+}
+
+type Shared = Arc<Mutex<PipeState>>;
+
+fn lock(shared: &Shared) -> std::sync::MutexGuard<'_, PipeState> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Create a one-way in-memory byte pipe. Use two for a full-duplex link.
+pub fn pipe() -> (PipeWriter, PipeReader) {
+    let shared = Shared::default();
+    (
+        PipeWriter {
+            shared: Arc::clone(&shared),
+        },
+        PipeReader { shared },
+    )
+}
+
+/// Write half of a [`pipe`]. Dropping it closes the pipe once the reader
+/// has drained the buffered bytes.
+pub struct PipeWriter {
+    shared: Shared,
+}
+
+impl PipeWriter {
+    /// A handle for disturbing the pipe after the writer has been moved
+    /// into a bridge.
+    pub fn line(&self) -> Line {
+        Line {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl ByteWriter for PipeWriter {
+    type Error = core::convert::Infallible;
+
+    fn poll_write(
+        &mut self,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Self::Error>> {
+        let mut state = lock(&self.shared);
+        if !state.lossy {
+            push(&mut state, buf);
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        let mut state = lock(&self.shared);
+        state.closed = true;
+        if let Some(waker) = state.reader_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Fault injection for a [`pipe`], obtained from [`PipeWriter::line`].
+#[derive(Clone)]
+pub struct Line {
+    shared: Shared,
+}
+
+impl Line {
+    /// While lossy, written bytes are accepted and silently discarded.
+    pub fn set_lossy(&self, lossy: bool) {
+        lock(&self.shared).lossy = lossy;
+    }
+
+    /// Inject raw bytes as if they arrived on the line.
+    pub fn inject(&self, bytes: &[u8]) {
+        push(&mut lock(&self.shared), bytes);
+    }
+}
+
+fn push(state: &mut PipeState, bytes: &[u8]) {
+    state.bytes.extend(bytes);
+    if let Some(waker) = state.reader_waker.take() {
+        waker.wake();
+    }
+}
+
+/// Read half of a [`pipe`].
+pub struct PipeReader {
+    shared: Shared,
+}
+
+impl ByteReader for PipeReader {
+    type Error = core::convert::Infallible;
+
+    fn poll_read(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, Self::Error>> {
+        let mut state = lock(&self.shared);
+        if state.bytes.is_empty() {
+            if state.closed {
+                return Poll::Ready(Ok(0));
+            }
+            state.reader_waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = buf.len().min(state.bytes.len());
+        for (slot, byte) in buf.iter_mut().zip(state.bytes.drain(..n)) {
+            *slot = byte;
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+// ── ManualClock ───────────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct ClockState {
+    now_ms: u64,
+    wakers: Vec<Waker>,
+}
+
+/// A clock that only moves when [`advance`](Self::advance) is called.
+/// Clones share the same time, so one handle can drive several bridges.
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move time forward by `ms` and wake every pending sleeper.
+    pub fn advance(&self, ms: u64) {
+        let wakers = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.now_ms += ms;
+            core::mem::take(&mut state.wakers)
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl BridgeClock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).now_ms
+    }
+
+    fn poll_sleep_until(&mut self, cx: &mut Context<'_>, deadline_ms: u64) -> Poll<()> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.now_ms >= deadline_ms {
+            Poll::Ready(())
+        } else {
+            if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                state.wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        }
+    }
+}
@@ -7,17 +7,20 @@
 //!
 //! Runtimes do NOT need to know about `SupervisorControl` — the app chooses `Ctrl`.
 
-use crate::{ChildGroup, ChildPolicy, GroupShutdown};
+use crate::{
+    ChildGroup, ChildPolicy, GroupShutdown, JournalSink, Liveness, RestartType, StartOrder,
+};
 use bloxide_core::{
-    capability::{BloxRuntime, DynamicChannelCap},
+    capability::{BloxRuntime, ClockCap, DynamicChannelCap},
     lifecycle::{ChildLifecycleEvent, LifecycleCommand},
     messaging::{ActorId, ActorRef},
 };
 
 /// Builder for assembling a `ChildGroup` with dynamic channels.
 ///
-/// Generic over runtime `R` (must support `DynamicChannelCap`) and control message
-/// type `Ctrl` (chosen by the app — e.g. `SupervisorControl<R>`).
+/// Generic over runtime `R` (must support `DynamicChannelCap` and `ClockCap`) and
+/// control message type `Ctrl` (chosen by the app — e.g. `SupervisorControl<R>`).
+/// The group measures restart intensity windows with `R`'s clock.
 ///
 /// Created with `::new(shutdown)`, children are added via `add_child()`, and the
 /// group is consumed via `finish()`.
@@ -31,7 +34,7 @@ pub struct ChildGroupBuilder<R: BloxRuntime, Ctrl: Send + 'static> {
 
 impl<R, Ctrl> ChildGroupBuilder<R, Ctrl>
 where
-    R: BloxRuntime + DynamicChannelCap,
+    R: BloxRuntime + DynamicChannelCap + ClockCap,
     Ctrl: Send + 'static,
 {
     /// Create a new builder with the given group shutdown policy.
@@ -47,7 +50,7 @@ where
         let (control_ref, control_rx) = R::channel::<Ctrl>(control_id, 16);
 
         Self {
-            group: ChildGroup::new(shutdown),
+            group: ChildGroup::new(shutdown).with_clock(R::now_ms),
             notify_ref,
             notify_rx: Some(notify_rx),
             control_ref,
@@ -55,6 +58,56 @@ where
         }
     }
 
+    /// Limit the group to `max` restarts within any `within_ms` window.
+    /// See [`ChildGroup::with_restart_intensity`].
+    pub fn with_restart_intensity(mut self, max: usize, within_ms: u64) -> Self {
+        self.group = self.group.with_restart_intensity(max, within_ms);
+        self
+    }
+
+    /// Bound how long shutdown waits for each child.
+    /// See [`ChildGroup::with_shutdown_timeouts`].
+    pub fn with_shutdown_timeouts(mut self, stop_ms: u64, abort_ms: u64) -> Self {
+        self.group = self.group.with_shutdown_timeouts(stop_ms, abort_ms);
+        self
+    }
+
+    /// Set how many missed or undeliverable pings fail a child.
+    /// See [`ChildGroup::with_health_thresholds`].
+    pub fn with_health_thresholds(mut self, max_missed: u32, max_undelivered: u32) -> Self {
+        self.group = self
+            .group
+            .with_health_thresholds(max_missed, max_undelivered);
+        self
+    }
+
+    /// Start children one at a time or all at once.
+    /// See [`ChildGroup::with_start_order`].
+    pub fn with_start_order(mut self, order: StartOrder) -> Self {
+        self.group = self.group.with_start_order(order);
+        self
+    }
+
+    /// Fail the group if a child does not start in time.
+    /// See [`ChildGroup::with_start_timeout`].
+    pub fn with_start_timeout(mut self, timeout_ms: u64) -> Self {
+        self.group = self.group.with_start_timeout(timeout_ms);
+        self
+    }
+
+    /// Journal the last `capacity` events and decisions.
+    /// See [`ChildGroup::with_journal`].
+    pub fn with_journal(mut self, capacity: usize) -> Self {
+        self.group = self.group.with_journal(capacity);
+        self
+    }
+
+    /// Where to dump the journal. See [`ChildGroup::with_journal_sink`].
+    pub fn with_journal_sink(mut self, sink: JournalSink) -> Self {
+        self.group = self.group.with_journal_sink(sink);
+        self
+    }
+
     /// Add a child to the group with the given policy.
     ///
     /// Creates a per-child lifecycle channel and registers the child.
@@ -72,6 +125,24 @@ where
         (cmd_rx, self.notify_ref.sender())
     }
 
+    /// Set the restart type of child `id`.
+    /// See [`ChildGroup::set_restart_type`].
+    pub fn set_restart_type(&mut self, id: ActorId, restart_type: RestartType) {
+        self.group.set_restart_type(id, restart_type);
+    }
+
+    /// Set how child `id` is health-checked.
+    /// See [`ChildGroup::set_liveness`].
+    pub fn set_liveness(&mut self, id: ActorId, liveness: Liveness) {
+        self.group.set_liveness(id, liveness);
+    }
+
+    /// Start child `id` only once `depends_on` are running.
+    /// See [`ChildGroup::set_depends_on`].
+    pub fn set_depends_on(&mut self, id: ActorId, depends_on: &[ActorId]) {
+        self.group.set_depends_on(id, depends_on);
+    }
+
     /// Get the control channel sender (for registering children externally).
     pub fn control_ref(&self) -> ActorRef<Ctrl, R> {
         self.control_ref.clone()
@@ -0,0 +1,145 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Bounded record of what a `ChildGroup` was told and what it decided.
+//!
+//! The journal is a ring buffer: once `capacity` entries are held, each new
+//! entry evicts the oldest. Sequence numbers keep counting across evictions
+//! and `clear_counters`, so a reader can tell what it missed.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use bloxide_core::{lifecycle::ChildLifecycleEvent, messaging::ActorId};
+
+/// One journal entry.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct JournalEntry {
+    /// Monotonic per group, starting at 0.
+    pub seq: u64,
+    /// Group clock reading when the entry was recorded.
+    pub at_ms: u64,
+    pub record: JournalRecord,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum JournalRecord {
+    /// A lifecycle event the managing blox received.
+    Event(ChildLifecycleEvent),
+    /// Something the group did to a child, and why.
+    Decision {
+        child_id: ActorId,
+        decision: Decision,
+        reason: DecisionReason,
+    },
+}
+
+/// What the group did to a child.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Decision {
+    /// `Reset` sent (or queued behind the child's dependencies).
+    Reset,
+    /// Restart delayed by the child's `Backoff`.
+    BackOff { delay_ms: u64 },
+    /// `Stop` sent.
+    Stop,
+    /// `AbortCommand` sent.
+    Abort,
+    /// Task killed with `KillCapability::kill`.
+    Kill,
+    /// Shutdown stopped waiting for a child it could not abort or kill.
+    Abandon,
+    /// Not restarted; the child is permanently done.
+    GiveUp,
+    /// Removed from the group.
+    Delete,
+    /// The group began shutting down.
+    Shutdown,
+}
+
+/// Why the group made a [`Decision`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DecisionReason {
+    /// The child reported `Done`.
+    Done,
+    /// The child reported `Failed`.
+    Failed,
+    /// The child left too many health-check pings unanswered.
+    MissedAlive,
+    /// Too many health-check pings could not be delivered.
+    UndeliveredPings,
+    /// The restart strategy restarts the child along with `failed`.
+    Sibling { failed: ActorId },
+    /// The child's backoff elapsed.
+    BackoffElapsed,
+    /// The child's restart budget is spent.
+    RestartsExhausted,
+    /// The group's restart intensity is exceeded.
+    IntensityExceeded,
+    /// The child's `RestartType` does not restart this exit.
+    NotRestartable,
+    /// `stop_all` is stopping the group.
+    GroupStopping,
+    /// The child missed its shutdown deadline.
+    ShutdownDeadline,
+    /// The child missed its start deadline.
+    StartTimeout,
+    /// The child's task has ended, so the group cannot restart it.
+    TaskEnded,
+    /// The managing blox asked for it (`terminate_child`, `restart_child`,
+    /// `delete_child`).
+    Operator,
+}
+
+/// The journal of a `ChildGroup`, enabled with `ChildGroup::with_journal`.
+pub struct Journal {
+    entries: VecDeque<JournalEntry>,
+    capacity: usize,
+    next_seq: u64,
+}
+
+impl Journal {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            next_seq: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, at_ms: u64, record: JournalRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(JournalEntry {
+            seq: self.next_seq,
+            at_ms,
+            record,
+        });
+        self.next_seq += 1;
+    }
+
+    /// Maximum number of entries held; 0 when the journal is disabled.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Entries evicted to make room for newer ones.
+    pub fn dropped(&self) -> u64 {
+        self.next_seq - self.entries.len() as u64
+    }
+
+    /// Held entries, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &JournalEntry> {
+        self.entries.iter()
+    }
+
+    /// Copies of the held entries with `seq >= since`, oldest first.
+    pub fn since(&self, since: u64) -> Vec<JournalEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.seq >= since)
+            .copied()
+            .collect()
+    }
+}
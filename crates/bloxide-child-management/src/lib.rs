@@ -10,13 +10,15 @@
 extern crate alloc;
 
 pub mod builder;
+pub mod journal;
 
 use alloc::vec::Vec;
 use bloxide_core::{
     capability::{BloxRuntime, KillCapability},
-    lifecycle::{ChildLifecycleEvent, LifecycleCommand},
+    lifecycle::{ChildLifecycleEvent, LifecycleCommand, ShutdownEscalation},
     messaging::{ActorId, ActorRef},
 };
+pub use journal::{Decision, DecisionReason, Journal, JournalEntry, JournalRecord};
 
 /// Command enum for the abort capability mailbox.
 ///
@@ -42,7 +44,8 @@ pub enum AbortCommand {
 ///
 /// | Policy | Mechanism | Cooperative? | Callbacks? | Restartable? |
 /// |--------|-----------|-------------|------------|--------------|
-/// | `Restart` | Send `Reset` | Yes | Exit + entry chain | Yes (immediately) |
+/// | `Restart` | Send `Reset` | Yes | Exit + entry chain | Yes (after `backoff`) |
+/// | `RestartWithin` | Send `Reset` | Yes | Exit + entry chain | Yes (after `backoff`) |
 /// | `Stop` | Send `Stop` | Yes | Exit + `on_init_entry` | Yes (via `Start`) |
 /// | `Abort` | Send `AbortCommand` on abort mailbox | Yes (cooperative) | None | Yes (respawn task) |
 /// | `Kill` | `KillCapability::kill(handle)` | No (forced) | None | No (permanently dead) |
@@ -52,8 +55,19 @@ pub enum ChildPolicy {
     /// `Reset` goes directly to `initial_state()` — the actor is immediately
     /// operational. `max` is the number of restart attempts allowed: after
     /// the `max`-th restart the next failure triggers group shutdown.
-    /// `Restart { max: 0 }` means no restarts — equivalent to `Stop`.
-    Restart { max: usize },
+    /// `Restart { max: 0, .. }` means no restarts — equivalent to `Stop`.
+    /// `backoff` delays each `Reset`; see [`Backoff`].
+    Restart { max: usize, backoff: Backoff },
+    /// Restart the child by sending `Reset`, at most `max` times within any
+    /// `within_ms` window (OTP restart intensity). Restarts older than the
+    /// window no longer count, so an occasional crash never exhausts the
+    /// budget while a crash loop does. Windows are measured with the group's
+    /// clock (see [`ChildGroup::with_clock`]).
+    RestartWithin {
+        max: usize,
+        within_ms: u64,
+        backoff: Backoff,
+    },
     /// Send `Stop` command for clean shutdown (exit chain + `on_init_entry` fire).
     /// Actor goes to Init, suspended, can be restarted with `Start`.
     Stop,
@@ -68,6 +82,87 @@ pub enum ChildPolicy {
     Kill,
 }
 
+/// Delay before a failed child is sent `Reset`.
+///
+/// A delayed restart parks the child in [`ChildPhase::BackingOff`] and
+/// queues the delay for the managing blox, which schedules it (the
+/// supervisor uses `bloxide-timer`) and calls
+/// [`ChildGroup::restart_after_backoff`] when it elapses. `attempt` below is
+/// the number of restarts already counted against the child's budget.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum Backoff {
+    /// Send `Reset` at once (default).
+    #[default]
+    Immediate,
+    /// Wait `delay_ms`, plus up to `jitter_ms` of random extra delay.
+    Fixed { delay_ms: u64, jitter_ms: u64 },
+    /// Wait `initial_ms * 2^attempt`, capped at `max_ms`, plus up to
+    /// `jitter_ms` of random extra delay.
+    Exponential {
+        initial_ms: u64,
+        max_ms: u64,
+        jitter_ms: u64,
+    },
+}
+
+impl Backoff {
+    /// Delay in milliseconds before restart number `attempt + 1`, without
+    /// jitter, and the jitter bound.
+    fn delay(self, attempt: usize) -> (u64, u64) {
+        match self {
+            Backoff::Immediate => (0, 0),
+            Backoff::Fixed {
+                delay_ms,
+                jitter_ms,
+            } => (delay_ms, jitter_ms),
+            Backoff::Exponential {
+                initial_ms,
+                max_ms,
+                jitter_ms,
+            } => {
+                let factor = u32::try_from(attempt)
+                    .ok()
+                    .and_then(|shift| 1u64.checked_shl(shift))
+                    .unwrap_or(u64::MAX);
+                (initial_ms.saturating_mul(factor).min(max_ms), jitter_ms)
+            }
+        }
+    }
+}
+
+/// Which exits of a child may be restarted, as in Erlang/OTP.
+///
+/// Orthogonal to [`ChildPolicy`]: the restart type decides *whether* an
+/// exit may be answered with a restart, the policy decides how — its budget
+/// and backoff — and what happens to a child that is not restarted
+/// (`Stop`, `Abort` or `Kill`).
+///
+/// | Type | Restarted after `Done` | Restarted after `Failed` |
+/// |------|------------------------|--------------------------|
+/// | `Permanent` | Yes | Yes |
+/// | `Transient` | No | Yes |
+/// | `Temporary` | No | No |
+///
+/// A child that is not restarted does not spend its restart budget, so it
+/// never counts as [`ChildGroup::restarts_exhausted`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum RestartType {
+    #[default]
+    Permanent,
+    Transient,
+    Temporary,
+}
+
+impl RestartType {
+    fn restarts_on(self, failed: bool) -> bool {
+        match self {
+            Self::Permanent => true,
+            Self::Transient => failed,
+            Self::Temporary => false,
+        }
+    }
+}
+
 /// Group-level restart strategy determining which children are restarted
 /// when a child fails. Inspired by Erlang/OTP supervisor strategies.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -81,6 +176,19 @@ pub enum RestartStrategy {
     RestForOne,
 }
 
+/// Whether `start_all` waits for each child before starting the next, set
+/// with [`ChildGroup::with_start_order`]. Per-child dependencies set with
+/// [`ChildGroup::set_depends_on`] apply in either order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartOrder {
+    /// Start every child whose dependencies are up at once (default).
+    #[default]
+    Concurrent,
+    /// Start children one at a time in the order they were added, each
+    /// after the previous one reported `Started`.
+    Sequential,
+}
+
 /// When to trigger group-level shutdown.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum GroupShutdown {
@@ -98,11 +206,15 @@ pub enum ChildAction {
     BeginShutdown,
 }
 
+/// Where a child is in its supervised lifecycle, as tracked by the group.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
-enum ChildPhase {
+pub enum ChildPhase {
     #[default]
     Init,
     Running,
+    /// The child failed and waits out its restart [`Backoff`] before
+    /// `Reset` is sent. Health checks and sibling restarts skip it.
+    BackingOff,
     /// Reset was sent; waiting for the child to report Started.
     /// Health checks skip children in this phase — the child is transitioning.
     ResetPending,
@@ -110,15 +222,179 @@ enum ChildPhase {
     Stopped,
 }
 
+/// How a child takes part in health checks, set with
+/// [`ChildGroup::set_liveness`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum Liveness {
+    /// Pinged on every health-check tick.
+    #[default]
+    EveryTick,
+    /// Pinged on the first tick at least `interval_ms` of group clock time
+    /// after its previous ping. Needs a clock (`with_clock`).
+    Every { interval_ms: u64 },
+    /// Never pinged, so never failed by health checks.
+    Disabled,
+}
+
+/// How [`ChildGroup::terminate_child`] ends a child, mirroring the
+/// `stop → abort → kill` levels of [`ChildPolicy`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Termination {
+    /// Send `Stop`. The child returns to Init and its task ends; only a
+    /// respawn brings it back.
+    Stop,
+    /// Send `AbortCommand` on the abort mailbox. Dynamic children only.
+    Abort,
+    /// `KillCapability::kill(handle)`. Dynamic children only.
+    Kill,
+}
+
+/// A snapshot of one child, as reported by [`ChildGroup::which_children`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChildInfo {
+    pub id: ActorId,
+    pub phase: ChildPhase,
+    pub policy: ChildPolicy,
+    pub restart_type: RestartType,
+    /// Restarts counted since the group last cleared its counters.
+    pub restarts: usize,
+    /// A health-check `Ping` is outstanding.
+    pub awaiting_alive: bool,
+    /// Group clock reading of the last `Alive` reply, if any.
+    pub last_alive_ms: Option<u64>,
+    /// Group clock reading of the last `Ping` sent, if any.
+    pub last_ping_ms: Option<u64>,
+    pub liveness: Liveness,
+    /// Consecutive pings the child did not answer.
+    pub missed_alive: u32,
+    /// Consecutive pings that could not be delivered (channel full).
+    pub undelivered_pings: u32,
+    /// Registered with an abort mailbox and kill handle (`add_dynamic`).
+    pub dynamic: bool,
+}
+
+/// Aggregate child counts, as reported by [`ChildGroup::counts`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChildCounts {
+    pub total: usize,
+    pub init: usize,
+    pub running: usize,
+    pub backing_off: usize,
+    pub reset_pending: usize,
+    pub stopped: usize,
+    pub permanently_done: usize,
+    /// Sum of every child's restart count.
+    pub restarts: usize,
+}
+
+/// Sliding window of restart timestamps for restart intensity tracking.
+struct RestartWindow {
+    max: usize,
+    within_ms: u64,
+    times: Vec<u64>,
+}
+
+impl RestartWindow {
+    fn new(max: usize, within_ms: u64) -> Self {
+        Self {
+            max,
+            within_ms,
+            times: Vec::new(),
+        }
+    }
+
+    fn for_policy(policy: ChildPolicy) -> Option<Self> {
+        match policy {
+            ChildPolicy::RestartWithin { max, within_ms, .. } => Some(Self::new(max, within_ms)),
+            _ => None,
+        }
+    }
+
+    /// Forget restarts that fell out of the window and report whether one
+    /// more restart fits.
+    fn admits(&mut self, now: u64) -> bool {
+        let within_ms = self.within_ms;
+        self.times.retain(|&t| now.saturating_sub(t) < within_ms);
+        self.times.len() < self.max
+    }
+
+    fn record(&mut self, now: u64) {
+        if self.max == 0 {
+            return;
+        }
+        if self.times.len() >= self.max {
+            self.times.remove(0);
+        }
+        self.times.push(now);
+    }
+}
+
+/// How long a stopping child may take at each level before the group
+/// escalates, set with `ChildGroup::with_shutdown_timeouts`.
+#[derive(Copy, Clone)]
+struct ShutdownTimeouts {
+    stop_ms: u64,
+    abort_ms: u64,
+}
+
+/// A lifecycle command held back until the child's dependencies are up.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum QueuedStart {
+    /// First start of the group.
+    Start,
+    /// Restart of a running child.
+    Reset,
+    /// Restart of the whole group: `Reset` then `Start`, see `restart_all`.
+    Restart,
+}
+
+impl QueuedStart {
+    fn commands(self) -> &'static [LifecycleCommand] {
+        match self {
+            Self::Start => &[LifecycleCommand::Start],
+            Self::Reset => &[LifecycleCommand::Reset],
+            Self::Restart => &[LifecycleCommand::Reset, LifecycleCommand::Start],
+        }
+    }
+}
+
+/// How far a child's shutdown has escalated.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum ShutdownStage {
+    #[default]
+    Idle,
+    /// `Stop` was sent by `stop_all`.
+    Stopping,
+    /// The stop deadline passed and `AbortCommand` was sent.
+    Aborting,
+}
+
+const DEFAULT_JITTER_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Clock used by groups without one: time never advances, so restart
+/// windows never expire and budgets count over the group's lifetime.
+fn no_clock() -> u64 {
+    0
+}
+
 struct ChildEntry<R: BloxRuntime> {
     id: ActorId,
     lifecycle_ref: ActorRef<LifecycleCommand, R>,
     policy: ChildPolicy,
+    restart_type: RestartType,
     restarts: usize,
+    /// Restart timestamps for `ChildPolicy::RestartWithin`.
+    window: Option<RestartWindow>,
     permanently_done: bool,
     stopped: bool,
     phase: ChildPhase,
     awaiting_alive: bool,
+    /// Group clock reading of the last `Alive` reply.
+    last_alive_ms: Option<u64>,
+    last_ping_ms: Option<u64>,
+    liveness: Liveness,
+    missed_alive: u32,
+    undelivered_pings: u32,
     /// Abort capability mailbox (send side). `None` for static children
     /// registered via `RegisterChild` (no abort capability).
     abort_ref: Option<ActorRef<AbortCommand, R>>,
@@ -127,6 +403,31 @@ struct ChildEntry<R: BloxRuntime> {
     /// `ChildPolicy::Kill` fires.
     /// This is `R::KillHandle` (Clone), not `R::TaskHandle` (not Clone).
     kill_handle: Option<<R::Kill as KillCapability<R>>::Handle>,
+    /// The child's task stopped, was aborted or was killed; only a respawn
+    /// brings it back.
+    task_ended: bool,
+    shutdown_stage: ShutdownStage,
+    /// Children that must be `Running` before this one is started.
+    depends_on: Vec<ActorId>,
+    /// Start or restart waiting for `start_ready`.
+    queued: Option<QueuedStart>,
+    /// Bumped by every `Start` or `Reset` sent and by every `Started`
+    /// report, so a start deadline armed before either is stale.
+    start_epoch: u32,
+    /// Bumped for every backoff queued, so only the latest one's timer
+    /// restarts the child.
+    backoff_generation: u32,
+}
+
+impl<R: BloxRuntime> ChildEntry<R> {
+    /// Has a kill handle, and the runtime's kill really ends the task.
+    fn can_kill(&self) -> bool {
+        R::Kill::CAN_KILL && self.kill_handle.is_some()
+    }
+
+    fn bump_start_epoch(&mut self) {
+        self.start_epoch = self.start_epoch.wrapping_add(1);
+    }
 }
 
 pub struct ChildGroup<R: BloxRuntime> {
@@ -134,8 +435,39 @@ pub struct ChildGroup<R: BloxRuntime> {
     shutdown: GroupShutdown,
     restart_strategy: RestartStrategy,
     stopped_count: usize,
+    /// Group-wide restart intensity, counted across all children.
+    window: Option<RestartWindow>,
+    now_ms: fn() -> u64,
+    /// Restarts waiting out a backoff, as `(child_id, generation, delay_ms)`,
+    /// until the managing blox takes them with `take_backoffs`.
+    backoffs: Vec<(ActorId, u32, u64)>,
+    /// xorshift64 state for backoff jitter.
+    jitter: u64,
+    /// A child's restart budget (or the group's intensity) ran out.
+    exhausted: bool,
+    shutdown_timeouts: Option<ShutdownTimeouts>,
+    /// Consecutive missed `Alive` replies that fail a child.
+    max_missed: u32,
+    /// Consecutive undeliverable pings that fail a child; 0 never does.
+    max_undelivered: u32,
+    /// Shutdown deadlines, as `(child_id, timeout_ms)`, until the managing
+    /// blox takes them with `take_deadlines`.
+    deadlines: Vec<(ActorId, u64)>,
+    start_order: StartOrder,
+    start_timeout_ms: Option<u64>,
+    /// Start deadlines, as `(child_id, epoch, timeout_ms)`, until the
+    /// managing blox takes them with `take_start_deadlines`.
+    start_deadlines: Vec<(ActorId, u32, u64)>,
+    /// A child did not report `Started` in time.
+    start_timed_out: bool,
+    journal: Journal,
+    journal_sink: Option<JournalSink>,
 }
 
+/// Where `ChildGroup::dump_journal` writes the journal, given the id of the
+/// managing blox. Runtimes provide one for their output.
+pub type JournalSink = fn(ActorId, &Journal);
+
 /// Accessor trait for the child group.
 pub trait HasChildGroup<R: BloxRuntime> {
     fn children(&self) -> &ChildGroup<R>;
@@ -159,6 +491,21 @@ impl<R: BloxRuntime> ChildGroup<R> {
             shutdown,
             restart_strategy: RestartStrategy::default(),
             stopped_count: 0,
+            window: None,
+            now_ms: no_clock,
+            backoffs: Vec::new(),
+            jitter: DEFAULT_JITTER_SEED,
+            exhausted: false,
+            shutdown_timeouts: None,
+            max_missed: 1,
+            max_undelivered: 1,
+            deadlines: Vec::new(),
+            start_order: StartOrder::default(),
+            start_timeout_ms: None,
+            start_deadlines: Vec::new(),
+            start_timed_out: false,
+            journal: Journal::new(0),
+            journal_sink: None,
         }
     }
 
@@ -168,6 +515,87 @@ impl<R: BloxRuntime> ChildGroup<R> {
         self
     }
 
+    /// Limit the whole group to `max` restarts within any `within_ms`
+    /// window, on top of each child's own policy.
+    ///
+    /// A failure that would exceed the group intensity is not restarted: the
+    /// child is marked permanently done and the group escalates according
+    /// to its `GroupShutdown`.
+    pub fn with_restart_intensity(mut self, max: usize, within_ms: u64) -> Self {
+        self.window = Some(RestartWindow::new(max, within_ms));
+        self
+    }
+
+    /// Fail a child after `max_missed` consecutive pings it did not answer
+    /// (at least 1), or after `max_undelivered` consecutive pings that could
+    /// not be delivered because its lifecycle channel was full (0 never
+    /// fails it). Both default to 1.
+    ///
+    /// A ping counts as missed once the child is due its next ping without
+    /// having replied `Alive`.
+    pub fn with_health_thresholds(mut self, max_missed: u32, max_undelivered: u32) -> Self {
+        self.max_missed = max_missed.max(1);
+        self.max_undelivered = max_undelivered;
+        self
+    }
+
+    /// Start children one at a time or all at once. See [`StartOrder`].
+    pub fn with_start_order(mut self, order: StartOrder) -> Self {
+        self.start_order = order;
+        self
+    }
+
+    /// Fail the group if a child started by `start_ready` has not reported
+    /// `Started` within `timeout_ms`. Each start queues a deadline for
+    /// [`take_start_deadlines`](Self::take_start_deadlines).
+    pub fn with_start_timeout(mut self, timeout_ms: u64) -> Self {
+        self.start_timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    /// Keep the last `capacity` lifecycle events and decisions in the
+    /// group's [`Journal`]. Disabled (capacity 0) by default.
+    pub fn with_journal(mut self, capacity: usize) -> Self {
+        self.journal = Journal::new(capacity);
+        self
+    }
+
+    /// Write the journal to `sink` on [`dump_journal`](Self::dump_journal).
+    pub fn with_journal_sink(mut self, sink: JournalSink) -> Self {
+        self.journal_sink = Some(sink);
+        self
+    }
+
+    /// Bound how long `stop_all` waits for each child. A child that has not
+    /// reported `Stopped` within `stop_ms` is aborted, if it has an abort
+    /// mailbox, and given `abort_ms` more. After that it is killed, if it has
+    /// a kill handle, or abandoned. See
+    /// [`shutdown_deadline_elapsed`](Self::shutdown_deadline_elapsed).
+    ///
+    /// Without timeouts, `stop_all` waits for `Stopped` indefinitely.
+    pub fn with_shutdown_timeouts(mut self, stop_ms: u64, abort_ms: u64) -> Self {
+        self.shutdown_timeouts = Some(ShutdownTimeouts { stop_ms, abort_ms });
+        self
+    }
+
+    /// Seed the pseudo-random generator used for backoff jitter, e.g. to
+    /// decorrelate groups on different nodes. Zero is replaced by a fixed
+    /// non-zero seed.
+    pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+        self.jitter = if seed == 0 { DEFAULT_JITTER_SEED } else { seed };
+        self
+    }
+
+    /// Measure restart windows with `now_ms`, typically a runtime's
+    /// [`ClockCap::now_ms`](bloxide_core::capability::ClockCap::now_ms).
+    ///
+    /// Without a clock, windows never expire and `RestartWithin` budgets
+    /// behave like lifetime `Restart` budgets.
+    pub fn with_clock(mut self, now_ms: fn() -> u64) -> Self {
+        self.now_ms = now_ms;
+        self
+    }
+
     pub fn add(
         &mut self,
         id: ActorId,
@@ -178,13 +606,26 @@ impl<R: BloxRuntime> ChildGroup<R> {
             id,
             lifecycle_ref,
             policy,
+            restart_type: RestartType::default(),
             restarts: 0,
+            window: RestartWindow::for_policy(policy),
             permanently_done: false,
             stopped: false,
             phase: ChildPhase::Init,
             awaiting_alive: false,
+            last_alive_ms: None,
+            last_ping_ms: None,
+            liveness: Liveness::default(),
+            missed_alive: 0,
+            undelivered_pings: 0,
             abort_ref: None,
             kill_handle: None,
+            task_ended: false,
+            shutdown_stage: ShutdownStage::Idle,
+            depends_on: Vec::new(),
+            queued: None,
+            start_epoch: 0,
+            backoff_generation: 0,
         });
     }
 
@@ -209,16 +650,99 @@ impl<R: BloxRuntime> ChildGroup<R> {
             id,
             lifecycle_ref,
             policy,
+            restart_type: RestartType::default(),
             restarts: 0,
+            window: RestartWindow::for_policy(policy),
             permanently_done: false,
             stopped: false,
             phase: ChildPhase::Init,
             awaiting_alive: false,
+            last_alive_ms: None,
+            last_ping_ms: None,
+            liveness: Liveness::default(),
+            missed_alive: 0,
+            undelivered_pings: 0,
             abort_ref: Some(abort_ref),
             kill_handle: Some(kill_handle),
+            task_ended: false,
+            shutdown_stage: ShutdownStage::Idle,
+            depends_on: Vec::new(),
+            queued: None,
+            start_epoch: 0,
+            backoff_generation: 0,
         });
     }
 
+    /// Set the [`RestartType`] of child `child_id`. Children are added as
+    /// `Permanent`.
+    pub fn set_restart_type(&mut self, child_id: ActorId, restart_type: RestartType) {
+        if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
+            entry.restart_type = restart_type;
+        }
+    }
+
+    /// Set how child `child_id` is health-checked. Children are added with
+    /// [`Liveness::EveryTick`].
+    pub fn set_liveness(&mut self, child_id: ActorId, liveness: Liveness) {
+        if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
+            entry.liveness = liveness;
+            if liveness == Liveness::Disabled {
+                entry.awaiting_alive = false;
+            }
+        }
+    }
+
+    /// Hold back the start of child `child_id` until every child in
+    /// `depends_on` is `Running`. Ids that are not in the group are never
+    /// up, so the child would not start.
+    pub fn set_depends_on(&mut self, child_id: ActorId, depends_on: &[ActorId]) {
+        if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
+            entry.depends_on = depends_on.to_vec();
+        }
+    }
+
+    /// The group's journal.
+    pub fn journal(&self) -> &Journal {
+        &self.journal
+    }
+
+    /// Record a lifecycle event the managing blox received. Call it for
+    /// every event, before handing it to the matching method.
+    pub fn journal_event(&mut self, event: ChildLifecycleEvent) {
+        let now = (self.now_ms)();
+        self.journal.push(now, JournalRecord::Event(event));
+    }
+
+    /// Hand the journal to the sink installed with
+    /// [`with_journal_sink`](Self::with_journal_sink), if any. `owner` is
+    /// the id of the managing blox.
+    pub fn dump_journal(&self, owner: ActorId) {
+        if let Some(sink) = self.journal_sink {
+            sink(owner, &self.journal);
+        }
+    }
+
+    fn journal_decision(&mut self, child_id: ActorId, decision: Decision, reason: DecisionReason) {
+        let now = (self.now_ms)();
+        self.journal.push(
+            now,
+            JournalRecord::Decision {
+                child_id,
+                decision,
+                reason,
+            },
+        );
+    }
+
+    /// `check_shutdown`, journaling the start of a shutdown.
+    fn check_shutdown_after(&mut self, child_id: ActorId, reason: DecisionReason) -> ChildAction {
+        let action = self.check_shutdown();
+        if action == ChildAction::BeginShutdown {
+            self.journal_decision(child_id, Decision::Shutdown, reason);
+        }
+        action
+    }
+
     pub fn start_child(&self, child_id: ActorId, from: ActorId) {
         if let Some(entry) = self.children.iter().find(|entry| entry.id == child_id) {
             if entry
@@ -235,24 +759,189 @@ impl<R: BloxRuntime> ChildGroup<R> {
         }
     }
 
-    pub fn start_all(&self, from: ActorId) {
-        for entry in &self.children {
-            if entry
-                .lifecycle_ref
-                .try_send(from, LifecycleCommand::Start)
+    /// Start every child, in [`StartOrder`] and after its dependencies.
+    ///
+    /// Children whose turn has not come are queued and started by later
+    /// calls to [`start_ready`](Self::start_ready). Children whose task has
+    /// ended are skipped.
+    pub fn start_all(&mut self, from: ActorId) {
+        for entry in self.children.iter_mut().filter(|e| !e.task_ended) {
+            entry.queued = Some(QueuedStart::Start);
+        }
+        self.start_ready(from);
+    }
+
+    /// Restart every child for a new epoch of the whole group: `Reset` for
+    /// children that are operational, `Start` for children sitting in Init.
+    ///
+    /// Both commands are sent to every child. The engine ignores `Reset` in
+    /// Init and `Start` outside it, so each child restarts exactly once.
+    /// Children are restarted in the same order as by `start_all`.
+    ///
+    /// A child whose task has ended cannot be restarted. It is reported as
+    /// `Failed` on `notify`, and handling that report gives up on it as if
+    /// its restart budget were spent, so a nested supervisor escalates to
+    /// its parent instead of running without the child.
+    pub fn restart_all(&mut self, from: ActorId, notify: &ActorRef<ChildLifecycleEvent, R>) {
+        for entry in &mut self.children {
+            if !entry.task_ended {
+                entry.queued = Some(QueuedStart::Restart);
+                continue;
+            }
+            bloxide_log::blox_log_warn!(
+                from,
+                "child {} cannot be restarted: its task has ended",
+                entry.id
+            );
+            entry.permanently_done = false;
+            entry.phase = ChildPhase::Init;
+            let child_id = entry.id;
+            if notify
+                .try_send(from, ChildLifecycleEvent::Failed { child_id })
                 .is_err()
             {
                 bloxide_log::blox_log_warn!(
                     from,
-                    "try_send Start to child {} failed (channel full)",
-                    entry.id
+                    "try_send Failed to supervisor for child {} failed (channel full or closed)",
+                    child_id
                 );
             }
         }
+        self.start_ready(from);
     }
 
-    pub fn stop_all(&self, from: ActorId) {
-        for entry in &self.children {
+    /// Send the queued start or restart of every child whose turn has come:
+    /// all of its dependencies are `Running` and, under
+    /// [`StartOrder::Sequential`], no earlier child is still starting.
+    ///
+    /// Call after `handle_started`, since a child coming up can unblock
+    /// others. With a start timeout, a deadline is queued for each child
+    /// started.
+    pub fn start_ready(&mut self, from: ActorId) {
+        let mut earlier_starting = false;
+        for idx in 0..self.children.len() {
+            if let Some(queued) = self.children[idx].queued {
+                let blocked = (self.start_order == StartOrder::Sequential && earlier_starting)
+                    || !self.dependencies_up(idx);
+                if !blocked {
+                    let entry = &mut self.children[idx];
+                    entry.queued = None;
+                    entry.bump_start_epoch();
+                    for &cmd in queued.commands() {
+                        if entry.lifecycle_ref.try_send(from, cmd).is_err() {
+                            bloxide_log::blox_log_warn!(
+                                from,
+                                "try_send {:?} to child {} failed (channel full)",
+                                cmd,
+                                entry.id
+                            );
+                        }
+                    }
+                    if let Some(timeout_ms) = self.start_timeout_ms {
+                        self.start_deadlines
+                            .push((entry.id, entry.start_epoch, timeout_ms));
+                    }
+                }
+            }
+            if Self::is_starting(&self.children[idx]) {
+                earlier_starting = true;
+            }
+        }
+    }
+
+    /// Whether every child `idx` depends on is `Running`.
+    fn dependencies_up(&self, idx: usize) -> bool {
+        self.children[idx].depends_on.iter().all(|dep| {
+            self.children
+                .iter()
+                .any(|e| e.id == *dep && e.phase == ChildPhase::Running)
+        })
+    }
+
+    /// Queued, or sent `Start`/`Reset` and not yet `Started`.
+    fn is_starting(entry: &ChildEntry<R>) -> bool {
+        !entry.permanently_done
+            && !entry.stopped
+            && !entry.task_ended
+            && (entry.queued.is_some()
+                || matches!(entry.phase, ChildPhase::Init | ChildPhase::ResetPending))
+    }
+
+    /// Take the start deadlines queued by `start_ready`, as
+    /// `(child_id, epoch, timeout_ms)` triples. The caller schedules each
+    /// one and calls [`start_deadline_elapsed`](Self::start_deadline_elapsed)
+    /// with its `epoch` when it expires.
+    pub fn take_start_deadlines(&mut self) -> Vec<(ActorId, u32, u64)> {
+        core::mem::take(&mut self.start_deadlines)
+    }
+
+    /// Fail the group if child `child_id` still has not reported `Started`
+    /// for the start the deadline was armed for.
+    ///
+    /// Returns `BeginShutdown` and sets
+    /// [`start_timed_out`](Self::start_timed_out) in that case, `Continue`
+    /// if the child has started (or finished) since. A deadline from an
+    /// earlier `epoch` is stale: the child reported `Started` or was started
+    /// or reset again after it was armed, so it is ignored.
+    pub fn start_deadline_elapsed(
+        &mut self,
+        child_id: ActorId,
+        epoch: u32,
+        from: ActorId,
+    ) -> ChildAction {
+        let late = self.children.iter().any(|e| {
+            e.id == child_id && e.start_epoch == epoch && e.queued.is_none() && Self::is_starting(e)
+        });
+        if !late {
+            return ChildAction::Continue;
+        }
+        bloxide_log::blox_log_warn!(
+            from,
+            "child {} did not start within {} ms",
+            child_id,
+            self.start_timeout_ms.unwrap_or_default()
+        );
+        self.start_timed_out = true;
+        self.journal_decision(child_id, Decision::Shutdown, DecisionReason::StartTimeout);
+        ChildAction::BeginShutdown
+    }
+
+    /// Whether a child missed its start deadline. Cleared by
+    /// `clear_counters`.
+    ///
+    /// A nested supervisor escalates to its parent when this is set.
+    pub fn start_timed_out(&self) -> bool {
+        self.start_timed_out
+    }
+
+    /// Send `Stop` to every child that has not already stopped and whose
+    /// task is still running, last added first, so children are stopped in the reverse of their start order.
+    ///
+    /// With [`with_shutdown_timeouts`](Self::with_shutdown_timeouts), a stop
+    /// deadline is queued for every child that `all_stopped` waits for.
+    ///
+    /// A child still waiting for its first start is never sent one; it
+    /// counts as stopped at once.
+    pub fn stop_all(&mut self, from: ActorId) {
+        for entry in &mut self.children {
+            if entry.queued.take() == Some(QueuedStart::Start) && !entry.stopped {
+                entry.stopped = true;
+                entry.phase = ChildPhase::Stopped;
+                self.stopped_count += 1;
+            }
+            // Reported by `restart_all` and not yet given up on
+            if entry.task_ended && !entry.permanently_done && !entry.stopped {
+                entry.permanently_done = true;
+                entry.phase = ChildPhase::PermanentlyDone;
+            }
+        }
+        let now = (self.now_ms)();
+        for entry in self
+            .children
+            .iter_mut()
+            .rev()
+            .filter(|e| !e.stopped && !e.task_ended)
+        {
             if entry
                 .lifecycle_ref
                 .try_send(from, LifecycleCommand::Stop)
@@ -264,12 +953,156 @@ impl<R: BloxRuntime> ChildGroup<R> {
                     entry.id
                 );
             }
+            if !entry.permanently_done {
+                entry.shutdown_stage = ShutdownStage::Stopping;
+                if let Some(timeouts) = self.shutdown_timeouts {
+                    self.deadlines.push((entry.id, timeouts.stop_ms));
+                }
+            }
+            self.journal.push(
+                now,
+                JournalRecord::Decision {
+                    child_id: entry.id,
+                    decision: Decision::Stop,
+                    reason: DecisionReason::GroupStopping,
+                },
+            );
+        }
+    }
+
+    /// Take the shutdown deadlines queued by `stop_all` and by escalations,
+    /// as `(child_id, timeout_ms)` pairs. The caller schedules each one and
+    /// calls [`shutdown_deadline_elapsed`](Self::shutdown_deadline_elapsed)
+    /// when it expires.
+    pub fn take_deadlines(&mut self) -> Vec<(ActorId, u64)> {
+        core::mem::take(&mut self.deadlines)
+    }
+
+    /// Escalate the shutdown of a child that missed its deadline: a stopping
+    /// child is aborted if it has an abort mailbox, and a further deadline
+    /// is queued. Otherwise, or once an abort deadline passes, it is killed
+    /// if it has a kill handle and the runtime can kill (not under
+    /// `NoKill`), or abandoned. A killed or abandoned child is
+    /// marked permanently done, so `all_stopped` no longer waits for it.
+    ///
+    /// Each step is reported on `notify` as
+    /// `ChildLifecycleEvent::ShutdownEscalated`. Does nothing if the child
+    /// has since stopped, been aborted, or is not shutting down.
+    pub fn shutdown_deadline_elapsed(
+        &mut self,
+        child_id: ActorId,
+        from: ActorId,
+        notify: &ActorRef<ChildLifecycleEvent, R>,
+    ) {
+        let Some(idx) = self
+            .children
+            .iter()
+            .position(|e| e.id == child_id && !e.stopped && !e.permanently_done)
+        else {
+            return;
+        };
+        let entry = &self.children[idx];
+        let to = match entry.shutdown_stage {
+            ShutdownStage::Idle => return,
+            ShutdownStage::Stopping if entry.abort_ref.is_some() => ShutdownEscalation::Abort,
+            _ if entry.can_kill() => ShutdownEscalation::Kill,
+            _ => ShutdownEscalation::Abandon,
+        };
+        if notify
+            .try_send(
+                from,
+                ChildLifecycleEvent::ShutdownEscalated { child_id, to },
+            )
+            .is_err()
+        {
+            bloxide_log::blox_log_warn!(
+                from,
+                "try_send ShutdownEscalated to supervisor for child {} failed (channel full or closed)",
+                child_id
+            );
+        }
+        let decision = match to {
+            ShutdownEscalation::Abort => Decision::Abort,
+            ShutdownEscalation::Kill => Decision::Kill,
+            ShutdownEscalation::Abandon => Decision::Abandon,
+        };
+        self.journal_decision(child_id, decision, DecisionReason::ShutdownDeadline);
+        match to {
+            ShutdownEscalation::Abort => {
+                // Unlike ChildPolicy::Abort, wait for the Aborted report.
+                self.send_abort(idx, from);
+                self.children[idx].shutdown_stage = ShutdownStage::Aborting;
+                if let Some(timeouts) = self.shutdown_timeouts {
+                    self.deadlines.push((child_id, timeouts.abort_ms));
+                }
+            }
+            ShutdownEscalation::Kill => self.kill_entry(idx, from, notify),
+            ShutdownEscalation::Abandon => {
+                bloxide_log::blox_log_warn!(
+                    from,
+                    "child {} missed its shutdown deadline and cannot be aborted or killed; abandoning it",
+                    child_id
+                );
+                let entry = &mut self.children[idx];
+                entry.permanently_done = true;
+                entry.phase = ChildPhase::PermanentlyDone;
+                entry.awaiting_alive = false;
+            }
+        }
+    }
+
+    /// Escalate every child still shutting down by one step, as if each had
+    /// missed its deadline (see
+    /// [`shutdown_deadline_elapsed`](Self::shutdown_deadline_elapsed)).
+    ///
+    /// For a caller that bounds the whole shutdown from outside, such as a
+    /// system shutdown whose deadline expired; works without shutdown
+    /// timeouts or a timer.
+    pub fn escalate_all_shutdowns(
+        &mut self,
+        from: ActorId,
+        notify: &ActorRef<ChildLifecycleEvent, R>,
+    ) {
+        let stopping: Vec<ActorId> = self
+            .children
+            .iter()
+            .filter(|e| e.shutdown_stage != ShutdownStage::Idle)
+            .map(|e| e.id)
+            .collect();
+        for child_id in stopping {
+            self.shutdown_deadline_elapsed(child_id, from, notify);
         }
     }
 
-    pub fn handle_done_or_failed(
+    /// Handle a child that finished normally (`ChildLifecycleEvent::Done`).
+    /// Only `Permanent` children are restarted.
+    pub fn handle_done(
+        &mut self,
+        child_id: ActorId,
+        from: ActorId,
+        notify: &ActorRef<ChildLifecycleEvent, R>,
+    ) -> ChildAction {
+        self.handle_exit(child_id, DecisionReason::Done, from, notify)
+    }
+
+    /// Handle a child that crashed (`ChildLifecycleEvent::Failed`, or a
+    /// missed health check). `Permanent` and `Transient` children are
+    /// restarted.
+    pub fn handle_failed(
+        &mut self,
+        child_id: ActorId,
+        from: ActorId,
+        notify: &ActorRef<ChildLifecycleEvent, R>,
+    ) -> ChildAction {
+        self.handle_exit(child_id, DecisionReason::Failed, from, notify)
+    }
+
+    /// `cause` is `Done`, `Failed`, `MissedAlive` or `UndeliveredPings`;
+    /// all but `Done` count as failures.
+    fn handle_exit(
         &mut self,
         child_id: ActorId,
+        cause: DecisionReason,
         from: ActorId,
         notify: &ActorRef<ChildLifecycleEvent, R>,
     ) -> ChildAction {
@@ -279,125 +1112,462 @@ impl<R: BloxRuntime> ChildGroup<R> {
         };
 
         // Extract values needed for decision-making to avoid borrow conflicts
-        let (phase, policy, restarts) = {
+        let (phase, policy, restarts, may_restart) = {
             let entry = &self.children[idx];
-            (entry.phase, entry.policy, entry.restarts)
+            (
+                entry.phase,
+                entry.policy,
+                entry.restarts,
+                entry
+                    .restart_type
+                    .restarts_on(cause != DecisionReason::Done),
+            )
         };
 
         if matches!(
             phase,
-            ChildPhase::PermanentlyDone | ChildPhase::Stopped | ChildPhase::ResetPending
+            ChildPhase::PermanentlyDone
+                | ChildPhase::Stopped
+                | ChildPhase::ResetPending
+                | ChildPhase::BackingOff
         ) {
             return ChildAction::Continue;
         }
 
-        // Handle Kill policy: call R::Kill::kill(kill_handle) — the ripcord.
-        // This immediately terminates the child — no callbacks fire, no
-        // cooperative shutdown. Permanently dead.
-        if policy == ChildPolicy::Kill {
-            // Take the kill_handle out — kill() consumes it by value.
-            let kill_handle = self.children[idx].kill_handle.take();
-            if let Some(handle) = kill_handle {
-                R::Kill::kill(handle);
-            }
-
-            // Emit the Killed lifecycle event so the supervisor (and any
-            // observers on the notify channel) learn the child was killed.
-            // This is analogous to how Abort sends AbortCommand on the abort
-            // mailbox and the run loop later reports Aborted — except here the
-            // kill is synchronous, so we emit the event directly.
-            if notify
-                .try_send(from, ChildLifecycleEvent::Killed { child_id })
-                .is_err()
-            {
-                bloxide_log::blox_log_warn!(
-                    from,
-                    "try_send Killed to supervisor for child {} failed (channel full or closed)",
-                    child_id
-                );
-            }
+        // Reported by `restart_all`: the task is gone, so give up on the
+        // child as if its restart budget were spent.
+        if self.children[idx].task_ended {
+            self.exhausted = true;
+            let entry = &mut self.children[idx];
+            entry.permanently_done = true;
+            entry.phase = ChildPhase::PermanentlyDone;
+            entry.awaiting_alive = false;
+            self.journal_decision(child_id, Decision::GiveUp, DecisionReason::TaskEnded);
+            return self.check_shutdown_after(child_id, DecisionReason::TaskEnded);
+        }
 
-            self.children[idx].permanently_done = true;
-            self.children[idx].phase = ChildPhase::PermanentlyDone;
-            self.children[idx].awaiting_alive = false;
-            return self.check_shutdown();
+        // Handle Kill policy: call R::Kill::kill(kill_handle) — the ripcord.
+        // This immediately terminates the child — no callbacks fire, no
+        // cooperative shutdown. Permanently dead.
+        if policy == ChildPolicy::Kill {
+            self.journal_decision(child_id, Decision::Kill, cause);
+            self.kill_entry(idx, from, notify);
+            return self.check_shutdown_after(child_id, cause);
         }
 
         // Handle Abort policy: send AbortCommand on the abort mailbox.
         // The child's task self-terminates cooperatively (no callbacks).
         if policy == ChildPolicy::Abort {
-            if let Some(abort_ref) = &self.children[idx].abort_ref {
-                if abort_ref
-                    .try_send(from, AbortCommand::Abort { child_id })
-                    .is_err()
-                {
-                    bloxide_log::blox_log_warn!(
-                        from,
-                        "try_send AbortCommand::Abort to child {} failed (channel full)",
-                        self.children[idx].id
-                    );
+            self.journal_decision(child_id, Decision::Abort, cause);
+            self.abort_entry(idx, from);
+            return self.check_shutdown_after(child_id, cause);
+        }
+
+        let now = (self.now_ms)();
+        let admitted = match policy {
+            _ if !may_restart => None,
+            ChildPolicy::Restart { max, backoff } => {
+                (restarts < max).then_some((restarts, backoff))
+            }
+            ChildPolicy::RestartWithin { backoff, .. } => self.children[idx]
+                .window
+                .as_mut()
+                .and_then(|window| window.admits(now).then_some((window.times.len(), backoff))),
+            _ => None,
+        };
+        // `max: 0` is documented as `Stop`, so only a spent budget counts.
+        let restartable = may_restart
+            && matches!(
+                policy,
+                ChildPolicy::Restart { max, .. } | ChildPolicy::RestartWithin { max, .. } if max > 0
+            );
+        if let Some((attempt, backoff)) = admitted {
+            let group_admits = self.window.as_mut().is_none_or(|window| window.admits(now));
+            if group_admits {
+                self.children[idx].restarts += 1;
+                if let Some(window) = self.children[idx].window.as_mut() {
+                    window.record(now);
+                }
+                if let Some(window) = self.window.as_mut() {
+                    window.record(now);
                 }
+                self.children[idx].awaiting_alive = false;
+
+                let (delay_ms, jitter_ms) = backoff.delay(attempt);
+                let delay_ms = delay_ms.saturating_add(self.next_jitter(jitter_ms));
+                if delay_ms > 0 {
+                    // The managing blox schedules the Reset; see restart_after_backoff.
+                    self.journal_decision(child_id, Decision::BackOff { delay_ms }, cause);
+                    let entry = &mut self.children[idx];
+                    entry.phase = ChildPhase::BackingOff;
+                    entry.backoff_generation = entry.backoff_generation.wrapping_add(1);
+                    self.backoffs
+                        .push((child_id, entry.backoff_generation, delay_ms));
+                } else {
+                    self.journal_decision(child_id, Decision::Reset, cause);
+                    self.reset_child(idx, from, now);
+                }
+                return ChildAction::Continue;
+            }
+        }
+        let reason = if !may_restart {
+            DecisionReason::NotRestartable
+        } else if admitted.is_some() {
+            DecisionReason::IntensityExceeded
+        } else if restartable {
+            DecisionReason::RestartsExhausted
+        } else {
+            cause
+        };
+        if restartable {
+            self.exhausted = true;
+        }
+
+        self.children[idx].permanently_done = true;
+        self.children[idx].phase = ChildPhase::PermanentlyDone;
+        self.children[idx].awaiting_alive = false;
+        self.journal_decision(child_id, Decision::GiveUp, reason);
+
+        self.check_shutdown_after(child_id, reason)
+    }
+
+    /// Send `Reset` to a child whose backoff has elapsed, and apply the
+    /// restart strategy to its siblings.
+    ///
+    /// Does nothing unless the child is still in [`ChildPhase::BackingOff`]
+    /// for the backoff `generation` names, so a late timer for a child that
+    /// has since been stopped, reset with the group, or restarted and
+    /// parked in a newer backoff is ignored.
+    pub fn restart_after_backoff(&mut self, child_id: ActorId, generation: u32, from: ActorId) {
+        if let Some(idx) = self.children.iter().position(|e| {
+            e.id == child_id
+                && e.phase == ChildPhase::BackingOff
+                && e.backoff_generation == generation
+        }) {
+            let now = (self.now_ms)();
+            self.journal_decision(child_id, Decision::Reset, DecisionReason::BackoffElapsed);
+            self.reset_child(idx, from, now);
+        }
+    }
+
+    /// Take the restarts queued by `handle_done`/`handle_failed` that wait out a
+    /// backoff, as `(child_id, generation, delay_ms)` triples. The caller
+    /// schedules each one and calls
+    /// [`restart_after_backoff`](Self::restart_after_backoff) with its
+    /// `generation` when its delay elapses.
+    pub fn take_backoffs(&mut self) -> Vec<(ActorId, u32, u64)> {
+        core::mem::take(&mut self.backoffs)
+    }
+
+    /// Whether the group gave up on a child because its restart budget, or
+    /// the group's restart intensity, ran out, or because `restart_all`
+    /// found its task ended. Cleared by `clear_counters`.
+    ///
+    /// A nested supervisor escalates to its parent when this is set.
+    pub fn restarts_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// A snapshot of every child, in the order they were added.
+    pub fn which_children(&self) -> Vec<ChildInfo> {
+        self.children
+            .iter()
+            .map(|e| ChildInfo {
+                id: e.id,
+                phase: e.phase,
+                policy: e.policy,
+                restart_type: e.restart_type,
+                restarts: e.restarts,
+                awaiting_alive: e.awaiting_alive,
+                last_alive_ms: e.last_alive_ms,
+                last_ping_ms: e.last_ping_ms,
+                liveness: e.liveness,
+                missed_alive: e.missed_alive,
+                undelivered_pings: e.undelivered_pings,
+                dynamic: e.abort_ref.is_some(),
+            })
+            .collect()
+    }
+
+    /// How many children are in each phase.
+    pub fn counts(&self) -> ChildCounts {
+        let mut counts = ChildCounts {
+            total: self.children.len(),
+            ..ChildCounts::default()
+        };
+        for entry in &self.children {
+            counts.restarts += entry.restarts;
+            match entry.phase {
+                ChildPhase::Init => counts.init += 1,
+                ChildPhase::Running => counts.running += 1,
+                ChildPhase::BackingOff => counts.backing_off += 1,
+                ChildPhase::ResetPending => counts.reset_pending += 1,
+                ChildPhase::Stopped => counts.stopped += 1,
+                ChildPhase::PermanentlyDone => counts.permanently_done += 1,
             }
-            // The child will self-terminate; we'll get Aborted event later.
-            //
-            // The child is marked PermanentlyDone immediately because the abort
-            // is fire-and-forget: once AbortCommand is queued on the abort
-            // mailbox there is no way to recall or observe its progress from
-            // here, so the ChildGroup's bookkeeping for this entry is already
-            // final. The Aborted lifecycle event will arrive later but is
-            // informational only — the ChildGroup state is already finalized
-            // (phase == PermanentlyDone), so `handle_aborted`/this method's
-            // early-return guard treat the late event as a no-op. The
-            // supervisor's state machine processes the Aborted event for its
-            // own transitions but does not re-enter the ChildGroup logic.
-            self.children[idx].permanently_done = true;
-            self.children[idx].phase = ChildPhase::PermanentlyDone;
-            self.children[idx].awaiting_alive = false;
-            return self.check_shutdown();
-        }
-
-        if let ChildPolicy::Restart { max } = policy {
-            if restarts < max {
-                // Send Reset to the failed child — goes directly to initial_state(),
-                // immediately operational. No need to send Start separately.
+        }
+        counts
+    }
+
+    /// The phase of child `child_id`, or `None` if it is not in the group.
+    pub fn phase(&self, child_id: ActorId) -> Option<ChildPhase> {
+        self.children
+            .iter()
+            .find(|e| e.id == child_id)
+            .map(|e| e.phase)
+    }
+
+    /// Terminate child `child_id` on request of the managing blox rather
+    /// than in response to a failure. The child is not restarted, and
+    /// termination never applies the restart strategy to its siblings.
+    ///
+    /// `Stop` marks the child [`ChildPhase::Stopped`] at once; its `Stopped`
+    /// report later completes the bookkeeping. `Abort` and `Kill` mark it
+    /// [`ChildPhase::PermanentlyDone`], as the matching policies do. A child
+    /// that is already stopped or permanently done is left alone.
+    ///
+    /// Returns `None` if the child is unknown, or if `how` needs an abort
+    /// mailbox or kill handle the child does not have. `Kill` also returns
+    /// `None` under `NoKill`, where killing does nothing. Otherwise returns
+    /// the group action: under `GroupShutdown::WhenAllDone` terminating the
+    /// last active child begins shutdown, while `WhenAnyDone` groups keep
+    /// running, since an operator stopping one child is not a child finishing.
+    pub fn terminate_child(
+        &mut self,
+        child_id: ActorId,
+        how: Termination,
+        from: ActorId,
+        notify: &ActorRef<ChildLifecycleEvent, R>,
+    ) -> Option<ChildAction> {
+        let idx = self.children.iter().position(|e| e.id == child_id)?;
+        let entry = &self.children[idx];
+        let available = match how {
+            Termination::Stop => true,
+            Termination::Abort => entry.abort_ref.is_some(),
+            Termination::Kill => entry.can_kill(),
+        };
+        if !available {
+            return None;
+        }
+        if matches!(
+            entry.phase,
+            ChildPhase::PermanentlyDone | ChildPhase::Stopped
+        ) {
+            return Some(ChildAction::Continue);
+        }
+
+        self.backoffs.retain(|&(id, ..)| id != child_id);
+        let decision = match how {
+            Termination::Stop => Decision::Stop,
+            Termination::Abort => Decision::Abort,
+            Termination::Kill => Decision::Kill,
+        };
+        self.journal_decision(child_id, decision, DecisionReason::Operator);
+        match how {
+            Termination::Stop => {
                 if self.children[idx]
                     .lifecycle_ref
-                    .try_send(from, LifecycleCommand::Reset)
+                    .try_send(from, LifecycleCommand::Stop)
                     .is_err()
                 {
                     bloxide_log::blox_log_warn!(
                         from,
-                        "try_send Reset to child {} failed (channel full)",
-                        self.children[idx].id
+                        "try_send Stop to child {} failed (channel full)",
+                        child_id
                     );
                 }
-                self.children[idx].restarts += 1;
-                self.children[idx].phase = ChildPhase::ResetPending;
+                self.children[idx].phase = ChildPhase::Stopped;
                 self.children[idx].awaiting_alive = false;
+            }
+            Termination::Abort => self.abort_entry(idx, from),
+            Termination::Kill => self.kill_entry(idx, from, notify),
+        }
 
-                // Apply restart strategy to other children
-                self.restart_siblings(idx, from);
+        Some(match self.shutdown {
+            GroupShutdown::WhenAllDone => {
+                self.check_shutdown_after(child_id, DecisionReason::Operator)
+            }
+            GroupShutdown::WhenAnyDone => ChildAction::Continue,
+        })
+    }
 
-                return ChildAction::Continue;
+    /// Restart child `child_id` on request of the managing blox by sending
+    /// `Reset` then `Start`. The engine ignores the command that does not
+    /// apply, so a running child resets and one that finished or failed
+    /// starts over.
+    ///
+    /// The restart is not counted against the child's budget and does not
+    /// restart siblings. A pending backoff for the child is dropped.
+    ///
+    /// Returns `false` if the child is unknown, or was stopped, aborted or
+    /// killed: every runtime ends a child's task once it stops, so such a
+    /// child has to be respawned, deleted and registered again.
+    pub fn restart_child(&mut self, child_id: ActorId, from: ActorId) -> bool {
+        let Some(idx) = self
+            .children
+            .iter()
+            .position(|e| e.id == child_id && !e.task_ended && e.phase != ChildPhase::Stopped)
+        else {
+            return false;
+        };
+        let entry = &mut self.children[idx];
+        for command in [LifecycleCommand::Reset, LifecycleCommand::Start] {
+            if entry.lifecycle_ref.try_send(from, command).is_err() {
+                bloxide_log::blox_log_warn!(
+                    from,
+                    "try_send restart command to child {} failed (channel full)",
+                    child_id
+                );
             }
         }
+        entry.permanently_done = false;
+        entry.phase = ChildPhase::ResetPending;
+        entry.awaiting_alive = false;
+        entry.shutdown_stage = ShutdownStage::Idle;
+        entry.bump_start_epoch();
+        self.backoffs.retain(|&(id, ..)| id != child_id);
+        self.journal_decision(child_id, Decision::Reset, DecisionReason::Operator);
+        true
+    }
 
-        self.children[idx].permanently_done = true;
-        self.children[idx].phase = ChildPhase::PermanentlyDone;
+    /// Remove child `child_id` from the group. Only a child that has
+    /// stopped or is permanently done can be deleted; terminate it first.
+    ///
+    /// Returns `false` if the child is unknown or still active.
+    pub fn delete_child(&mut self, child_id: ActorId) -> bool {
+        let Some(idx) = self
+            .children
+            .iter()
+            .position(|e| e.id == child_id && (e.stopped || e.permanently_done))
+        else {
+            return false;
+        };
+        if self.children.remove(idx).stopped {
+            self.stopped_count -= 1;
+        }
+        self.backoffs.retain(|&(id, ..)| id != child_id);
+        self.journal_decision(child_id, Decision::Delete, DecisionReason::Operator);
+        true
+    }
+
+    /// Send `AbortCommand` on the abort mailbox of the child at `idx`. The
+    /// child's task self-terminates cooperatively (no callbacks).
+    fn abort_entry(&mut self, idx: usize, from: ActorId) {
+        self.send_abort(idx, from);
+        // The child will self-terminate; we'll get Aborted event later.
+        //
+        // The child is marked PermanentlyDone immediately because the abort
+        // is fire-and-forget: once AbortCommand is queued on the abort
+        // mailbox there is no way to recall or observe its progress from
+        // here, so the ChildGroup's bookkeeping for this entry is already
+        // final. The Aborted lifecycle event will arrive later but is
+        // informational only — the ChildGroup state is already finalized
+        // (phase == PermanentlyDone), so `handle_exit`'s
+        // early-return guard treat the late event as a no-op. The
+        // supervisor's state machine processes the Aborted event for its
+        // own transitions but does not re-enter the ChildGroup logic.
+        let entry = &mut self.children[idx];
+        entry.permanently_done = true;
+        entry.phase = ChildPhase::PermanentlyDone;
+        entry.awaiting_alive = false;
+        entry.task_ended = true;
+    }
+
+    fn send_abort(&self, idx: usize, from: ActorId) {
+        let child_id = self.children[idx].id;
+        if let Some(abort_ref) = &self.children[idx].abort_ref {
+            if abort_ref
+                .try_send(from, AbortCommand::Abort { child_id })
+                .is_err()
+            {
+                bloxide_log::blox_log_warn!(
+                    from,
+                    "try_send AbortCommand::Abort to child {} failed (channel full)",
+                    child_id
+                );
+            }
+        }
+    }
+
+    /// Call `R::Kill::kill(kill_handle)` on the child at `idx` — the ripcord.
+    /// This immediately terminates the child — no callbacks fire, no
+    /// cooperative shutdown. Permanently dead.
+    fn kill_entry(&mut self, idx: usize, from: ActorId, notify: &ActorRef<ChildLifecycleEvent, R>) {
+        let child_id = self.children[idx].id;
+        // Take the kill_handle out — kill() consumes it by value.
+        if let Some(handle) = self.children[idx].kill_handle.take() {
+            R::Kill::kill(handle);
+        }
+
+        // Emit the Killed lifecycle event so the supervisor (and any
+        // observers on the notify channel) learn the child was killed.
+        // This is analogous to how Abort sends AbortCommand on the abort
+        // mailbox and the run loop later reports Aborted — except here the
+        // kill is synchronous, so we emit the event directly.
+        if notify
+            .try_send(from, ChildLifecycleEvent::Killed { child_id })
+            .is_err()
+        {
+            bloxide_log::blox_log_warn!(
+                from,
+                "try_send Killed to supervisor for child {} failed (channel full or closed)",
+                child_id
+            );
+        }
+
+        let entry = &mut self.children[idx];
+        entry.permanently_done = true;
+        entry.phase = ChildPhase::PermanentlyDone;
+        entry.awaiting_alive = false;
+        entry.task_ended = true;
+    }
+
+    /// Send `Reset` to the child at `idx` — it goes directly to
+    /// `initial_state()`, immediately operational, so no separate `Start` is
+    /// needed — then restart its siblings per the restart strategy.
+    fn reset_child(&mut self, idx: usize, from: ActorId, now: u64) {
+        if self.children[idx]
+            .lifecycle_ref
+            .try_send(from, LifecycleCommand::Reset)
+            .is_err()
+        {
+            bloxide_log::blox_log_warn!(
+                from,
+                "try_send Reset to child {} failed (channel full)",
+                self.children[idx].id
+            );
+        }
+        self.children[idx].phase = ChildPhase::ResetPending;
         self.children[idx].awaiting_alive = false;
+        self.children[idx].bump_start_epoch();
 
-        self.check_shutdown()
+        // Apply restart strategy to other children
+        self.restart_siblings(idx, from, now);
+    }
+
+    /// Uniform pseudo-random jitter in `0..=bound` milliseconds.
+    fn next_jitter(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return 0;
+        }
+        let mut x = self.jitter;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.jitter = x;
+        x % bound.saturating_add(1)
     }
 
     /// Send Reset to sibling children based on the restart strategy.
     ///
     /// - `OneForOne`: no siblings are restarted (only the failed child).
     /// - `OneForAll`: all other active children are restarted.
-    /// - `RestForOne`: all children declared after the failed child are restarted.
+    /// - `RestForOne`: all children declared after the failed child are
+    ///   restarted, in start order (see [`start_ready`](Self::start_ready)).
     ///
     /// Only children in `Init` or `Running` phase are restarted. Children that
     /// are `PermanentlyDone` or `Stopped` are skipped.
-    fn restart_siblings(&mut self, failed_idx: usize, from: ActorId) {
+    fn restart_siblings(&mut self, failed_idx: usize, from: ActorId, now: u64) {
         let strategy = self.restart_strategy;
         if strategy == RestartStrategy::OneForOne {
             return;
@@ -420,7 +1590,13 @@ impl<R: BloxRuntime> ChildGroup<R> {
             ) {
                 continue;
             }
-            if self.children[i]
+            if strategy == RestartStrategy::RestForOne {
+                // Restart in start order: each waits for the children it
+                // depends on (and, if sequential, those before it).
+                let entry = &mut self.children[i];
+                entry.queued = Some(QueuedStart::Reset);
+                entry.phase = ChildPhase::ResetPending;
+            } else if self.children[i]
                 .lifecycle_ref
                 .try_send(from, LifecycleCommand::Reset)
                 .is_err()
@@ -432,7 +1608,20 @@ impl<R: BloxRuntime> ChildGroup<R> {
                 );
             }
             self.children[i].restarts += 1;
+            if let Some(window) = self.children[i].window.as_mut() {
+                window.record(now);
+            }
             self.children[i].awaiting_alive = false;
+            self.children[i].bump_start_epoch();
+            let (child_id, failed) = (self.children[i].id, self.children[failed_idx].id);
+            self.journal_decision(
+                child_id,
+                Decision::Reset,
+                DecisionReason::Sibling { failed },
+            );
+        }
+        if strategy == RestartStrategy::RestForOne {
+            self.start_ready(from);
         }
     }
 
@@ -440,10 +1629,12 @@ impl<R: BloxRuntime> ChildGroup<R> {
         match self.shutdown {
             GroupShutdown::WhenAnyDone => ChildAction::BeginShutdown,
             GroupShutdown::WhenAllDone => {
+                // A child terminated with `Stop` is finished before its
+                // `Stopped` report arrives.
                 if self
                     .children
                     .iter()
-                    .all(|e| e.permanently_done || e.stopped)
+                    .all(|e| e.permanently_done || e.stopped || e.phase == ChildPhase::Stopped)
                 {
                     ChildAction::BeginShutdown
                 } else {
@@ -461,65 +1652,111 @@ impl<R: BloxRuntime> ChildGroup<R> {
     /// self-contained.
     pub fn handle_started(&mut self, child_id: ActorId) {
         if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
+            entry.bump_start_epoch();
             if !matches!(
                 entry.phase,
                 ChildPhase::PermanentlyDone | ChildPhase::Stopped
             ) {
                 entry.phase = ChildPhase::Running;
                 entry.awaiting_alive = false;
+                entry.missed_alive = 0;
+                entry.undelivered_pings = 0;
             }
         }
     }
 
     pub fn handle_alive(&mut self, child_id: ActorId) {
+        let now = (self.now_ms)();
         if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
             if !matches!(
                 entry.phase,
                 ChildPhase::PermanentlyDone | ChildPhase::Stopped
             ) {
                 entry.awaiting_alive = false;
+                entry.missed_alive = 0;
+                entry.last_alive_ms = Some(now);
             }
         }
     }
 
+    /// Run one health-check round.
+    ///
+    /// Each monitored child that is due a ping (see [`Liveness`]) is checked
+    /// first: an unanswered previous ping counts as missed, and a child that
+    /// reached either threshold set by
+    /// [`with_health_thresholds`](Self::with_health_thresholds) is treated
+    /// as failed (`handle_failed`). The remaining due children are pinged.
     pub fn health_check_tick(
         &mut self,
         from: ActorId,
         notify: &ActorRef<ChildLifecycleEvent, R>,
     ) -> ChildAction {
-        let stale_ids: Vec<ActorId> = self
-            .children
-            .iter()
-            .filter(|entry| Self::is_health_monitored(entry) && entry.awaiting_alive)
-            .map(|entry| entry.id)
-            .collect();
+        let now = (self.now_ms)();
+        let mut stale_ids = Vec::new();
+        let mut due_ids = Vec::new();
+        for entry in &mut self.children {
+            if !Self::is_health_monitored(entry) {
+                entry.awaiting_alive = false;
+                continue;
+            }
+            let due = match entry.liveness {
+                Liveness::EveryTick => true,
+                Liveness::Every { interval_ms } => entry
+                    .last_ping_ms
+                    .is_none_or(|last| now.saturating_sub(last) >= interval_ms),
+                Liveness::Disabled => false,
+            };
+            if !due {
+                continue;
+            }
+            if core::mem::take(&mut entry.awaiting_alive) {
+                entry.missed_alive += 1;
+            }
+            let undeliverable =
+                self.max_undelivered > 0 && entry.undelivered_pings >= self.max_undelivered;
+            if entry.missed_alive >= self.max_missed || undeliverable {
+                entry.missed_alive = 0;
+                entry.undelivered_pings = 0;
+                let cause = if undeliverable {
+                    DecisionReason::UndeliveredPings
+                } else {
+                    DecisionReason::MissedAlive
+                };
+                stale_ids.push((entry.id, cause));
+            } else {
+                due_ids.push(entry.id);
+            }
+        }
 
         let mut action = ChildAction::Continue;
-        for child_id in stale_ids {
-            if self.handle_done_or_failed(child_id, from, notify) == ChildAction::BeginShutdown {
+        for (child_id, cause) in stale_ids {
+            if self.handle_exit(child_id, cause, from, notify) == ChildAction::BeginShutdown {
                 action = ChildAction::BeginShutdown;
             }
         }
 
-        // Ping all health-monitored children. ResetPending children are
-        // excluded by is_health_monitored — they're transitioning and will
-        // be pinged again once they report Started (moving to Running).
+        // Ping the due children. A failure above may have moved a sibling
+        // out of monitoring (e.g. a Kill), so check again.
         for entry in &mut self.children {
-            if Self::is_health_monitored(entry) {
-                if entry
-                    .lifecycle_ref
-                    .try_send(from, LifecycleCommand::Ping)
-                    .is_err()
-                {
-                    bloxide_log::blox_log_warn!(
-                        from,
-                        "try_send Ping to child {} failed (channel full)",
-                        entry.id
-                    );
-                }
-                entry.awaiting_alive = true;
+            if !due_ids.contains(&entry.id) || !Self::is_health_monitored(entry) {
+                continue;
+            }
+            entry.last_ping_ms = Some(now);
+            if entry
+                .lifecycle_ref
+                .try_send(from, LifecycleCommand::Ping)
+                .is_err()
+            {
+                // No reply can come, so this is not a missed Alive.
+                entry.undelivered_pings += 1;
+                bloxide_log::blox_log_warn!(
+                    from,
+                    "try_send Ping to child {} failed (channel full)",
+                    entry.id
+                );
             } else {
-                entry.awaiting_alive = false;
+                entry.undelivered_pings = 0;
+                entry.awaiting_alive = true;
             }
         }
 
@@ -529,18 +1766,24 @@ impl<R: BloxRuntime> ChildGroup<R> {
     fn is_health_monitored(entry: &ChildEntry<R>) -> bool {
         !entry.permanently_done
             && !entry.stopped
+            && !entry.task_ended
+            && entry.liveness != Liveness::Disabled
             && !matches!(
                 entry.phase,
-                ChildPhase::PermanentlyDone | ChildPhase::ResetPending
+                ChildPhase::PermanentlyDone | ChildPhase::ResetPending | ChildPhase::BackingOff
             )
     }
 
+    /// Record that a child reported `Stopped`. The supervised run loops of
+    /// every runtime end the child's task once it stops, so, as after an
+    /// abort, only a respawn brings the child back.
     pub fn record_stopped(&mut self, child_id: ActorId) {
         if let Some(entry) = self.children.iter_mut().find(|e| e.id == child_id) {
             if !entry.stopped {
                 entry.stopped = true;
                 entry.phase = ChildPhase::Stopped;
                 entry.awaiting_alive = false;
+                entry.task_ended = true;
                 self.stopped_count += 1;
             }
         }
@@ -555,6 +1798,7 @@ impl<R: BloxRuntime> ChildGroup<R> {
             entry.permanently_done = true;
             entry.phase = ChildPhase::PermanentlyDone;
             entry.awaiting_alive = false;
+            entry.task_ended = true;
         }
     }
 
@@ -565,6 +1809,7 @@ impl<R: BloxRuntime> ChildGroup<R> {
             entry.permanently_done = true;
             entry.phase = ChildPhase::PermanentlyDone;
             entry.awaiting_alive = false;
+            entry.task_ended = true;
         }
     }
 
@@ -583,22 +1828,44 @@ impl<R: BloxRuntime> ChildGroup<R> {
     /// this reset may be delivered to children after the next `start_all`.
     /// Callers must ensure child tasks have consumed all previously queued
     /// commands before calling `clear_counters`.
+    ///
+    /// A child whose task has ended stays permanently done: nothing but a
+    /// respawn brings it back.
     pub fn clear_counters(&mut self) {
         for entry in &mut self.children {
             entry.restarts = 0;
-            entry.permanently_done = false;
+            if let Some(window) = entry.window.as_mut() {
+                window.times.clear();
+            }
+            entry.permanently_done = entry.task_ended;
             entry.stopped = false;
-            entry.phase = ChildPhase::Init;
+            entry.phase = if entry.task_ended {
+                ChildPhase::PermanentlyDone
+            } else {
+                ChildPhase::Init
+            };
             entry.awaiting_alive = false;
+            entry.missed_alive = 0;
+            entry.undelivered_pings = 0;
+            entry.shutdown_stage = ShutdownStage::Idle;
+            entry.queued = None;
         }
         self.stopped_count = 0;
+        self.backoffs.clear();
+        self.deadlines.clear();
+        self.start_deadlines.clear();
+        self.exhausted = false;
+        self.start_timed_out = false;
+        if let Some(window) = self.window.as_mut() {
+            window.times.clear();
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bloxide_core::capability::DynamicChannelCap;
+    use bloxide_core::capability::{ClockCap, DynamicChannelCap};
     use bloxide_test_runtime::{TestReceiver, TestRuntime};
 
     fn setup_one_child(
@@ -619,25 +1886,105 @@ mod tests {
 
     #[test]
     fn duplicate_done_while_awaiting_restart_is_coalesced() {
-        let (mut group, mut rx, notify_ref, _notify_rx) =
-            setup_one_child(ChildPolicy::Restart { max: 2 });
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 2,
+            backoff: Backoff::Immediate,
+        });
         let from = 100usize;
 
         // First Done → triggers Reset
-        let action = group.handle_done_or_failed(1, from, &notify_ref);
+        let action = group.handle_done(1, from, &notify_ref);
         assert_eq!(action, ChildAction::Continue);
         assert_eq!(rx.drain_payloads().len(), 1); // Reset sent
 
         // Second Done while ResetPending → coalesced (no second Reset)
-        let action = group.handle_done_or_failed(1, from, &notify_ref);
+        let action = group.handle_done(1, from, &notify_ref);
         assert_eq!(action, ChildAction::Continue);
         assert_eq!(rx.drain_payloads().len(), 0); // nothing sent
     }
 
+    #[test]
+    fn spent_restart_budget_is_reported_until_counters_clear() {
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        });
+        let from = 100usize;
+
+        group.handle_done(1, from, &notify_ref);
+        group.handle_started(1);
+        assert!(!group.restarts_exhausted());
+
+        let action = group.handle_done(1, from, &notify_ref);
+        assert_eq!(action, ChildAction::BeginShutdown);
+        assert!(group.restarts_exhausted());
+
+        group.clear_counters();
+        assert!(!group.restarts_exhausted());
+
+        // A new epoch resets running children and starts stopped ones.
+        rx.drain_payloads();
+        group.restart_all(from, &notify_ref);
+        assert_eq!(
+            rx.drain_payloads(),
+            [LifecycleCommand::Reset, LifecycleCommand::Start]
+        );
+    }
+
+    #[test]
+    fn which_children_and_counts_reflect_phases_and_restarts() {
+        let (mut group, _rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 2,
+            backoff: Backoff::Immediate,
+        });
+        let (lifecycle_ref, _rx2) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        group.add(2, lifecycle_ref, ChildPolicy::Stop);
+        let mut group = group.with_clock(TestRuntime::now_ms);
+        let from = 100usize;
+
+        group.handle_started(1);
+        group.handle_started(2);
+        TestRuntime::advance_clock(40);
+        group.handle_alive(2);
+        group.handle_done(1, from, &notify_ref);
+
+        let children = group.which_children();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].id, 1);
+        assert_eq!(children[0].phase, ChildPhase::ResetPending);
+        assert_eq!(children[0].restarts, 1);
+        assert!(!children[0].dynamic);
+        assert_eq!(children[1].policy, ChildPolicy::Stop);
+        assert_eq!(children[1].phase, ChildPhase::Running);
+        assert!(children[1].last_alive_ms.is_some());
+        assert_eq!(children[0].last_alive_ms, None);
+
+        assert_eq!(
+            group.counts(),
+            ChildCounts {
+                total: 2,
+                running: 1,
+                reset_pending: 1,
+                restarts: 1,
+                ..ChildCounts::default()
+            }
+        );
+    }
+
+    #[test]
+    fn stop_policy_never_counts_as_exhausted() {
+        let (mut group, _rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Stop);
+        let action = group.handle_done(1, 100, &notify_ref);
+        assert_eq!(action, ChildAction::BeginShutdown);
+        assert!(!group.restarts_exhausted());
+    }
+
     #[test]
     fn health_tick_pings_child_and_marks_missed_alive_as_failed() {
-        let (mut group, mut rx, notify_ref, _notify_rx) =
-            setup_one_child(ChildPolicy::Restart { max: 1 });
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        });
         let from = 100usize;
         // Start the child first
         group.handle_started(1);
@@ -648,10 +1995,137 @@ mod tests {
         assert!(matches!(cmds[0], LifecycleCommand::Ping));
     }
 
+    #[test]
+    fn health_tick_fails_child_after_max_missed_alives() {
+        let (group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        });
+        let mut group = group.with_health_thresholds(3, 1);
+        let from = 100usize;
+        group.handle_started(1);
+
+        // The first tick pings; the next two find the ping unanswered.
+        for missed in 0..3 {
+            group.health_check_tick(from, &notify_ref);
+            assert_eq!(group.which_children()[0].missed_alive, missed);
+        }
+        rx.drain_payloads();
+
+        // An Alive reply forgives earlier misses.
+        group.handle_alive(1);
+        assert_eq!(group.which_children()[0].missed_alive, 0);
+        for _ in 0..3 {
+            group.health_check_tick(from, &notify_ref);
+        }
+        assert!(!rx
+            .drain_payloads()
+            .iter()
+            .any(|c| matches!(c, LifecycleCommand::Reset)));
+
+        // The third consecutive miss fails the child, which is restarted.
+        group.health_check_tick(from, &notify_ref);
+        let cmds = rx.drain_payloads();
+        assert!(matches!(cmds[0], LifecycleCommand::Reset));
+        assert_eq!(group.which_children()[0].missed_alive, 0);
+    }
+
+    #[test]
+    fn health_tick_counts_undeliverable_pings_separately() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone).with_health_thresholds(1, 2);
+        let (lifecycle_ref, mut rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        lifecycle_ref.sender().set_full(true);
+        group.add(
+            1,
+            lifecycle_ref.clone(),
+            ChildPolicy::Restart {
+                max: 1,
+                backoff: Backoff::Immediate,
+            },
+        );
+        let from = 100usize;
+        group.handle_started(1);
+
+        // A ping that never arrived is not a missed Alive.
+        group.health_check_tick(from, &notify_ref);
+        group.health_check_tick(from, &notify_ref);
+        let info = &group.which_children()[0];
+        assert_eq!(info.undelivered_pings, 2);
+        assert_eq!(info.missed_alive, 0);
+        assert_eq!(info.phase, ChildPhase::Running);
+
+        // The next due tick fails the child.
+        lifecycle_ref.sender().set_full(false);
+        group.health_check_tick(from, &notify_ref);
+        let cmds = rx.drain_payloads();
+        assert!(matches!(cmds[0], LifecycleCommand::Reset));
+    }
+
+    #[test]
+    fn health_tick_never_fails_on_undeliverable_pings_when_threshold_is_zero() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone).with_health_thresholds(1, 0);
+        let (lifecycle_ref, _rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        lifecycle_ref.sender().set_full(true);
+        group.add(1, lifecycle_ref, ChildPolicy::Stop);
+        group.handle_started(1);
+
+        for _ in 0..5 {
+            assert_eq!(
+                group.health_check_tick(100, &notify_ref),
+                ChildAction::Continue
+            );
+        }
+        assert_eq!(group.which_children()[0].undelivered_pings, 5);
+        assert_eq!(group.which_children()[0].phase, ChildPhase::Running);
+    }
+
+    #[test]
+    fn health_tick_skips_disabled_children() {
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Stop);
+        group.set_liveness(1, Liveness::Disabled);
+        group.handle_started(1);
+
+        for _ in 0..3 {
+            assert_eq!(
+                group.health_check_tick(100, &notify_ref),
+                ChildAction::Continue
+            );
+        }
+        assert!(rx.drain_payloads().is_empty());
+        assert_eq!(group.which_children()[0].last_ping_ms, None);
+    }
+
+    #[test]
+    fn health_tick_honours_per_child_ping_interval() {
+        let (group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Stop);
+        let mut group = group.with_clock(TestRuntime::now_ms);
+        group.set_liveness(1, Liveness::Every { interval_ms: 500 });
+        let from = 100usize;
+        group.handle_started(1);
+        let start = TestRuntime::now_ms();
+
+        group.health_check_tick(from, &notify_ref);
+        assert_eq!(rx.drain_payloads().len(), 1);
+        assert_eq!(group.which_children()[0].last_ping_ms, Some(start));
+        group.handle_alive(1);
+
+        // Ticks inside the interval neither ping nor count a miss.
+        TestRuntime::advance_clock(200);
+        group.health_check_tick(from, &notify_ref);
+        assert!(rx.drain_payloads().is_empty());
+
+        TestRuntime::advance_clock(300);
+        group.health_check_tick(from, &notify_ref);
+        assert_eq!(rx.drain_payloads().len(), 1);
+        assert_eq!(group.which_children()[0].last_ping_ms, Some(start + 500));
+    }
+
     #[test]
     fn kill_policy_emits_killed_event() {
         // A child with ChildPolicy::Kill and a kill_handle should emit
-        // ChildLifecycleEvent::Killed when handle_done_or_failed fires.
+        // ChildLifecycleEvent::Killed when handle_failed fires.
         let (mut group, _rx, notify_ref, mut notify_rx) = {
             let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
             let id = 1usize;
@@ -668,7 +2142,7 @@ mod tests {
         // Start the child so it's in Running phase (not skipped).
         group.handle_started(1);
 
-        let action = group.handle_done_or_failed(1, from, &notify_ref);
+        let action = group.handle_failed(1, from, &notify_ref);
         assert_eq!(action, ChildAction::BeginShutdown);
 
         // The Killed event should have been sent on the notify channel.
@@ -680,4 +2154,739 @@ mod tests {
             events[0]
         );
     }
+
+    #[test]
+    fn terminating_the_last_active_child_completes_a_when_all_done_group() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone);
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        let (ref_1, _rx_1) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (ref_2, _rx_2) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        group.add(1, ref_1, ChildPolicy::Stop);
+        group.add(2, ref_2, ChildPolicy::Stop);
+        let from = 100usize;
+
+        // Static children have no abort mailbox or kill handle.
+        assert_eq!(
+            group.terminate_child(1, Termination::Abort, from, &notify_ref),
+            None
+        );
+        assert_eq!(
+            group.terminate_child(3, Termination::Stop, from, &notify_ref),
+            None
+        );
+
+        assert_eq!(
+            group.terminate_child(1, Termination::Stop, from, &notify_ref),
+            Some(ChildAction::Continue)
+        );
+        assert_eq!(
+            group.terminate_child(2, Termination::Stop, from, &notify_ref),
+            Some(ChildAction::BeginShutdown)
+        );
+    }
+
+    #[test]
+    fn only_finished_children_can_be_deleted() {
+        let (mut group, _rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Stop);
+        let from = 100usize;
+
+        assert!(!group.delete_child(1));
+        group.terminate_child(1, Termination::Stop, from, &notify_ref);
+        // Still stopping: the Stopped report has not arrived.
+        assert!(!group.delete_child(1));
+        group.record_stopped(1);
+        assert!(group.delete_child(1));
+        assert_eq!(group.phase(1), None);
+        assert_eq!(group.counts(), ChildCounts::default());
+        assert!(!group.delete_child(1));
+    }
+
+    #[test]
+    fn killed_child_cannot_be_restarted_in_place() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone);
+        let (lifecycle_ref, mut rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (abort_ref, _abort_rx) = TestRuntime::channel::<AbortCommand>(101, 16);
+        let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.add_dynamic(
+            1,
+            lifecycle_ref,
+            abort_ref,
+            (),
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+        );
+        let from = 100usize;
+        group.handle_started(1);
+
+        assert_eq!(
+            group.terminate_child(1, Termination::Kill, from, &notify_ref),
+            Some(ChildAction::BeginShutdown)
+        );
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [ChildLifecycleEvent::Killed { child_id: 1 }]
+        ));
+        assert_eq!(group.phase(1), Some(ChildPhase::PermanentlyDone));
+        assert!(!group.restart_child(1, from));
+        assert!(rx.drain_payloads().is_empty());
+        assert!(group.delete_child(1));
+    }
+
+    #[test]
+    fn transient_child_is_restarted_only_after_a_failure() {
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        });
+        group.set_restart_type(1, RestartType::Transient);
+        let from = 100usize;
+        group.handle_started(1);
+
+        assert_eq!(
+            group.handle_failed(1, from, &notify_ref),
+            ChildAction::Continue
+        );
+        assert!(matches!(
+            rx.drain_payloads().as_slice(),
+            [LifecycleCommand::Reset]
+        ));
+        group.handle_started(1);
+
+        // A normal exit is final, and does not spend the restart budget.
+        assert_eq!(
+            group.handle_done(1, from, &notify_ref),
+            ChildAction::BeginShutdown
+        );
+        assert!(rx.drain_payloads().is_empty());
+        assert_eq!(group.phase(1), Some(ChildPhase::PermanentlyDone));
+        assert!(!group.restarts_exhausted());
+        assert_eq!(
+            group.which_children()[0].restart_type,
+            RestartType::Transient
+        );
+    }
+
+    #[test]
+    fn temporary_child_is_never_restarted_but_keeps_its_policy_mechanism() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone);
+        let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        let (ref_1, mut rx_1) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (ref_2, _rx_2) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        let (abort_ref, _abort_rx) = TestRuntime::channel::<AbortCommand>(102, 16);
+        group.add(
+            1,
+            ref_1,
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+        );
+        group.add_dynamic(2, ref_2, abort_ref, (), ChildPolicy::Kill);
+        group.set_restart_type(1, RestartType::Temporary);
+        group.set_restart_type(2, RestartType::Temporary);
+        let from = 100usize;
+        group.handle_started(1);
+        group.handle_started(2);
+
+        assert_eq!(
+            group.handle_failed(1, from, &notify_ref),
+            ChildAction::Continue
+        );
+        assert!(rx_1.drain_payloads().is_empty());
+        assert_eq!(group.phase(1), Some(ChildPhase::PermanentlyDone));
+        assert!(!group.restarts_exhausted());
+
+        // Kill still applies to a temporary child that is not restarted.
+        assert_eq!(
+            group.handle_failed(2, from, &notify_ref),
+            ChildAction::BeginShutdown
+        );
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [ChildLifecycleEvent::Killed { child_id: 2 }]
+        ));
+    }
+
+    #[test]
+    fn missed_stop_deadline_escalates_to_abort_then_kill() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone).with_shutdown_timeouts(100, 50);
+        let (lifecycle_ref, mut rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (abort_ref, mut abort_rx) = TestRuntime::channel::<AbortCommand>(101, 16);
+        let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.add_dynamic(1, lifecycle_ref, abort_ref, (), ChildPolicy::Stop);
+        let from = 100usize;
+        group.handle_started(1);
+
+        group.stop_all(from);
+        assert!(matches!(
+            rx.drain_payloads().as_slice(),
+            [LifecycleCommand::Stop]
+        ));
+        assert_eq!(group.take_deadlines(), [(1, 100)]);
+
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [ChildLifecycleEvent::ShutdownEscalated {
+                child_id: 1,
+                to: ShutdownEscalation::Abort
+            }]
+        ));
+        assert!(matches!(
+            abort_rx.drain_payloads().as_slice(),
+            [AbortCommand::Abort { child_id: 1 }]
+        ));
+        assert!(!group.all_stopped(), "waits for the Aborted report");
+        assert_eq!(group.take_deadlines(), [(1, 50)]);
+
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [
+                ChildLifecycleEvent::ShutdownEscalated {
+                    child_id: 1,
+                    to: ShutdownEscalation::Kill
+                },
+                ChildLifecycleEvent::Killed { child_id: 1 }
+            ]
+        ));
+        assert!(group.all_stopped());
+        assert!(group.take_deadlines().is_empty());
+    }
+
+    #[test]
+    fn only_children_still_stopping_are_escalated() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone).with_shutdown_timeouts(100, 50);
+        let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        let (ref_1, _rx_1) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (ref_2, _rx_2) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        group.add(1, ref_1, ChildPolicy::Stop);
+        group.add(2, ref_2, ChildPolicy::Stop);
+        let from = 100usize;
+
+        // Deadlines are not armed outside a shutdown.
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        assert!(notify_rx.drain_payloads().is_empty());
+
+        group.stop_all(from);
+        assert_eq!(group.take_deadlines(), [(2, 100), (1, 100)]);
+        group.record_stopped(2);
+        group.shutdown_deadline_elapsed(2, from, &notify_ref);
+        assert!(notify_rx.drain_payloads().is_empty());
+
+        // A static child has nothing left to escalate to.
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [ChildLifecycleEvent::ShutdownEscalated {
+                child_id: 1,
+                to: ShutdownEscalation::Abandon
+            }]
+        ));
+        assert!(group.all_stopped());
+    }
+
+    #[test]
+    fn escalate_all_shutdowns_steps_every_child_still_stopping() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone);
+        let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        let (ref_1, _rx_1) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (ref_2, _rx_2) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        let (ref_3, _rx_3) = TestRuntime::channel::<LifecycleCommand>(3, 16);
+        let (abort_ref, mut abort_rx) = TestRuntime::channel::<AbortCommand>(101, 16);
+        group.add_dynamic(1, ref_1, abort_ref, (), ChildPolicy::Stop);
+        group.add(2, ref_2, ChildPolicy::Stop);
+        group.add(3, ref_3, ChildPolicy::Stop);
+        let from = 100usize;
+        for id in 1..=3 {
+            group.handle_started(id);
+        }
+
+        // Nothing is shutting down yet.
+        group.escalate_all_shutdowns(from, &notify_ref);
+        assert!(notify_rx.drain_payloads().is_empty());
+
+        group.stop_all(from);
+        group.record_stopped(3);
+        group.escalate_all_shutdowns(from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [
+                ChildLifecycleEvent::ShutdownEscalated {
+                    child_id: 1,
+                    to: ShutdownEscalation::Abort
+                },
+                ChildLifecycleEvent::ShutdownEscalated {
+                    child_id: 2,
+                    to: ShutdownEscalation::Abandon
+                }
+            ]
+        ));
+        assert!(matches!(
+            abort_rx.drain_payloads().as_slice(),
+            [AbortCommand::Abort { child_id: 1 }]
+        ));
+        assert!(!group.all_stopped(), "waits for the Aborted report");
+        assert!(group.take_deadlines().is_empty(), "no timeouts configured");
+
+        group.escalate_all_shutdowns(from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [
+                ChildLifecycleEvent::ShutdownEscalated {
+                    child_id: 1,
+                    to: ShutdownEscalation::Kill
+                },
+                ChildLifecycleEvent::Killed { child_id: 1 }
+            ]
+        ));
+        assert!(group.all_stopped());
+    }
+
+    /// Under `NoKill` the kill handle is `()` and killing does nothing, so a
+    /// child that ignores its abort is abandoned rather than reported killed.
+    #[test]
+    fn missed_abort_deadline_abandons_without_a_real_kill() {
+        use bloxide_test_runtime::NoKillTestRuntime;
+
+        let mut group = ChildGroup::<NoKillTestRuntime>::new(GroupShutdown::WhenAnyDone)
+            .with_shutdown_timeouts(100, 50);
+        let (lifecycle_ref, _rx) = NoKillTestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (abort_ref, _abort_rx) = NoKillTestRuntime::channel::<AbortCommand>(101, 16);
+        let (notify_ref, mut notify_rx) =
+            NoKillTestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.add_dynamic(1, lifecycle_ref, abort_ref, (), ChildPolicy::Stop);
+        let from = 100usize;
+        group.handle_started(1);
+        group.stop_all(from);
+
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        group.shutdown_deadline_elapsed(1, from, &notify_ref);
+        assert!(matches!(
+            notify_rx.drain_payloads().as_slice(),
+            [
+                ChildLifecycleEvent::ShutdownEscalated {
+                    to: ShutdownEscalation::Abort,
+                    ..
+                },
+                ChildLifecycleEvent::ShutdownEscalated {
+                    child_id: 1,
+                    to: ShutdownEscalation::Abandon
+                }
+            ]
+        ));
+        assert!(group.all_stopped());
+    }
+
+    #[test]
+    fn kill_termination_is_unavailable_without_a_real_kill() {
+        use bloxide_test_runtime::NoKillTestRuntime;
+
+        let mut group = ChildGroup::<NoKillTestRuntime>::new(GroupShutdown::WhenAllDone);
+        let (lifecycle_ref, _rx) = NoKillTestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (abort_ref, _abort_rx) = NoKillTestRuntime::channel::<AbortCommand>(101, 16);
+        let (notify_ref, mut notify_rx) =
+            NoKillTestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.add_dynamic(1, lifecycle_ref, abort_ref, (), ChildPolicy::Stop);
+        let from = 100usize;
+        group.handle_started(1);
+
+        assert_eq!(
+            group.terminate_child(1, Termination::Kill, from, &notify_ref),
+            None
+        );
+        assert!(notify_rx.drain_payloads().is_empty());
+        assert_eq!(group.phase(1), Some(ChildPhase::Running));
+    }
+
+    fn setup_children(
+        ids: &[ActorId],
+        group: ChildGroup<TestRuntime>,
+    ) -> (ChildGroup<TestRuntime>, Vec<TestReceiver<LifecycleCommand>>) {
+        let mut group = group;
+        let mut rxs = Vec::new();
+        for &id in ids {
+            let (lifecycle_ref, rx) = TestRuntime::channel::<LifecycleCommand>(id, 16);
+            group.add(
+                id,
+                lifecycle_ref,
+                ChildPolicy::Restart {
+                    max: 3,
+                    backoff: Backoff::Immediate,
+                },
+            );
+            rxs.push(rx);
+        }
+        (group, rxs)
+    }
+
+    fn started(rxs: &mut [TestReceiver<LifecycleCommand>]) -> Vec<bool> {
+        rxs.iter_mut()
+            .map(|rx| {
+                rx.drain_payloads()
+                    .iter()
+                    .any(|c| matches!(c, LifecycleCommand::Start | LifecycleCommand::Reset))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sequential_start_waits_for_each_child_to_report_started() {
+        let group =
+            ChildGroup::new(GroupShutdown::WhenAnyDone).with_start_order(StartOrder::Sequential);
+        let (mut group, mut rxs) = setup_children(&[1, 2, 3], group);
+        let from = 100usize;
+
+        group.start_all(from);
+        assert_eq!(started(&mut rxs), [true, false, false]);
+
+        // Nothing moves until the child being started reports in.
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, false, false]);
+
+        group.handle_started(1);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, true, false]);
+
+        group.handle_started(2);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, false, true]);
+    }
+
+    #[test]
+    fn child_starts_once_its_dependencies_are_running() {
+        let (mut group, mut rxs) =
+            setup_children(&[1, 2, 3], ChildGroup::new(GroupShutdown::WhenAnyDone));
+        group.set_depends_on(1, &[3]);
+        let from = 100usize;
+
+        // Concurrent order: only the child with a pending dependency waits.
+        group.start_all(from);
+        assert_eq!(started(&mut rxs), [false, true, true]);
+
+        group.handle_started(2);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, false, false]);
+
+        group.handle_started(3);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [true, false, false]);
+    }
+
+    #[test]
+    fn rest_for_one_restarts_follow_the_start_order() {
+        let group = ChildGroup::new(GroupShutdown::WhenAnyDone)
+            .with_restart_strategy(RestartStrategy::RestForOne)
+            .with_start_order(StartOrder::Sequential);
+        let (mut group, mut rxs) = setup_children(&[1, 2, 3], group);
+        let from = 100usize;
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(from, 16);
+        group.start_all(from);
+        for id in 1..=3 {
+            group.handle_started(id);
+            group.start_ready(from);
+        }
+        started(&mut rxs);
+
+        // Child 1 is reset at once; the children after it wait their turn.
+        group.handle_failed(1, from, &notify_ref);
+        assert_eq!(started(&mut rxs), [true, false, false]);
+        assert_eq!(group.phase(3), Some(ChildPhase::ResetPending));
+
+        group.handle_started(1);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, true, false]);
+
+        group.handle_started(2);
+        group.start_ready(from);
+        assert_eq!(started(&mut rxs), [false, false, true]);
+        assert_eq!(group.which_children()[2].restarts, 1);
+    }
+
+    #[test]
+    fn missed_start_deadline_fails_the_group() {
+        let group = ChildGroup::new(GroupShutdown::WhenAllDone)
+            .with_start_order(StartOrder::Sequential)
+            .with_start_timeout(1000);
+        let (mut group, _rxs) = setup_children(&[1, 2], group);
+        let from = 100usize;
+
+        group.start_all(from);
+        let first = group.take_start_deadlines();
+        assert_eq!(first.len(), 1);
+        assert_eq!((first[0].0, first[0].2), (1, 1000));
+
+        // A child that started in time is not held against the group.
+        group.handle_started(1);
+        group.start_ready(from);
+        let second = group.take_start_deadlines();
+        assert_eq!(second.len(), 1);
+        assert_eq!((second[0].0, second[0].2), (2, 1000));
+        assert_eq!(
+            group.start_deadline_elapsed(1, first[0].1, from),
+            ChildAction::Continue
+        );
+        assert!(!group.start_timed_out());
+
+        assert_eq!(
+            group.start_deadline_elapsed(2, second[0].1, from),
+            ChildAction::BeginShutdown
+        );
+        assert!(group.start_timed_out());
+
+        group.clear_counters();
+        assert!(!group.start_timed_out());
+    }
+
+    /// A deadline armed for an earlier start is ignored, even when the child
+    /// is starting again by the time it fires.
+    #[test]
+    fn stale_start_deadline_is_ignored() {
+        let group = ChildGroup::new(GroupShutdown::WhenAllDone).with_start_timeout(1000);
+        let (mut group, _rxs) = setup_children(&[1], group);
+        let from = 100usize;
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(from, 16);
+
+        group.start_all(from);
+        let [(_, armed, _)] = group.take_start_deadlines()[..] else {
+            panic!("expected one start deadline");
+        };
+        group.handle_started(1);
+        group.handle_failed(1, from, &notify_ref);
+        assert_eq!(group.phase(1), Some(ChildPhase::ResetPending));
+
+        assert_eq!(
+            group.start_deadline_elapsed(1, armed, from),
+            ChildAction::Continue
+        );
+        assert!(!group.start_timed_out());
+    }
+
+    #[test]
+    fn children_never_started_count_as_stopped() {
+        let group =
+            ChildGroup::new(GroupShutdown::WhenAnyDone).with_start_order(StartOrder::Sequential);
+        let (mut group, mut rxs) = setup_children(&[1, 2], group);
+        let from = 100usize;
+        group.start_all(from);
+        started(&mut rxs);
+
+        group.stop_all(from);
+        assert!(rxs[1].drain_payloads().is_empty());
+        assert!(matches!(
+            rxs[0].drain_payloads()[..],
+            [LifecycleCommand::Stop]
+        ));
+        group.record_stopped(1);
+        assert!(group.all_stopped());
+    }
+
+    #[test]
+    fn restart_within_forgets_restarts_outside_the_window() {
+        let (group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::RestartWithin {
+            max: 2,
+            within_ms: 1000,
+            backoff: Backoff::Immediate,
+        });
+        let mut group = group.with_clock(TestRuntime::now_ms);
+        let from = 100usize;
+
+        // Two crashes inside the window use up the budget.
+        for _ in 0..2 {
+            group.handle_started(1);
+            let action = group.handle_done(1, from, &notify_ref);
+            assert_eq!(action, ChildAction::Continue);
+            TestRuntime::advance_clock(100);
+        }
+        assert_eq!(rx.drain_payloads().len(), 2);
+
+        // Once the first restart ages out, the child may restart again.
+        TestRuntime::advance_clock(850);
+        group.handle_started(1);
+        let action = group.handle_done(1, from, &notify_ref);
+        assert_eq!(action, ChildAction::Continue);
+        assert!(matches!(rx.drain_payloads()[..], [LifecycleCommand::Reset]));
+
+        // A crash loop exhausts the budget and escalates.
+        group.handle_started(1);
+        let action = group.handle_done(1, from, &notify_ref);
+        assert_eq!(action, ChildAction::BeginShutdown);
+        assert!(rx.drain_payloads().is_empty());
+    }
+
+    #[test]
+    fn group_intensity_caps_restarts_across_children() {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone)
+            .with_restart_intensity(1, 1000)
+            .with_clock(TestRuntime::now_ms);
+        let (first_ref, mut first_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        let (second_ref, mut second_rx) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.add(
+            1,
+            first_ref,
+            ChildPolicy::Restart {
+                max: 5,
+                backoff: Backoff::Immediate,
+            },
+        );
+        group.add(
+            2,
+            second_ref,
+            ChildPolicy::Restart {
+                max: 5,
+                backoff: Backoff::Immediate,
+            },
+        );
+        group.handle_started(1);
+        group.handle_started(2);
+        let from = 100usize;
+
+        assert_eq!(
+            group.handle_done(1, from, &notify_ref),
+            ChildAction::Continue
+        );
+        assert_eq!(first_rx.drain_payloads().len(), 1);
+
+        // The second child still has its own budget, but the group does not.
+        assert_eq!(
+            group.handle_done(2, from, &notify_ref),
+            ChildAction::BeginShutdown
+        );
+        assert!(second_rx.drain_payloads().is_empty());
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_up_to_the_cap_with_bounded_jitter() {
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 5,
+            backoff: Backoff::Exponential {
+                initial_ms: 100,
+                max_ms: 300,
+                jitter_ms: 10,
+            },
+        });
+        let from = 100usize;
+
+        let mut delays = Vec::new();
+        let mut last_generation = 0;
+        for _ in 0..4 {
+            group.handle_started(1);
+            group.handle_done(1, from, &notify_ref);
+            assert_eq!(group.phase(1), Some(ChildPhase::BackingOff));
+            // Further failures while backing off are coalesced.
+            group.handle_done(1, from, &notify_ref);
+            let backoffs = group.take_backoffs();
+            assert_eq!(backoffs.len(), 1);
+            let (_, generation, delay) = backoffs[0];
+            delays.push(delay);
+            assert!(rx.drain_payloads().is_empty());
+            group.restart_after_backoff(1, generation, from);
+            assert!(matches!(rx.drain_payloads()[..], [LifecycleCommand::Reset]));
+            last_generation = generation;
+        }
+
+        for (delay, base) in delays.iter().zip([100, 200, 300, 300]) {
+            assert!(
+                (base..=base + 10).contains(delay),
+                "delay {delay} outside {base}..={}",
+                base + 10
+            );
+        }
+
+        // A late timer after the child restarted is ignored.
+        group.restart_after_backoff(1, last_generation, from);
+        assert!(rx.drain_payloads().is_empty());
+    }
+
+    /// The timer of a backoff overtaken by an operator restart does not cut
+    /// short the longer backoff that follows.
+    #[test]
+    fn superseded_backoff_timer_is_ignored() {
+        let (mut group, mut rx, notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Restart {
+            max: 5,
+            backoff: Backoff::Exponential {
+                initial_ms: 100,
+                max_ms: 1000,
+                jitter_ms: 0,
+            },
+        });
+        let from = 100usize;
+
+        group.handle_started(1);
+        group.handle_failed(1, from, &notify_ref);
+        let [(_, stale, 100)] = group.take_backoffs()[..] else {
+            panic!("expected a 100 ms backoff");
+        };
+        assert!(group.restart_child(1, from));
+        group.handle_started(1);
+        group.handle_failed(1, from, &notify_ref);
+        let [(_, current, 200)] = group.take_backoffs()[..] else {
+            panic!("expected a 200 ms backoff");
+        };
+        rx.drain_payloads();
+
+        group.restart_after_backoff(1, stale, from);
+        assert_eq!(group.phase(1), Some(ChildPhase::BackingOff));
+        assert!(rx.drain_payloads().is_empty());
+
+        group.restart_after_backoff(1, current, from);
+        assert!(matches!(rx.drain_payloads()[..], [LifecycleCommand::Reset]));
+    }
+
+    #[test]
+    fn journal_keeps_the_newest_entries_in_sequence() {
+        let (mut group, _rx, _notify_ref, _notify_rx) = setup_one_child(ChildPolicy::Stop);
+        group.journal_event(ChildLifecycleEvent::Started { child_id: 1 });
+        assert_eq!(group.journal().iter().count(), 0); // disabled by default
+
+        let mut group = group.with_journal(2);
+        for _ in 0..3 {
+            group.journal_event(ChildLifecycleEvent::Alive { child_id: 1 });
+        }
+        let seqs: Vec<u64> = group.journal().iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, [1, 2]);
+        assert_eq!(group.journal().dropped(), 1);
+        assert_eq!(group.journal().since(2).len(), 1);
+    }
+
+    #[test]
+    fn journal_records_each_decision_with_its_reason() {
+        let group = ChildGroup::new(GroupShutdown::WhenAnyDone)
+            .with_restart_intensity(1, 1000)
+            .with_journal(16)
+            .with_clock(TestRuntime::now_ms);
+        let (mut group, _rxs) = setup_children(&[1, 2], group);
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        group.handle_started(1);
+        group.handle_started(2);
+        let from = 100usize;
+
+        group.handle_done(1, from, &notify_ref);
+        let action = group.handle_failed(2, from, &notify_ref);
+        assert_eq!(action, ChildAction::BeginShutdown);
+
+        let decisions: Vec<_> = group
+            .journal()
+            .iter()
+            .filter_map(|e| match e.record {
+                JournalRecord::Decision {
+                    child_id,
+                    decision,
+                    reason,
+                } => Some((child_id, decision, reason)),
+                JournalRecord::Event(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            decisions,
+            [
+                (1, Decision::Reset, DecisionReason::Done),
+                (2, Decision::GiveUp, DecisionReason::IntensityExceeded),
+                (2, Decision::Shutdown, DecisionReason::IntensityExceeded),
+            ]
+        );
+    }
 }
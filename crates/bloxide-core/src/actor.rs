@@ -2,6 +2,7 @@
 use core::future::poll_fn;
 
 use crate::{
+    batch::{poll_batch, BatchConfig, BatchStep, YieldBudget},
     engine::{DispatchOutcome, MachineState, StateMachine},
     mailboxes::Mailboxes,
     spec::MachineSpec,
@@ -12,17 +13,38 @@ use crate::{
 /// Selects the next event from `mailboxes` (in priority order — index 0 wins
 /// on ties) and dispatches it to `machine` (run-to-completion semantics).
 /// This function never returns under normal operation.
-pub async fn run_actor<S, M>(mut machine: StateMachine<S>, mut mailboxes: M)
+pub async fn run_actor<S, M>(machine: StateMachine<S>, mailboxes: M)
 where
     S: MachineSpec + 'static,
     M: Mailboxes<S::Event>,
 {
+    run_actor_batched(machine, mailboxes, BatchConfig::UNBATCHED).await;
+}
+
+/// Like [`run_actor`], but dispatches up to `batch.max_batch` ready events per
+/// poll and yields to the executor whenever `batch.yield_budget` is spent.
+pub async fn run_actor_batched<S, M>(
+    mut machine: StateMachine<S>,
+    mut mailboxes: M,
+    batch: BatchConfig,
+) where
+    S: MachineSpec + 'static,
+    M: Mailboxes<S::Event>,
+{
+    let mut budget = YieldBudget::new(&batch);
     loop {
-        let event = match poll_fn(|cx| mailboxes.poll_next(cx)).await {
-            Some(event) => event,
-            None => return,
-        };
-        machine.dispatch(event);
+        let step = poll_fn(|cx| {
+            poll_batch(&mut mailboxes, cx, batch.max_batch, |event| {
+                machine.dispatch(event);
+                false
+            })
+        })
+        .await;
+        match step {
+            BatchStep::Dispatched(n) => budget.consume(n),
+            BatchStep::Finished => return,
+        }
+        budget.checkpoint().await;
     }
 }
 
@@ -38,32 +60,40 @@ where
 ///
 /// For unsupervised actors without a lifecycle mailbox, use `run_actor_auto_start`
 /// instead, which auto-starts before running.
-pub async fn run_actor_to_completion<S, M>(mut machine: StateMachine<S>, mut mailboxes: M)
+pub async fn run_actor_to_completion<S, M>(machine: StateMachine<S>, mailboxes: M)
 where
     S: MachineSpec + 'static,
     M: Mailboxes<S::Event>,
 {
+    run_actor_to_completion_batched(machine, mailboxes, BatchConfig::UNBATCHED).await;
+}
+
+/// Like [`run_actor_to_completion`], but dispatches up to `batch.max_batch`
+/// ready events per poll and yields whenever `batch.yield_budget` is spent.
+///
+/// Events still queued behind the one that completes the actor are left in
+/// the mailboxes.
+pub async fn run_actor_to_completion_batched<S, M>(
+    mut machine: StateMachine<S>,
+    mut mailboxes: M,
+    batch: BatchConfig,
+) where
+    S: MachineSpec + 'static,
+    M: Mailboxes<S::Event>,
+{
+    let mut budget = YieldBudget::new(&batch);
     loop {
-        let event = match poll_fn(|cx| mailboxes.poll_next(cx)).await {
-            Some(event) => event,
-            None => return,
-        };
-        match machine.dispatch(event) {
-            DispatchOutcome::Started(MachineState::State(state))
-                if S::is_terminal(&state) || S::is_error(&state) =>
-            {
-                return;
-            }
-            DispatchOutcome::Transition(MachineState::State(state))
-                if S::is_terminal(&state) || S::is_error(&state) =>
-            {
-                return;
-            }
-            DispatchOutcome::Done(_) => return,
-            DispatchOutcome::Failed => return,
-            DispatchOutcome::Stopped => return,
-            _ => {}
+        let step = poll_fn(|cx| {
+            poll_batch(&mut mailboxes, cx, batch.max_batch, |event| {
+                completes::<S>(&machine.dispatch(event))
+            })
+        })
+        .await;
+        match step {
+            BatchStep::Dispatched(n) => budget.consume(n),
+            BatchStep::Finished => return,
         }
+        budget.checkpoint().await;
     }
 }
 
@@ -72,7 +102,7 @@ where
 /// For actors that don't have a lifecycle mailbox and need to start immediately.
 /// Calls `handle_lifecycle(Start)` to transition from Init, then runs like
 /// `run_actor_to_completion`.
-pub async fn run_actor_auto_start<S, M>(mut machine: StateMachine<S>, mut mailboxes: M)
+pub async fn run_actor_auto_start<S, M>(mut machine: StateMachine<S>, mailboxes: M)
 where
     S: MachineSpec + 'static,
     M: Mailboxes<S::Event>,
@@ -89,26 +119,17 @@ where
     }
 
     // Run to completion
-    loop {
-        let event = match poll_fn(|cx| mailboxes.poll_next(cx)).await {
-            Some(event) => event,
-            None => return,
-        };
-        match machine.dispatch(event) {
-            DispatchOutcome::Started(MachineState::State(state))
-                if S::is_terminal(&state) || S::is_error(&state) =>
-            {
-                return;
-            }
-            DispatchOutcome::Transition(MachineState::State(state))
-                if S::is_terminal(&state) || S::is_error(&state) =>
-            {
-                return;
-            }
-            DispatchOutcome::Done(_) => return,
-            DispatchOutcome::Failed => return,
-            DispatchOutcome::Stopped => return,
-            _ => {}
+    run_actor_to_completion(machine, mailboxes).await;
+}
+
+/// Whether `outcome` ends a run-to-completion actor.
+fn completes<S: MachineSpec>(outcome: &DispatchOutcome<S::State>) -> bool {
+    match outcome {
+        DispatchOutcome::Started(MachineState::State(state))
+        | DispatchOutcome::Transition(MachineState::State(state)) => {
+            S::is_terminal(state) || S::is_error(state)
         }
+        DispatchOutcome::Done(_) | DispatchOutcome::Failed | DispatchOutcome::Stopped => true,
+        _ => false,
     }
 }
@@ -0,0 +1,176 @@
+// Copyright 2025 Bloxide, all rights reserved
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::mailboxes::Mailboxes;
+
+// ── BatchConfig ───────────────────────────────────────────────────────────────
+
+/// Per-actor batching configuration for the run loops.
+///
+/// `max_batch` bounds how many ready events a run loop dispatches per poll
+/// before returning to the `await` point. `yield_budget`, when set, bounds how
+/// many events are dispatched before the loop yields back to the executor even
+/// if more events are ready — this keeps a hot actor from monopolising a
+/// cooperative executor such as Embassy.
+///
+/// [`BatchConfig::UNBATCHED`] (the default) reproduces the classic
+/// one-event-per-poll behaviour of [`run_actor`](crate::actor::run_actor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Maximum number of events dispatched per poll. Always at least 1.
+    pub max_batch: usize,
+    /// Number of events after which the loop yields to the executor.
+    /// `None` never yields voluntarily.
+    pub yield_budget: Option<usize>,
+}
+
+impl BatchConfig {
+    /// One event per poll, no voluntary yields.
+    pub const UNBATCHED: Self = Self {
+        max_batch: 1,
+        yield_budget: None,
+    };
+
+    /// Dispatch up to `max_batch` ready events per poll. A `max_batch` of 0
+    /// is treated as 1.
+    pub const fn new(max_batch: usize) -> Self {
+        Self {
+            max_batch: if max_batch == 0 { 1 } else { max_batch },
+            yield_budget: None,
+        }
+    }
+
+    /// Yield to the executor after every `budget` dispatched events. A
+    /// `budget` of 0 is treated as 1.
+    pub const fn with_yield_budget(mut self, budget: usize) -> Self {
+        self.yield_budget = Some(if budget == 0 { 1 } else { budget });
+        self
+    }
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self::UNBATCHED
+    }
+}
+
+// ── YieldBudget ───────────────────────────────────────────────────────────────
+
+/// Tracks events dispatched since the last voluntary yield.
+///
+/// Run loops call [`consume`](Self::consume) after each batch and then await
+/// [`checkpoint`](Self::checkpoint), which yields once the budget is spent.
+#[derive(Debug)]
+pub struct YieldBudget {
+    limit: Option<usize>,
+    used: usize,
+}
+
+impl YieldBudget {
+    pub const fn new(config: &BatchConfig) -> Self {
+        Self {
+            limit: config.yield_budget,
+            used: 0,
+        }
+    }
+
+    /// Record `n` dispatched events.
+    pub fn consume(&mut self, n: usize) {
+        self.used = self.used.saturating_add(n);
+    }
+
+    /// Whether the budget has been spent since the last yield.
+    pub fn is_exhausted(&self) -> bool {
+        matches!(self.limit, Some(limit) if self.used >= limit)
+    }
+
+    /// Yield to the executor if the budget is spent, then reset it.
+    pub async fn checkpoint(&mut self) {
+        if self.is_exhausted() {
+            self.used = 0;
+            yield_now().await;
+        }
+    }
+}
+
+// ── yield_now ─────────────────────────────────────────────────────────────────
+
+/// Yield once to the executor.
+///
+/// Runtime-agnostic: the returned future wakes itself and returns `Pending`
+/// on its first poll, so any executor reschedules the task behind other
+/// ready tasks.
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Future returned by [`yield_now`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// ── poll_batch ────────────────────────────────────────────────────────────────
+
+/// Result of one [`poll_batch`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchStep {
+    /// This many events were dispatched; the loop should continue.
+    Dispatched(usize),
+    /// The mailboxes closed or `on_event` asked the loop to stop.
+    Finished,
+}
+
+/// Drain up to `max_batch` ready events from `mailboxes`, handing each to
+/// `on_event`.
+///
+/// `on_event` returns `true` to stop the loop (e.g. the actor reached a
+/// terminal state). Returns `Poll::Pending` only when no event was ready,
+/// so the waker is registered exactly as in the unbatched loop.
+pub fn poll_batch<E, M, F>(
+    mailboxes: &mut M,
+    cx: &mut Context<'_>,
+    max_batch: usize,
+    mut on_event: F,
+) -> Poll<BatchStep>
+where
+    E: Send + 'static,
+    M: Mailboxes<E>,
+    F: FnMut(E) -> bool,
+{
+    let mut dispatched = 0;
+    while dispatched < max_batch {
+        match mailboxes.poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                dispatched += 1;
+                if on_event(event) {
+                    return Poll::Ready(BatchStep::Finished);
+                }
+            }
+            Poll::Ready(None) => return Poll::Ready(BatchStep::Finished),
+            Poll::Pending => break,
+        }
+    }
+    if dispatched == 0 {
+        Poll::Pending
+    } else {
+        Poll::Ready(BatchStep::Dispatched(dispatched))
+    }
+}
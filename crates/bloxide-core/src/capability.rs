@@ -124,6 +124,17 @@ pub trait DynamicChannelCap: BloxRuntime {
     ) -> (ActorRef<M, Self>, Self::Receiver<M>);
 }
 
+/// Monotonic millisecond clock provided by the runtime.
+///
+/// Needs neither `alloc` nor a timer service task, so `no_std` platform
+/// crates can use it to measure elapsed time (e.g. restart intensity windows
+/// in a child group). The epoch is runtime-defined; only differences between
+/// readings are meaningful. Only the wiring layer calls this trait.
+pub trait ClockCap: BloxRuntime {
+    /// Milliseconds elapsed since the runtime's epoch.
+    fn now_ms() -> u64;
+}
+
 /// Type-level kill capability for a runtime.
 ///
 /// `NoKill` — no external task kill (Embassy, static-only). `Handle = ()` (ZST).
@@ -142,12 +153,17 @@ pub trait DynamicChannelCap: BloxRuntime {
 /// placing it in `RegisterDynamicChild`.
 pub trait KillCapability<R: BloxRuntime> {
     type Handle: Clone + Send + 'static;
+    /// Whether `kill` actually ends the task. Supervisors only escalate to
+    /// a kill when it does, and abandon the child otherwise.
+    const CAN_KILL: bool;
     fn kill(handle: Self::Handle);
 }
 
 /// No kill capability — static runtimes (Embassy). `Handle = ()` (ZST).
+/// `kill` does nothing.
 pub struct NoKill;
 impl<R: BloxRuntime> KillCapability<R> for NoKill {
     type Handle = ();
+    const CAN_KILL: bool = false;
     fn kill(_: ()) {}
 }
@@ -185,9 +185,15 @@ pub enum DispatchOutcome<State> {
 /// `on_init_entry` fires only when the machine **enters Init** (via Stop),
 /// not on first construction or on Reset (which skips Init entirely).
 /// `on_init_exit` fires only when the machine **leaves Init** (via Start).
+///
+/// A machine whose spec provides [`MachineSpec::stop_event`] stops
+/// gracefully: Stop dispatches that event, and the machine reports `Stopped`
+/// only when it later reaches a terminal or error state.
 pub struct StateMachine<S: MachineSpec> {
     /// Current state - either implicit Init or a user state.
     current: MachineState<S::State>,
+    /// A graceful Stop is in progress (see `MachineSpec::stop_event`).
+    stopping: bool,
     ctx: S::Ctx,
 }
 
@@ -211,6 +217,7 @@ impl<S: MachineSpec> StateMachine<S> {
         trace_init_entry!();
         Self {
             current: MachineState::Init,
+            stopping: false,
             ctx,
         }
     }
@@ -250,7 +257,12 @@ impl<S: MachineSpec> StateMachine<S> {
             }
             MachineState::State(current) => {
                 trace_on_event_received!(current, &event);
-                self.process_operational_event(event)
+                let outcome = self.process_operational_event(event);
+                if self.stopping {
+                    self.finish_stop(outcome)
+                } else {
+                    outcome
+                }
             }
         }
     }
@@ -283,6 +295,8 @@ impl<S: MachineSpec> StateMachine<S> {
                         // Reset directly to initial_state() — skip Init entirely.
                         // Fire the full exit chain, then the entry chain for
                         // initial_state(). No on_init_entry or on_init_exit.
+                        // A Reset also cancels a graceful Stop in progress.
+                        self.stopping = false;
                         let target = S::initial_state();
                         self.transition_to_state(target);
                         DispatchOutcome::Started(MachineState::State(target))
@@ -295,10 +309,36 @@ impl<S: MachineSpec> StateMachine<S> {
                         // Already in Init - no-op
                         DispatchOutcome::HandledNoTransition
                     }
-                    MachineState::State(_) => {
-                        // Transition to Init, report Stopped
-                        self.transition_to_init();
-                        DispatchOutcome::Stopped
+                    MachineState::State(_) if self.stopping => {
+                        // Graceful Stop already underway - keep waiting
+                        DispatchOutcome::HandledNoTransition
+                    }
+                    MachineState::State(current) => {
+                        let wind_down = if S::is_terminal(&current) {
+                            None
+                        } else {
+                            S::stop_event(&self.ctx)
+                        };
+                        // Graceful Stop: dispatch the spec's stop event (even
+                        // in an error state) and report Stopped once it settles
+                        let outcome = match wind_down {
+                            Some(event) => {
+                                self.stopping = true;
+                                self.run_handlers(current, event)
+                            }
+                            None => DispatchOutcome::NoRuleMatched,
+                        };
+                        let settled = match self.current {
+                            MachineState::State(s) => S::is_terminal(&s) || S::is_error(&s),
+                            MachineState::Init => true,
+                        };
+                        if settled || outcome == DispatchOutcome::NoRuleMatched {
+                            // Transition to Init, report Stopped
+                            self.transition_to_init();
+                            DispatchOutcome::Stopped
+                        } else {
+                            outcome
+                        }
                     }
                 }
             }
@@ -309,6 +349,18 @@ impl<S: MachineSpec> StateMachine<S> {
         }
     }
 
+    /// Complete a graceful Stop once the machine reaches a terminal or error
+    /// state; any other outcome leaves the Stop pending.
+    fn finish_stop(&mut self, outcome: DispatchOutcome<S::State>) -> DispatchOutcome<S::State> {
+        match outcome {
+            DispatchOutcome::Done(_) | DispatchOutcome::Failed => {
+                self.transition_to_init();
+                DispatchOutcome::Stopped
+            }
+            other => other,
+        }
+    }
+
     /// Process event while in operational state.
     fn process_operational_event(&mut self, event: S::Event) -> DispatchOutcome<S::State> {
         let current = match self.current {
@@ -321,6 +373,12 @@ impl<S: MachineSpec> StateMachine<S> {
             return DispatchOutcome::HandledNoTransition;
         }
 
+        self.run_handlers(current, event)
+    }
+
+    /// Walk the handler tables from `current` up to VirtualRoot and apply the
+    /// first matching rule.
+    fn run_handlers(&mut self, current: S::State, event: S::Event) -> DispatchOutcome<S::State> {
         let event_tag = event.event_tag();
         let current_path = current.path();
 
@@ -414,6 +472,7 @@ impl<S: MachineSpec> StateMachine<S> {
 
     /// Transition to implicit Init (with LCA exit callbacks).
     fn transition_to_init(&mut self) {
+        self.stopping = false;
         match self.current {
             MachineState::Init => {
                 // Already in Init, nothing to do
@@ -11,6 +11,7 @@ extern crate std;
 pub mod tracing;
 pub mod accessor;
 pub mod actor;
+pub mod batch;
 pub mod capability;
 pub mod engine;
 pub mod event_tag;
@@ -18,6 +19,7 @@ pub mod generated;
 pub mod lifecycle;
 pub mod mailboxes;
 pub mod messaging;
+pub mod multiplex;
 pub mod prelude;
 pub mod spec;
 pub mod supervision;
@@ -28,13 +30,20 @@ pub mod transition;
 mod tests;
 
 pub use accessor::HasSelfId;
-pub use actor::{run_actor, run_actor_auto_start, run_actor_to_completion};
-pub use capability::{BloxRuntime, DynamicChannelCap, KillCapability, NoKill, StaticChannelCap};
+pub use actor::{
+    run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion,
+    run_actor_to_completion_batched,
+};
+pub use batch::{BatchConfig, YieldBudget};
+pub use capability::{
+    BloxRuntime, ClockCap, DynamicChannelCap, KillCapability, NoKill, StaticChannelCap,
+};
 pub use engine::{DispatchOutcome, MachineState, StateMachine};
 pub use event_tag::{EventTag, LifecycleEvent, LIFECYCLE_TAG, WILDCARD_TAG};
-pub use lifecycle::{ChildLifecycleEvent, LifecycleCommand};
+pub use lifecycle::{ChildLifecycleEvent, LifecycleCommand, ShutdownEscalation};
 pub use mailboxes::{Mailboxes, NoMailboxes};
 pub use messaging::{ActorId, ActorRef, Envelope};
+pub use multiplex::{run_multiplexed, run_multiplexed_batched, ActorSlot, Multiplexed, SlotPoll};
 pub use spec::{MachineSpec, StateFns};
 pub use supervision::report_outcome;
 pub use topology::{LeafState, StateTopology};
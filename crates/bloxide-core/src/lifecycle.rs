@@ -65,4 +65,22 @@ pub enum ChildLifecycleEvent {
     Killed { child_id: ActorId },
     /// Child responded to a Ping — its run loop is healthy.
     Alive { child_id: ActorId },
+    /// Child missed a shutdown deadline and the managing blox escalated to
+    /// the next level. Sent by the managing blox, not the child's run loop.
+    ShutdownEscalated {
+        child_id: ActorId,
+        to: ShutdownEscalation,
+    },
+}
+
+/// The step taken when a child misses a shutdown deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownEscalation {
+    /// `AbortCommand` was sent on the child's abort mailbox.
+    Abort,
+    /// The child's task was killed with `KillCapability::kill`.
+    Kill,
+    /// The child has no abort mailbox or kill handle left to try. The
+    /// managing blox stops waiting for it.
+    Abandon,
 }
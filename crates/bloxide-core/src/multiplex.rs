@@ -0,0 +1,163 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Run many actors inside one task.
+//!
+//! Each actor normally gets its own task (`actor_task!`, `spawn_child!`). On a
+//! small MCU every Embassy task carries its own future storage, so a
+//! multiplexed task trades per-actor tasks for one task that polls a fixed
+//! set of [`Multiplexed`] slots in round-robin order.
+//!
+//! A slot dispatches at most one event per call, so run-to-completion holds
+//! per actor and between actors: one handler finishes before the next
+//! begins. [`ActorSlot`] covers unsupervised actors; each runtime provides a
+//! `SupervisedSlot` that adds the lifecycle and abort mailboxes of its
+//! supervised run loop.
+//!
+//! ```ignore
+//! let mut ping = ActorSlot::new(ping_machine, ping_mbox);
+//! let mut pong = SupervisedSlot::new(pong_machine, pong_mbox, pong_lc, pong_id, notify);
+//! let mut slots: [&mut dyn Multiplexed; 2] = [&mut ping, &mut pong];
+//! run_multiplexed(&mut slots).await;
+//! ```
+use core::future::poll_fn;
+use core::task::{Context, Poll};
+
+use crate::{
+    batch::{BatchConfig, YieldBudget},
+    engine::StateMachine,
+    mailboxes::Mailboxes,
+    spec::MachineSpec,
+};
+
+/// What one [`Multiplexed::poll_dispatch`] call did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotPoll {
+    /// One event was dispatched; more may be ready.
+    Dispatched,
+    /// Nothing was ready. The slot registered the waker from the context.
+    Idle,
+    /// The actor has ended and will never dispatch again.
+    Finished,
+}
+
+/// An actor that a multiplexed task can drive one event at a time.
+pub trait Multiplexed {
+    /// Dispatch at most one ready event.
+    ///
+    /// Must keep returning [`SlotPoll::Finished`] once the actor has ended.
+    fn poll_dispatch(&mut self, cx: &mut Context<'_>) -> SlotPoll;
+}
+
+/// An unsupervised actor in a multiplexed task, the counterpart of
+/// [`run_actor`](crate::actor::run_actor).
+///
+/// The actor ends when its mailboxes close.
+pub struct ActorSlot<S: MachineSpec, M> {
+    machine: StateMachine<S>,
+    mailboxes: M,
+    finished: bool,
+}
+
+impl<S: MachineSpec, M: Mailboxes<S::Event>> ActorSlot<S, M> {
+    pub fn new(machine: StateMachine<S>, mailboxes: M) -> Self {
+        Self {
+            machine,
+            mailboxes,
+            finished: false,
+        }
+    }
+
+    pub fn machine(&self) -> &StateMachine<S> {
+        &self.machine
+    }
+}
+
+impl<S: MachineSpec, M: Mailboxes<S::Event>> Multiplexed for ActorSlot<S, M> {
+    fn poll_dispatch(&mut self, cx: &mut Context<'_>) -> SlotPoll {
+        if self.finished {
+            return SlotPoll::Finished;
+        }
+        match self.mailboxes.poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                self.machine.dispatch(event);
+                SlotPoll::Dispatched
+            }
+            Poll::Ready(None) => {
+                self.finished = true;
+                SlotPoll::Finished
+            }
+            Poll::Pending => SlotPoll::Idle,
+        }
+    }
+}
+
+/// Drive `actors` on the current task until every one has finished.
+///
+/// Each wake-up visits every slot once, starting one slot further along than
+/// the previous round, so a busy actor cannot starve the others.
+pub async fn run_multiplexed<A>(actors: &mut [&mut A])
+where
+    A: Multiplexed + ?Sized,
+{
+    run_multiplexed_batched(actors, BatchConfig::UNBATCHED).await;
+}
+
+/// Like [`run_multiplexed`], but lets each slot dispatch up to
+/// `batch.max_batch` ready events per round, and yields to the executor
+/// whenever `batch.yield_budget` events have been dispatched in total.
+pub async fn run_multiplexed_batched<A>(actors: &mut [&mut A], batch: BatchConfig)
+where
+    A: Multiplexed + ?Sized,
+{
+    let mut budget = YieldBudget::new(&batch);
+    let mut first = 0;
+    loop {
+        let round = poll_fn(|cx| poll_round(actors, &mut first, batch.max_batch, cx)).await;
+        match round {
+            Some(dispatched) => budget.consume(dispatched),
+            None => return,
+        }
+        budget.checkpoint().await;
+    }
+}
+
+/// Visit every slot once. Returns `Ready(None)` when all have finished and
+/// `Ready(Some(n))` when `n > 0` events were dispatched.
+fn poll_round<A>(
+    actors: &mut [&mut A],
+    first: &mut usize,
+    max_batch: usize,
+    cx: &mut Context<'_>,
+) -> Poll<Option<usize>>
+where
+    A: Multiplexed + ?Sized,
+{
+    let count = actors.len();
+    let mut dispatched = 0;
+    let mut finished = 0;
+    for offset in 0..count {
+        let slot = &mut *actors[(*first + offset) % count];
+        let mut handled = 0;
+        while handled < max_batch {
+            match slot.poll_dispatch(cx) {
+                SlotPoll::Dispatched => handled += 1,
+                SlotPoll::Idle => break,
+                SlotPoll::Finished => {
+                    finished += 1;
+                    break;
+                }
+            }
+        }
+        dispatched += handled;
+    }
+    if count > 0 {
+        *first = (*first + 1) % count;
+    }
+
+    if finished == count {
+        Poll::Ready(None)
+    } else if dispatched > 0 {
+        Poll::Ready(Some(dispatched))
+    } else {
+        Poll::Pending
+    }
+}
@@ -52,6 +52,22 @@ pub trait MachineSpec: Sized + 'static {
     /// Does NOT fire on Reset (which skips Init).
     fn on_init_exit(_ctx: &mut Self::Ctx) {}
 
+    /// Domain event that winds the machine down before a Stop completes.
+    ///
+    /// - `None` (default): Stop exits to Init at once and reports
+    ///   `DispatchOutcome::Stopped`.
+    /// - `Some(event)`: Stop from a non-terminal state dispatches `event`
+    ///   instead — even from an error state, which otherwise absorbs domain
+    ///   events. The machine keeps handling events and reports `Stopped`
+    ///   (exit chain and `on_init_entry` included) only once it reaches a
+    ///   terminal or error state. If no rule handles `event`, or it leaves
+    ///   the machine in a terminal or error state, Stop completes at once.
+    ///   A supervisor uses this to stop its own children before it reports
+    ///   Stopped to its parent.
+    fn stop_event(_ctx: &Self::Ctx) -> Option<Self::Event> {
+        None
+    }
+
     /// User-defined error recovery state for `Guard::Fail`.
     ///
     /// - `Some(state)`: the engine transitions to this state (firing exit/entry
@@ -260,3 +260,76 @@ pub fn machine_in_c() -> StateMachine<TSpec> {
     take_log();
     m
 }
+
+// ── Graceful-stop fixture ───────────────────────────────────────────────────
+//
+// Same topology and events as `TSpec`, but Stop dispatches `SelfLoop` and
+// only completes once `GoB` reaches the terminal state B.
+
+pub struct GracefulSpec;
+
+impl MachineSpec for GracefulSpec {
+    type State = TState;
+    type Event = TEvent;
+    type Ctx = TCtx;
+    type Mailboxes<R: crate::capability::BloxRuntime> = crate::mailboxes::NoMailboxes;
+
+    const HANDLER_TABLE: &'static [&'static crate::spec::StateFns<Self>] = &[
+        &GRACEFUL_EMPTY_FNS,
+        &GRACEFUL_A_FNS,
+        &GRACEFUL_EMPTY_FNS,
+        &GRACEFUL_EMPTY_FNS,
+        &GRACEFUL_EMPTY_FNS,
+    ];
+
+    fn initial_state() -> TState {
+        TState::A
+    }
+
+    fn on_init_entry(_ctx: &mut TCtx) {
+        log("Init:entry");
+    }
+
+    fn stop_event(_ctx: &TCtx) -> Option<TEvent> {
+        Some(TEvent::SelfLoop)
+    }
+
+    fn is_terminal(state: &TState) -> bool {
+        matches!(state, TState::B)
+    }
+}
+
+pub static GRACEFUL_EMPTY_FNS: StateFns<GracefulSpec> = StateFns {
+    on_entry: &[],
+    on_exit: &[],
+    transitions: &[],
+};
+
+pub static GRACEFUL_A_FNS: StateFns<GracefulSpec> = StateFns {
+    on_entry: &[|_| log("A:entry")],
+    on_exit: &[|_| log("A:exit")],
+    transitions: &[
+        StateRule {
+            event_tag: TEvent::GO_B_TAG,
+            matches: |ev| matches!(ev, TEvent::GoB),
+            actions: &[],
+            guard: |_, _, _| Guard::Transition(LeafState::new(TState::B)),
+        },
+        StateRule {
+            event_tag: TEvent::SELF_LOOP_TAG,
+            matches: |ev| matches!(ev, TEvent::SelfLoop),
+            actions: &[|_, _| {
+                log("A:wind_down");
+                ActionResult::Ok
+            }],
+            guard: |_, _, _| Guard::Stay,
+        },
+    ],
+};
+
+pub fn graceful_machine_in_a() -> StateMachine<GracefulSpec> {
+    let mut m = StateMachine::<GracefulSpec>::new(TCtx);
+    m.dispatch(TEvent::Lifecycle(LifecycleCommand::Start));
+    take_log();
+    m
+}
@@ -311,6 +311,52 @@ mod hsm_engine {
         assert!(take_log().contains(&"A:exit") || take_log().contains(&"Top:exit"));
     }
 
+    // ── Graceful Stop (MachineSpec::stop_event) ────────────────────────────
+
+    #[test]
+    fn graceful_stop_waits_for_a_terminal_state() {
+        let mut m = graceful_machine_in_a();
+        let outcome = m.dispatch(TEvent::Lifecycle(LifecycleCommand::Stop));
+        assert!(matches!(outcome, DispatchOutcome::HandledNoTransition));
+        assert!(matches!(m.current_state(), MachineState::State(TState::A)));
+        assert_eq!(take_log(), vec!["A:wind_down"]);
+
+        // A repeated Stop keeps waiting rather than re-dispatching
+        let outcome = m.dispatch(TEvent::Lifecycle(LifecycleCommand::Stop));
+        assert!(matches!(outcome, DispatchOutcome::HandledNoTransition));
+        assert!(take_log().is_empty());
+
+        let outcome = m.dispatch(TEvent::GoB);
+        assert!(matches!(outcome, DispatchOutcome::Stopped));
+        assert!(m.current_state().is_init());
+        assert_eq!(take_log(), vec!["A:exit", "Init:entry"]);
+    }
+
+    #[test]
+    fn graceful_stop_from_terminal_state_is_immediate() {
+        let mut m = graceful_machine_in_a();
+        m.dispatch(TEvent::GoB);
+        take_log();
+        let outcome = m.dispatch(TEvent::Lifecycle(LifecycleCommand::Stop));
+        assert!(matches!(outcome, DispatchOutcome::Stopped));
+        assert!(m.current_state().is_init());
+        assert_eq!(take_log(), vec!["Init:entry"]);
+    }
+
+    #[test]
+    fn reset_cancels_a_graceful_stop() {
+        let mut m = graceful_machine_in_a();
+        m.dispatch(TEvent::Lifecycle(LifecycleCommand::Stop));
+        m.dispatch(TEvent::Lifecycle(LifecycleCommand::Reset));
+        take_log();
+        // Reaching the terminal state is a normal Done again, not a Stop
+        let outcome = m.dispatch(TEvent::GoB);
+        assert!(matches!(
+            outcome,
+            DispatchOutcome::Done(MachineState::State(TState::B))
+        ));
+    }
+
     #[test]
     fn ping_command_returns_alive_outcome() {
         let mut m = machine_in_a();
@@ -56,9 +56,14 @@ pub fn delegatable_inner(item: TokenStream) -> Result<TokenStream> {
             TraitItem::Type(assoc) => {
                 let type_name = &assoc.ident;
                 if has_generics {
-                    // For generic traits, use the trait with trait_args from macro
+                    // For generic traits, name the trait with the same `$aN`
+                    // captures the macro arms bind for `trait_args`.
+                    let args = substitute_generic_params(
+                        &quote! { #(#generic_param_names),* },
+                        &generic_param_names,
+                    );
                     assoc_type_items.push(quote! {
-                        type #type_name = <$field_type as #trait_name<$($trait_args)*>>::#type_name;
+                        type #type_name = <$field_type as #trait_name<#args>>::#type_name;
                     });
                 } else {
                     // For non-generic traits, use the trait without angle brackets
@@ -124,3 +124,47 @@ fn forwarding_generic_wrapper() {
     w.set_round(99);
     assert_eq!(w.round(), 99);
 }
+
+// ── Generic trait with an associated type ────────────────────────────────────
+
+#[delegatable]
+pub trait HoldsItems<T> {
+    type Store: AsRef<[T]>;
+    fn items(&self) -> &Self::Store;
+}
+
+struct ItemsInner {
+    items: Vec<u8>,
+}
+
+impl HoldsItems<u8> for ItemsInner {
+    type Store = Vec<u8>;
+    fn items(&self) -> &Vec<u8> {
+        &self.items
+    }
+}
+
+struct ItemsWrapper {
+    inner: ItemsInner,
+}
+
+__delegate_HoldsItems! {
+    struct_name: ItemsWrapper,
+    field: inner,
+    field_type: ItemsInner,
+    impl_generics: {},
+    ty_generics: {},
+    where_clause: {},
+    trait_args: { u8 }
+}
+
+#[test]
+fn forwarding_generic_trait_associated_type() {
+    let w = ItemsWrapper {
+        inner: ItemsInner {
+            items: vec![1, 2, 3],
+        },
+    };
+    let store: &Vec<u8> = w.items();
+    assert_eq!(store.as_slice(), &[1, 2, 3]);
+}
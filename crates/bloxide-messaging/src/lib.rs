@@ -40,8 +40,14 @@
 //! }
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use bloxide_core::{capability::BloxRuntime, messaging::ActorRef};
 
+#[cfg(feature = "postcard")]
+pub mod wire;
+
 /// Reference to this actor's own mailbox (for self-delivered messages).
 ///
 /// Auto-generated from a `self_ref: ActorRef<M, R>` field by `#[derive(BloxCtx)]`.
@@ -0,0 +1,82 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! `postcard` encoding for message enums generated with
+//! `serialize = "serde"`.
+//!
+//! Generated enums serialize as a `(wire_tag, payload)` pair, so the bytes
+//! produced here stay decodable when variants are reordered or added. All
+//! helpers work on caller-provided buffers and need no allocator; `to_vec`
+//! is available with the `alloc` feature.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+pub use postcard::Error as WireError;
+
+/// Encode `msg` into `buf`, returning the used prefix.
+pub fn to_slice<'a, M: Serialize>(msg: &M, buf: &'a mut [u8]) -> Result<&'a mut [u8], WireError> {
+    postcard::to_slice(msg, buf)
+}
+
+/// Decode one message from `bytes`.
+pub fn from_bytes<'a, M: Deserialize<'a>>(bytes: &'a [u8]) -> Result<M, WireError> {
+    postcard::from_bytes(bytes)
+}
+
+/// Encode `msg` into `buf` and decode it back. Useful in tests to check that
+/// a message type survives the wire unchanged.
+pub fn round_trip<M: Serialize + DeserializeOwned>(
+    msg: &M,
+    buf: &mut [u8],
+) -> Result<M, WireError> {
+    let used = to_slice(msg, buf)?;
+    from_bytes(used)
+}
+
+/// Encode `msg` into a freshly allocated buffer.
+#[cfg(feature = "alloc")]
+pub fn to_vec<M: Serialize>(msg: &M) -> Result<alloc::vec::Vec<u8>, WireError> {
+    postcard::to_allocvec(msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ping_pong_messages::{Ping, PingPongMsg, Pong, Resume};
+
+    #[test]
+    fn generated_enum_round_trips() {
+        let mut buf = [0u8; 16];
+        for msg in [
+            PingPongMsg::Ping(Ping { round: 3 }),
+            PingPongMsg::Pong(Pong { round: u32::MAX }),
+            PingPongMsg::Resume(Resume),
+        ] {
+            let back = round_trip(&msg, &mut buf).unwrap();
+            assert_eq!(back.message_name(), msg.message_name());
+            assert_eq!(back.wire_tag(), msg.wire_tag());
+        }
+        let back = round_trip(&PingPongMsg::Pong(Pong { round: 9 }), &mut buf).unwrap();
+        assert!(matches!(back, PingPongMsg::Pong(Pong { round: 9 })));
+    }
+
+    #[test]
+    fn encoding_leads_with_wire_tag() {
+        let mut buf = [0u8; 16];
+        let msg = PingPongMsg::Resume(Resume);
+        let used = to_slice(&msg, &mut buf).unwrap();
+        let tag: u32 = from_bytes(used).unwrap();
+        assert_eq!(tag, msg.wire_tag());
+    }
+
+    #[test]
+    fn unknown_wire_tag_is_rejected() {
+        let mut buf = [0u8; 16];
+        let used = to_slice(&(0xdead_beef_u32, ()), &mut buf).unwrap();
+        assert!(from_bytes::<PingPongMsg>(used).is_err());
+    }
+
+    #[test]
+    fn buffer_too_small_is_an_error() {
+        let mut buf = [0u8; 2];
+        assert!(to_slice(&PingPongMsg::Ping(Ping { round: 1 }), &mut buf).is_err());
+    }
+}
@@ -0,0 +1,410 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Peer collections and multicast sends.
+//!
+//! [`PeerSet`] is the storage behind [`HasPeers`](crate::HasPeers): a
+//! `Vec<ActorRef<M, R>>` (with `alloc`) or a bounded [`ActorGroup`]. Both get
+//! the same send API — [`try_broadcast`](PeerSet::try_broadcast) and
+//! [`send_all`](PeerSet::send_all) — which clone the message for every member
+//! but the last and report per-member failures through a callback.
+
+use core::fmt;
+
+use bloxide_core::{
+    capability::BloxRuntime,
+    messaging::{ActorId, ActorRef},
+};
+
+// ── GroupFull / BroadcastReport ───────────────────────────────────────────────
+
+/// A bounded peer collection had no room for `peer_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupFull {
+    pub peer_id: ActorId,
+}
+
+impl fmt::Display for GroupFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "peer group full, cannot add actor {}", self.peer_id)
+    }
+}
+
+/// Outcome of a multicast send.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BroadcastReport {
+    /// Members the message was queued for.
+    pub delivered: usize,
+    /// Members whose send failed.
+    pub failed: usize,
+}
+
+impl BroadcastReport {
+    /// Whether every member received the message.
+    pub fn is_complete(&self) -> bool {
+        self.failed == 0
+    }
+
+    fn record<E>(
+        &mut self,
+        peer_id: ActorId,
+        result: Result<(), E>,
+        on_failure: &mut impl FnMut(ActorId, E),
+    ) {
+        match result {
+            Ok(()) => self.delivered += 1,
+            Err(e) => {
+                self.failed += 1;
+                on_failure(peer_id, e);
+            }
+        }
+    }
+}
+
+// ── PeerSet ───────────────────────────────────────────────────────────────────
+
+/// A collection of peer refs, keyed by actor ID.
+#[allow(async_fn_in_trait)]
+pub trait PeerSet<M: Send + 'static, R: BloxRuntime> {
+    /// The current members, in insertion order.
+    fn as_slice(&self) -> &[ActorRef<M, R>];
+
+    /// Add `peer`, replacing any member with the same ID.
+    fn add_peer(&mut self, peer: ActorRef<M, R>) -> Result<(), GroupFull>;
+
+    /// Remove the member with `peer_id`. Returns whether one was present.
+    fn remove_peer(&mut self, peer_id: ActorId) -> bool;
+
+    fn clear_peers(&mut self);
+
+    /// `try_send` a clone of `msg` to every member, ignoring failures beyond
+    /// counting them.
+    fn try_broadcast(&self, from: ActorId, msg: M) -> BroadcastReport
+    where
+        M: Clone,
+    {
+        self.try_broadcast_with(from, msg, |_, _| {})
+    }
+
+    /// `try_send` a clone of `msg` to every member, calling `on_failure` for
+    /// each member whose mailbox refused it.
+    fn try_broadcast_with(
+        &self,
+        from: ActorId,
+        msg: M,
+        mut on_failure: impl FnMut(ActorId, R::TrySendError),
+    ) -> BroadcastReport
+    where
+        M: Clone,
+    {
+        let mut report = BroadcastReport::default();
+        let Some((last, rest)) = self.as_slice().split_last() else {
+            return report;
+        };
+        for peer in rest {
+            report.record(peer.id(), peer.try_send(from, msg.clone()), &mut on_failure);
+        }
+        report.record(last.id(), last.try_send(from, msg), &mut on_failure);
+        report
+    }
+
+    /// Send a clone of `msg` to every member in turn, awaiting capacity for
+    /// each.
+    async fn send_all(&self, from: ActorId, msg: M) -> BroadcastReport
+    where
+        M: Clone,
+    {
+        self.send_all_with(from, msg, |_, _| {}).await
+    }
+
+    /// Like [`send_all`](Self::send_all), calling `on_failure` for each
+    /// member whose channel is closed.
+    async fn send_all_with(
+        &self,
+        from: ActorId,
+        msg: M,
+        mut on_failure: impl FnMut(ActorId, R::SendError),
+    ) -> BroadcastReport
+    where
+        M: Clone,
+    {
+        let mut report = BroadcastReport::default();
+        let Some((last, rest)) = self.as_slice().split_last() else {
+            return report;
+        };
+        for peer in rest {
+            let result = peer.send(from, msg.clone()).await;
+            report.record(peer.id(), result, &mut on_failure);
+        }
+        let result = last.send(from, msg).await;
+        report.record(last.id(), result, &mut on_failure);
+        report
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: Send + 'static, R: BloxRuntime> PeerSet<M, R> for alloc::vec::Vec<ActorRef<M, R>> {
+    fn as_slice(&self) -> &[ActorRef<M, R>] {
+        self
+    }
+
+    fn add_peer(&mut self, peer: ActorRef<M, R>) -> Result<(), GroupFull> {
+        match self.iter_mut().find(|r| r.id() == peer.id()) {
+            Some(slot) => *slot = peer,
+            None => self.push(peer),
+        }
+        Ok(())
+    }
+
+    fn remove_peer(&mut self, peer_id: ActorId) -> bool {
+        let before = self.len();
+        self.retain(|r| r.id() != peer_id);
+        self.len() != before
+    }
+
+    fn clear_peers(&mut self) {
+        self.clear();
+    }
+}
+
+// ── ActorGroup ────────────────────────────────────────────────────────────────
+
+/// A peer collection holding at most `N` members, for contexts without an
+/// allocator.
+pub struct ActorGroup<M: Send + 'static, R: BloxRuntime, const N: usize> {
+    members: heapless::Vec<ActorRef<M, R>, N>,
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> ActorGroup<M, R, N> {
+    pub const fn new() -> Self {
+        Self {
+            members: heapless::Vec::new(),
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.members.is_full()
+    }
+
+    pub fn contains(&self, peer_id: ActorId) -> bool {
+        self.members.iter().any(|r| r.id() == peer_id)
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, ActorRef<M, R>> {
+        self.members.iter()
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> PeerSet<M, R> for ActorGroup<M, R, N> {
+    fn as_slice(&self) -> &[ActorRef<M, R>] {
+        &self.members
+    }
+
+    fn add_peer(&mut self, peer: ActorRef<M, R>) -> Result<(), GroupFull> {
+        if let Some(slot) = self.members.iter_mut().find(|r| r.id() == peer.id()) {
+            *slot = peer;
+            return Ok(());
+        }
+        self.members
+            .push(peer)
+            .map_err(|peer| GroupFull { peer_id: peer.id() })
+    }
+
+    fn remove_peer(&mut self, peer_id: ActorId) -> bool {
+        let before = self.members.len();
+        self.members.retain(|r| r.id() != peer_id);
+        self.members.len() != before
+    }
+
+    fn clear_peers(&mut self) {
+        self.members.clear();
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> Default for ActorGroup<M, R, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> Clone for ActorGroup<M, R, N> {
+    fn clone(&self) -> Self {
+        Self {
+            members: self.members.clone(),
+        }
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> fmt::Debug for ActorGroup<M, R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.members.iter().map(|r| r.id()))
+            .finish()
+    }
+}
+
+impl<'a, M: Send + 'static, R: BloxRuntime, const N: usize> IntoIterator
+    for &'a ActorGroup<M, R, N>
+{
+    type Item = &'a ActorRef<M, R>;
+    type IntoIter = core::slice::Iter<'a, ActorRef<M, R>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::{apply_peer_control, AddPeer, HasPeers, PeerCtrl, RemovePeer};
+    use bloxide_core::capability::DynamicChannelCap;
+    use bloxide_test_runtime::{TestReceiver, TestRuntime};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    type Ref = ActorRef<u32, TestRuntime>;
+
+    fn member() -> (Ref, TestReceiver<u32>) {
+        TestRuntime::channel(TestRuntime::alloc_actor_id(), 8)
+    }
+
+    struct Ctx {
+        peers: ActorGroup<u32, TestRuntime, 2>,
+    }
+
+    impl HasPeers<u32, TestRuntime> for Ctx {
+        type Peers = ActorGroup<u32, TestRuntime, 2>;
+        fn peers(&self) -> &Self::Peers {
+            &self.peers
+        }
+        fn peers_mut(&mut self) -> &mut Self::Peers {
+            &mut self.peers
+        }
+    }
+
+    fn add(peer_ref: &Ref) -> PeerCtrl<u32, TestRuntime> {
+        PeerCtrl::AddPeer(AddPeer {
+            peer_id: peer_ref.id(),
+            peer_ref: peer_ref.clone(),
+        })
+    }
+
+    #[test]
+    fn group_is_bounded_and_keyed_by_id() {
+        let mut group: ActorGroup<u32, TestRuntime, 2> = ActorGroup::new();
+        let (a, _ra) = member();
+        let (b, _rb) = member();
+        let (c, _rc) = member();
+
+        group.add_peer(a.clone()).unwrap();
+        group.add_peer(a.clone()).unwrap();
+        assert_eq!(group.len(), 1, "re-adding an ID replaces it");
+        group.add_peer(b.clone()).unwrap();
+        assert_eq!(
+            group.add_peer(c.clone()),
+            Err(GroupFull { peer_id: c.id() })
+        );
+
+        assert!(group.remove_peer(a.id()));
+        assert!(!group.remove_peer(a.id()));
+        group.add_peer(c.clone()).unwrap();
+        let ids: Vec<_> = group.iter().map(|r| r.id()).collect();
+        assert_eq!(ids, [b.id(), c.id()]);
+    }
+
+    #[test]
+    fn try_broadcast_reaches_every_member_and_reports_failures() {
+        let mut group: ActorGroup<u32, TestRuntime, 4> = ActorGroup::new();
+        let (a, mut ra) = member();
+        let (b, mut rb) = member();
+        let (c, mut rc) = member();
+        for r in [&a, &b, &c] {
+            group.add_peer(r.clone()).unwrap();
+        }
+        b.sender().set_full(true);
+
+        let mut failed = Vec::new();
+        let report = group.try_broadcast_with(0, 7, |id, _| failed.push(id));
+        assert_eq!(
+            report,
+            BroadcastReport {
+                delivered: 2,
+                failed: 1
+            }
+        );
+        assert!(!report.is_complete());
+        assert_eq!(failed, [b.id()]);
+        assert_eq!(ra.drain_payloads(), [7]);
+        assert!(rb.drain_payloads().is_empty());
+        assert_eq!(rc.drain_payloads(), [7]);
+    }
+
+    #[test]
+    fn send_all_awaits_each_member() {
+        let (a, mut ra) = member();
+        let (b, mut rb) = member();
+        let peers: Vec<Ref> = Vec::from([a, b]);
+
+        let mut fut = pin!(peers.send_all(3, 11));
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(report) = fut.as_mut().poll(&mut cx) else {
+            panic!("test runtime sends complete immediately");
+        };
+        assert_eq!(
+            report,
+            BroadcastReport {
+                delivered: 2,
+                failed: 0
+            }
+        );
+        assert_eq!(ra.drain_envelopes()[0].0, 3);
+        assert_eq!(rb.drain_payloads(), [11]);
+    }
+
+    #[test]
+    fn empty_group_broadcast_is_a_no_op() {
+        let group: ActorGroup<u32, TestRuntime, 1> = ActorGroup::default();
+        assert_eq!(group.try_broadcast(0, 1), BroadcastReport::default());
+    }
+
+    #[test]
+    fn apply_peer_control_keeps_group_in_sync() {
+        let mut ctx = Ctx {
+            peers: ActorGroup::new(),
+        };
+        let (a, _ra) = member();
+        let (b, _rb) = member();
+        let (c, _rc) = member();
+
+        apply_peer_control(&mut ctx, &add(&a)).unwrap();
+        apply_peer_control(&mut ctx, &add(&b)).unwrap();
+        assert_eq!(
+            apply_peer_control(&mut ctx, &add(&c)),
+            Err(GroupFull { peer_id: c.id() })
+        );
+        apply_peer_control(
+            &mut ctx,
+            &PeerCtrl::RemovePeer(RemovePeer { peer_id: a.id() }),
+        )
+        .unwrap();
+        apply_peer_control(&mut ctx, &add(&c)).unwrap();
+
+        assert!(!ctx.peers().contains(a.id()));
+        assert!(ctx.peers().contains(b.id()) && ctx.peers().contains(c.id()));
+    }
+}
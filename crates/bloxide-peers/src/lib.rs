@@ -5,12 +5,19 @@
 //! `introduce_peers` / `apply_peer_control` helper functions.  Domain
 //! code uses these directly instead of defining per-domain copies like
 //! `WorkerCtrl`, `AddWorkerPeer`, etc.
+//!
+//! Peer refs live in a [`PeerSet`] — a `Vec` or a bounded [`ActorGroup`] —
+//! which also provides the multicast send API.
 
 #![no_std]
+#[cfg(feature = "alloc")]
 extern crate alloc;
-use alloc::vec::Vec;
 use core::fmt;
 
+mod group;
+
+pub use group::{ActorGroup, BroadcastReport, GroupFull, PeerSet};
+
 use bloxide_core::{
     capability::BloxRuntime,
     messaging::{ActorId, ActorRef},
@@ -91,10 +98,12 @@ impl<M: Send + 'static, R: BloxRuntime> fmt::Debug for PeerCtrl<M, R> {
 /// Accessor trait for contexts that track a collection of peer refs.
 #[delegatable]
 pub trait HasPeers<M: Send + 'static, R: BloxRuntime> {
-    /// Returns the current peer refs.
-    fn peers(&self) -> &[ActorRef<M, R>];
+    /// Peer storage: `Vec<ActorRef<M, R>>` or an [`ActorGroup`].
+    type Peers: PeerSet<M, R>;
+    /// Returns the current peer collection.
+    fn peers(&self) -> &Self::Peers;
     /// Returns the mutable peer collection.
-    fn peers_mut(&mut self) -> &mut Vec<ActorRef<M, R>>;
+    fn peers_mut(&mut self) -> &mut Self::Peers;
 }
 
 /// Introduce two actors to each other by sending `AddPeer` on both control channels.
@@ -128,17 +137,19 @@ pub fn introduce_peers<M, R>(
 
 /// Apply a `PeerCtrl` command to a context's peer collection.
 ///
-/// Handles both `AddPeer` and `RemovePeer` variants.
-pub fn apply_peer_control<M, R, C>(ctx: &mut C, ctrl: &PeerCtrl<M, R>)
+/// `AddPeer` for an ID already present replaces that member's ref. Fails
+/// only when a bounded collection is full; the command is then not applied.
+pub fn apply_peer_control<M, R, C>(ctx: &mut C, ctrl: &PeerCtrl<M, R>) -> Result<(), GroupFull>
 where
     M: Send + 'static,
     R: BloxRuntime,
     C: HasPeers<M, R>,
 {
     match ctrl {
-        PeerCtrl::AddPeer(add) => ctx.peers_mut().push(add.peer_ref.clone()),
+        PeerCtrl::AddPeer(add) => ctx.peers_mut().add_peer(add.peer_ref.clone()),
         PeerCtrl::RemovePeer(remove) => {
-            ctx.peers_mut().retain(|r| r.id() != remove.peer_id);
+            ctx.peers_mut().remove_peer(remove.peer_id);
+            Ok(())
         }
     }
 }
@@ -0,0 +1,104 @@
+// Copyright 2025 Bloxide, all rights reserved
+use std::fmt;
+use std::string::String;
+use std::vec::Vec;
+
+/// Converts messages of type `M` to and from the bytes carried in a frame.
+///
+/// Both ends of a link must agree on the codec for each mailbox. The codec is
+/// shared between the forwarding and delivery tasks, so it must be
+/// `Send + Sync`.
+pub trait Codec<M>: Send + Sync + 'static {
+    /// Append the encoded form of `msg` to `buf`.
+    fn encode(&self, msg: &M, buf: &mut Vec<u8>) -> Result<(), CodecError>;
+
+    /// Decode one message from a complete frame payload.
+    fn decode(&self, bytes: &[u8]) -> Result<M, CodecError>;
+}
+
+/// A message could not be encoded or decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodecError(pub String);
+
+impl CodecError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self(reason.into())
+    }
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "codec error: {}", self.0)
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+/// Pass-through codec for raw `Vec<u8>` messages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BytesCodec;
+
+impl Codec<Vec<u8>> for BytesCodec {
+    fn encode(&self, msg: &Vec<u8>, buf: &mut Vec<u8>) -> Result<(), CodecError> {
+        buf.extend_from_slice(msg);
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>, CodecError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// `postcard` codec for any serde message, including enums generated with
+/// `serialize = "serde"`.
+#[cfg(feature = "postcard")]
+pub struct PostcardCodec<M>(core::marker::PhantomData<fn() -> M>);
+
+#[cfg(feature = "postcard")]
+impl<M> PostcardCodec<M> {
+    pub const fn new() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<M> Default for PostcardCodec<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<M> Codec<M> for PostcardCodec<M>
+where
+    M: serde::Serialize + serde::de::DeserializeOwned + 'static,
+{
+    fn encode(&self, msg: &M, buf: &mut Vec<u8>) -> Result<(), CodecError> {
+        let bytes =
+            bloxide_messaging::wire::to_vec(msg).map_err(|e| CodecError::new(e.to_string()))?;
+        buf.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<M, CodecError> {
+        bloxide_messaging::wire::from_bytes(bytes).map_err(|e| CodecError::new(e.to_string()))
+    }
+}
+
+#[cfg(all(test, feature = "postcard"))]
+mod tests {
+    use super::*;
+    use ping_pong_messages::{Ping, PingPongMsg};
+
+    #[test]
+    fn postcard_codec_round_trips_generated_messages() {
+        let codec = PostcardCodec::<PingPongMsg>::new();
+        let mut buf = Vec::new();
+        codec
+            .encode(&PingPongMsg::Ping(Ping { round: 4 }), &mut buf)
+            .unwrap();
+        let back = codec.decode(&buf).unwrap();
+        assert!(matches!(back, PingPongMsg::Ping(Ping { round: 4 })));
+        assert!(codec.decode(&buf[..1]).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Length-prefixed wire framing.
+//!
+//! Every frame is `len: u32 | mailbox: u32 | from: u64 | payload`, all
+//! integers big-endian, where `len` counts everything after itself.
+
+use bloxide_core::messaging::ActorId;
+use std::vec::Vec;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::RemoteError;
+
+/// Identifies an exported mailbox on the far side of a link. Both ends agree
+/// on these numbers out of band (typically constants shared by both binaries).
+pub type MailboxId = u32;
+
+/// Bytes of header following the length prefix.
+const HEADER_LEN: usize = 4 + 8;
+
+/// Largest frame (header + payload) accepted from a peer.
+pub const MAX_FRAME_LEN: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Frame {
+    pub mailbox: MailboxId,
+    pub from: ActorId,
+    pub payload: Vec<u8>,
+}
+
+pub(crate) async fn write_frame<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    frame: &Frame,
+) -> Result<(), RemoteError> {
+    let len = HEADER_LEN + frame.payload.len();
+    if len > MAX_FRAME_LEN {
+        return Err(RemoteError::FrameTooLarge(len));
+    }
+    let mut buf = Vec::with_capacity(4 + len);
+    buf.extend_from_slice(&(len as u32).to_be_bytes());
+    buf.extend_from_slice(&frame.mailbox.to_be_bytes());
+    buf.extend_from_slice(&(frame.from as u64).to_be_bytes());
+    buf.extend_from_slice(&frame.payload);
+    writer.write_all(&buf).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Read one frame. Returns `Ok(None)` on a clean end-of-stream at a frame
+/// boundary; a stream that ends partway through the length prefix is
+/// [`RemoteError::MalformedFrame`].
+pub(crate) async fn read_frame<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<Frame>, RemoteError> {
+    let mut len_buf = [0u8; 4];
+    let mut filled = 0;
+    while filled < len_buf.len() {
+        match reader.read(&mut len_buf[filled..]).await? {
+            0 if filled == 0 => return Ok(None),
+            0 => return Err(RemoteError::MalformedFrame),
+            n => filled += n,
+        }
+    }
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(RemoteError::FrameTooLarge(len));
+    }
+    if len < HEADER_LEN {
+        return Err(RemoteError::MalformedFrame);
+    }
+    let mut body = vec![0u8; len];
+    reader.read_exact(&mut body).await?;
+    let mailbox = u32::from_be_bytes(body[0..4].try_into().expect("4 bytes"));
+    let from = u64::from_be_bytes(body[4..12].try_into().expect("8 bytes")) as ActorId;
+    body.drain(..HEADER_LEN);
+    Ok(Some(Frame {
+        mailbox,
+        from,
+        payload: body,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn frame_round_trip() {
+        let (mut a, mut b) = tokio::io::duplex(256);
+        let frame = Frame {
+            mailbox: 7,
+            from: 42,
+            payload: vec![1, 2, 3],
+        };
+        write_frame(&mut a, &frame).await.unwrap();
+        drop(a);
+        assert_eq!(read_frame(&mut b).await.unwrap(), Some(frame));
+        assert_eq!(read_frame(&mut b).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn oversized_length_prefix_is_rejected() {
+        let (mut a, mut b) = tokio::io::duplex(64);
+        a.write_all(&(MAX_FRAME_LEN as u32 + 1).to_be_bytes())
+            .await
+            .unwrap();
+        assert!(matches!(
+            read_frame(&mut b).await,
+            Err(RemoteError::FrameTooLarge(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn truncated_header_is_rejected() {
+        let (mut a, mut b) = tokio::io::duplex(64);
+        a.write_all(&3u32.to_be_bytes()).await.unwrap();
+        assert!(matches!(
+            read_frame(&mut b).await,
+            Err(RemoteError::MalformedFrame)
+        ));
+    }
+
+    #[tokio::test]
+    async fn partial_length_prefix_is_rejected() {
+        let (mut a, mut b) = tokio::io::duplex(64);
+        a.write_all(&[0, 0]).await.unwrap();
+        drop(a);
+        assert!(matches!(
+            read_frame(&mut b).await,
+            Err(RemoteError::MalformedFrame)
+        ));
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Remote `ActorRef` transport over a byte stream.
+//!
+//! A [`RemoteNode`] sits at one end of a link (a `TcpStream`, `UnixStream`,
+//! or any `AsyncRead + AsyncWrite`). It is configured before the link starts:
+//!
+//! - [`export`](RemoteNode::export) makes a local mailbox reachable from the
+//!   peer under a [`MailboxId`].
+//! - [`import`](RemoteNode::import) returns a proxy `ActorRef<M, TokioRuntime>`
+//!   for a mailbox the peer exported. Sends on the proxy are encoded with the
+//!   given [`Codec`] and forwarded over the link.
+//! - [`watch`](RemoteNode::watch) registers a `ChildLifecycleEvent` sink that
+//!   hears about every imported proxy when the link goes down — `Stopped` on a
+//!   clean close, `Failed` on an I/O or framing error — so a supervisor can
+//!   treat remote actors like local children.
+//!
+//! [`serve`](RemoteNode::serve) then spawns the link driver. The sender
+//! `ActorId` in each envelope is carried across unchanged; it names an actor
+//! in the peer's process, not a local one.
+//!
+//! ```ignore
+//! // Doc test ignored: needs a peer process
+//! let mut node = RemoteNode::new();
+//! node.export(PING_MAILBOX, ping_ref, PingCodec);
+//! let pong_ref = node.import(PONG_MAILBOX, PongCodec, 16);
+//! node.watch(sup_notify_ref.clone());
+//! let link = node.serve(TcpStream::connect(addr).await?);
+//! ```
+
+#![forbid(unsafe_code)]
+
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::vec::Vec;
+
+use bloxide_core::{
+    capability::DynamicChannelCap,
+    lifecycle::ChildLifecycleEvent,
+    messaging::{ActorId, ActorRef, Envelope},
+};
+use bloxide_log::blox_log_warn;
+use bloxide_tokio::TokioRuntime;
+use futures_core::Stream;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+mod codec;
+mod frame;
+
+#[cfg(feature = "postcard")]
+pub use codec::PostcardCodec;
+pub use codec::{BytesCodec, Codec, CodecError};
+pub use frame::{MailboxId, MAX_FRAME_LEN};
+
+use frame::{read_frame, write_frame, Frame};
+
+/// Capacity of the queue between proxy forwarders and the socket writer.
+const OUTBOUND_CAPACITY: usize = 64;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type Deliver = Box<dyn Fn(ActorId, &[u8]) -> Option<BoxFuture> + Send + Sync>;
+
+// ── RemoteError ───────────────────────────────────────────────────────────────
+
+/// Why a link terminated.
+#[derive(Debug)]
+pub enum RemoteError {
+    /// The underlying stream failed.
+    Io(std::io::Error),
+    /// A frame length exceeded [`MAX_FRAME_LEN`].
+    FrameTooLarge(usize),
+    /// A frame was shorter than its header.
+    MalformedFrame,
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteError::Io(e) => write!(f, "link i/o error: {e}"),
+            RemoteError::FrameTooLarge(len) => write!(f, "frame of {len} bytes exceeds limit"),
+            RemoteError::MalformedFrame => write!(f, "malformed frame"),
+        }
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+impl From<std::io::Error> for RemoteError {
+    fn from(e: std::io::Error) -> Self {
+        RemoteError::Io(e)
+    }
+}
+
+// ── RemoteNode ────────────────────────────────────────────────────────────────
+
+/// One end of a remote link. See the [crate docs](crate).
+pub struct RemoteNode {
+    id: ActorId,
+    exports: BTreeMap<MailboxId, Deliver>,
+    forwarders: Vec<BoxFuture>,
+    proxies: Vec<ActorId>,
+    watchers: Vec<ActorRef<ChildLifecycleEvent, TokioRuntime>>,
+    outbound_tx: mpsc::Sender<Frame>,
+    outbound_rx: mpsc::Receiver<Frame>,
+}
+
+impl Default for RemoteNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RemoteNode {
+    pub fn new() -> Self {
+        let (outbound_tx, outbound_rx) = mpsc::channel(OUTBOUND_CAPACITY);
+        Self {
+            id: <TokioRuntime as DynamicChannelCap>::alloc_actor_id(),
+            exports: BTreeMap::new(),
+            forwarders: Vec::new(),
+            proxies: Vec::new(),
+            watchers: Vec::new(),
+            outbound_tx,
+            outbound_rx,
+        }
+    }
+
+    /// The node's own actor ID, used as the source of lifecycle events and
+    /// in log lines.
+    pub fn id(&self) -> ActorId {
+        self.id
+    }
+
+    /// Deliver frames addressed to `mailbox` to `target`, decoding them with
+    /// `codec`. Frames that fail to decode are logged and dropped.
+    ///
+    /// Exporting the same `mailbox` twice replaces the earlier export.
+    pub fn export<M, C>(&mut self, mailbox: MailboxId, target: ActorRef<M, TokioRuntime>, codec: C)
+    where
+        M: Send + 'static,
+        C: Codec<M>,
+    {
+        let node_id = self.id;
+        let deliver: Deliver = Box::new(move |from, bytes| match codec.decode(bytes) {
+            Ok(msg) => {
+                let target = target.clone();
+                Some(Box::pin(async move {
+                    if target.send(from, msg).await.is_err() {
+                        blox_log_warn!(
+                            node_id,
+                            "remote: exported mailbox {} is closed, dropping message",
+                            mailbox
+                        );
+                    }
+                }))
+            }
+            Err(e) => {
+                blox_log_warn!(node_id, "remote: mailbox {}: {}", mailbox, e);
+                None
+            }
+        });
+        self.exports.insert(mailbox, deliver);
+    }
+
+    /// Create a proxy for the peer's exported `mailbox`.
+    ///
+    /// The proxy is an ordinary `ActorRef` with a local queue of `capacity`
+    /// messages; a forwarding task encodes each one with `codec` and writes
+    /// it to the link. Once the link is down the proxy's channel is closed
+    /// and sends return an error.
+    pub fn import<M, C>(
+        &mut self,
+        mailbox: MailboxId,
+        codec: C,
+        capacity: usize,
+    ) -> ActorRef<M, TokioRuntime>
+    where
+        M: Send + 'static,
+        C: Codec<M>,
+    {
+        let proxy_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (proxy, mut rx) = <TokioRuntime as DynamicChannelCap>::channel::<M>(proxy_id, capacity);
+        let outbound = self.outbound_tx.clone();
+        let node_id = self.id;
+        self.proxies.push(proxy_id);
+        self.forwarders.push(Box::pin(async move {
+            while let Some(Envelope(from, msg)) =
+                poll_fn(|cx| Pin::new(&mut rx).poll_next(cx)).await
+            {
+                let mut payload = Vec::new();
+                if let Err(e) = codec.encode(&msg, &mut payload) {
+                    blox_log_warn!(node_id, "remote: mailbox {}: {}", mailbox, e);
+                    continue;
+                }
+                let frame = Frame {
+                    mailbox,
+                    from,
+                    payload,
+                };
+                if outbound.send(frame).await.is_err() {
+                    break;
+                }
+            }
+        }));
+        proxy
+    }
+
+    /// Report link loss for every imported proxy to `notify`.
+    pub fn watch(&mut self, notify: ActorRef<ChildLifecycleEvent, TokioRuntime>) {
+        self.watchers.push(notify);
+    }
+
+    /// Start the link over `stream`.
+    ///
+    /// Spawns the proxy forwarders and a driver task on the current Tokio
+    /// runtime. The returned handle resolves when the link closes: `Ok(())`
+    /// when the peer closed the stream cleanly, `Err` otherwise. Watchers are
+    /// notified before the handle resolves.
+    pub fn serve<S>(self, stream: S) -> JoinHandle<Result<(), RemoteError>>
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let RemoteNode {
+            id,
+            exports,
+            forwarders,
+            proxies,
+            watchers,
+            outbound_tx,
+            mut outbound_rx,
+        } = self;
+        drop(outbound_tx);
+
+        let forwarders: Vec<JoinHandle<()>> = forwarders.into_iter().map(tokio::spawn).collect();
+
+        tokio::spawn(async move {
+            let (mut reader, mut writer) = tokio::io::split(stream);
+
+            let read_loop = async {
+                while let Some(frame) = read_frame(&mut reader).await? {
+                    match exports.get(&frame.mailbox) {
+                        Some(deliver) => {
+                            if let Some(delivery) = deliver(frame.from, &frame.payload) {
+                                delivery.await;
+                            }
+                        }
+                        None => {
+                            blox_log_warn!(
+                                id,
+                                "remote: frame for unknown mailbox {}",
+                                frame.mailbox
+                            );
+                        }
+                    }
+                }
+                Ok(())
+            };
+
+            let write_loop = async {
+                while let Some(frame) = outbound_rx.recv().await {
+                    write_frame(&mut writer, &frame).await?;
+                }
+                // Every proxy was dropped; keep the link up for inbound traffic.
+                core::future::pending::<Result<(), RemoteError>>().await
+            };
+
+            let result = tokio::select! {
+                r = read_loop => r,
+                w = write_loop => w,
+            };
+
+            for forwarder in &forwarders {
+                forwarder.abort();
+            }
+            for watcher in &watchers {
+                for &child_id in &proxies {
+                    let event = match result {
+                        Ok(()) => ChildLifecycleEvent::Stopped { child_id },
+                        Err(_) => ChildLifecycleEvent::Failed { child_id },
+                    };
+                    if watcher.try_send(id, event).is_err() {
+                        blox_log_warn!(id, "remote: watcher mailbox full, dropped link event");
+                    }
+                }
+            }
+            result
+        })
+    }
+}
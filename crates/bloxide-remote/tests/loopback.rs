@@ -0,0 +1,166 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Loopback tests: two `RemoteNode`s in one process, linked by a real socket.
+
+use core::future::poll_fn;
+use core::pin::Pin;
+use std::time::Duration;
+
+use bloxide_core::{
+    capability::DynamicChannelCap,
+    lifecycle::ChildLifecycleEvent,
+    messaging::{ActorRef, Envelope},
+};
+use bloxide_remote::{BytesCodec, Codec, CodecError, RemoteNode};
+use bloxide_tokio::{TokioRuntime, TokioStream};
+use futures_core::Stream;
+use tokio::net::{TcpListener, TcpStream, UnixStream};
+use tokio::time::timeout;
+
+const GREETER: u32 = 1;
+const RAW: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Greeting {
+    Hello(u32),
+    Bye,
+}
+
+struct GreetingCodec;
+
+impl Codec<Greeting> for GreetingCodec {
+    fn encode(&self, msg: &Greeting, buf: &mut Vec<u8>) -> Result<(), CodecError> {
+        match msg {
+            Greeting::Hello(n) => {
+                buf.push(0);
+                buf.extend_from_slice(&n.to_be_bytes());
+            }
+            Greeting::Bye => buf.push(1),
+        }
+        Ok(())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Greeting, CodecError> {
+        match bytes {
+            [0, rest @ ..] if rest.len() == 4 => Ok(Greeting::Hello(u32::from_be_bytes(
+                rest.try_into().unwrap(),
+            ))),
+            [1] => Ok(Greeting::Bye),
+            _ => Err(CodecError::new("bad greeting")),
+        }
+    }
+}
+
+fn mailbox<M: Send + 'static>() -> (ActorRef<M, TokioRuntime>, TokioStream<M>) {
+    let id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+    <TokioRuntime as DynamicChannelCap>::channel::<M>(id, 16)
+}
+
+async fn recv<M: Send + 'static>(rx: &mut TokioStream<M>) -> Envelope<M> {
+    timeout(
+        Duration::from_secs(2),
+        poll_fn(|cx| Pin::new(&mut *rx).poll_next(cx)),
+    )
+    .await
+    .expect("timed out waiting for message")
+    .expect("mailbox closed")
+}
+
+#[tokio::test]
+async fn proxy_send_reaches_exported_mailbox_over_tcp() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let (greeter_ref, mut greeter_rx) = mailbox::<Greeting>();
+    let mut server = RemoteNode::new();
+    server.export(GREETER, greeter_ref, GreetingCodec);
+
+    let mut client = RemoteNode::new();
+    let proxy = client.import(GREETER, GreetingCodec, 8);
+
+    let (accepted, connected) = tokio::join!(listener.accept(), TcpStream::connect(addr));
+    let _server_link = server.serve(accepted.unwrap().0);
+    let _client_link = client.serve(connected.unwrap());
+
+    proxy.send(77, Greeting::Hello(5)).await.unwrap();
+    proxy.send(77, Greeting::Bye).await.unwrap();
+
+    let Envelope(from, msg) = recv(&mut greeter_rx).await;
+    assert_eq!(from, 77, "sender id is carried across the link");
+    assert_eq!(msg, Greeting::Hello(5));
+    assert_eq!(recv(&mut greeter_rx).await.1, Greeting::Bye);
+}
+
+#[tokio::test]
+async fn link_is_bidirectional_over_unix_socket() {
+    let (a_stream, b_stream) = UnixStream::pair().unwrap();
+
+    let (a_inbox, mut a_rx) = mailbox::<Vec<u8>>();
+    let (b_inbox, mut b_rx) = mailbox::<Vec<u8>>();
+
+    let mut a = RemoteNode::new();
+    a.export(RAW, a_inbox, BytesCodec);
+    let to_b = a.import(RAW, BytesCodec, 8);
+
+    let mut b = RemoteNode::new();
+    b.export(RAW, b_inbox, BytesCodec);
+    let to_a = b.import(RAW, BytesCodec, 8);
+
+    let _a_link = a.serve(a_stream);
+    let _b_link = b.serve(b_stream);
+
+    to_b.send(1, b"ping".to_vec()).await.unwrap();
+    assert_eq!(recv(&mut b_rx).await.1, b"ping");
+    to_a.send(2, b"pong".to_vec()).await.unwrap();
+    assert_eq!(recv(&mut a_rx).await.1, b"pong");
+}
+
+#[tokio::test]
+async fn undecodable_frame_is_dropped_and_link_survives() {
+    let (a_stream, b_stream) = UnixStream::pair().unwrap();
+
+    let (greeter_ref, mut greeter_rx) = mailbox::<Greeting>();
+    let mut server = RemoteNode::new();
+    server.export(GREETER, greeter_ref, GreetingCodec);
+
+    // The client speaks raw bytes to the greeter mailbox.
+    let mut client = RemoteNode::new();
+    let raw = client.import(GREETER, BytesCodec, 8);
+
+    let _server_link = server.serve(a_stream);
+    let _client_link = client.serve(b_stream);
+
+    raw.send(0, vec![9, 9, 9]).await.unwrap();
+    raw.send(0, vec![1]).await.unwrap();
+    assert_eq!(recv(&mut greeter_rx).await.1, Greeting::Bye);
+}
+
+#[tokio::test]
+async fn disconnect_reports_proxies_to_watchers() {
+    let (a_stream, b_stream) = UnixStream::pair().unwrap();
+
+    let (notify_ref, mut notify_rx) = mailbox::<ChildLifecycleEvent>();
+    let mut client = RemoteNode::new();
+    let proxy = client.import(GREETER, GreetingCodec, 8);
+    client.watch(notify_ref);
+    let link = client.serve(a_stream);
+
+    drop(b_stream);
+
+    let result = timeout(Duration::from_secs(2), link)
+        .await
+        .expect("link should close")
+        .expect("driver task");
+    assert!(result.is_ok(), "peer closed cleanly: {result:?}");
+
+    let Envelope(_, event) = recv(&mut notify_rx).await;
+    assert_eq!(
+        event,
+        ChildLifecycleEvent::Stopped {
+            child_id: proxy.id()
+        }
+    );
+
+    // Forwarders are torn down with the link, so the proxy is closed.
+    tokio::task::yield_now().await;
+    assert!(proxy.send(0, Greeting::Bye).await.is_err());
+}
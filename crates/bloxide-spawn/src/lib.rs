@@ -3,9 +3,9 @@
 //! Spawn capability for bloxide — `SpawnCap`, `Kill`, and the `spawn_child` helper.
 //!
 //! This crate is a platform primitive: the ability to spawn actor tasks at
-//! runtime. Runtimes that support dynamic spawning (Tokio) implement
-//! `SpawnCap` and use `Kill` as their `KillCapability`. Runtimes that don't
-//! (Embassy) use `NoKill` from `bloxide-core` and never depend on this crate.
+//! runtime. Tokio implements `SpawnCap` and uses `Kill` as its
+//! `KillCapability`. Embassy implements `SpawnCap` behind its `dynamic`
+//! feature, on pre-declared task and channel pools, and keeps `NoKill`.
 //!
 //! The `spawn_child` helper and `ChildRegistrar` trait let any managing blox
 //! (supervisor or custom) register spawned children without depending on
@@ -32,22 +32,42 @@ pub trait HasNotifyRef<R: BloxRuntime> {
 // from one place.
 pub use bloxide_core::capability::{KillCapability, NoKill};
 
+/// Why a child could not be spawned.
+///
+/// Runtimes with bounded, pre-declared storage (Embassy) return this when a
+/// pool is exhausted. Unbounded runtimes (Tokio) never do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnError {
+    /// Every task slot is running a task, or none were provided.
+    NoTaskSlot,
+    /// No channel slot is free for one of the child's mailboxes.
+    NoChannelSlot,
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnError::NoTaskSlot => f.write_str("no free task slot"),
+            SpawnError::NoChannelSlot => f.write_str("no free channel slot"),
+        }
+    }
+}
+
 /// Tier 2 capability for runtimes that support spawning actor tasks at runtime.
 ///
 /// Extends `DynamicChannelCap` (which provides `alloc_actor_id` and `channel`).
 /// This is a Tier 2 runtime-facing trait — blox crates never declare `R: SpawnCap`
 /// directly. Blox crates that need dynamic spawning use factory injection
 /// (see AGENTS.md invariant #15 and spec doc 13). The trait is implemented by
-/// runtimes (Tokio) and consumed by the spawn infrastructure and wiring layer.
-/// Embassy does NOT implement this trait — use static wiring for Embassy.
+/// runtimes (Tokio, and Embassy with its `dynamic` feature) and consumed by the
+/// spawn infrastructure and wiring layer.
 ///
 /// The associated `TaskHandle` type is returned by `spawn` and is used to
 /// produce a `KillHandle` (the cloneable ripcord). For Tokio,
 /// `TaskHandle = JoinHandle<()>` and `KillHandle = tokio::task::AbortHandle`.
-/// For a future Embassy task-pool runtime, `KillHandle` would be `()` (no
-/// external kill — the kill mailbox is sufficient) or whatever Embassy
-/// provides if [issue #3197](https://github.com/embassy-rs/embassy/issues/3197)
-/// is implemented.
+/// Embassy's task pools use `()` for both: there is no external kill, so the
+/// abort mailbox is the only way to end a task (see
+/// [issue #3197](https://github.com/embassy-rs/embassy/issues/3197)).
 ///
 /// All types are concrete, by-value — no `Arc<dyn>`, no dynamic dispatch.
 pub trait SpawnCap: DynamicChannelCap {
@@ -61,8 +81,19 @@ pub trait SpawnCap: DynamicChannelCap {
     type KillHandle: Clone + Send + 'static;
 
     /// Spawn a future as an independent task and return a handle.
+    ///
+    /// Runtimes with bounded task storage panic when it is exhausted; spawn
+    /// functions that must survive that use [`try_spawn`](Self::try_spawn).
     fn spawn(future: impl Future<Output = ()> + Send + 'static) -> Self::TaskHandle;
 
+    /// Like [`spawn`](Self::spawn), but returns [`SpawnError::NoTaskSlot`]
+    /// instead of panicking when the runtime has no room for another task.
+    fn try_spawn(
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<Self::TaskHandle, SpawnError> {
+        Ok(Self::spawn(future))
+    }
+
     /// Derive a cloneable kill handle from a task handle.
     /// The task handle is consumed; the task continues running (drop does not kill).
     fn kill_handle(handle: Self::TaskHandle) -> Self::KillHandle;
@@ -81,6 +112,7 @@ pub struct Kill;
 
 impl<R: BloxRuntime + SpawnCap> KillCapability<R> for Kill {
     type Handle = R::KillHandle;
+    const CAN_KILL: bool = true;
     fn kill(handle: R::KillHandle) {
         R::kill(handle);
     }
@@ -148,13 +180,25 @@ where
 ///
 /// This is a `fn` pointer, not a trait. The application provides the
 /// concrete function at wiring time. The function is stateless — all
-/// per-request state comes through the request parameter.
+/// per-request state comes through the request parameter. It returns a
+/// [`SpawnError`] when the runtime has no room for the child.
 ///
 /// The `Req` type parameter is the application's concrete spawn request
 /// enum (e.g., `SpawnRequest<R>` in pool-messages). The runtime helper
 /// is generic over `Req` so it doesn't depend on any specific app's
 /// messages crate.
-pub type SpawnFn<R, Req> = fn(req: Req, notify: ActorRef<ChildLifecycleEvent, R>) -> SpawnOutput<R>;
+pub type SpawnFn<R, Req> =
+    fn(req: Req, notify: ActorRef<ChildLifecycleEvent, R>) -> Result<SpawnOutput<R>, SpawnError>;
+
+/// Why [`spawn_child`] failed.
+#[derive(Debug)]
+pub enum SpawnChildError<E> {
+    /// The spawn function could not create the child.
+    Spawn(SpawnError),
+    /// The child was spawned but the registration message could not be sent
+    /// (the managing blox's control mailbox was full).
+    Register(E),
+}
 
 /// A blox that manages spawned children implements this to define how
 /// `SpawnOutput` is wrapped into its own control-plane message type.
@@ -183,6 +227,9 @@ pub trait ChildRegistrar<R: BloxRuntime> {
 ///   2. Sends the registration message (typed by `C::RegisterMsg`) to the
 ///      managing blox's control mailbox
 ///
+/// If the spawn function fails nothing is sent. A failed registration leaves
+/// the child running unmanaged, as it did before spawning was fallible.
+///
 /// The managing blox receives the registration message and starts managing the
 /// child's lifecycle. The managing blox never sees the request type.
 ///
@@ -198,18 +245,21 @@ pub fn spawn_child<R, Req, C>(
     control_ref: &ActorRef<C::RegisterMsg, R>,
     notify_ref: &ActorRef<ChildLifecycleEvent, R>,
     from: ActorId,
-) -> Result<(), R::TrySendError>
+) -> Result<(), SpawnChildError<R::TrySendError>>
 where
     R: BloxRuntime,
     Req: Send + Clone + 'static,
     C: ChildRegistrar<R>,
 {
     // 1. Call the spawn function — creates channels, constructs child, spawns task
-    let output: SpawnOutput<R> = spawn_fn(req, notify_ref.clone());
+    let output: SpawnOutput<R> =
+        spawn_fn(req, notify_ref.clone()).map_err(SpawnChildError::Spawn)?;
 
     // 2. Wrap output into the managing blox's registration message and send it
     let msg = C::register(output);
-    control_ref.try_send(from, msg)?;
+    control_ref
+        .try_send(from, msg)
+        .map_err(SpawnChildError::Register)?;
 
     Ok(())
 }
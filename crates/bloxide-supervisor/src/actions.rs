@@ -5,7 +5,7 @@
 //! `SupervisorEvent` enum is generated by the codegen from the `[event]`
 //! section in `blox.toml`.
 
-use crate::SupervisorControl;
+use crate::{JournalReport, SupervisorControl, SupervisorReport};
 use bloxide_child_management::ChildAction;
 use bloxide_core::{
     accessor::HasSelfId, lifecycle::ChildLifecycleEvent, messaging::Envelope,
@@ -20,51 +20,380 @@ use crate::{SupervisorCtx, SupervisorEvent};
 /// model, `Guard::Reset` goes directly to `initial_state()` (Running) — it
 /// does NOT fire `on_init_entry`. So counters must be cleared here, in the
 /// Running on_entry, which fires both on initial Start and on Reset.
+///
+/// On Reset — a parent supervisor restarting this subtree — every child is
+/// restarted too, not just started. Children whose turn in the start order
+/// has not come are started as their predecessors report `Started`. A child
+/// whose task has ended is reported as `Failed`, which gives up on it and
+/// escalates to the parent.
 pub fn start_children<R>(ctx: &mut SupervisorCtx<R>)
 where
     R: bloxide_core::capability::BloxRuntime,
 {
+    let reset = core::mem::replace(&mut ctx.started, true);
     ctx.children.clear_counters();
     ctx.pending = ChildAction::default();
-    ctx.children.start_all(ctx.self_id);
+    if reset {
+        ctx.children.restart_all(ctx.self_id, &ctx.child_notify);
+    } else {
+        ctx.children.start_all(ctx.self_id);
+    }
+    schedule_start_deadlines(ctx);
+}
+
+/// Fail the group if a child missed its start deadline.
+pub fn check_start_deadline<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(
+        _,
+        SupervisorControl::StartDeadline { child_id, epoch },
+    )) = ev
+    {
+        let from = ctx.self_id();
+        ctx.pending = ctx.children.start_deadline_elapsed(*child_id, *epoch, from);
+    }
+    ActionResult::Ok
+}
+
+/// Schedule the start deadlines `ChildGroup` queued.
+///
+/// Without a `SupervisorTimer` the deadlines cannot fire, so children may take
+/// as long as they need to start.
+fn schedule_start_deadlines<R>(ctx: &mut SupervisorCtx<R>)
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    let from = ctx.self_id();
+    for (child_id, epoch, timeout_ms) in ctx.children.take_start_deadlines() {
+        match &ctx.timer {
+            Some(timer) => timer.schedule_start_deadline(child_id, epoch, timeout_ms),
+            None => bloxide_log::blox_log_error!(
+                from,
+                "no supervisor timer installed, child {} has no {} ms start deadline",
+                child_id,
+                timeout_ms
+            ),
+        }
+    }
 }
 
-/// Stop all children in the group.
+/// Stop all children in the group and arm their shutdown deadlines.
 pub fn stop_all_children<R>(ctx: &mut SupervisorCtx<R>)
 where
     R: bloxide_core::capability::BloxRuntime,
 {
     ctx.children.stop_all(ctx.self_id);
+    schedule_deadlines(ctx);
 }
 
-/// Handle a Done or Failed child lifecycle event.
-pub fn handle_done_or_failed<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+/// Escalate the shutdown of a child whose deadline expired, or of every
+/// child still stopping on `EscalateShutdown`.
+pub fn escalate_shutdown<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
 where
     R: bloxide_core::capability::BloxRuntime,
 {
-    if let SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Done { child_id }))
-    | SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Failed { child_id })) = ev
-    {
+    let from = ctx.self_id();
+    match ev {
+        SupervisorEvent::Control(Envelope(_, SupervisorControl::ShutdownDeadline { child_id })) => {
+            ctx.children
+                .shutdown_deadline_elapsed(*child_id, from, &ctx.child_notify);
+        }
+        SupervisorEvent::Control(Envelope(_, SupervisorControl::EscalateShutdown)) => {
+            ctx.children.escalate_all_shutdowns(from, &ctx.child_notify);
+        }
+        _ => return ActionResult::Ok,
+    }
+    schedule_deadlines(ctx);
+    ActionResult::Ok
+}
+
+/// Schedule the shutdown deadlines `ChildGroup` queued.
+///
+/// Without a `SupervisorTimer` the deadlines cannot fire, so shutdown waits
+/// for every child as if no timeouts were set.
+fn schedule_deadlines<R>(ctx: &mut SupervisorCtx<R>)
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    let from = ctx.self_id();
+    for (child_id, timeout_ms) in ctx.children.take_deadlines() {
+        match &ctx.timer {
+            Some(timer) => timer.schedule_deadline(child_id, timeout_ms),
+            None => bloxide_log::blox_log_error!(
+                from,
+                "no supervisor timer installed, child {} has no {} ms shutdown deadline",
+                child_id,
+                timeout_ms
+            ),
+        }
+    }
+}
+
+/// Handle a Done child lifecycle event.
+pub fn handle_done<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Done { child_id })) = ev {
+        let from = ctx.self_id();
+        let action = ctx.children.handle_done(*child_id, from, &ctx.child_notify);
+        ctx.pending = action;
+        schedule_backoffs(ctx);
+        schedule_start_deadlines(ctx);
+    }
+    ActionResult::Ok
+}
+
+/// Handle a Failed child lifecycle event.
+pub fn handle_failed<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Failed { child_id })) = ev {
         let from = ctx.self_id();
         let action = ctx
             .children
-            .handle_done_or_failed(*child_id, from, &ctx.child_notify);
+            .handle_failed(*child_id, from, &ctx.child_notify);
         ctx.pending = action;
+        schedule_backoffs(ctx);
+        schedule_start_deadlines(ctx);
+    }
+    ActionResult::Ok
+}
+
+/// Schedule the restarts `ChildGroup` parked in `BackingOff`.
+///
+/// Without a `SupervisorTimer` the delay cannot be honoured, so the child is
+/// restarted at once.
+fn schedule_backoffs<R>(ctx: &mut SupervisorCtx<R>)
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    let from = ctx.self_id();
+    for (child_id, generation, delay_ms) in ctx.children.take_backoffs() {
+        match &ctx.timer {
+            Some(timer) => timer.schedule(child_id, generation, delay_ms),
+            None => {
+                bloxide_log::blox_log_error!(
+                    from,
+                    "no supervisor timer installed, restarting child {} without its {} ms backoff",
+                    child_id,
+                    delay_ms
+                );
+                ctx.children
+                    .restart_after_backoff(child_id, generation, from);
+            }
+        }
+    }
+}
+
+/// Send `Reset` to a child whose restart backoff elapsed.
+pub fn restart_backed_off_child<R>(
+    ctx: &mut SupervisorCtx<R>,
+    ev: &SupervisorEvent<R>,
+) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(
+        _,
+        SupervisorControl::BackoffElapsed {
+            child_id,
+            generation,
+        },
+    )) = ev
+    {
+        let from = ctx.self_id();
+        ctx.children
+            .restart_after_backoff(*child_id, *generation, from);
+        schedule_start_deadlines(ctx);
+    }
+    ActionResult::Ok
+}
+
+/// Answer a `Query` with a snapshot of the child group.
+pub fn answer_query<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(_, SupervisorControl::Query { reply_to })) = ev {
+        let from = ctx.self_id();
+        let report = SupervisorReport {
+            supervisor_id: from,
+            children: ctx.children.which_children(),
+            counts: ctx.children.counts(),
+        };
+        if reply_to.try_send(from, report).is_err() {
+            bloxide_log::blox_log_warn!(
+                from,
+                "try_send SupervisorReport to {} failed (channel full or closed)",
+                reply_to.id()
+            );
+        }
+    }
+    ActionResult::Ok
+}
+
+/// Answer a `QueryJournal` with the requested journal entries.
+pub fn answer_journal_query<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(
+        _,
+        SupervisorControl::QueryJournal { since, reply_to },
+    )) = ev
+    {
+        let from = ctx.self_id();
+        let journal = ctx.children.journal();
+        let report = JournalReport {
+            supervisor_id: from,
+            entries: journal.since(*since),
+            dropped: journal.dropped(),
+        };
+        if reply_to.try_send(from, report).is_err() {
+            bloxide_log::blox_log_warn!(
+                from,
+                "try_send JournalReport to {} failed (channel full or closed)",
+                reply_to.id()
+            );
+        }
     }
     ActionResult::Ok
 }
 
+/// Journal a lifecycle event. Runs first on every `Child` transition, so
+/// the event precedes the decisions it leads to.
+pub fn journal_child_event<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Child(Envelope(_, event)) = ev {
+        ctx.children.journal_event(*event);
+    }
+    ActionResult::Ok
+}
+
+/// Hand the journal to the group's sink. The `on_entry` for
+/// `ShutdownComplete` and `Escalated`.
+pub fn dump_journal<R>(ctx: &mut SupervisorCtx<R>)
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    ctx.children.dump_journal(ctx.self_id);
+}
+
+/// Terminate one child on a `TerminateChild` request.
+pub fn terminate_child<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(
+        _,
+        SupervisorControl::TerminateChild { child_id, how },
+    )) = ev
+    {
+        let from = ctx.self_id();
+        match ctx
+            .children
+            .terminate_child(*child_id, *how, from, &ctx.child_notify)
+        {
+            Some(action) => ctx.pending = action,
+            None => bloxide_log::blox_log_warn!(
+                from,
+                "cannot terminate child {}: unknown, or no abort/kill capability",
+                child_id
+            ),
+        }
+    }
+    ActionResult::Ok
+}
+
+/// Restart one child on a `RestartChild` request.
+pub fn restart_child<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(_, SupervisorControl::RestartChild { child_id })) = ev
+    {
+        let from = ctx.self_id();
+        if !ctx.children.restart_child(*child_id, from) {
+            bloxide_log::blox_log_warn!(
+                from,
+                "cannot restart child {}: unknown, or its task has ended",
+                child_id
+            );
+        }
+    }
+    ActionResult::Ok
+}
+
+/// Remove one finished child on a `DeleteChild` request.
+pub fn delete_child<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    if let SupervisorEvent::Control(Envelope(_, SupervisorControl::DeleteChild { child_id })) = ev {
+        let from = ctx.self_id();
+        if !ctx.children.delete_child(*child_id) {
+            bloxide_log::blox_log_warn!(
+                from,
+                "cannot delete child {}: unknown, or not yet stopped",
+                child_id
+            );
+        }
+    }
+    ActionResult::Ok
+}
+
+/// Refuse a `TerminateChild`, `RestartChild` or `DeleteChild` request
+/// while the group is shutting down: the shutdown already decides every
+/// child's fate.
+pub fn reject_child_request<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
+where
+    R: bloxide_core::capability::BloxRuntime,
+{
+    let (request, child_id) = match ev {
+        SupervisorEvent::Control(Envelope(
+            _,
+            SupervisorControl::TerminateChild { child_id, .. },
+        )) => ("terminate", child_id),
+        SupervisorEvent::Control(Envelope(_, SupervisorControl::RestartChild { child_id })) => {
+            ("restart", child_id)
+        }
+        SupervisorEvent::Control(Envelope(_, SupervisorControl::DeleteChild { child_id })) => {
+            ("delete", child_id)
+        }
+        _ => return ActionResult::Ok,
+    };
+    bloxide_log::blox_log_warn!(
+        ctx.self_id(),
+        "rejected request to {} child {}: the group is shutting down",
+        request,
+        child_id
+    );
+    ActionResult::Ok
+}
+
 /// Record a started child.
 ///
 /// In the four-level lifecycle model, `Started` covers both initial `Start`
 /// and `Reset` (both go directly to `initial_state()`). The supervisor does
 /// not need to send `Start` after `Reset`.
+///
+/// A child coming up may be the one others wait for, so queued starts are
+/// sent once their turn has come.
 pub fn record_started<R>(ctx: &mut SupervisorCtx<R>, ev: &SupervisorEvent<R>) -> ActionResult
 where
     R: bloxide_core::capability::BloxRuntime,
 {
     if let SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Started { child_id })) = ev {
+        let from = ctx.self_id();
         ctx.children.handle_started(*child_id);
+        ctx.children.start_ready(from);
+        schedule_start_deadlines(ctx);
     }
     ActionResult::Ok
 }
@@ -172,6 +501,8 @@ where
         let from = ctx.self_id();
         let action = ctx.children.health_check_tick(from, &ctx.child_notify);
         ctx.pending = action;
+        schedule_backoffs(ctx);
+        schedule_start_deadlines(ctx);
     }
     ActionResult::Ok
 }
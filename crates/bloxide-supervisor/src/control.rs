@@ -1,7 +1,10 @@
 // Copyright 2025 Bloxide, all rights reserved
+use alloc::vec::Vec;
 use core::fmt;
 
-use bloxide_child_management::{AbortCommand, ChildPolicy};
+use bloxide_child_management::{
+    AbortCommand, ChildCounts, ChildInfo, ChildPolicy, JournalEntry, Termination,
+};
 use bloxide_core::{
     capability::{BloxRuntime, KillCapability},
     lifecycle::LifecycleCommand,
@@ -87,6 +90,29 @@ impl<R: BloxRuntime> fmt::Debug for RegisterDynamicChild<R> {
     }
 }
 
+/// Reply to [`SupervisorControl::Query`]: what the supervisor's children
+/// are doing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupervisorReport {
+    /// The supervisor that answered.
+    pub supervisor_id: ActorId,
+    /// Every child, in the order it was added.
+    pub children: Vec<ChildInfo>,
+    pub counts: ChildCounts,
+}
+
+/// Reply to [`SupervisorControl::QueryJournal`]: the supervisor's journal
+/// entries from the requested sequence number on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JournalReport {
+    /// The supervisor that answered.
+    pub supervisor_id: ActorId,
+    /// Held entries with `seq >= since`, oldest first.
+    pub entries: Vec<JournalEntry>,
+    /// Entries the journal has evicted so far.
+    pub dropped: u64,
+}
+
 /// Supervisor control-plane events delivered through a dedicated mailbox.
 ///
 /// There is no `Spawn` variant — spawning is decoupled from the supervisor.
@@ -103,6 +129,47 @@ pub enum SupervisorControl<R: BloxRuntime> {
     RegisterDynamicChild(RegisterDynamicChild<R>),
     /// Trigger one health-check round.
     HealthCheckTick,
+    /// Stop every child, last started first, and complete once they have
+    /// all reported `Stopped`. Sent by the wiring layer on system shutdown.
+    Shutdown,
+    /// A child's restart backoff elapsed; send it `Reset`. Scheduled by the
+    /// supervisor itself through its [`SupervisorTimer`](crate::SupervisorTimer).
+    /// `generation` identifies the backoff the timer was armed for.
+    BackoffElapsed { child_id: ActorId, generation: u32 },
+    /// A child's shutdown deadline expired; escalate its shutdown. Scheduled
+    /// by the supervisor itself through its [`SupervisorTimer`](crate::SupervisorTimer).
+    ShutdownDeadline { child_id: ActorId },
+    /// Escalate the shutdown of every child still stopping by one step
+    /// (`Stop` → `Abort` → `Kill`, or abandon), as if each had missed its
+    /// deadline. Sent by the wiring layer when the supervisor misses the
+    /// system shutdown deadline; ignored unless `ShuttingDown`.
+    EscalateShutdown,
+    /// A child's start deadline expired; fail the group if it has not
+    /// reported `Started`. Scheduled by the supervisor itself through its
+    /// [`SupervisorTimer`](crate::SupervisorTimer).
+    /// `epoch` identifies the start the deadline was armed for.
+    StartDeadline { child_id: ActorId, epoch: u32 },
+    /// Send a [`SupervisorReport`] to `reply_to`. Answered while `Running`
+    /// or `ShuttingDown`.
+    Query {
+        reply_to: ActorRef<SupervisorReport, R>,
+    },
+    /// Send a [`JournalReport`] with the journal entries numbered `since`
+    /// or later to `reply_to`. Answered while `Running` or `ShuttingDown`.
+    QueryJournal {
+        since: u64,
+        reply_to: ActorRef<JournalReport, R>,
+    },
+    /// Terminate one child without restarting it. Under
+    /// `GroupShutdown::WhenAllDone`, terminating the last active child
+    /// shuts the supervisor down.
+    TerminateChild { child_id: ActorId, how: Termination },
+    /// Restart one child with `Reset` then `Start`, outside its restart
+    /// budget. The task of a stopped, aborted or killed child has ended, so
+    /// such a child must be deleted and registered again instead.
+    RestartChild { child_id: ActorId },
+    /// Forget a child that has stopped or is permanently done.
+    DeleteChild { child_id: ActorId },
 }
 
 impl<R: BloxRuntime> Clone for SupervisorControl<R> {
@@ -111,6 +178,39 @@ impl<R: BloxRuntime> Clone for SupervisorControl<R> {
             Self::RegisterChild(r) => Self::RegisterChild(r.clone()),
             Self::RegisterDynamicChild(r) => Self::RegisterDynamicChild(r.clone()),
             Self::HealthCheckTick => Self::HealthCheckTick,
+            Self::Shutdown => Self::Shutdown,
+            Self::BackoffElapsed {
+                child_id,
+                generation,
+            } => Self::BackoffElapsed {
+                child_id: *child_id,
+                generation: *generation,
+            },
+            Self::ShutdownDeadline { child_id } => Self::ShutdownDeadline {
+                child_id: *child_id,
+            },
+            Self::EscalateShutdown => Self::EscalateShutdown,
+            Self::StartDeadline { child_id, epoch } => Self::StartDeadline {
+                child_id: *child_id,
+                epoch: *epoch,
+            },
+            Self::Query { reply_to } => Self::Query {
+                reply_to: reply_to.clone(),
+            },
+            Self::QueryJournal { since, reply_to } => Self::QueryJournal {
+                since: *since,
+                reply_to: reply_to.clone(),
+            },
+            Self::TerminateChild { child_id, how } => Self::TerminateChild {
+                child_id: *child_id,
+                how: *how,
+            },
+            Self::RestartChild { child_id } => Self::RestartChild {
+                child_id: *child_id,
+            },
+            Self::DeleteChild { child_id } => Self::DeleteChild {
+                child_id: *child_id,
+            },
         }
     }
 }
@@ -123,6 +223,47 @@ impl<R: BloxRuntime> fmt::Debug for SupervisorControl<R> {
                 f.debug_tuple("RegisterDynamicChild").field(r).finish()
             }
             Self::HealthCheckTick => write!(f, "HealthCheckTick"),
+            Self::Shutdown => write!(f, "Shutdown"),
+            Self::BackoffElapsed {
+                child_id,
+                generation,
+            } => f
+                .debug_struct("BackoffElapsed")
+                .field("child_id", child_id)
+                .field("generation", generation)
+                .finish(),
+            Self::ShutdownDeadline { child_id } => f
+                .debug_struct("ShutdownDeadline")
+                .field("child_id", child_id)
+                .finish(),
+            Self::EscalateShutdown => write!(f, "EscalateShutdown"),
+            Self::StartDeadline { child_id, epoch } => f
+                .debug_struct("StartDeadline")
+                .field("child_id", child_id)
+                .field("epoch", epoch)
+                .finish(),
+            Self::Query { reply_to } => f
+                .debug_struct("Query")
+                .field("reply_to", &reply_to.id())
+                .finish(),
+            Self::QueryJournal { since, reply_to } => f
+                .debug_struct("QueryJournal")
+                .field("since", since)
+                .field("reply_to", &reply_to.id())
+                .finish(),
+            Self::TerminateChild { child_id, how } => f
+                .debug_struct("TerminateChild")
+                .field("child_id", child_id)
+                .field("how", how)
+                .finish(),
+            Self::RestartChild { child_id } => f
+                .debug_struct("RestartChild")
+                .field("child_id", child_id)
+                .finish(),
+            Self::DeleteChild { child_id } => f
+                .debug_struct("DeleteChild")
+                .field("child_id", child_id)
+                .finish(),
         }
     }
 }
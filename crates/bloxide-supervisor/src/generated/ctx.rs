@@ -2,12 +2,14 @@
 // Auto-generated by bloxide-codegen. Do not edit manually.
 #[allow(unused_imports)]
 use crate::HasChildNotify;
+use crate::{SupervisorControl, SupervisorTimer};
 use ::bloxide_core::{capability::BloxRuntime, messaging::ActorRef};
 use ::bloxide_macros::BloxCtx;
 #[allow(unused_imports)]
 use bloxide_child_management::HasChildGroup;
 use bloxide_child_management::{ChildAction, ChildGroup, HasChildGroupMut, HasPending};
 use bloxide_core::lifecycle::ChildLifecycleEvent;
+use bloxide_timer::TimerCommand;
 #[derive(BloxCtx)]
 pub struct SupervisorCtx<R: BloxRuntime> {
     pub self_id: ::bloxide_core::ActorId,
@@ -17,6 +19,9 @@ pub struct SupervisorCtx<R: BloxRuntime> {
     #[provides(HasChildNotify<R>)]
     pub child_notify: ActorRef<ChildLifecycleEvent, R>,
     pub pending: ChildAction,
+    pub timer: Option<SupervisorTimer<R>>,
+    pub escalate: bool,
+    pub started: bool,
 }
 impl<R: BloxRuntime> HasPending for SupervisorCtx<R> {
     fn pending(&self) -> ChildAction {
@@ -30,4 +35,21 @@ impl<R: BloxRuntime> SupervisorCtx<R> {
     pub fn all_children_stopped(&self) -> bool {
         self.children.all_stopped()
     }
+    pub fn should_escalate(&self) -> bool {
+        self.escalate
+            && self.pending == ChildAction::BeginShutdown
+            && (self.children.restarts_exhausted() || self.children.start_timed_out())
+    }
+    pub fn escalate_to_parent(mut self) -> Self {
+        self.escalate = true;
+        self
+    }
+    pub fn with_timer(
+        mut self,
+        timer_ref: ActorRef<TimerCommand, R>,
+        control_ref: ActorRef<SupervisorControl<R>, R>,
+    ) -> Self {
+        self.timer = Some(SupervisorTimer::new(self.self_id, timer_ref, control_ref));
+        self
+    }
 }
@@ -2,9 +2,11 @@
 // Auto-generated by bloxide-codegen. Do not edit manually.
 #[allow(unused_imports)]
 use crate::actions::{
-    handle_done_or_failed, handle_health_check, handle_register_dynamic_child, record_aborted,
-    record_alive, record_killed, record_started, record_stopped, register_child, start_children,
-    stop_all_children,
+    answer_journal_query, answer_query, check_start_deadline, delete_child, dump_journal,
+    escalate_shutdown, handle_done, handle_failed, handle_health_check,
+    handle_register_dynamic_child, journal_child_event, record_aborted, record_alive,
+    record_killed, record_started, record_stopped, register_child, reject_child_request,
+    restart_backed_off_child, restart_child, start_children, stop_all_children, terminate_child,
 };
 #[allow(unused_imports)]
 use crate::control::SupervisorControl;
@@ -41,9 +43,13 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Done { .. }))
                     )
                 },
-                actions: &[handle_done_or_failed::<R>],
+                actions: &[journal_child_event::<R>, handle_done::<R>],
                 guard: |ctx, results, _ev| {
-                    if ctx.pending == ChildAction::BeginShutdown {
+                    if ctx.should_escalate() {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::Escalated),
+                        )
+                    } else if ctx.pending == ChildAction::BeginShutdown {
                         ::bloxide_core::transition::Guard::Transition(
                             ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
                         )
@@ -60,9 +66,13 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Failed { .. }))
                     )
                 },
-                actions: &[handle_done_or_failed::<R>],
+                actions: &[journal_child_event::<R>, handle_failed::<R>],
                 guard: |ctx, results, _ev| {
-                    if ctx.pending == ChildAction::BeginShutdown {
+                    if ctx.should_escalate() {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::Escalated),
+                        )
+                    } else if ctx.pending == ChildAction::BeginShutdown {
                         ::bloxide_core::transition::Guard::Transition(
                             ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
                         )
@@ -79,7 +89,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Started { .. }))
                     )
                 },
-                actions: &[record_started::<R>],
+                actions: &[journal_child_event::<R>, record_started::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -90,7 +100,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Aborted { .. }))
                     )
                 },
-                actions: &[record_aborted::<R>],
+                actions: &[journal_child_event::<R>, record_aborted::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -101,7 +111,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Killed { .. }))
                     )
                 },
-                actions: &[record_killed::<R>],
+                actions: &[journal_child_event::<R>, record_killed::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -112,7 +122,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Alive { .. }))
                     )
                 },
-                actions: &[record_alive::<R>],
+                actions: &[journal_child_event::<R>, record_alive::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -123,7 +133,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Stopped { .. }))
                     )
                 },
-                actions: &[record_stopped::<R>],
+                actions: &[journal_child_event::<R>, record_stopped::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -160,6 +170,97 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                     )
                 },
                 actions: &[handle_health_check::<R>],
+                guard: |ctx, results, _ev| {
+                    if ctx.should_escalate() {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::Escalated),
+                        )
+                    } else if ctx.pending == ChildAction::BeginShutdown {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
+                        )
+                    } else {
+                        ::bloxide_core::transition::Guard::Stay
+                    }
+                },
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::BackoffElapsed { .. }
+                        ))
+                    )
+                },
+                actions: &[restart_backed_off_child::<R>],
+                guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::StartDeadline { .. }
+                        ))
+                    )
+                },
+                actions: &[check_start_deadline::<R>],
+                guard: |ctx, results, _ev| {
+                    if ctx.should_escalate() {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::Escalated),
+                        )
+                    } else if ctx.pending == ChildAction::BeginShutdown {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
+                        )
+                    } else {
+                        ::bloxide_core::transition::Guard::Stay
+                    }
+                },
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(_, SupervisorControl::Query { .. }))
+                    )
+                },
+                actions: &[answer_query::<R>],
+                guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::QueryJournal { .. }
+                        ))
+                    )
+                },
+                actions: &[answer_journal_query::<R>],
+                guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::TerminateChild { .. }
+                        ))
+                    )
+                },
+                actions: &[terminate_child::<R>],
                 guard: |ctx, results, _ev| {
                     if ctx.pending == ChildAction::BeginShutdown {
                         ::bloxide_core::transition::Guard::Transition(
@@ -170,10 +271,61 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                     }
                 },
             },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::RestartChild { .. }
+                        ))
+                    )
+                },
+                actions: &[restart_child::<R>],
+                guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(
+                            _,
+                            SupervisorControl::DeleteChild { .. }
+                        ))
+                    )
+                },
+                actions: &[delete_child::<R>],
+                guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+            },
+            ::bloxide_core::transition::StateRule {
+                event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                matches: |__ev| {
+                    ::core::matches!(
+                        __ev,
+                        SupervisorEvent::Control(Envelope(_, SupervisorControl::Shutdown))
+                    )
+                },
+                actions: &[],
+                guard: |ctx, results, _ev| {
+                    if ctx.all_children_stopped() {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(
+                                SupervisorState::ShutdownComplete,
+                            ),
+                        )
+                    } else {
+                        ::bloxide_core::transition::Guard::Transition(
+                            ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
+                        )
+                    }
+                },
+            },
             ::bloxide_core::transition::StateRule {
                 event_tag: SupervisorEvent::<R>::CHILD_TAG,
                 matches: |__ev| ::core::matches!(__ev, SupervisorEvent::Child(_)),
-                actions: &[],
+                actions: &[journal_child_event::<R>],
                 guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
             },
             ::bloxide_core::transition::StateRule {
@@ -201,7 +353,7 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                             ))
                         )
                     },
-                    actions: &[record_stopped::<R>],
+                    actions: &[journal_child_event::<R>, record_stopped::<R>],
                     guard: |ctx, results, _ev| {
                         if ctx.all_children_stopped() {
                             ::bloxide_core::transition::Guard::Transition(
@@ -214,10 +366,170 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
                         }
                     },
                 },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CHILD_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Child(Envelope(
+                                _,
+                                ChildLifecycleEvent::Aborted { .. }
+                            ))
+                        )
+                    },
+                    actions: &[journal_child_event::<R>, record_aborted::<R>],
+                    guard: |ctx, results, _ev| {
+                        if ctx.all_children_stopped() {
+                            ::bloxide_core::transition::Guard::Transition(
+                                ::bloxide_core::topology::LeafState::new(
+                                    SupervisorState::ShutdownComplete,
+                                ),
+                            )
+                        } else {
+                            ::bloxide_core::transition::Guard::Stay
+                        }
+                    },
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CHILD_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Child(Envelope(_, ChildLifecycleEvent::Killed { .. }))
+                        )
+                    },
+                    actions: &[journal_child_event::<R>, record_killed::<R>],
+                    guard: |ctx, results, _ev| {
+                        if ctx.all_children_stopped() {
+                            ::bloxide_core::transition::Guard::Transition(
+                                ::bloxide_core::topology::LeafState::new(
+                                    SupervisorState::ShutdownComplete,
+                                ),
+                            )
+                        } else {
+                            ::bloxide_core::transition::Guard::Stay
+                        }
+                    },
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::ShutdownDeadline { .. }
+                            ))
+                        )
+                    },
+                    actions: &[escalate_shutdown::<R>],
+                    guard: |ctx, results, _ev| {
+                        if ctx.all_children_stopped() {
+                            ::bloxide_core::transition::Guard::Transition(
+                                ::bloxide_core::topology::LeafState::new(
+                                    SupervisorState::ShutdownComplete,
+                                ),
+                            )
+                        } else {
+                            ::bloxide_core::transition::Guard::Stay
+                        }
+                    },
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::EscalateShutdown
+                            ))
+                        )
+                    },
+                    actions: &[escalate_shutdown::<R>],
+                    guard: |ctx, results, _ev| {
+                        if ctx.all_children_stopped() {
+                            ::bloxide_core::transition::Guard::Transition(
+                                ::bloxide_core::topology::LeafState::new(
+                                    SupervisorState::ShutdownComplete,
+                                ),
+                            )
+                        } else {
+                            ::bloxide_core::transition::Guard::Stay
+                        }
+                    },
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(_, SupervisorControl::Query { .. }))
+                        )
+                    },
+                    actions: &[answer_query::<R>],
+                    guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::QueryJournal { .. }
+                            ))
+                        )
+                    },
+                    actions: &[answer_journal_query::<R>],
+                    guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::TerminateChild { .. }
+                            ))
+                        )
+                    },
+                    actions: &[reject_child_request::<R>],
+                    guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::RestartChild { .. }
+                            ))
+                        )
+                    },
+                    actions: &[reject_child_request::<R>],
+                    guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+                },
+                ::bloxide_core::transition::StateRule {
+                    event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+                    matches: |__ev| {
+                        ::core::matches!(
+                            __ev,
+                            SupervisorEvent::Control(Envelope(
+                                _,
+                                SupervisorControl::DeleteChild { .. }
+                            ))
+                        )
+                    },
+                    actions: &[reject_child_request::<R>],
+                    guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
+                },
                 ::bloxide_core::transition::StateRule {
                     event_tag: SupervisorEvent::<R>::CHILD_TAG,
                     matches: |__ev| ::core::matches!(__ev, SupervisorEvent::Child(_)),
-                    actions: &[],
+                    actions: &[journal_child_event::<R>],
                     guard: |ctx, results, _ev| ::bloxide_core::transition::Guard::Stay,
                 },
                 ::bloxide_core::transition::StateRule {
@@ -231,10 +543,36 @@ impl<R: BloxRuntime> SupervisorSpec<R> {
     #[allow(unused_variables)]
     const SHUTDOWN_COMPLETE_FNS: ::bloxide_core::spec::StateFns<Self> =
         ::bloxide_core::spec::StateFns {
-            on_entry: &[],
+            on_entry: &[dump_journal::<R>],
             on_exit: &[],
             transitions: &[],
         };
+    #[allow(unused_variables)]
+    const ESCALATED_FNS: ::bloxide_core::spec::StateFns<Self> = ::bloxide_core::spec::StateFns {
+        on_entry: &[dump_journal::<R>],
+        on_exit: &[],
+        transitions: &[::bloxide_core::transition::StateRule {
+            event_tag: SupervisorEvent::<R>::CONTROL_TAG,
+            matches: |__ev| {
+                ::core::matches!(
+                    __ev,
+                    SupervisorEvent::Control(Envelope(_, SupervisorControl::Shutdown))
+                )
+            },
+            actions: &[],
+            guard: |ctx, results, _ev| {
+                if ctx.all_children_stopped() {
+                    ::bloxide_core::transition::Guard::Transition(
+                        ::bloxide_core::topology::LeafState::new(SupervisorState::ShutdownComplete),
+                    )
+                } else {
+                    ::bloxide_core::transition::Guard::Transition(
+                        ::bloxide_core::topology::LeafState::new(SupervisorState::ShuttingDown),
+                    )
+                }
+            },
+        }],
+    };
 }
 impl<R: BloxRuntime> MachineSpec for SupervisorSpec<R> {
     type State = SupervisorState;
@@ -251,11 +589,18 @@ impl<R: BloxRuntime> MachineSpec for SupervisorSpec<R> {
     fn is_terminal(state: &SupervisorState) -> bool {
         ::core::matches!(state, SupervisorState::ShutdownComplete)
     }
-    fn is_error(_state: &SupervisorState) -> bool {
-        false
+    fn is_error(state: &SupervisorState) -> bool {
+        ::core::matches!(state, SupervisorState::Escalated)
     }
     fn on_init_entry(ctx: &mut Self::Ctx) {
         ctx.children.clear_counters();
         ctx.pending = ChildAction::default();
+        ctx.started = false;
+    }
+    fn stop_event(ctx: &Self::Ctx) -> Option<Self::Event> {
+        Some(SupervisorEvent::Control(Envelope(
+            ctx.self_id,
+            SupervisorControl::Shutdown,
+        )))
     }
 }
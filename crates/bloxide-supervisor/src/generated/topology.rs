@@ -6,15 +6,17 @@ pub enum SupervisorState {
     Running = 0u8,
     ShuttingDown = 1u8,
     ShutdownComplete = 2u8,
+    Escalated = 3u8,
 }
 impl ::bloxide_core::topology::StateTopology for SupervisorState {
-    const STATE_COUNT: usize = 3usize;
+    const STATE_COUNT: usize = 4usize;
     #[inline]
     fn parent(self) -> ::core::option::Option<Self> {
         match self {
             Self::Running => ::core::option::Option::None,
             Self::ShuttingDown => ::core::option::Option::None,
             Self::ShutdownComplete => ::core::option::Option::None,
+            Self::Escalated => ::core::option::Option::None,
         }
     }
     #[inline]
@@ -23,6 +25,7 @@ impl ::bloxide_core::topology::StateTopology for SupervisorState {
             Self::Running => true,
             Self::ShuttingDown => true,
             Self::ShutdownComplete => true,
+            Self::Escalated => true,
         }
     }
     fn path(self) -> &'static [Self] {
@@ -30,10 +33,12 @@ impl ::bloxide_core::topology::StateTopology for SupervisorState {
         static __PATH_SHUTTINGDOWN: [SupervisorState; 1usize] = [SupervisorState::ShuttingDown];
         static __PATH_SHUTDOWNCOMPLETE: [SupervisorState; 1usize] =
             [SupervisorState::ShutdownComplete];
+        static __PATH_ESCALATED: [SupervisorState; 1usize] = [SupervisorState::Escalated];
         match self {
             Self::Running => &__PATH_RUNNING,
             Self::ShuttingDown => &__PATH_SHUTTINGDOWN,
             Self::ShutdownComplete => &__PATH_SHUTDOWNCOMPLETE,
+            Self::Escalated => &__PATH_ESCALATED,
         }
     }
     #[inline]
@@ -42,6 +47,7 @@ impl ::bloxide_core::topology::StateTopology for SupervisorState {
             Self::Running => 0usize,
             Self::ShuttingDown => 1usize,
             Self::ShutdownComplete => 2usize,
+            Self::Escalated => 3usize,
         }
     }
 }
@@ -53,6 +59,7 @@ macro_rules! supervisor_state_handler_table {
             &<$ty>::RUNNING_FNS,
             &<$ty>::SHUTTING_DOWN_FNS,
             &<$ty>::SHUTDOWN_COMPLETE_FNS,
+            &<$ty>::ESCALATED_FNS,
         ]
     };
 }
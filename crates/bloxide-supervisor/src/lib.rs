@@ -10,6 +10,9 @@ pub mod control;
 // Spawn accessor trait (HasChildNotify)
 pub mod spawn;
 
+// Restart backoffs and shutdown/start deadlines (SupervisorTimer)
+pub mod timer;
+
 // Hand-written action functions (concrete, take &SupervisorEvent<R> directly)
 pub mod actions;
 
@@ -22,20 +25,28 @@ mod tests;
 
 // Re-export child-management types from bloxide-child-management
 pub use bloxide_child_management::{
-    AbortCommand, ChildAction, ChildGroup, ChildPolicy, GroupShutdown, HasChildGroup,
-    HasChildGroupMut, HasPending, RestartStrategy,
+    AbortCommand, Backoff, ChildAction, ChildCounts, ChildGroup, ChildInfo, ChildPhase,
+    ChildPolicy, Decision, DecisionReason, GroupShutdown, HasChildGroup, HasChildGroupMut,
+    HasPending, Journal, JournalEntry, JournalRecord, Liveness, RestartStrategy, RestartType,
+    StartOrder, Termination,
 };
 
 // Re-export supervisor-specific types from local modules
-pub use control::{RegisterChild, RegisterDynamicChild, SupervisorControl, SupervisorRegistrar};
+pub use control::{
+    JournalReport, RegisterChild, RegisterDynamicChild, SupervisorControl, SupervisorRegistrar,
+    SupervisorReport,
+};
 pub use spawn::HasChildNotify;
+pub use timer::SupervisorTimer;
 
 // Re-export from generated (SupervisorEvent now codegen-generated, not hand-written)
 pub use generated::{SupervisorCtx, SupervisorEvent, SupervisorSpec, SupervisorState};
 
 // Re-export action functions from the local actions module
 pub use actions::{
-    handle_done_or_failed, handle_health_check, handle_register_dynamic_child, record_aborted,
-    record_alive, record_killed, record_started, record_stopped, register_child, start_children,
-    stop_all_children,
+    answer_journal_query, answer_query, check_start_deadline, delete_child, dump_journal,
+    escalate_shutdown, handle_done, handle_failed, handle_health_check,
+    handle_register_dynamic_child, journal_child_event, record_aborted, record_alive,
+    record_killed, record_started, record_stopped, register_child, restart_backed_off_child,
+    restart_child, start_children, stop_all_children, terminate_child,
 };
@@ -10,11 +10,12 @@ use alloc::vec::Vec;
 
 use crate::RegisterDynamicChild;
 use crate::{
-    control::{RegisterChild, SupervisorControl},
+    control::{JournalReport, RegisterChild, SupervisorControl, SupervisorReport},
     SupervisorCtx, SupervisorEvent, SupervisorSpec, SupervisorState,
 };
 use bloxide_child_management::{
-    AbortCommand, ChildGroup, ChildPolicy, GroupShutdown, RestartStrategy,
+    AbortCommand, Backoff, ChildGroup, ChildPhase, ChildPolicy, Decision, DecisionReason,
+    GroupShutdown, Journal, JournalRecord, RestartStrategy, RestartType, Termination,
 };
 use bloxide_core::lifecycle::{ChildLifecycleEvent, LifecycleCommand};
 use bloxide_core::messaging::Envelope;
@@ -110,7 +111,10 @@ fn start_enters_running() {
 fn restart_policy_stays_running_on_done() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 3 }],
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -131,7 +135,10 @@ fn restart_policy_stays_running_on_done() {
 fn restart_policy_reset_returns_started_no_separate_start() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 3 }],
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -156,7 +163,10 @@ fn restart_policy_reset_returns_started_no_separate_start() {
 fn restart_limit_exhausted_shuts_down() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 1 }],
+        &[ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -301,7 +311,10 @@ fn shutdown_completes_when_single_child_stops() {
 fn failed_event_treated_same_as_done() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 3 }],
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -341,7 +354,10 @@ fn register_child_event_adds_child_and_sends_start() {
 fn health_check_tick_marks_unresponsive_restart_child_and_sends_ping() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 2 }],
+        &[ChildPolicy::Restart {
+            max: 2,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -369,9 +385,18 @@ fn one_for_one_restarts_only_failed_child() {
     let (mut machine, mut receivers) = make_supervisor_with_strategy(
         GroupShutdown::WhenAnyDone,
         &[
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
         ],
         RestartStrategy::OneForOne,
     );
@@ -401,9 +426,18 @@ fn one_for_all_restarts_all_children() {
     let (mut machine, mut receivers) = make_supervisor_with_strategy(
         GroupShutdown::WhenAnyDone,
         &[
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
         ],
         RestartStrategy::OneForAll,
     );
@@ -437,10 +471,22 @@ fn rest_for_one_restarts_subsequent_children() {
     let (mut machine, mut receivers) = make_supervisor_with_strategy(
         GroupShutdown::WhenAnyDone,
         &[
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
         ],
         RestartStrategy::RestForOne,
     );
@@ -480,8 +526,14 @@ fn restart_strategy_respects_max_restarts() {
     let (mut machine, mut receivers) = make_supervisor_with_strategy(
         GroupShutdown::WhenAnyDone,
         [
-            ChildPolicy::Restart { max: 1 },
-            ChildPolicy::Restart { max: 1 },
+            ChildPolicy::Restart {
+                max: 1,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 1,
+                backoff: Backoff::Immediate,
+            },
         ]
         .as_slice(),
         RestartStrategy::OneForAll,
@@ -523,8 +575,14 @@ fn aborted_child_marked_permanently_done() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAllDone,
         &[
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
         ],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
@@ -558,8 +616,14 @@ fn aborted_child_does_not_trigger_shutdown_check() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAllDone,
         &[
-            ChildPolicy::Restart { max: 3 },
-            ChildPolicy::Restart { max: 3 },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
         ],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
@@ -583,7 +647,10 @@ fn aborted_child_does_not_trigger_shutdown_check() {
 fn register_dynamic_child_adds_and_starts() {
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAllDone,
-        &[ChildPolicy::Restart { max: 3 }],
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -598,7 +665,10 @@ fn register_dynamic_child_adds_and_starts() {
         lifecycle_ref,
         abort_ref,
         kill_handle: (),
-        policy: ChildPolicy::Restart { max: 3 },
+        policy: ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        },
     };
 
     let outcome =
@@ -628,7 +698,10 @@ fn register_dynamic_child_during_shutdown_still_starts_child() {
     // starting the child. This test documents the current behavior.
     let (mut machine, mut receivers) = make_supervisor(
         GroupShutdown::WhenAnyDone,
-        &[ChildPolicy::Restart { max: 3 }],
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        }],
     );
     machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
     drain_start_commands(&mut receivers);
@@ -647,7 +720,10 @@ fn register_dynamic_child_during_shutdown_still_starts_child() {
         lifecycle_ref,
         abort_ref,
         kill_handle: (),
-        policy: ChildPolicy::Restart { max: 3 },
+        policy: ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Immediate,
+        },
     };
 
     let outcome =
@@ -667,3 +743,863 @@ fn register_dynamic_child_during_shutdown_still_starts_child() {
     );
     assert!(matches!(cmds[0], LifecycleCommand::Start));
 }
+
+#[test]
+fn shutdown_control_stops_children_and_completes() {
+    let (mut machine, mut receivers) = make_supervisor(
+        GroupShutdown::WhenAllDone,
+        &[
+            ChildPolicy::Stop,
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+        ],
+    );
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+    for rx in receivers.iter_mut() {
+        let cmds = rx.drain_payloads();
+        assert!(
+            matches!(cmds.as_slice(), [LifecycleCommand::Stop]),
+            "expected a single Stop, got {:?}",
+            cmds,
+        );
+    }
+
+    // A second Shutdown while already shutting down is absorbed.
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 2 });
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn shutdown_control_completes_at_once_when_no_child_is_running() {
+    let (mut machine, mut receivers) =
+        make_supervisor(GroupShutdown::WhenAllDone, &[ChildPolicy::Stop]);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+    assert!(receivers[0].drain_payloads().is_empty());
+}
+
+#[test]
+fn restart_backoff_delays_reset_until_timer_fires() {
+    use bloxide_child_management::ChildPhase;
+    use bloxide_timer::{test_utils::VirtualClock, TimerCommand};
+
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (child_ref, mut child_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    group.add(
+        1,
+        child_ref,
+        ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Fixed {
+                delay_ms: 250,
+                jitter_ms: 0,
+            },
+        },
+    );
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(101, 16);
+    let (control_ref, mut control_rx) =
+        TestRuntime::channel::<SupervisorControl<TestRuntime>>(102, 16);
+    let clock = VirtualClock::new(timer_rx);
+    let ctx = SupervisorCtx::new(100, group, notify_ref).with_timer(timer_ref, control_ref);
+    let mut machine = StateMachine::<Spec>::new(ctx);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(core::slice::from_mut(&mut child_rx));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert!(
+        child_rx.drain_payloads().is_empty(),
+        "Reset must wait out the backoff"
+    );
+    assert_eq!(
+        machine.ctx().children.phase(1),
+        Some(ChildPhase::BackingOff)
+    );
+
+    // Health checks leave a backing-off child alone.
+    dispatch_control_event(&mut machine, SupervisorControl::HealthCheckTick);
+    dispatch_control_event(&mut machine, SupervisorControl::HealthCheckTick);
+    assert!(child_rx.drain_payloads().is_empty());
+
+    assert_eq!(clock.advance(249), 0);
+    assert_eq!(clock.advance(1), 1);
+    for control in control_rx.drain_payloads() {
+        dispatch_control_event(&mut machine, control);
+    }
+    assert!(matches!(
+        child_rx.drain_payloads().as_slice(),
+        [LifecycleCommand::Reset]
+    ));
+    assert_eq!(
+        machine.ctx().children.phase(1),
+        Some(ChildPhase::ResetPending)
+    );
+}
+
+#[test]
+fn restart_backoff_without_timer_restarts_at_once() {
+    let (mut machine, mut receivers) = make_supervisor(
+        GroupShutdown::WhenAnyDone,
+        &[ChildPolicy::Restart {
+            max: 3,
+            backoff: Backoff::Fixed {
+                delay_ms: 250,
+                jitter_ms: 0,
+            },
+        }],
+    );
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert!(matches!(
+        receivers[0].drain_payloads().as_slice(),
+        [LifecycleCommand::Reset]
+    ));
+}
+
+/// A supervisor set up as a parent's child: one restartable child with
+/// `id` 1 and an escalating context.
+fn make_nested_supervisor(
+    policy: ChildPolicy,
+) -> (StateMachine<Spec>, TestReceiver<LifecycleCommand>) {
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (actor_ref, rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    group.add(1, actor_ref, policy);
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(50, 16);
+    let ctx = SupervisorCtx::new(50, group, notify_ref).escalate_to_parent();
+    (StateMachine::new(ctx), rx)
+}
+
+#[test]
+fn nested_supervisor_escalates_failure_to_parent_which_restarts_subtree() {
+    let (mut nested, mut grandchild_rx) = make_nested_supervisor(ChildPolicy::Restart {
+        max: 1,
+        backoff: Backoff::Immediate,
+    });
+
+    // The parent supervises the nested supervisor (id 50) and is told about
+    // it through the same notify channel its run loop would use.
+    let mut parent_group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (nested_lifecycle_ref, mut nested_lifecycle_rx) =
+        TestRuntime::channel::<LifecycleCommand>(50, 16);
+    parent_group.add(
+        50,
+        nested_lifecycle_ref,
+        ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        },
+    );
+    let (parent_notify_ref, mut parent_notify_rx) =
+        TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let mut parent = StateMachine::<Spec>::new(SupervisorCtx::new(
+        100,
+        parent_group,
+        parent_notify_ref.clone(),
+    ));
+    parent.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+
+    let mut deliver_to_nested = |nested: &mut StateMachine<Spec>| {
+        for cmd in nested_lifecycle_rx.drain_payloads() {
+            let outcome = nested.handle_lifecycle(cmd);
+            bloxide_core::report_outcome::<Spec, TestRuntime>(
+                &outcome,
+                50,
+                &parent_notify_ref.sender(),
+            );
+        }
+    };
+    deliver_to_nested(&mut nested);
+    assert_eq!(grandchild_rx.drain_payloads(), [LifecycleCommand::Start]);
+
+    // The grandchild fails once (restarted), then again (budget spent).
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Failed { child_id: 1 });
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 1 });
+    assert_eq!(grandchild_rx.drain_payloads(), [LifecycleCommand::Reset]);
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert_eq!(outcome, DispatchOutcome::Failed);
+    assert_eq!(
+        nested.current_state(),
+        MachineState::State(SupervisorState::Escalated)
+    );
+    // Escalating leaves the subtree for the parent to decide on.
+    assert!(grandchild_rx.drain_payloads().is_empty());
+
+    // The nested run loop reports Failed; the parent restarts the subtree.
+    bloxide_core::report_outcome::<Spec, TestRuntime>(&outcome, 50, &parent_notify_ref.sender());
+    for event in parent_notify_rx.drain_payloads() {
+        parent.dispatch(SupervisorEvent::Child(Envelope(50, event)));
+    }
+    assert_eq!(
+        parent.current_state(),
+        MachineState::State(SupervisorState::Running)
+    );
+    deliver_to_nested(&mut nested);
+    assert_eq!(
+        nested.current_state(),
+        MachineState::State(SupervisorState::Running)
+    );
+    assert_eq!(
+        grandchild_rx.drain_payloads(),
+        [LifecycleCommand::Reset, LifecycleCommand::Start]
+    );
+
+    // The restart gave the subtree a fresh budget.
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert_eq!(grandchild_rx.drain_payloads(), [LifecycleCommand::Reset]);
+}
+
+#[test]
+fn nested_supervisor_completing_normally_shuts_down_instead_of_escalating() {
+    let (mut nested, mut grandchild_rx) = make_nested_supervisor(ChildPolicy::Stop);
+    nested.handle_lifecycle(LifecycleCommand::Start);
+    grandchild_rx.drain_payloads();
+
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Done { child_id: 1 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+}
+
+#[test]
+fn stop_from_parent_stops_the_subtree() {
+    let (mut nested, mut grandchild_rx) = make_nested_supervisor(ChildPolicy::Stop);
+    nested.handle_lifecycle(LifecycleCommand::Start);
+    grandchild_rx.drain_payloads();
+
+    // Stop winds the subtree down first; the nested supervisor reports
+    // Stopped only once its child has stopped.
+    let outcome = nested.handle_lifecycle(LifecycleCommand::Stop);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+    assert_eq!(grandchild_rx.drain_payloads(), [LifecycleCommand::Stop]);
+    let outcome = nested.handle_lifecycle(LifecycleCommand::Stop);
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert!(grandchild_rx.drain_payloads().is_empty());
+
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Stopped { child_id: 1 });
+    assert_eq!(outcome, DispatchOutcome::Stopped);
+    assert_eq!(nested.current_state(), MachineState::Init);
+
+    // The child's task ended when it stopped: starting again leaves it alone.
+    nested.handle_lifecycle(LifecycleCommand::Start);
+    assert!(grandchild_rx.drain_payloads().is_empty());
+    assert_eq!(
+        nested.ctx().children.phase(1),
+        Some(ChildPhase::PermanentlyDone)
+    );
+}
+
+#[test]
+fn reset_from_parent_escalates_when_a_child_task_has_ended() {
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (stopped_ref, mut stopped_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    let (steady_ref, mut steady_rx) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+    group.add(1, stopped_ref, ChildPolicy::Stop);
+    group.add(2, steady_ref, ChildPolicy::Stop);
+    let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(50, 16);
+    let ctx = SupervisorCtx::new(50, group, notify_ref).escalate_to_parent();
+    let mut nested = StateMachine::<Spec>::new(ctx);
+    nested.handle_lifecycle(LifecycleCommand::Start);
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 2 });
+    stopped_rx.drain_payloads();
+    steady_rx.drain_payloads();
+
+    // An operator stops child 1; its task ends.
+    dispatch_control_event(
+        &mut nested,
+        SupervisorControl::TerminateChild {
+            child_id: 1,
+            how: Termination::Stop,
+        },
+    );
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Stopped { child_id: 1 });
+    stopped_rx.drain_payloads();
+
+    // The parent resets the subtree: only the live child is reset, and the
+    // ended one is reported as failed to the nested supervisor itself.
+    nested.handle_lifecycle(LifecycleCommand::Reset);
+    assert!(stopped_rx.drain_payloads().is_empty());
+    assert_eq!(
+        steady_rx.drain_payloads(),
+        [LifecycleCommand::Reset, LifecycleCommand::Start]
+    );
+    let reports = notify_rx.drain_payloads();
+    assert_eq!(reports, [ChildLifecycleEvent::Failed { child_id: 1 }]);
+
+    // Handling the report gives up on the child and escalates.
+    let outcome = dispatch_child_event(&mut nested, reports[0]);
+    assert_eq!(outcome, DispatchOutcome::Failed);
+    assert_eq!(
+        nested.current_state(),
+        MachineState::State(SupervisorState::Escalated)
+    );
+    assert_eq!(
+        nested.ctx().children.phase(1),
+        Some(ChildPhase::PermanentlyDone)
+    );
+    assert!(stopped_rx.drain_payloads().is_empty());
+}
+
+#[test]
+fn stop_after_escalating_stops_the_subtree_before_reporting() {
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (flaky_ref, mut flaky_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    let (steady_ref, mut steady_rx) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+    group.add(
+        1,
+        flaky_ref,
+        ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        },
+    );
+    group.add(2, steady_ref, ChildPolicy::Stop);
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(50, 16);
+    let ctx = SupervisorCtx::new(50, group, notify_ref).escalate_to_parent();
+    let mut nested = StateMachine::<Spec>::new(ctx);
+    nested.handle_lifecycle(LifecycleCommand::Start);
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 2 });
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Failed { child_id: 1 });
+    dispatch_child_event(&mut nested, ChildLifecycleEvent::Started { child_id: 1 });
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert_eq!(outcome, DispatchOutcome::Failed);
+    flaky_rx.drain_payloads();
+    assert_eq!(steady_rx.drain_payloads(), [LifecycleCommand::Start]);
+
+    // The parent gives up on the subtree: its Stop reaches the escalated
+    // supervisor, which stops the child it left running.
+    let outcome = nested.handle_lifecycle(LifecycleCommand::Stop);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+    assert_eq!(steady_rx.drain_payloads(), [LifecycleCommand::Stop]);
+
+    let outcome = dispatch_child_event(&mut nested, ChildLifecycleEvent::Stopped { child_id: 2 });
+    assert_eq!(outcome, DispatchOutcome::Stopped);
+    assert_eq!(nested.current_state(), MachineState::Init);
+}
+
+#[test]
+fn query_reports_children_and_counts() {
+    let (mut machine, mut receivers) = make_supervisor(
+        GroupShutdown::WhenAllDone,
+        &[
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Stop,
+        ],
+    );
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 2 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Failed { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Done { child_id: 2 });
+
+    let (reply_ref, mut reply_rx) = TestRuntime::channel::<SupervisorReport>(200, 4);
+    let outcome = dispatch_control_event(
+        &mut machine,
+        SupervisorControl::Query {
+            reply_to: reply_ref.clone(),
+        },
+    );
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+
+    let reports = reply_rx.drain_payloads();
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report.supervisor_id, 100);
+    let phases: Vec<_> = report.children.iter().map(|c| (c.id, c.phase)).collect();
+    assert_eq!(
+        phases,
+        [
+            (1, ChildPhase::ResetPending),
+            (2, ChildPhase::PermanentlyDone)
+        ]
+    );
+    assert_eq!(report.children[0].restarts, 1);
+    assert_eq!(report.children[1].policy, ChildPolicy::Stop);
+    assert_eq!(report.counts.total, 2);
+    assert_eq!(report.counts.reset_pending, 1);
+    assert_eq!(report.counts.permanently_done, 1);
+
+    // Still answered while shutting down.
+    dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    dispatch_control_event(
+        &mut machine,
+        SupervisorControl::Query {
+            reply_to: reply_ref,
+        },
+    );
+    assert_eq!(reply_rx.drain_payloads().len(), 1);
+}
+
+#[test]
+fn terminate_and_delete_a_single_child() {
+    let (mut machine, mut receivers) = make_supervisor(
+        GroupShutdown::WhenAnyDone,
+        &[
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+            ChildPolicy::Stop,
+        ],
+    );
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 2 });
+
+    // Terminating one child of a WhenAnyDone group leaves the rest running.
+    let outcome = dispatch_control_event(
+        &mut machine,
+        SupervisorControl::TerminateChild {
+            child_id: 1,
+            how: Termination::Stop,
+        },
+    );
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert!(matches!(
+        receivers[0].drain_payloads().as_slice(),
+        [LifecycleCommand::Stop]
+    ));
+    assert!(receivers[1].drain_payloads().is_empty());
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+    assert_eq!(machine.ctx().children.phase(1), Some(ChildPhase::Stopped));
+
+    // Its task has ended, so it cannot be restarted, only deleted.
+    dispatch_control_event(
+        &mut machine,
+        SupervisorControl::RestartChild { child_id: 1 },
+    );
+    assert!(receivers[0].drain_payloads().is_empty());
+    assert_eq!(machine.ctx().children.phase(1), Some(ChildPhase::Stopped));
+
+    // A running child cannot be deleted; a stopped one can.
+    dispatch_control_event(&mut machine, SupervisorControl::DeleteChild { child_id: 2 });
+    assert_eq!(machine.ctx().children.phase(2), Some(ChildPhase::Running));
+    dispatch_control_event(&mut machine, SupervisorControl::DeleteChild { child_id: 1 });
+    assert_eq!(machine.ctx().children.phase(1), None);
+    assert_eq!(machine.ctx().children.counts().total, 1);
+
+    // Shutdown now only waits for the remaining child.
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 2 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn per_child_requests_are_rejected_while_shutting_down() {
+    let (mut machine, mut receivers) = make_supervisor(
+        GroupShutdown::WhenAllDone,
+        &[ChildPolicy::Stop, ChildPolicy::Stop],
+    );
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+    for rx in receivers.iter_mut() {
+        rx.drain_payloads();
+    }
+
+    for request in [
+        SupervisorControl::TerminateChild {
+            child_id: 2,
+            how: Termination::Stop,
+        },
+        SupervisorControl::RestartChild { child_id: 1 },
+        SupervisorControl::DeleteChild { child_id: 1 },
+    ] {
+        let outcome = dispatch_control_event(&mut machine, request);
+        assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    }
+    assert!(receivers
+        .iter_mut()
+        .all(|rx| rx.drain_payloads().is_empty()));
+    assert_eq!(machine.ctx().children.phase(1), Some(ChildPhase::Stopped));
+    assert_eq!(machine.ctx().children.counts().total, 2);
+
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 2 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn terminating_the_last_child_of_a_when_all_done_group_shuts_down() {
+    let (mut machine, mut receivers) =
+        make_supervisor(GroupShutdown::WhenAllDone, &[ChildPolicy::Stop]);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+
+    // A static child has no kill handle: the request is ignored.
+    let outcome = dispatch_control_event(
+        &mut machine,
+        SupervisorControl::TerminateChild {
+            child_id: 1,
+            how: Termination::Kill,
+        },
+    );
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert_eq!(machine.ctx().children.phase(1), Some(ChildPhase::Running));
+
+    let outcome = dispatch_control_event(
+        &mut machine,
+        SupervisorControl::TerminateChild {
+            child_id: 1,
+            how: Termination::Stop,
+        },
+    );
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Transition(MachineState::State(SupervisorState::ShuttingDown))
+    );
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn restart_type_separates_done_from_failed() {
+    let restart = ChildPolicy::Restart {
+        max: 3,
+        backoff: Backoff::Immediate,
+    };
+    let (mut machine, mut receivers) =
+        make_supervisor(GroupShutdown::WhenAllDone, &[restart, restart]);
+    machine
+        .ctx_mut()
+        .children
+        .set_restart_type(1, RestartType::Transient);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(&mut receivers);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 2 });
+
+    // The permanent child is restarted after a normal exit...
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Done { child_id: 2 });
+    assert!(matches!(
+        receivers[1].drain_payloads().as_slice(),
+        [LifecycleCommand::Reset]
+    ));
+
+    // ...the transient one only after a failure.
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Failed { child_id: 1 });
+    assert!(matches!(
+        receivers[0].drain_payloads().as_slice(),
+        [LifecycleCommand::Reset]
+    ));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Done { child_id: 1 });
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert!(receivers[0].drain_payloads().is_empty());
+    assert_eq!(
+        machine.ctx().children.phase(1),
+        Some(ChildPhase::PermanentlyDone)
+    );
+}
+
+#[test]
+fn shutdown_escalates_a_hung_child_from_stop_to_abort_to_kill() {
+    use bloxide_core::lifecycle::ShutdownEscalation;
+    use bloxide_timer::{test_utils::VirtualClock, TimerCommand};
+
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone).with_shutdown_timeouts(100, 50);
+    let (ok_ref, mut ok_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    let (hung_ref, mut hung_rx) = TestRuntime::channel::<LifecycleCommand>(2, 16);
+    let (abort_ref, mut abort_rx) = TestRuntime::channel::<AbortCommand>(3, 16);
+    group.add(1, ok_ref, ChildPolicy::Stop);
+    group.add_dynamic(2, hung_ref, abort_ref, (), ChildPolicy::Stop);
+    let (notify_ref, mut notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(101, 16);
+    let (control_ref, mut control_rx) =
+        TestRuntime::channel::<SupervisorControl<TestRuntime>>(102, 16);
+    let clock = VirtualClock::new(timer_rx);
+    let ctx = SupervisorCtx::new(100, group, notify_ref).with_timer(timer_ref, control_ref);
+    let mut machine = StateMachine::<Spec>::new(ctx);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    drain_start_commands(core::slice::from_mut(&mut ok_rx));
+    drain_start_commands(core::slice::from_mut(&mut hung_rx));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 2 });
+
+    dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    assert!(matches!(
+        ok_rx.drain_payloads().as_slice(),
+        [LifecycleCommand::Stop]
+    ));
+    assert!(matches!(
+        hung_rx.drain_payloads().as_slice(),
+        [LifecycleCommand::Stop]
+    ));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+
+    // Child 2 never reports Stopped. Child 1's deadline is a no-op.
+    assert_eq!(clock.advance(100), 2);
+    for control in control_rx.drain_payloads() {
+        let outcome = dispatch_control_event(&mut machine, control);
+        assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    }
+    assert!(matches!(
+        abort_rx.drain_payloads().as_slice(),
+        [AbortCommand::Abort { child_id: 2 }]
+    ));
+
+    // Nor does it report Aborted: it is killed when the abort deadline passes.
+    assert_eq!(clock.advance(50), 1);
+    let deadline = control_rx.drain_payloads().pop().expect("abort deadline");
+    let outcome = dispatch_control_event(&mut machine, deadline);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+    assert!(matches!(
+        notify_rx.drain_payloads().as_slice(),
+        [
+            ChildLifecycleEvent::ShutdownEscalated {
+                child_id: 2,
+                to: ShutdownEscalation::Abort
+            },
+            ChildLifecycleEvent::ShutdownEscalated {
+                child_id: 2,
+                to: ShutdownEscalation::Kill
+            },
+            ChildLifecycleEvent::Killed { child_id: 2 },
+        ]
+    ));
+}
+
+#[test]
+fn escalate_shutdown_steps_hung_children_without_timeouts() {
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone);
+    let (hung_ref, mut hung_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    let (abort_ref, mut abort_rx) = TestRuntime::channel::<AbortCommand>(2, 16);
+    group.add_dynamic(1, hung_ref, abort_ref, (), ChildPolicy::Stop);
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let mut machine = StateMachine::<Spec>::new(SupervisorCtx::new(100, group, notify_ref));
+
+    // Ignored outside a shutdown.
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_control_event(&mut machine, SupervisorControl::EscalateShutdown);
+    assert!(abort_rx.drain_payloads().is_empty());
+
+    dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    hung_rx.drain_payloads();
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::EscalateShutdown);
+    assert_eq!(outcome, DispatchOutcome::HandledNoTransition);
+    assert!(matches!(
+        abort_rx.drain_payloads().as_slice(),
+        [AbortCommand::Abort { child_id: 1 }]
+    ));
+
+    // The next step kills the child, which completes the shutdown.
+    let outcome = dispatch_control_event(&mut machine, SupervisorControl::EscalateShutdown);
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn shutdown_completes_when_an_escalated_child_reports_aborted() {
+    use bloxide_timer::{test_utils::VirtualClock, TimerCommand};
+
+    let mut group = ChildGroup::new(GroupShutdown::WhenAnyDone).with_shutdown_timeouts(100, 50);
+    let (child_ref, _child_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    let (abort_ref, mut abort_rx) = TestRuntime::channel::<AbortCommand>(2, 16);
+    group.add_dynamic(1, child_ref, abort_ref, (), ChildPolicy::Stop);
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(101, 16);
+    let (control_ref, mut control_rx) =
+        TestRuntime::channel::<SupervisorControl<TestRuntime>>(102, 16);
+    let clock = VirtualClock::new(timer_rx);
+    let ctx = SupervisorCtx::new(100, group, notify_ref).with_timer(timer_ref, control_ref);
+    let mut machine = StateMachine::<Spec>::new(ctx);
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+
+    dispatch_control_event(&mut machine, SupervisorControl::Shutdown);
+    clock.advance(100);
+    for control in control_rx.drain_payloads() {
+        dispatch_control_event(&mut machine, control);
+    }
+    assert_eq!(abort_rx.drain_payloads().len(), 1);
+
+    let outcome = dispatch_child_event(&mut machine, ChildLifecycleEvent::Aborted { child_id: 1 });
+    assert_eq!(
+        outcome,
+        DispatchOutcome::Done(MachineState::State(SupervisorState::ShutdownComplete))
+    );
+}
+
+#[test]
+fn sequential_start_waits_for_each_started_event() {
+    use bloxide_child_management::StartOrder;
+
+    let mut group =
+        ChildGroup::new(GroupShutdown::WhenAnyDone).with_start_order(StartOrder::Sequential);
+    let mut receivers = Vec::new();
+    for id in 1..=2 {
+        let (actor_ref, rx) = TestRuntime::channel::<LifecycleCommand>(id, 16);
+        group.add(id, actor_ref, ChildPolicy::Stop);
+        receivers.push(rx);
+    }
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let mut machine = StateMachine::<Spec>::new(SupervisorCtx::new(100, group, notify_ref));
+
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    assert_eq!(receivers[0].drain_payloads(), [LifecycleCommand::Start]);
+    assert!(receivers[1].drain_payloads().is_empty());
+
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    assert_eq!(receivers[1].drain_payloads(), [LifecycleCommand::Start]);
+}
+
+#[test]
+fn missed_start_deadline_shuts_down_or_escalates() {
+    use bloxide_timer::{test_utils::VirtualClock, TimerCommand};
+
+    for escalate in [false, true] {
+        let mut group = ChildGroup::new(GroupShutdown::WhenAllDone).with_start_timeout(500);
+        let (child_ref, _child_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+        group.add(
+            1,
+            child_ref,
+            ChildPolicy::Restart {
+                max: 3,
+                backoff: Backoff::Immediate,
+            },
+        );
+        let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(101, 16);
+        let (control_ref, mut control_rx) =
+            TestRuntime::channel::<SupervisorControl<TestRuntime>>(102, 16);
+        let clock = VirtualClock::new(timer_rx);
+        let mut ctx = SupervisorCtx::new(100, group, notify_ref).with_timer(timer_ref, control_ref);
+        if escalate {
+            ctx = ctx.escalate_to_parent();
+        }
+        let mut machine = StateMachine::<Spec>::new(ctx);
+        machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+
+        assert_eq!(clock.advance(500), 1);
+        for control in control_rx.drain_payloads() {
+            dispatch_control_event(&mut machine, control);
+        }
+        let expected = if escalate {
+            SupervisorState::Escalated
+        } else {
+            SupervisorState::ShuttingDown
+        };
+        assert_eq!(machine.current_state(), MachineState::State(expected));
+    }
+}
+
+static JOURNAL_DUMPS: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+fn count_journal_dump(supervisor_id: bloxide_core::messaging::ActorId, journal: &Journal) {
+    assert_eq!(supervisor_id, 100);
+    assert!(journal.iter().count() > 0);
+    JOURNAL_DUMPS.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+}
+
+#[test]
+fn journal_records_events_and_decisions_and_is_dumped_on_completion() {
+    let mut group = ChildGroup::new(GroupShutdown::WhenAllDone)
+        .with_journal(32)
+        .with_journal_sink(count_journal_dump);
+    let (child_ref, _child_rx) = TestRuntime::channel::<LifecycleCommand>(1, 16);
+    group.add(1, child_ref, ChildPolicy::Stop);
+    let (notify_ref, _notify_rx) = TestRuntime::channel::<ChildLifecycleEvent>(100, 16);
+    let mut machine = StateMachine::<Spec>::new(SupervisorCtx::new(100, group, notify_ref));
+    machine.dispatch(SupervisorEvent::Lifecycle(LifecycleCommand::Start));
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Started { child_id: 1 });
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Done { child_id: 1 });
+
+    let (reply_ref, mut reply_rx) = TestRuntime::channel::<JournalReport>(200, 4);
+    dispatch_control_event(
+        &mut machine,
+        SupervisorControl::QueryJournal {
+            since: 1,
+            reply_to: reply_ref,
+        },
+    );
+    let reports = reply_rx.drain_payloads();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].supervisor_id, 100);
+    assert_eq!(reports[0].dropped, 0);
+    let records: Vec<_> = reports[0].entries.iter().map(|e| e.record).collect();
+    let decision = |decision, reason| JournalRecord::Decision {
+        child_id: 1,
+        decision,
+        reason,
+    };
+    assert_eq!(
+        records,
+        [
+            JournalRecord::Event(ChildLifecycleEvent::Done { child_id: 1 }),
+            decision(Decision::GiveUp, DecisionReason::Done),
+            decision(Decision::Shutdown, DecisionReason::Done),
+            decision(Decision::Stop, DecisionReason::GroupStopping),
+        ]
+    );
+
+    let dumps = JOURNAL_DUMPS.load(core::sync::atomic::Ordering::Relaxed);
+    dispatch_child_event(&mut machine, ChildLifecycleEvent::Stopped { child_id: 1 });
+    assert_eq!(
+        machine.current_state(),
+        MachineState::State(SupervisorState::ShutdownComplete)
+    );
+    assert_eq!(
+        JOURNAL_DUMPS.load(core::sync::atomic::Ordering::Relaxed),
+        dumps + 1
+    );
+}
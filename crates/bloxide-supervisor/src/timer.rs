@@ -0,0 +1,91 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! The supervisor's timer: delayed restarts, shutdown and start deadlines.
+//!
+//! `ChildGroup` parks a child whose policy has a `Backoff` in the
+//! `BackingOff` phase and queues the delay. The supervisor schedules each
+//! delay on the timer service as a `SupervisorControl::BackoffElapsed`
+//! addressed to its own control mailbox, and sends `Reset` when it arrives.
+//! Shutdown deadlines queued by `ChildGroup::stop_all` travel the same way
+//! as `SupervisorControl::ShutdownDeadline`, and start deadlines queued by
+//! `ChildGroup::start_ready` as `SupervisorControl::StartDeadline`.
+
+use bloxide_core::{
+    accessor::HasSelfId,
+    capability::BloxRuntime,
+    messaging::{ActorId, ActorRef},
+};
+use bloxide_timer::{set_timer, HasTimerRef, TimerCommand};
+
+use crate::SupervisorControl;
+
+/// The timer service and control mailbox a supervisor uses to delay
+/// restarts and time out shutdowns and starts. Installed with
+/// `SupervisorCtx::with_timer`.
+pub struct SupervisorTimer<R: BloxRuntime> {
+    self_id: ActorId,
+    timer_ref: ActorRef<TimerCommand, R>,
+    control_ref: ActorRef<SupervisorControl<R>, R>,
+}
+
+impl<R: BloxRuntime> SupervisorTimer<R> {
+    /// `control_ref` must be the supervisor's own control mailbox.
+    pub fn new(
+        self_id: ActorId,
+        timer_ref: ActorRef<TimerCommand, R>,
+        control_ref: ActorRef<SupervisorControl<R>, R>,
+    ) -> Self {
+        Self {
+            self_id,
+            timer_ref,
+            control_ref,
+        }
+    }
+
+    /// Deliver `BackoffElapsed { child_id, generation }` to the supervisor
+    /// after `delay_ms`.
+    pub(crate) fn schedule(&self, child_id: ActorId, generation: u32, delay_ms: u64) {
+        set_timer(
+            self,
+            delay_ms,
+            &self.control_ref,
+            SupervisorControl::BackoffElapsed {
+                child_id,
+                generation,
+            },
+        );
+    }
+
+    /// Deliver `ShutdownDeadline { child_id }` to the supervisor after
+    /// `timeout_ms`.
+    pub(crate) fn schedule_deadline(&self, child_id: ActorId, timeout_ms: u64) {
+        set_timer(
+            self,
+            timeout_ms,
+            &self.control_ref,
+            SupervisorControl::ShutdownDeadline { child_id },
+        );
+    }
+
+    /// Deliver `StartDeadline { child_id, epoch }` to the supervisor after
+    /// `timeout_ms`.
+    pub(crate) fn schedule_start_deadline(&self, child_id: ActorId, epoch: u32, timeout_ms: u64) {
+        set_timer(
+            self,
+            timeout_ms,
+            &self.control_ref,
+            SupervisorControl::StartDeadline { child_id, epoch },
+        );
+    }
+}
+
+impl<R: BloxRuntime> HasSelfId for SupervisorTimer<R> {
+    fn self_id(&self) -> ActorId {
+        self.self_id
+    }
+}
+
+impl<R: BloxRuntime> HasTimerRef<R> for SupervisorTimer<R> {
+    fn timer_ref(&self) -> &ActorRef<TimerCommand, R> {
+        &self.timer_ref
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! `TimerQueue` cost per operation as the number of pending timers grows.
+//!
+//! Run with `cargo bench -p bloxide-timer --bench timer_queue`. Each round
+//! sets `n` timers with scattered deadlines, cancels every other one, then
+//! drains the rest in deadline order. A sorted-`Vec` queue, the shape
+//! `TimerQueue` had before it moved to a heap, runs the same workload for
+//! comparison up to the sizes where it stays tolerable.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bloxide_timer::{next_timer_id, TimerId, TimerQueue};
+
+const ROUNDS: usize = 3;
+
+type Deliver = Box<dyn FnOnce() + Send>;
+
+/// Deadlines spread over `0..n * 4` ms in a scrambled order.
+fn deadlines(n: usize) -> Vec<u64> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    (0..n)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % (n as u64 * 4)
+        })
+        .collect()
+}
+
+trait Queue {
+    fn set(&mut self, id: TimerId, after_ms: u64, deliver: Deliver);
+    fn cancel(&mut self, id: TimerId);
+    fn drain(&mut self, now_ms: u64) -> Vec<Deliver>;
+}
+
+impl Queue for TimerQueue {
+    fn set(&mut self, id: TimerId, after_ms: u64, deliver: Deliver) {
+        TimerQueue::set(self, 0, id, after_ms, 0, deliver);
+    }
+    fn cancel(&mut self, id: TimerId) {
+        TimerQueue::cancel(self, id);
+    }
+    fn drain(&mut self, now_ms: u64) -> Vec<Deliver> {
+        self.drain_expired(now_ms)
+    }
+}
+
+#[derive(Default)]
+struct SortedVec(Vec<(TimerId, u64, Deliver)>);
+
+impl Queue for SortedVec {
+    fn set(&mut self, id: TimerId, after_ms: u64, deliver: Deliver) {
+        let pos = self
+            .0
+            .iter()
+            .position(|t| t.1 > after_ms)
+            .unwrap_or(self.0.len());
+        self.0.insert(pos, (id, after_ms, deliver));
+    }
+    fn cancel(&mut self, id: TimerId) {
+        if let Some(pos) = self.0.iter().position(|t| t.0 == id) {
+            drop(self.0.remove(pos));
+        }
+    }
+    fn drain(&mut self, now_ms: u64) -> Vec<Deliver> {
+        let split = self
+            .0
+            .iter()
+            .position(|t| t.1 > now_ms)
+            .unwrap_or(self.0.len());
+        self.0.drain(..split).map(|t| t.2).collect()
+    }
+}
+
+struct Timings {
+    set: Duration,
+    cancel: Duration,
+    drain: Duration,
+}
+
+fn round<Q: Queue>(queue: &mut Q, deadlines: &[u64]) -> Timings {
+    let ids: Vec<TimerId> = deadlines.iter().map(|_| next_timer_id()).collect();
+
+    let start = Instant::now();
+    for (id, after_ms) in ids.iter().zip(deadlines) {
+        queue.set(*id, *after_ms, Box::new(|| {}));
+    }
+    let set = start.elapsed();
+
+    let start = Instant::now();
+    for id in ids.iter().step_by(2) {
+        queue.cancel(*id);
+    }
+    let cancel = start.elapsed();
+
+    let start = Instant::now();
+    let horizon = deadlines.len() as u64 * 4;
+    let mut fired = 0;
+    for now_ms in (0..horizon + 64).step_by(64) {
+        for deliver in queue.drain(now_ms) {
+            deliver();
+            fired += 1;
+        }
+    }
+    let drain = start.elapsed();
+    assert_eq!(fired, deadlines.len() / 2);
+    black_box(fired);
+
+    Timings { set, cancel, drain }
+}
+
+fn bench<Q: Queue + Default>(name: &str, n: usize) {
+    let deadlines = deadlines(n);
+    let best = (0..ROUNDS)
+        .map(|_| round(&mut Q::default(), &deadlines))
+        .min_by_key(|t| t.set + t.cancel + t.drain)
+        .expect("at least one round");
+    let per_op = |d: Duration, ops: usize| d.as_nanos() as f64 / ops as f64;
+    println!(
+        "{name:<10} n={n:<7} set {:>8.1} ns/op   cancel {:>8.1} ns/op   drain {:>8.1} ns/timer",
+        per_op(best.set, n),
+        per_op(best.cancel, n / 2),
+        per_op(best.drain, n - n / 2),
+    );
+}
+
+fn main() {
+    for n in [1_000, 10_000, 100_000] {
+        bench::<TimerQueue>("heap", n);
+        if n <= 10_000 {
+            bench::<SortedVec>("sorted-vec", n);
+        }
+    }
+}
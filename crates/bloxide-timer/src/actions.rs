@@ -1,70 +1,131 @@
 // Copyright 2025 Bloxide, all rights reserved
-use bloxide_core::{accessor::HasSelfId, capability::BloxRuntime, messaging::ActorRef};
+use bloxide_core::{
+    accessor::HasSelfId,
+    capability::BloxRuntime,
+    engine::{DispatchOutcome, MachineState},
+    messaging::{ActorId, ActorRef},
+};
 
-use crate::command::{next_timer_id, TimerCommand, TimerId, TIMER_ACTOR_ID};
+#[cfg(feature = "alloc")]
+use crate::command::TimerCommand;
+use crate::command::{
+    next_timer_id, timer_id_watermark, MissedTicks, TimerId, TimerRequest, TimerSchedule,
+    TimerSchedulePeriodic,
+};
 
 /// Accessor trait for blox contexts that hold a timer service reference.
 ///
 /// Auto-detected from a `timer_ref: ActorRef<TimerCommand, R>` field
-/// in a `#[derive(BloxCtx)]` context struct.
-pub trait HasTimerRef<R: BloxRuntime> {
-    fn timer_ref(&self) -> &ActorRef<TimerCommand, R>;
+/// in a `#[derive(BloxCtx)]` context struct. Contexts using the
+/// allocation-free service bind `HasTimerRef<R, TypedTimerCommand<M, R>>`
+/// with `#[provides(...)]` instead.
+#[cfg(feature = "alloc")]
+pub trait HasTimerRef<R: BloxRuntime, T: TimerRequest = TimerCommand> {
+    fn timer_ref(&self) -> &ActorRef<T, R>;
+}
+
+/// Accessor trait for blox contexts that hold a timer service reference.
+///
+/// Without `alloc`, `T` is a `TypedTimerCommand<M, R>`.
+#[cfg(not(feature = "alloc"))]
+pub trait HasTimerRef<R: BloxRuntime, T: TimerRequest> {
+    fn timer_ref(&self) -> &ActorRef<T, R>;
 }
 
 /// Schedule `event` to be delivered to `target` after `after_ms` milliseconds.
 ///
 /// Returns the `TimerId` that can be passed to `cancel_timer` later.
 /// Logs a warning if the timer channel is full and the command was dropped.
-pub fn set_timer<R, C, M>(ctx: &C, after_ms: u64, target: &ActorRef<M, R>, event: M) -> TimerId
+pub fn set_timer<R, C, M, T>(ctx: &C, after_ms: u64, target: &ActorRef<M, R>, event: M) -> TimerId
 where
     R: BloxRuntime,
-    C: HasSelfId + HasTimerRef<R>,
+    C: HasSelfId + HasTimerRef<R, T>,
     M: Send + 'static,
+    T: TimerSchedule<M, R>,
 {
     let id = next_timer_id();
     let self_id = ctx.self_id();
-    let target = target.clone();
-    let deliver = alloc::boxed::Box::new(move || {
-        if target.try_send(TIMER_ACTOR_ID, event).is_err() {
-            bloxide_log::blox_log_warn!(
-                self_id,
-                "timer delivery: target mailbox full, timer event dropped"
-            );
-        }
-    });
+    let cmd = T::schedule(self_id, id, after_ms, target, event);
+    if ctx.timer_ref().try_send(self_id, cmd).is_err() {
+        bloxide_log::blox_log_warn!(
+            self_id,
+            "set_timer: timer channel full, timer {} dropped — it will never fire",
+            id.as_u64()
+        );
+    }
+    id
+}
+
+/// Deliver a clone of `event` to `target` every `period_ms` milliseconds
+/// until the returned `TimerId` is cancelled.
+///
+/// The timer service re-arms the timer itself, so a heartbeat keeps running
+/// even if a handler is slow or a message is dropped. See [`MissedTicks`] for
+/// what happens when the service falls behind.
+pub fn set_periodic_timer<R, C, M, T>(
+    ctx: &C,
+    period_ms: u64,
+    missed: MissedTicks,
+    target: &ActorRef<M, R>,
+    event: M,
+) -> TimerId
+where
+    R: BloxRuntime,
+    C: HasSelfId + HasTimerRef<R, T>,
+    M: Send + 'static,
+    T: TimerSchedulePeriodic<M, R>,
+{
+    let id = next_timer_id();
+    let self_id = ctx.self_id();
+    let cmd = T::schedule_periodic(self_id, id, period_ms, missed, target, event);
+    if ctx.timer_ref().try_send(self_id, cmd).is_err() {
+        bloxide_log::blox_log_warn!(
+            self_id,
+            "set_periodic_timer: timer channel full, timer {} dropped — it will never fire",
+            id.as_u64()
+        );
+    }
+    id
+}
+
+/// Move a pending timer so it next fires `after_ms` from now, e.g. to push
+/// back an inactivity timeout. Has no effect if the timer already fired or
+/// was cancelled.
+///
+/// Logs a warning if the timer channel is full and the command was dropped
+/// (the timer keeps its old deadline).
+pub fn reschedule_timer<R, C, T>(ctx: &C, id: TimerId, after_ms: u64)
+where
+    R: BloxRuntime,
+    C: HasSelfId + HasTimerRef<R, T>,
+    T: TimerRequest,
+{
     if ctx
         .timer_ref()
-        .try_send(
-            ctx.self_id(),
-            TimerCommand::Set {
-                id,
-                after_ms,
-                deliver,
-            },
-        )
+        .try_send(ctx.self_id(), T::reschedule(id, after_ms))
         .is_err()
     {
         bloxide_log::blox_log_warn!(
             ctx.self_id(),
-            "set_timer: timer channel full, timer {} dropped — it will never fire",
+            "reschedule_timer: timer channel full, timer {} keeps its old deadline",
             id.as_u64()
         );
     }
-    id
 }
 
 /// Cancel a previously scheduled timer.
 ///
 /// Logs a warning if the timer channel is full and the cancel command was dropped
 /// (the timer may still fire).
-pub fn cancel_timer<R, C>(ctx: &C, id: TimerId)
+pub fn cancel_timer<R, C, T>(ctx: &C, id: TimerId)
 where
     R: BloxRuntime,
-    C: HasSelfId + HasTimerRef<R>,
+    C: HasSelfId + HasTimerRef<R, T>,
+    T: TimerRequest,
 {
     if ctx
         .timer_ref()
-        .try_send(ctx.self_id(), TimerCommand::Cancel { id })
+        .try_send(ctx.self_id(), T::cancel(id))
         .is_err()
     {
         bloxide_log::blox_log_warn!(
@@ -74,3 +135,61 @@ where
         );
     }
 }
+
+/// Cancel every timer `owner` set before `before` on the service behind
+/// `timer_ref`.
+///
+/// Supervised run loops call this through [`TimerMark`]; blox code cancels
+/// its own timers with [`cancel_timer`].
+pub fn cancel_timers_for<R, T>(timer_ref: &ActorRef<T, R>, owner: ActorId, before: TimerId)
+where
+    R: BloxRuntime,
+    T: TimerRequest,
+{
+    if timer_ref
+        .try_send(owner, T::cancel_all_for(owner, before))
+        .is_err()
+    {
+        bloxide_log::blox_log_warn!(
+            owner,
+            "cancel_timers_for: timer channel full, stale timers may still fire"
+        );
+    }
+}
+
+/// Snapshot a supervised run loop takes before dispatching an event, used to
+/// decide afterwards whether the actor's earlier timers must be discarded.
+///
+/// Timers belong to one incarnation of an actor. A `Stopped` or `Aborted`
+/// outcome ends it, and so does `Started` when the machine was not in `Init`
+/// beforehand (a reset). Timers armed while handling the event itself, such
+/// as by the new initial state's `on_entry`, have ids at or above the
+/// snapshot's watermark and are kept.
+#[derive(Clone, Copy, Debug)]
+pub struct TimerMark {
+    before: TimerId,
+    was_init: bool,
+}
+
+impl TimerMark {
+    pub fn new<S>(state: MachineState<S>) -> Self {
+        Self {
+            before: timer_id_watermark(),
+            was_init: state.is_init(),
+        }
+    }
+
+    /// Returns `true` if `outcome` ends the actor's current incarnation.
+    pub fn discards<S>(&self, outcome: &DispatchOutcome<S>) -> bool {
+        match outcome {
+            DispatchOutcome::Stopped | DispatchOutcome::Aborted => true,
+            DispatchOutcome::Started(_) => !self.was_init,
+            _ => false,
+        }
+    }
+
+    /// Timers with ids below this were set before the snapshot.
+    pub fn before(&self) -> TimerId {
+        self.before
+    }
+}
@@ -4,14 +4,18 @@ use core::cell::Cell;
 #[cfg(target_has_atomic = "ptr")]
 use core::sync::atomic::{AtomicUsize, Ordering};
 
-use bloxide_core::messaging::ActorId;
+use bloxide_core::capability::BloxRuntime;
+use bloxide_core::messaging::{ActorId, ActorRef};
 #[cfg(not(target_has_atomic = "ptr"))]
 use critical_section::Mutex;
 
+#[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
 
 /// Unique identifier for a pending timer, returned by `set_timer`.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct TimerId(usize);
 
 impl TimerId {
@@ -22,6 +26,7 @@ impl TimerId {
 }
 
 /// Messages sent to the timer service actor.
+#[cfg(feature = "alloc")]
 pub enum TimerCommand {
     /// Schedule a callback after `after_ms` milliseconds.
     Set {
@@ -29,13 +34,157 @@ pub enum TimerCommand {
         after_ms: u64,
         deliver: Box<dyn FnOnce() + Send>,
     },
+    /// Call `deliver` every `period_ms` milliseconds, first at
+    /// `now + period_ms`, until cancelled.
+    ///
+    /// Ticks are due at fixed multiples of the period from the first
+    /// deadline, so late service wake-ups do not accumulate drift. `missed`
+    /// decides what happens when more than one tick is overdue.
+    SetPeriodic {
+        id: TimerId,
+        period_ms: u64,
+        missed: MissedTicks,
+        deliver: Arc<dyn Fn() + Send + Sync>,
+    },
+    /// Move a pending timer so it next fires `after_ms` from now. A periodic
+    /// timer keeps its period from the new deadline. Unknown ids are ignored.
+    Reschedule { id: TimerId, after_ms: u64 },
     /// Cancel a previously scheduled timer.
     Cancel { id: TimerId },
+    /// Cancel every pending timer set by `owner` whose id is below `before`
+    /// (see [`timer_id_watermark`]). Supervised run loops send this when an
+    /// actor stops, resets or aborts.
+    CancelAllFor { owner: ActorId, before: TimerId },
     /// Shut down the timer service. All pending timers are drained (expired
     /// ones fire their callbacks) and the service loop exits.
     Shutdown,
 }
 
+/// What a periodic timer does when the service falls more than one period
+/// behind.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MissedTicks {
+    /// Fire once for the overdue ticks and resume at the next tick that is
+    /// still in the future.
+    #[default]
+    Skip,
+    /// Fire once per overdue tick, back to back, to catch up.
+    Burst,
+}
+
+/// A command type a timer service accepts.
+///
+/// Implemented by `TimerCommand` (boxed callbacks, needs `alloc`) and by
+/// `TypedTimerCommand` (feature `typed`, no allocation). `set_timer` and
+/// `cancel_timer` build their messages through these traits, so the same
+/// actions work with either service.
+pub trait TimerRequest: Send + 'static {
+    fn cancel(id: TimerId) -> Self;
+
+    fn reschedule(id: TimerId, after_ms: u64) -> Self;
+
+    fn cancel_all_for(owner: ActorId, before: TimerId) -> Self;
+}
+
+/// A [`TimerRequest`] that can schedule delivery of `M` to an `ActorRef<M, R>`.
+pub trait TimerSchedule<M: Send + 'static, R: BloxRuntime>: TimerRequest {
+    /// Build the command that delivers `event` to `target` after `after_ms`.
+    /// `owner` is the actor setting the timer; `cancel_all_for` matches it.
+    fn schedule(
+        owner: ActorId,
+        id: TimerId,
+        after_ms: u64,
+        target: &ActorRef<M, R>,
+        event: M,
+    ) -> Self;
+}
+
+/// A [`TimerSchedule`] that can also deliver a copy of `M` periodically.
+pub trait TimerSchedulePeriodic<M: Send + 'static, R: BloxRuntime>: TimerSchedule<M, R> {
+    /// Build the command that delivers a clone of `event` to `target`
+    /// every `period_ms`.
+    fn schedule_periodic(
+        owner: ActorId,
+        id: TimerId,
+        period_ms: u64,
+        missed: MissedTicks,
+        target: &ActorRef<M, R>,
+        event: M,
+    ) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl TimerRequest for TimerCommand {
+    fn cancel(id: TimerId) -> Self {
+        TimerCommand::Cancel { id }
+    }
+
+    fn reschedule(id: TimerId, after_ms: u64) -> Self {
+        TimerCommand::Reschedule { id, after_ms }
+    }
+
+    fn cancel_all_for(owner: ActorId, before: TimerId) -> Self {
+        TimerCommand::CancelAllFor { owner, before }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: Send + 'static, R: BloxRuntime> TimerSchedule<M, R> for TimerCommand {
+    fn schedule(
+        owner: ActorId,
+        id: TimerId,
+        after_ms: u64,
+        target: &ActorRef<M, R>,
+        event: M,
+    ) -> Self {
+        let target = target.clone();
+        TimerCommand::Set {
+            id,
+            after_ms,
+            deliver: Box::new(move || {
+                if target.try_send(TIMER_ACTOR_ID, event).is_err() {
+                    bloxide_log::blox_log_warn!(
+                        owner,
+                        "timer delivery: target mailbox full, timer event dropped"
+                    );
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, R> TimerSchedulePeriodic<M, R> for TimerCommand
+where
+    M: Clone + Send + Sync + 'static,
+    R: BloxRuntime,
+{
+    fn schedule_periodic(
+        owner: ActorId,
+        id: TimerId,
+        period_ms: u64,
+        missed: MissedTicks,
+        target: &ActorRef<M, R>,
+        event: M,
+    ) -> Self {
+        let target = target.clone();
+        TimerCommand::SetPeriodic {
+            id,
+            period_ms,
+            missed,
+            deliver: Arc::new(move || {
+                if target.try_send(TIMER_ACTOR_ID, event.clone()).is_err() {
+                    bloxide_log::blox_log_warn!(
+                        owner,
+                        "periodic timer {}: target mailbox full, tick dropped",
+                        id.as_u64()
+                    );
+                }
+            }),
+        }
+    }
+}
+
 /// Sentinel sender ID stamped into the sender field of `Envelope` when a timer
 /// callback fires.
 /// Uses `0` because the `next_actor_id!()` counter starts at 1, so `0` is
@@ -66,3 +215,18 @@ pub fn next_timer_id() -> TimerId {
         })
     }
 }
+
+/// The `TimerId` the next [`next_timer_id`] call will return. Every timer
+/// set before this call has a smaller id, every timer set after it a larger
+/// one, which lets `CancelAllFor` spare timers armed after a restart.
+pub fn timer_id_watermark() -> TimerId {
+    #[cfg(target_has_atomic = "ptr")]
+    {
+        TimerId(NEXT_TIMER_ID.load(Ordering::Relaxed))
+    }
+
+    #[cfg(not(target_has_atomic = "ptr"))]
+    {
+        critical_section::with(|cs| TimerId(NEXT_TIMER_ID.borrow(cs).get()))
+    }
+}
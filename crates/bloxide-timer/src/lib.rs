@@ -1,6 +1,13 @@
 // Copyright 2025 Bloxide, all rights reserved
 #![no_std]
+//! Timer service for bloxide.
+//!
+//! With `alloc` (the default), timers are boxed callbacks in a
+//! `TimerQueue` served by a runtime's `TimerService`. The `typed` feature
+//! adds an allocation-free service whose fixed-capacity queue stores the
+//! target `ActorRef` and event directly.
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
@@ -8,14 +15,31 @@ extern crate std;
 pub mod actions;
 pub mod command;
 pub mod prelude;
+#[cfg(feature = "alloc")]
 pub mod queue;
 pub mod service;
 #[cfg(feature = "std")]
 pub mod test_utils;
+#[cfg(feature = "typed")]
+pub mod typed;
 
-pub use actions::{cancel_timer, set_timer, HasTimerRef};
-pub use command::{next_timer_id, TimerCommand, TimerId, TIMER_ACTOR_ID};
+pub use actions::{
+    cancel_timer, cancel_timers_for, reschedule_timer, set_periodic_timer, set_timer, HasTimerRef,
+    TimerMark,
+};
+#[cfg(feature = "alloc")]
+pub use command::TimerCommand;
+pub use command::{
+    next_timer_id, timer_id_watermark, MissedTicks, TimerId, TimerRequest, TimerSchedule,
+    TimerSchedulePeriodic, TIMER_ACTOR_ID,
+};
+#[cfg(feature = "alloc")]
 pub use queue::TimerQueue;
+#[cfg(feature = "alloc")]
 pub use service::TimerService;
+#[cfg(feature = "typed")]
+pub use service::TypedTimerService;
 #[cfg(feature = "std")]
 pub use test_utils::VirtualClock;
+#[cfg(feature = "typed")]
+pub use typed::{TimerQueueFull, TypedTimerCommand, TypedTimerQueue};
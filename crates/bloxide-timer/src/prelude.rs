@@ -3,7 +3,21 @@
 //!
 //! Import with `use bloxide_timer::prelude::*;` for quick access to commonly used types.
 
-pub use crate::actions::{cancel_timer, set_timer, HasTimerRef};
-pub use crate::command::{next_timer_id, TimerCommand, TimerId, TIMER_ACTOR_ID};
+pub use crate::actions::{
+    cancel_timer, cancel_timers_for, reschedule_timer, set_periodic_timer, set_timer, HasTimerRef,
+    TimerMark,
+};
+#[cfg(feature = "alloc")]
+pub use crate::command::TimerCommand;
+pub use crate::command::{
+    next_timer_id, timer_id_watermark, MissedTicks, TimerId, TimerRequest, TimerSchedule,
+    TimerSchedulePeriodic, TIMER_ACTOR_ID,
+};
+#[cfg(feature = "alloc")]
 pub use crate::queue::TimerQueue;
+#[cfg(feature = "alloc")]
 pub use crate::service::TimerService;
+#[cfg(feature = "typed")]
+pub use crate::service::TypedTimerService;
+#[cfg(feature = "typed")]
+pub use crate::typed::{TypedTimerCommand, TypedTimerQueue};
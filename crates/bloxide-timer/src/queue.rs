@@ -1,103 +1,287 @@
 // Copyright 2025 Bloxide, all rights reserved
 use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Reverse;
 
-use crate::command::{TimerCommand, TimerId};
+use bloxide_core::messaging::ActorId;
+
+use crate::command::{MissedTicks, TimerCommand, TimerId};
+
+type Deliver = Box<dyn FnOnce() + Send>;
+
+enum Action {
+    Once(Deliver),
+    Periodic {
+        period_ms: u64,
+        missed: MissedTicks,
+        deliver: Arc<dyn Fn() + Send + Sync>,
+    },
+}
 
 struct PendingTimer {
-    id: TimerId,
+    seq: u64,
+    owner: ActorId,
     deadline_ms: u64,
-    deliver: Box<dyn FnOnce() + Send>,
+    action: Action,
 }
 
-/// A sorted queue of pending timers. Shared across all runtimes.
+/// Heap key: earliest deadline first, then the order timers were set in.
+type Slot = Reverse<(u64, u64, TimerId)>;
+
+/// A queue of pending timers ordered by deadline. Shared across all runtimes.
 ///
 /// Each runtime's timer service (`TimerService` impl) owns a `TimerQueue`
 /// and drives it using the runtime's native timer primitive.
+///
+/// `set`, `cancel` and each expired timer cost O(log n). Cancelled timers
+/// leave a stale heap slot that is skipped when it reaches the top; the heap
+/// is rebuilt once stale slots outnumber live timers, so memory stays
+/// proportional to the number of pending timers.
 pub struct TimerQueue {
-    timers: Vec<PendingTimer>,
+    heap: BinaryHeap<Slot>,
+    live: BTreeMap<TimerId, PendingTimer>,
+    next_seq: u64,
 }
 
 impl TimerQueue {
     pub fn new() -> Self {
-        Self { timers: Vec::new() }
+        Self {
+            heap: BinaryHeap::new(),
+            live: BTreeMap::new(),
+            next_seq: 0,
+        }
     }
 
     /// Insert a new timer that expires at `now_ms + after_ms`.
+    ///
+    /// Timers with equal deadlines fire in the order they were set. Setting
+    /// an `id` that is already pending replaces the earlier timer.
     pub fn set(
         &mut self,
+        owner: ActorId,
         id: TimerId,
         after_ms: u64,
         now_ms: u64,
-        deliver: Box<dyn FnOnce() + Send>,
+        deliver: Deliver,
     ) {
         let deadline_ms = now_ms.saturating_add(after_ms);
-        let pos = self
-            .timers
-            .iter()
-            .position(|t| t.deadline_ms > deadline_ms)
-            .unwrap_or(self.timers.len());
-        self.timers.insert(
-            pos,
-            PendingTimer {
-                id,
-                deadline_ms,
-                deliver,
-            },
-        );
+        self.insert(owner, id, deadline_ms, Action::Once(deliver));
+    }
+
+    /// Insert a timer that calls `deliver` every `period_ms`, first at
+    /// `now_ms + period_ms`. A zero period is treated as 1 ms.
+    pub fn set_periodic(
+        &mut self,
+        owner: ActorId,
+        id: TimerId,
+        period_ms: u64,
+        missed: MissedTicks,
+        now_ms: u64,
+        deliver: Arc<dyn Fn() + Send + Sync>,
+    ) {
+        let period_ms = period_ms.max(1);
+        let action = Action::Periodic {
+            period_ms,
+            missed,
+            deliver,
+        };
+        self.insert(owner, id, now_ms.saturating_add(period_ms), action);
+    }
+
+    /// Move a pending timer so it next fires at `now_ms + after_ms`.
+    /// Returns `false` if `id` is not pending.
+    pub fn reschedule(&mut self, id: TimerId, after_ms: u64, now_ms: u64) -> bool {
+        let seq = self.next_seq;
+        let Some(timer) = self.live.get_mut(&id) else {
+            return false;
+        };
+        self.next_seq += 1;
+        timer.seq = seq;
+        timer.deadline_ms = now_ms.saturating_add(after_ms);
+        self.heap.push(Reverse((timer.deadline_ms, seq, id)));
+        self.prune();
+        true
     }
 
     /// Cancel a pending timer. Returns `true` if it was found and removed.
     pub fn cancel(&mut self, id: TimerId) -> bool {
-        if let Some(pos) = self.timers.iter().position(|t| t.id == id) {
-            self.timers.remove(pos);
-            true
-        } else {
-            false
+        let found = self.live.remove(&id).is_some();
+        if found {
+            self.prune();
+        }
+        found
+    }
+
+    /// Cancel `owner`'s timers with ids below `before` and return how many
+    /// were removed.
+    pub fn cancel_all_for(&mut self, owner: ActorId, before: TimerId) -> usize {
+        let len = self.live.len();
+        self.live.retain(|id, t| t.owner != owner || *id >= before);
+        let removed = len - self.live.len();
+        if removed > 0 {
+            self.prune();
         }
+        removed
+    }
+
+    /// Ids of the pending timers set by `owner`, oldest first.
+    pub fn owned_by(&self, owner: ActorId) -> impl Iterator<Item = TimerId> + '_ {
+        self.live
+            .iter()
+            .filter(move |(_, t)| t.owner == owner)
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns `true` if `id` is still pending.
+    pub fn contains(&self, id: TimerId) -> bool {
+        self.live.contains_key(&id)
+    }
+
+    /// Number of pending timers.
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    /// Number of pending periodic timers. They stay pending until cancelled.
+    pub fn periodic_len(&self) -> usize {
+        self.live
+            .values()
+            .filter(|t| matches!(t.action, Action::Periodic { .. }))
+            .count()
     }
 
     /// Returns the deadline (in ms) of the earliest pending timer, if any.
     pub fn next_deadline(&self) -> Option<u64> {
-        self.timers.first().map(|t| t.deadline_ms)
+        self.heap
+            .peek()
+            .map(|Reverse((deadline_ms, _, _))| *deadline_ms)
     }
 
     /// Returns `true` if there are no pending timers.
     pub fn is_empty(&self) -> bool {
-        self.timers.is_empty()
+        self.live.is_empty()
     }
 
     /// Remove and return all timers whose deadline has passed.
     ///
-    /// Callbacks are returned in deadline order (earliest first).
-    pub fn drain_expired(&mut self, now_ms: u64) -> Vec<Box<dyn FnOnce() + Send>> {
-        let split = self
-            .timers
-            .iter()
-            .position(|t| t.deadline_ms > now_ms)
-            .unwrap_or(self.timers.len());
-        self.timers.drain(..split).map(|t| t.deliver).collect()
+    /// Callbacks are returned in deadline order (earliest first). A periodic
+    /// timer contributes one callback per tick that fires and stays queued
+    /// with its next deadline.
+    pub fn drain_expired(&mut self, now_ms: u64) -> Vec<Deliver> {
+        let mut ready = Vec::new();
+        while let Some(Reverse((deadline_ms, seq, id))) = self.heap.peek().copied() {
+            if deadline_ms > now_ms {
+                break;
+            }
+            self.heap.pop();
+            let timer = match self.live.get_mut(&id) {
+                Some(timer) if timer.seq == seq => timer,
+                _ => continue,
+            };
+            let Action::Periodic {
+                period_ms,
+                missed,
+                deliver,
+            } = &timer.action
+            else {
+                if let Some(PendingTimer {
+                    action: Action::Once(deliver),
+                    ..
+                }) = self.live.remove(&id)
+                {
+                    ready.push(deliver);
+                }
+                continue;
+            };
+            let (period_ms, missed, deliver) = (*period_ms, *missed, Arc::clone(deliver));
+            ready.push(Box::new(move || deliver()));
+            let ticks = match missed {
+                MissedTicks::Burst => 1,
+                MissedTicks::Skip => (now_ms - deadline_ms) / period_ms + 1,
+            };
+            timer.deadline_ms = deadline_ms.saturating_add(ticks.saturating_mul(period_ms));
+            timer.seq = self.next_seq;
+            self.next_seq += 1;
+            self.heap.push(Reverse((timer.deadline_ms, timer.seq, id)));
+        }
+        self.prune();
+        ready
     }
 
-    /// Process a `TimerCommand`, dispatching to `set`, `cancel`, or signalling
-    /// shutdown. Returns `true` when the caller should exit its service loop.
-    pub fn handle_command(&mut self, cmd: TimerCommand, now_ms: u64) -> bool {
+    /// Process a `TimerCommand` sent by `owner`, dispatching to `set`,
+    /// `cancel`, etc., or signalling shutdown. Returns `true` when the caller
+    /// should exit its service loop.
+    pub fn handle_command(&mut self, owner: ActorId, cmd: TimerCommand, now_ms: u64) -> bool {
         match cmd {
             TimerCommand::Set {
                 id,
                 after_ms,
                 deliver,
             } => {
-                self.set(id, after_ms, now_ms, deliver);
+                self.set(owner, id, after_ms, now_ms, deliver);
+                false
+            }
+            TimerCommand::SetPeriodic {
+                id,
+                period_ms,
+                missed,
+                deliver,
+            } => {
+                self.set_periodic(owner, id, period_ms, missed, now_ms, deliver);
+                false
+            }
+            TimerCommand::Reschedule { id, after_ms } => {
+                self.reschedule(id, after_ms, now_ms);
                 false
             }
             TimerCommand::Cancel { id } => {
                 self.cancel(id);
                 false
             }
+            TimerCommand::CancelAllFor { owner, before } => {
+                self.cancel_all_for(owner, before);
+                false
+            }
             TimerCommand::Shutdown => true,
         }
     }
+
+    fn insert(&mut self, owner: ActorId, id: TimerId, deadline_ms: u64, action: Action) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.heap.push(Reverse((deadline_ms, seq, id)));
+        self.live.insert(
+            id,
+            PendingTimer {
+                seq,
+                owner,
+                deadline_ms,
+                action,
+            },
+        );
+        self.prune();
+    }
+
+    fn is_live(&self, Reverse((_, seq, id)): &Slot) -> bool {
+        self.live.get(id).is_some_and(|t| t.seq == *seq)
+    }
+
+    /// Keep a live timer (or nothing) at the top of the heap, and drop stale
+    /// slots wholesale once they make up more than half of it.
+    fn prune(&mut self) {
+        if self.heap.len() > 2 * self.live.len() + 16 {
+            let heap = core::mem::take(&mut self.heap);
+            self.heap = heap.into_iter().filter(|slot| self.is_live(slot)).collect();
+        }
+        while let Some(top) = self.heap.peek() {
+            if self.is_live(top) {
+                break;
+            }
+            self.heap.pop();
+        }
+    }
 }
 
 impl Default for TimerQueue {
@@ -105,3 +289,198 @@ impl Default for TimerQueue {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::next_timer_id;
+    use std::sync::Mutex;
+    use std::vec;
+
+    type Log = Arc<Mutex<Vec<u64>>>;
+
+    fn record(log: &Log, tag: u64) -> Deliver {
+        let log = Arc::clone(log);
+        Box::new(move || log.lock().unwrap().push(tag))
+    }
+
+    fn take(log: &Log) -> Vec<u64> {
+        core::mem::take(&mut *log.lock().unwrap())
+    }
+
+    fn fire(queue: &mut TimerQueue, now_ms: u64) -> usize {
+        let ready = queue.drain_expired(now_ms);
+        let count = ready.len();
+        ready.into_iter().for_each(|deliver| deliver());
+        count
+    }
+
+    #[test]
+    fn expired_timers_fire_in_deadline_then_insertion_order() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        for (after_ms, tag) in [(30, 1), (10, 2), (20, 3), (10, 4), (30, 5)] {
+            queue.set(1, next_timer_id(), after_ms, 0, record(&log, tag));
+        }
+
+        assert_eq!(queue.next_deadline(), Some(10));
+        assert_eq!(fire(&mut queue, 20), 3);
+        assert_eq!(take(&log), vec![2, 4, 3]);
+        assert_eq!(fire(&mut queue, 29), 0);
+        assert_eq!(fire(&mut queue, 30), 2);
+        assert_eq!(take(&log), vec![1, 5]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn cancelled_timers_never_fire_and_do_not_hold_next_deadline() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        let early = next_timer_id();
+        queue.set(1, early, 5, 0, record(&log, 1));
+        queue.set(1, next_timer_id(), 50, 0, record(&log, 2));
+
+        assert!(queue.cancel(early));
+        assert!(!queue.cancel(early));
+        assert_eq!(queue.next_deadline(), Some(50));
+        assert_eq!(queue.len(), 1);
+
+        assert_eq!(fire(&mut queue, 100), 1);
+        assert_eq!(take(&log), vec![2]);
+    }
+
+    #[test]
+    fn resetting_an_id_replaces_the_earlier_timer() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        let id = next_timer_id();
+        queue.set(1, id, 5, 0, record(&log, 1));
+        queue.set(1, id, 15, 0, record(&log, 2));
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(fire(&mut queue, 10), 0);
+        assert_eq!(fire(&mut queue, 15), 1);
+        assert_eq!(take(&log), vec![2]);
+    }
+
+    #[test]
+    fn stale_slots_are_compacted() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        let ids: Vec<_> = (0..1000).map(|_| next_timer_id()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            queue.set(1, *id, 1000 - i as u64, 0, record(&log, 0));
+        }
+        for id in &ids[1..] {
+            queue.cancel(*id);
+        }
+        assert_eq!(queue.len(), 1);
+        assert!(queue.heap.len() <= 2 * queue.len() + 16);
+        assert_eq!(queue.next_deadline(), Some(1000));
+    }
+
+    fn tick(log: &Log, tag: u64) -> Arc<dyn Fn() + Send + Sync> {
+        let log = Arc::clone(log);
+        Arc::new(move || log.lock().unwrap().push(tag))
+    }
+
+    #[test]
+    fn periodic_ticks_stay_on_the_original_grid() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        queue.set_periodic(1, next_timer_id(), 10, MissedTicks::Skip, 3, tick(&log, 1));
+
+        assert_eq!(queue.next_deadline(), Some(13));
+        assert_eq!(fire(&mut queue, 15), 1);
+        assert_eq!(
+            queue.next_deadline(),
+            Some(23),
+            "late wake-up does not shift the grid"
+        );
+        assert_eq!(fire(&mut queue, 23), 1);
+        assert_eq!(queue.next_deadline(), Some(33));
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.periodic_len(), 1);
+    }
+
+    #[test]
+    fn missed_ticks_skip_or_burst() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        queue.set_periodic(1, next_timer_id(), 10, MissedTicks::Skip, 0, tick(&log, 1));
+        queue.set_periodic(1, next_timer_id(), 10, MissedTicks::Burst, 0, tick(&log, 2));
+
+        assert_eq!(fire(&mut queue, 35), 4);
+        let mut fired = take(&log);
+        fired.sort_unstable();
+        assert_eq!(fired, vec![1, 2, 2, 2]);
+        assert_eq!(queue.next_deadline(), Some(40));
+        assert_eq!(fire(&mut queue, 40), 2);
+    }
+
+    #[test]
+    fn periodic_and_one_shot_interleave_by_deadline() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        queue.set_periodic(1, next_timer_id(), 10, MissedTicks::Burst, 0, tick(&log, 1));
+        queue.set(1, next_timer_id(), 15, 0, record(&log, 2));
+
+        assert_eq!(fire(&mut queue, 30), 4);
+        assert_eq!(take(&log), vec![1, 2, 1, 1]);
+    }
+
+    #[test]
+    fn cancel_stops_a_periodic_timer() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        let id = next_timer_id();
+        queue.set_periodic(1, id, 10, MissedTicks::Skip, 0, tick(&log, 1));
+        assert_eq!(fire(&mut queue, 10), 1);
+        assert!(queue.cancel(id));
+        assert_eq!(fire(&mut queue, 100), 0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn reschedule_moves_one_shot_and_periodic_timers() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        let once = next_timer_id();
+        let every = next_timer_id();
+        queue.set(1, once, 10, 0, record(&log, 1));
+        queue.set_periodic(1, every, 10, MissedTicks::Skip, 0, tick(&log, 2));
+
+        assert!(queue.reschedule(once, 30, 5));
+        assert!(queue.reschedule(every, 2, 5));
+        assert!(!queue.reschedule(next_timer_id(), 1, 5));
+
+        assert_eq!(fire(&mut queue, 10), 1);
+        assert_eq!(take(&log), vec![2]);
+        assert_eq!(
+            queue.next_deadline(),
+            Some(17),
+            "period resumes from the new deadline"
+        );
+        assert_eq!(fire(&mut queue, 35), 2);
+        assert_eq!(take(&log), vec![2, 1]);
+    }
+
+    #[test]
+    fn cancel_all_for_keeps_other_owners_and_timers_past_the_watermark() {
+        let log = Log::default();
+        let mut queue = TimerQueue::new();
+        queue.set(1, next_timer_id(), 10, 0, record(&log, 1));
+        queue.set_periodic(1, next_timer_id(), 10, MissedTicks::Skip, 0, tick(&log, 2));
+        queue.set(2, next_timer_id(), 10, 0, record(&log, 3));
+        let before = crate::timer_id_watermark();
+        let rearmed = next_timer_id();
+        queue.set(1, rearmed, 10, 0, record(&log, 4));
+
+        assert_eq!(queue.cancel_all_for(1, before), 2);
+        assert_eq!(queue.owned_by(1).collect::<Vec<_>>(), vec![rearmed]);
+        assert_eq!(queue.periodic_len(), 0);
+        assert_eq!(fire(&mut queue, 10), 2);
+        assert_eq!(take(&log), vec![3, 4]);
+    }
+}
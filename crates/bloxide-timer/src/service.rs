@@ -1,5 +1,8 @@
 // Copyright 2025 Bloxide, all rights reserved
+#[cfg(feature = "alloc")]
 use crate::command::TimerCommand;
+#[cfg(feature = "typed")]
+use crate::typed::TypedTimerCommand;
 
 /// Trait that runtimes implement to provide a timer service.
 ///
@@ -12,6 +15,7 @@ use crate::command::TimerCommand;
 /// timer service implementation.
 ///
 /// [`TimerQueue`]: crate::TimerQueue
+#[cfg(feature = "alloc")]
 #[allow(async_fn_in_trait)]
 pub trait TimerService: bloxide_core::capability::BloxRuntime {
     /// Run the timer service loop forever.
@@ -21,3 +25,19 @@ pub trait TimerService: bloxide_core::capability::BloxRuntime {
     /// using the runtime's native timer.
     async fn run_timer_service(stream: Self::Stream<TimerCommand>);
 }
+
+/// Allocation-free counterpart of `TimerService` for
+/// [`TypedTimerCommand`]s, holding at most `N` pending timers.
+///
+/// Runtimes usually implement this by calling
+/// [`run_typed_timer_service`](crate::typed::run_typed_timer_service) with
+/// their native clock and sleep.
+#[cfg(feature = "typed")]
+#[allow(async_fn_in_trait)]
+pub trait TypedTimerService: bloxide_core::capability::BloxRuntime {
+    /// Run the typed timer service loop until `Shutdown` or until every
+    /// sender is dropped.
+    async fn run_typed_timer_service<M: Send + 'static, const N: usize>(
+        stream: Self::Stream<TypedTimerCommand<M, Self>>,
+    );
+}
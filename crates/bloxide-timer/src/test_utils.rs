@@ -1,54 +1,257 @@
 // Copyright 2025 Bloxide, all rights reserved
-use crate::{TimerCommand, TimerQueue};
-use bloxide_test_runtime::TestReceiver;
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::vec::Vec;
+
+use bloxide_core::messaging::{ActorId, Envelope};
+use bloxide_test_runtime::{TestReceiver, TestRuntime};
+use futures_core::Stream;
+
+use crate::{TimerCommand, TimerId, TimerQueue, TimerService};
 
 /// Deterministic timer harness for `TestRuntime`-based tests.
 ///
-/// `VirtualClock` owns the timer command receiver, drains pending
-/// `TimerCommand`s into a `TimerQueue`, and fires ready callbacks when time is
-/// advanced. This keeps timer simulation out of `bloxide-core` while still
-/// providing a reusable helper for blox tests.
+/// `VirtualClock` drains pending `TimerCommand`s from one or more timer
+/// mailboxes into a `TimerQueue` and fires ready callbacks only when time is
+/// advanced, so timeouts are tested instantly and in a fixed order. It
+/// remembers which actor set each timer (the envelope sender), so tests can
+/// assert on the timers an actor still has pending.
+///
+/// Handles are cheap clones of the same clock. [`VirtualClock::current`] is
+/// the per-thread clock that `TestRuntime`'s [`TimerService`] attaches to.
+#[derive(Clone, Default)]
 pub struct VirtualClock {
-    timer_rx: TestReceiver<TimerCommand>,
-    queue: TimerQueue,
+    state: Arc<Mutex<ClockState>>,
+}
+
+#[derive(Default)]
+struct ClockState {
     now_ms: u64,
+    queue: TimerQueue,
+    sources: Vec<Source>,
+}
+
+/// One attached timer mailbox. A `Shutdown` on it ends only the service
+/// that reads it; other sources keep working.
+struct Source {
+    rx: TestReceiver<TimerCommand>,
+    shut_down: bool,
+    service_waker: Option<Waker>,
+}
+
+std::thread_local! {
+    static CURRENT: RefCell<Option<VirtualClock>> = const { RefCell::new(None) };
 }
 
 impl VirtualClock {
+    /// A clock that reads timer commands from `timer_rx`.
     pub fn new(timer_rx: TestReceiver<TimerCommand>) -> Self {
-        Self {
-            timer_rx,
-            queue: TimerQueue::new(),
-            now_ms: 0,
-        }
+        let clock = Self::default();
+        clock.attach(timer_rx);
+        clock
+    }
+
+    /// The clock shared by every `TestRuntime` timer service on this thread,
+    /// created on first use.
+    pub fn current() -> Self {
+        CURRENT.with(|c| c.borrow_mut().get_or_insert_with(Self::default).clone())
+    }
+
+    /// Also read timer commands from `timer_rx`.
+    pub fn attach(&self, timer_rx: TestReceiver<TimerCommand>) {
+        self.attach_source(timer_rx);
+    }
+
+    fn attach_source(&self, timer_rx: TestReceiver<TimerCommand>) -> usize {
+        let mut state = self.lock();
+        state.sources.push(Source {
+            rx: timer_rx,
+            shut_down: false,
+            service_waker: None,
+        });
+        state.sources.len() - 1
     }
 
     pub fn now_ms(&self) -> u64 {
-        self.now_ms
+        self.lock().now_ms
     }
 
     /// Drain any pending `TimerCommand`s into the internal queue.
-    pub fn drain_commands(&mut self) -> usize {
-        let commands = self.timer_rx.drain_payloads();
-        let count = commands.len();
-        for cmd in commands {
-            self.queue.handle_command(cmd, self.now_ms);
-        }
-        count
+    pub fn drain_commands(&self) -> usize {
+        self.lock().drain_commands()
     }
 
     /// Advance virtual time, fire all ready timers, and return how many fired.
-    pub fn advance(&mut self, delta_ms: u64) -> usize {
-        self.drain_commands();
-        self.now_ms = self.now_ms.saturating_add(delta_ms);
-
-        let ready = self.queue.drain_expired(self.now_ms);
-        let count = ready.len();
-        for deliver in ready {
-            deliver();
+    ///
+    /// Time stops at each deadline on the way, as a real service would wake
+    /// for it, so periodic timers tick once per period.
+    pub fn advance(&self, delta_ms: u64) -> usize {
+        let target = {
+            let mut state = self.lock();
+            state.drain_commands();
+            state.now_ms.saturating_add(delta_ms)
+        };
+        let mut fired = 0;
+        loop {
+            let ready = {
+                let mut state = self.lock();
+                state.drain_commands();
+                match state.queue.next_deadline() {
+                    Some(deadline) if deadline <= target => {
+                        state.now_ms = state.now_ms.max(deadline);
+                        state.take_expired()
+                    }
+                    _ => {
+                        state.now_ms = target;
+                        return fired;
+                    }
+                }
+            };
+            fired += fire(ready);
+        }
+    }
+
+    /// Jump from deadline to deadline until no one-shot timers are pending,
+    /// and return how many fired.
+    ///
+    /// Periodic timers never go idle: they tick along with the one-shots
+    /// but do not keep the loop running. Callbacks only enqueue events, so
+    /// timers that an actor re-arms while handling them are not seen until
+    /// the test dispatches those events.
+    pub fn run_until_idle(&self) -> usize {
+        let mut fired = 0;
+        loop {
+            let ready = {
+                let mut state = self.lock();
+                state.drain_commands();
+                if state.queue.len() == state.queue.periodic_len() {
+                    return fired;
+                }
+                let Some(deadline) = state.queue.next_deadline() else {
+                    return fired;
+                };
+                state.now_ms = state.now_ms.max(deadline);
+                state.take_expired()
+            };
+            fired += fire(ready);
+        }
+    }
+
+    /// Deadline of the earliest pending timer, if any.
+    pub fn next_deadline(&self) -> Option<u64> {
+        let mut state = self.lock();
+        state.drain_commands();
+        state.queue.next_deadline()
+    }
+
+    /// Number of timers waiting to fire.
+    pub fn pending_count(&self) -> usize {
+        let mut state = self.lock();
+        state.drain_commands();
+        state.queue.len()
+    }
+
+    /// Timers set by `owner` that have neither fired nor been cancelled, in
+    /// the order they were set.
+    pub fn pending_for(&self, owner: ActorId) -> Vec<TimerId> {
+        let mut state = self.lock();
+        state.drain_commands();
+        state.queue.owned_by(owner).collect()
+    }
+
+    /// Returns `true` if timer `id` has neither fired nor been cancelled.
+    pub fn is_pending(&self, id: TimerId) -> bool {
+        let mut state = self.lock();
+        state.drain_commands();
+        state.queue.contains(id)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ClockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Service loop body for the service reading `source`: ingest its
+    /// commands, fire anything already due, and finish once a `Shutdown`
+    /// has been handled on that source.
+    fn poll_service(&self, source: usize, cx: &mut Context<'_>) -> Poll<()> {
+        let (ready, done) = {
+            let mut state = self.lock();
+            state.poll_commands(source, cx);
+            state.sources[source].service_waker = Some(cx.waker().clone());
+            (state.take_expired(), state.sources[source].shut_down)
+        };
+        fire(ready);
+        if done {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl ClockState {
+    fn drain_commands(&mut self) -> usize {
+        let mut envelopes = Vec::new();
+        for (index, source) in self.sources.iter_mut().enumerate() {
+            envelopes.extend(source.rx.drain_envelopes().into_iter().map(|e| (index, e)));
+        }
+        let count = envelopes.len();
+        for (index, env) in envelopes {
+            self.handle(index, env);
         }
         count
     }
+
+    /// Like `drain_commands` for one source, but leaves the service's waker
+    /// registered with it.
+    fn poll_commands(&mut self, source: usize, cx: &mut Context<'_>) {
+        let mut envelopes = Vec::new();
+        while let Poll::Ready(Some(env)) = Pin::new(&mut self.sources[source].rx).poll_next(cx) {
+            envelopes.push(env);
+        }
+        for env in envelopes {
+            self.handle(source, env);
+        }
+    }
+
+    fn handle(&mut self, source: usize, Envelope(owner, cmd): Envelope<TimerCommand>) {
+        if self.sources[source].shut_down {
+            return;
+        }
+        if self.queue.handle_command(owner, cmd, self.now_ms) {
+            let source = &mut self.sources[source];
+            source.shut_down = true;
+            if let Some(waker) = source.service_waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    fn take_expired(&mut self) -> Vec<alloc::boxed::Box<dyn FnOnce() + Send>> {
+        self.queue.drain_expired(self.now_ms)
+    }
+}
+
+fn fire(ready: Vec<alloc::boxed::Box<dyn FnOnce() + Send>>) -> usize {
+    let count = ready.len();
+    for deliver in ready {
+        deliver();
+    }
+    count
+}
+
+/// Timers run on [`VirtualClock::current`]: the service attaches its stream
+/// to that clock and fires nothing until the test advances it. The future
+/// completes after a `TimerCommand::Shutdown` on its own stream.
+impl TimerService for TestRuntime {
+    async fn run_timer_service(stream: TestReceiver<TimerCommand>) {
+        let clock = VirtualClock::current();
+        let source = clock.attach_source(stream);
+        poll_fn(|cx| clock.poll_service(source, cx)).await
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +297,7 @@ mod tests {
             )
             .unwrap();
 
-        let mut clock = VirtualClock::new(timer_rx);
+        let clock = VirtualClock::new(timer_rx);
 
         assert_eq!(clock.advance(5), 1);
         assert_eq!(*fired.lock().unwrap(), vec![1]);
@@ -126,9 +329,197 @@ mod tests {
             .try_send(timer_id, TimerCommand::Cancel { id })
             .unwrap();
 
-        let mut clock = VirtualClock::new(timer_rx);
+        let clock = VirtualClock::new(timer_rx);
 
         assert_eq!(clock.advance(5), 0);
         assert!(fired.lock().unwrap().is_empty());
     }
+
+    fn set(
+        timer_ref: &bloxide_core::messaging::ActorRef<TimerCommand, TestRuntime>,
+        owner: usize,
+        after_ms: u64,
+        fired: &Arc<Mutex<Vec<u64>>>,
+    ) -> crate::TimerId {
+        let id = next_timer_id();
+        let fired = Arc::clone(fired);
+        timer_ref
+            .try_send(
+                owner,
+                TimerCommand::Set {
+                    id,
+                    after_ms,
+                    deliver: Box::new(move || fired.lock().unwrap().push(after_ms)),
+                },
+            )
+            .unwrap();
+        id
+    }
+
+    #[test]
+    fn pending_timers_are_tracked_by_owner() {
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let a1 = set(&timer_ref, 1, 10, &fired);
+        let b = set(&timer_ref, 2, 20, &fired);
+        let a2 = set(&timer_ref, 1, 30, &fired);
+        let clock = VirtualClock::new(timer_rx);
+
+        assert_eq!(clock.pending_for(1), vec![a1, a2]);
+        assert_eq!(clock.pending_for(2), vec![b]);
+        assert_eq!(clock.pending_count(), 3);
+
+        timer_ref
+            .try_send(1, TimerCommand::Cancel { id: a2 })
+            .unwrap();
+        clock.advance(10);
+        assert!(clock.pending_for(1).is_empty());
+        assert!(clock.is_pending(b));
+        assert_eq!(clock.next_deadline(), Some(20));
+    }
+
+    #[test]
+    fn timer_mark_discards_the_ended_incarnations_timers() {
+        use crate::{cancel_timers_for, TimerMark};
+        use bloxide_core::engine::{DispatchOutcome, MachineState};
+
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let stale = set(&timer_ref, 1, 10, &fired);
+        let other = set(&timer_ref, 2, 10, &fired);
+
+        let started = DispatchOutcome::Started(MachineState::State(0u8));
+        assert!(!TimerMark::new(MachineState::<u8>::Init).discards(&started));
+        assert!(!TimerMark::new(MachineState::State(1u8)).discards(&DispatchOutcome::<u8>::Failed));
+
+        // A reset re-enters the initial state, whose on_entry arms a fresh timer.
+        let mark = TimerMark::new(MachineState::State(1u8));
+        let fresh = set(&timer_ref, 1, 10, &fired);
+        assert!(mark.discards(&started));
+        cancel_timers_for(&timer_ref, 1, mark.before());
+
+        let clock = VirtualClock::new(timer_rx);
+        assert!(!clock.is_pending(stale));
+        assert_eq!(clock.pending_for(1), vec![fresh]);
+        assert!(clock.is_pending(other));
+        assert!(TimerMark::new(MachineState::<u8>::Init).discards(&DispatchOutcome::<u8>::Stopped));
+        assert!(TimerMark::new(MachineState::<u8>::Init).discards(&DispatchOutcome::<u8>::Aborted));
+    }
+
+    #[test]
+    fn run_until_idle_fires_everything_in_order() {
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        for after_ms in [300, 100, 200] {
+            set(&timer_ref, 1, after_ms, &fired);
+        }
+        let clock = VirtualClock::new(timer_rx);
+
+        assert_eq!(clock.run_until_idle(), 3);
+        assert_eq!(*fired.lock().unwrap(), vec![100, 200, 300]);
+        assert_eq!(clock.now_ms(), 300);
+        assert_eq!(clock.run_until_idle(), 0);
+    }
+
+    #[test]
+    fn test_runtime_timer_service_uses_current_clock() {
+        use crate::TimerService;
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let mut service = pin!(TestRuntime::run_timer_service(timer_rx));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(service.as_mut().poll(&mut cx).is_pending());
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let id = set(&timer_ref, 7, 50, &fired);
+        let clock = VirtualClock::current();
+        assert_eq!(clock.pending_for(7), vec![id]);
+
+        assert_eq!(clock.advance(49), 0);
+        assert_eq!(clock.advance(1), 1);
+        assert_eq!(*fired.lock().unwrap(), vec![50]);
+
+        timer_ref.try_send(0, TimerCommand::Shutdown).unwrap();
+        assert_eq!(service.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+
+    #[test]
+    fn shutdown_ends_only_the_service_it_was_sent_to() {
+        use crate::TimerService;
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        let (first_ref, first_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let (second_ref, second_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let mut first = pin!(TestRuntime::run_timer_service(first_rx));
+        let mut second = pin!(TestRuntime::run_timer_service(second_rx));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(first.as_mut().poll(&mut cx).is_pending());
+        assert!(second.as_mut().poll(&mut cx).is_pending());
+
+        first_ref.try_send(0, TimerCommand::Shutdown).unwrap();
+        assert_eq!(first.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let id = set(&second_ref, 8, 10, &fired);
+        assert!(second.as_mut().poll(&mut cx).is_pending());
+        let clock = VirtualClock::current();
+        assert_eq!(clock.pending_for(8), vec![id]);
+        assert_eq!(clock.advance(10), 1);
+    }
+
+    struct Ctx {
+        self_id: usize,
+        timer_ref: bloxide_core::messaging::ActorRef<TimerCommand, TestRuntime>,
+    }
+
+    impl bloxide_core::accessor::HasSelfId for Ctx {
+        fn self_id(&self) -> usize {
+            self.self_id
+        }
+    }
+
+    impl crate::HasTimerRef<TestRuntime> for Ctx {
+        fn timer_ref(&self) -> &bloxide_core::messaging::ActorRef<TimerCommand, TestRuntime> {
+            &self.timer_ref
+        }
+    }
+
+    #[test]
+    fn periodic_and_reschedule_helpers_drive_the_clock() {
+        use crate::{reschedule_timer, set_periodic_timer, set_timer, MissedTicks};
+
+        let (timer_ref, timer_rx) = TestRuntime::channel::<TimerCommand>(0, 8);
+        let (target, mut target_rx) = TestRuntime::channel::<&'static str>(1, 16);
+        let ctx = Ctx {
+            self_id: 1,
+            timer_ref,
+        };
+        let clock = VirtualClock::new(timer_rx);
+
+        let heartbeat = set_periodic_timer(&ctx, 10, MissedTicks::Skip, &target, "beat");
+        let timeout = set_timer(&ctx, 25, &target, "timeout");
+        assert_eq!(clock.pending_for(1), vec![heartbeat, timeout]);
+
+        clock.advance(20);
+        reschedule_timer(&ctx, timeout, 25);
+        clock.advance(20);
+        assert_eq!(
+            target_rx.drain_payloads(),
+            vec!["beat", "beat", "beat", "beat"]
+        );
+
+        assert_eq!(
+            clock.run_until_idle(),
+            1,
+            "stops once only the heartbeat is left"
+        );
+        assert_eq!(target_rx.drain_payloads(), vec!["timeout"]);
+        assert_eq!(clock.now_ms(), 45);
+        assert_eq!(clock.pending_for(1), vec![heartbeat]);
+    }
 }
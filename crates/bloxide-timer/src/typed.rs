@@ -0,0 +1,477 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Allocation-free timers for targets without a global allocator.
+//!
+//! A [`TypedTimerCommand<M, R>`] carries the target `ActorRef<M, R>` and the
+//! event itself instead of a boxed callback, and a [`TypedTimerQueue`] keeps
+//! at most `N` of them in a fixed-capacity buffer. One typed timer service
+//! therefore serves one event type; a blox that sets timers on several event
+//! types needs one service per type. Periodic timers need to clone the
+//! event on every tick and are only offered by the `alloc` service.
+//!
+//! Contexts opt in by binding `HasTimerRef<R, TypedTimerCommand<M, R>>`;
+//! [`set_timer`](crate::set_timer) and [`cancel_timer`](crate::cancel_timer)
+//! work unchanged.
+
+use core::future::{poll_fn, Future};
+use core::pin::{pin, Pin};
+use core::task::Poll;
+
+use bloxide_core::capability::BloxRuntime;
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use futures_core::Stream;
+
+use crate::command::{TimerId, TimerRequest, TimerSchedule, TIMER_ACTOR_ID};
+
+/// Messages sent to a typed timer service.
+pub enum TypedTimerCommand<M: Send + 'static, R: BloxRuntime> {
+    /// Deliver `event` to `target` after `after_ms` milliseconds. `owner`
+    /// is the actor that set the timer, matched by `CancelAllFor`.
+    Set {
+        owner: ActorId,
+        id: TimerId,
+        after_ms: u64,
+        target: ActorRef<M, R>,
+        event: M,
+    },
+    /// Move a pending timer so it fires `after_ms` from now.
+    Reschedule { id: TimerId, after_ms: u64 },
+    /// Cancel a previously scheduled timer.
+    Cancel { id: TimerId },
+    /// Cancel every pending timer set by `owner` whose id is below `before`.
+    CancelAllFor { owner: ActorId, before: TimerId },
+    /// Shut down the timer service. Expired timers fire, pending ones are
+    /// discarded, and the service loop exits.
+    Shutdown,
+}
+
+impl<M: Send + 'static, R: BloxRuntime> TimerRequest for TypedTimerCommand<M, R> {
+    fn cancel(id: TimerId) -> Self {
+        Self::Cancel { id }
+    }
+
+    fn reschedule(id: TimerId, after_ms: u64) -> Self {
+        Self::Reschedule { id, after_ms }
+    }
+
+    fn cancel_all_for(owner: ActorId, before: TimerId) -> Self {
+        Self::CancelAllFor { owner, before }
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime> TimerSchedule<M, R> for TypedTimerCommand<M, R> {
+    fn schedule(
+        owner: ActorId,
+        id: TimerId,
+        after_ms: u64,
+        target: &ActorRef<M, R>,
+        event: M,
+    ) -> Self {
+        Self::Set {
+            owner,
+            id,
+            after_ms,
+            target: target.clone(),
+            event,
+        }
+    }
+}
+
+/// Returned by [`TypedTimerQueue::set`] when all `N` slots are in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerQueueFull;
+
+struct PendingDelivery<M: Send + 'static, R: BloxRuntime> {
+    id: TimerId,
+    owner: ActorId,
+    deadline_ms: u64,
+    target: ActorRef<M, R>,
+    event: M,
+}
+
+/// A sorted, fixed-capacity queue of pending typed deliveries.
+///
+/// The allocation-free counterpart of `TimerQueue`:
+/// timers with equal deadlines fire in the order they were set.
+pub struct TypedTimerQueue<M: Send + 'static, R: BloxRuntime, const N: usize> {
+    timers: heapless::Vec<PendingDelivery<M, R>, N>,
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> TypedTimerQueue<M, R, N> {
+    pub const fn new() -> Self {
+        Self {
+            timers: heapless::Vec::new(),
+        }
+    }
+
+    /// Insert a timer that delivers `event` to `target` at `now_ms + after_ms`,
+    /// replacing any pending timer with the same `id`.
+    pub fn set(
+        &mut self,
+        owner: ActorId,
+        id: TimerId,
+        after_ms: u64,
+        now_ms: u64,
+        target: ActorRef<M, R>,
+        event: M,
+    ) -> Result<(), TimerQueueFull> {
+        self.cancel(id);
+        let deadline_ms = now_ms.saturating_add(after_ms);
+        let pos = self
+            .timers
+            .iter()
+            .position(|t| t.deadline_ms > deadline_ms)
+            .unwrap_or(self.timers.len());
+        self.timers
+            .insert(
+                pos,
+                PendingDelivery {
+                    id,
+                    owner,
+                    deadline_ms,
+                    target,
+                    event,
+                },
+            )
+            .map_err(|_| TimerQueueFull)
+    }
+
+    /// Cancel a pending timer. Returns `true` if it was found and removed.
+    pub fn cancel(&mut self, id: TimerId) -> bool {
+        match self.timers.iter().position(|t| t.id == id) {
+            Some(pos) => {
+                self.timers.remove(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move a pending timer to `now_ms + after_ms`, keeping insertion order
+    /// among equal deadlines. Returns `false` if `id` is not pending.
+    pub fn reschedule(&mut self, id: TimerId, after_ms: u64, now_ms: u64) -> bool {
+        let Some(pos) = self.timers.iter().position(|t| t.id == id) else {
+            return false;
+        };
+        let timer = self.timers.remove(pos);
+        // The slot just freed guarantees room.
+        let _ = self.set(timer.owner, id, after_ms, now_ms, timer.target, timer.event);
+        true
+    }
+
+    /// Cancel `owner`'s timers with ids below `before` and return how many
+    /// were removed.
+    pub fn cancel_all_for(&mut self, owner: ActorId, before: TimerId) -> usize {
+        let len = self.timers.len();
+        self.timers.retain(|t| t.owner != owner || t.id >= before);
+        len - self.timers.len()
+    }
+
+    /// Returns `true` if `id` is still pending.
+    pub fn contains(&self, id: TimerId) -> bool {
+        self.timers.iter().any(|t| t.id == id)
+    }
+
+    /// Returns the deadline (in ms) of the earliest pending timer, if any.
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.timers.first().map(|t| t.deadline_ms)
+    }
+
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Deliver every timer whose deadline has passed, earliest first, and
+    /// return how many were removed. A delivery to a full mailbox is logged
+    /// against the timer's owner and dropped.
+    pub fn fire_expired(&mut self, now_ms: u64) -> usize {
+        let mut fired = 0;
+        while self.next_deadline().is_some_and(|d| d <= now_ms) {
+            let timer = self.timers.remove(0);
+            if timer.target.try_send(TIMER_ACTOR_ID, timer.event).is_err() {
+                bloxide_log::blox_log_warn!(
+                    timer.owner,
+                    "timer delivery: target mailbox full, timer event dropped"
+                );
+            }
+            fired += 1;
+        }
+        fired
+    }
+
+    /// Process a command. Returns `true` when the caller should exit its
+    /// service loop.
+    pub fn handle_command(&mut self, cmd: TypedTimerCommand<M, R>, now_ms: u64) -> bool {
+        match cmd {
+            TypedTimerCommand::Set {
+                owner,
+                id,
+                after_ms,
+                target,
+                event,
+            } => {
+                if self
+                    .set(owner, id, after_ms, now_ms, target, event)
+                    .is_err()
+                {
+                    bloxide_log::blox_log_warn!(
+                        owner,
+                        "set_timer: timer queue full ({} slots), timer {} dropped",
+                        N,
+                        id.as_u64()
+                    );
+                }
+                false
+            }
+            TypedTimerCommand::Reschedule { id, after_ms } => {
+                self.reschedule(id, after_ms, now_ms);
+                false
+            }
+            TypedTimerCommand::Cancel { id } => {
+                self.cancel(id);
+                false
+            }
+            TypedTimerCommand::CancelAllFor { owner, before } => {
+                self.cancel_all_for(owner, before);
+                false
+            }
+            TypedTimerCommand::Shutdown => true,
+        }
+    }
+}
+
+impl<M: Send + 'static, R: BloxRuntime, const N: usize> Default for TypedTimerQueue<M, R, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runtime-agnostic typed timer service loop.
+///
+/// `now_ms` reads the runtime's monotonic clock and `sleep_until` returns a
+/// future that completes at the given deadline. Runtimes implement
+/// [`TypedTimerService`](crate::TypedTimerService) by calling this with
+/// their native timer; tests can pass a manual clock.
+pub async fn run_typed_timer_service<M, R, const N: usize, S, F>(
+    mut stream: R::Stream<TypedTimerCommand<M, R>>,
+    now_ms: impl Fn() -> u64,
+    mut sleep_until: S,
+) where
+    M: Send + 'static,
+    R: BloxRuntime,
+    S: FnMut(u64) -> F,
+    F: Future<Output = ()>,
+{
+    let mut queue = TypedTimerQueue::<M, R, N>::new();
+    loop {
+        let next = match queue.next_deadline() {
+            Some(deadline_ms) => {
+                let mut sleep = pin!(sleep_until(deadline_ms));
+                poll_fn(|cx| match Pin::new(&mut stream).poll_next(cx) {
+                    Poll::Ready(item) => Poll::Ready(Some(item)),
+                    Poll::Pending => sleep.as_mut().poll(cx).map(|()| None),
+                })
+                .await
+            }
+            None => Some(poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await),
+        };
+        let now = now_ms();
+        match next {
+            Some(Some(Envelope(_, cmd))) => {
+                let shutdown = queue.handle_command(cmd, now);
+                queue.fire_expired(now);
+                if shutdown {
+                    return;
+                }
+            }
+            // All senders dropped.
+            Some(None) => return,
+            None => {
+                queue.fire_expired(now);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{cancel_timer, next_timer_id, set_timer, HasTimerRef};
+    use bloxide_core::accessor::HasSelfId;
+    use bloxide_core::DynamicChannelCap;
+    use bloxide_test_runtime::{TestReceiver, TestRuntime};
+    use core::cell::Cell;
+    use core::task::{Context, Waker};
+    use std::vec::Vec;
+
+    type Cmd = TypedTimerCommand<u32, TestRuntime>;
+
+    struct Ctx {
+        self_id: ActorId,
+        timer_ref: ActorRef<Cmd, TestRuntime>,
+    }
+
+    impl HasSelfId for Ctx {
+        fn self_id(&self) -> ActorId {
+            self.self_id
+        }
+    }
+
+    impl HasTimerRef<TestRuntime, Cmd> for Ctx {
+        fn timer_ref(&self) -> &ActorRef<Cmd, TestRuntime> {
+            &self.timer_ref
+        }
+    }
+
+    fn setup() -> (
+        Ctx,
+        TestReceiver<Cmd>,
+        ActorRef<u32, TestRuntime>,
+        TestReceiver<u32>,
+    ) {
+        let (timer_ref, timer_rx) = TestRuntime::channel::<Cmd>(0, 8);
+        let (target, target_rx) = TestRuntime::channel::<u32>(TestRuntime::alloc_actor_id(), 8);
+        let ctx = Ctx {
+            self_id: target.id(),
+            timer_ref,
+        };
+        (ctx, timer_rx, target, target_rx)
+    }
+
+    #[test]
+    fn set_timer_sends_typed_command_through_actions() {
+        let (ctx, mut timer_rx, target, mut target_rx) = setup();
+        let keep = set_timer(&ctx, 20, &target, 2);
+        let drop = set_timer(&ctx, 10, &target, 1);
+        cancel_timer(&ctx, drop);
+
+        let mut queue = TypedTimerQueue::<u32, TestRuntime, 4>::new();
+        for Envelope(owner, cmd) in timer_rx.drain_envelopes() {
+            assert_eq!(owner, ctx.self_id);
+            assert!(!queue.handle_command(cmd, 0));
+        }
+        assert!(queue.contains(keep));
+        assert!(!queue.contains(drop));
+
+        assert_eq!(queue.fire_expired(19), 0);
+        assert_eq!(queue.fire_expired(20), 1);
+        let delivered = target_rx.drain_envelopes();
+        assert_eq!(delivered.len(), 1);
+        assert_eq!((delivered[0].0, delivered[0].1), (TIMER_ACTOR_ID, 2));
+    }
+
+    #[test]
+    fn queue_orders_by_deadline_and_rejects_overflow() {
+        let (_, _, target, mut target_rx) = setup();
+        let mut queue = TypedTimerQueue::<u32, TestRuntime, 2>::new();
+        queue
+            .set(1, next_timer_id(), 30, 0, target.clone(), 30)
+            .unwrap();
+        queue
+            .set(1, next_timer_id(), 10, 0, target.clone(), 10)
+            .unwrap();
+        assert_eq!(
+            queue.set(1, next_timer_id(), 20, 0, target.clone(), 20),
+            Err(TimerQueueFull)
+        );
+
+        assert_eq!(queue.next_deadline(), Some(10));
+        let first = queue.timers[0].id;
+        assert!(queue.reschedule(first, 40, 0));
+        assert_eq!(queue.next_deadline(), Some(30));
+        assert_eq!(queue.fire_expired(100), 2);
+        assert_eq!(target_rx.drain_payloads(), [30, 10]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn cancel_all_for_only_drops_the_owners_older_timers() {
+        let (_, _, target, mut target_rx) = setup();
+        let mut queue = TypedTimerQueue::<u32, TestRuntime, 4>::new();
+        queue
+            .set(1, next_timer_id(), 10, 0, target.clone(), 1)
+            .unwrap();
+        queue
+            .set(2, next_timer_id(), 10, 0, target.clone(), 2)
+            .unwrap();
+        let before = crate::timer_id_watermark();
+        let cmd = Cmd::cancel_all_for(1, before);
+        queue
+            .set(1, next_timer_id(), 10, 0, target.clone(), 3)
+            .unwrap();
+
+        assert!(!queue.handle_command(cmd, 0));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.fire_expired(10), 2);
+        assert_eq!(target_rx.drain_payloads(), [2, 3]);
+    }
+
+    #[test]
+    fn set_replaces_a_pending_timer_with_the_same_id() {
+        let (_, _, target, mut target_rx) = setup();
+        let mut queue = TypedTimerQueue::<u32, TestRuntime, 2>::new();
+        let id = next_timer_id();
+        queue.set(1, id, 10, 0, target.clone(), 1).unwrap();
+        queue.set(1, id, 20, 0, target.clone(), 2).unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.next_deadline(), Some(20));
+        assert_eq!(queue.fire_expired(20), 1);
+        assert_eq!(target_rx.drain_payloads(), [2]);
+    }
+
+    #[test]
+    fn cancel_all_for_matches_the_owner_carried_by_the_command() {
+        let (_, _, target, mut target_rx) = setup();
+        let mut queue = TypedTimerQueue::<u32, TestRuntime, 4>::new();
+        let cmd = Cmd::schedule(1, next_timer_id(), 10, &target, 1);
+        assert!(!queue.handle_command(cmd, 0));
+        let cancel = Cmd::cancel_all_for(1, crate::timer_id_watermark());
+        assert!(!queue.handle_command(cancel, 0));
+        assert!(queue.is_empty());
+        assert_eq!(queue.fire_expired(10), 0);
+        assert!(target_rx.drain_payloads().is_empty());
+    }
+
+    #[test]
+    fn service_fires_on_sleep_and_exits_on_shutdown() {
+        let (ctx, timer_rx, target, mut target_rx) = setup();
+        let now = Cell::new(0u64);
+        let deadlines = core::cell::RefCell::new(Vec::new());
+        let mut service = pin!(run_typed_timer_service::<u32, TestRuntime, 4, _, _>(
+            timer_rx,
+            || now.get(),
+            |deadline| {
+                deadlines.borrow_mut().push(deadline);
+                let now = &now;
+                poll_fn(move |_| {
+                    if now.get() >= deadline {
+                        Poll::Ready(())
+                    } else {
+                        Poll::Pending
+                    }
+                })
+            },
+        ));
+        let mut cx = Context::from_waker(Waker::noop());
+
+        set_timer(&ctx, 50, &target, 7);
+        assert!(service.as_mut().poll(&mut cx).is_pending());
+        assert!(service.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(deadlines.borrow().last(), Some(&50));
+        assert!(target_rx.drain_payloads().is_empty());
+
+        now.set(50);
+        assert!(service.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(target_rx.drain_payloads(), [7]);
+
+        ctx.timer_ref.try_send(ctx.self_id, Cmd::Shutdown).unwrap();
+        assert_eq!(service.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+}
@@ -15,7 +15,7 @@ use bloxide_core::{
     StateMachine,
 };
 use bloxide_peers::PeerCtrl;
-use bloxide_spawn::{SpawnCap, SpawnOutput};
+use bloxide_spawn::{SpawnCap, SpawnError, SpawnOutput};
 use bloxide_tokio::{run_supervised_actor_with_abort, TokioRuntime};
 use pool_actions::traits::{HasCurrentTask, HasPeers};
 use pool_messages::{SpawnRequest, SpawnedWorker, WorkerMsg};
@@ -54,7 +54,8 @@ impl<R: BloxRuntime> HasCurrentTask for WorkerBehavior<R> {
 }
 
 impl<R: BloxRuntime> HasPeers<WorkerMsg, R> for WorkerBehavior<R> {
-    fn peers(&self) -> &[ActorRef<WorkerMsg, R>] {
+    type Peers = Vec<ActorRef<WorkerMsg, R>>;
+    fn peers(&self) -> &Vec<ActorRef<WorkerMsg, R>> {
         &self.peers
     }
     fn peers_mut(&mut self) -> &mut Vec<ActorRef<WorkerMsg, R>> {
@@ -73,7 +74,7 @@ impl<R: BloxRuntime> HasPeers<WorkerMsg, R> for WorkerBehavior<R> {
 pub fn spawn_worker(
     req: SpawnRequest<TokioRuntime>,
     notify: ActorRef<ChildLifecycleEvent, TokioRuntime>,
-) -> SpawnOutput<TokioRuntime> {
+) -> Result<SpawnOutput<TokioRuntime>, SpawnError> {
     match req {
         SpawnRequest::Worker {
             task_id: _,
@@ -125,13 +126,13 @@ pub fn spawn_worker(
                 },
             );
 
-            SpawnOutput {
+            Ok(SpawnOutput {
                 child_id: worker_id,
                 lifecycle_ref,
                 abort_ref,
                 kill_handle,
                 policy: ChildPolicy::Stop,
-            }
+            })
         }
     }
 }
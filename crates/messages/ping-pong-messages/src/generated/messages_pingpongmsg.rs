@@ -1,14 +1,17 @@
 // Copyright 2025 Bloxide, all rights reserved
 // Auto-generated by bloxide-codegen. Do not edit manually.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Ping {
     pub round: u32,
 }
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Pong {
     pub round: u32,
 }
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Resume;
 #[derive(Debug, Clone, Copy)]
 pub enum PingPongMsg {
@@ -26,3 +29,66 @@ impl PingPongMsg {
         }
     }
 }
+impl PingPongMsg {
+    /// Stable per-variant wire tag (FNV-1a of `message_name()`).
+    pub const fn wire_tag(&self) -> u32 {
+        match self {
+            PingPongMsg::Ping(..) => 2142761129u32,
+            PingPongMsg::Pong(..) => 3787107799u32,
+            PingPongMsg::Resume(..) => 1162681738u32,
+        }
+    }
+}
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for PingPongMsg {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use ::serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.wire_tag())?;
+        match self {
+            PingPongMsg::Ping(payload) => tuple.serialize_element(payload)?,
+            PingPongMsg::Pong(payload) => tuple.serialize_element(payload)?,
+            PingPongMsg::Resume(payload) => tuple.serialize_element(payload)?,
+        }
+        tuple.end()
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for PingPongMsg {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct WireVisitor;
+        impl<'de> ::serde::de::Visitor<'de> for WireVisitor {
+            type Value = PingPongMsg;
+            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("a (wire_tag, payload) pair for PingPongMsg")
+            }
+            fn visit_seq<A: ::serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let tag: u32 = seq
+                    .next_element()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length(0, &self))?;
+                match tag {
+                    2142761129u32 => seq
+                        .next_element()?
+                        .map(PingPongMsg::Ping)
+                        .ok_or_else(|| ::serde::de::Error::invalid_length(1, &self)),
+                    3787107799u32 => seq
+                        .next_element()?
+                        .map(PingPongMsg::Pong)
+                        .ok_or_else(|| ::serde::de::Error::invalid_length(1, &self)),
+                    1162681738u32 => seq
+                        .next_element()?
+                        .map(PingPongMsg::Resume)
+                        .ok_or_else(|| ::serde::de::Error::invalid_length(1, &self)),
+                    other => Err(::serde::de::Error::invalid_value(
+                        ::serde::de::Unexpected::Unsigned(other as u64),
+                        &self,
+                    )),
+                }
+            }
+        }
+        deserializer.deserialize_tuple(2, WireVisitor)
+    }
+}
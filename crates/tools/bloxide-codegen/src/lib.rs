@@ -12,6 +12,7 @@ mod topology;
 pub mod util;
 pub mod wiring;
 
+pub use messages::wire_tag as message_wire_tag;
 use schema::{BloxConfig, SystemConfig};
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
@@ -20,12 +20,32 @@ pub fn generate(config: &MessageEnumConfig) -> anyhow::Result<String> {
 
     let enum_ident = format_ident!("{}", config.name);
 
+    let serde = match config.serialize.as_deref() {
+        None => false,
+        Some("serde") => true,
+        Some(other) => anyhow::bail!(
+            "message enum '{}': unsupported serialize = \"{}\" (expected \"serde\")",
+            config.name,
+            other
+        ),
+    };
+
     // Build the derive attribute: always Debug + Clone; add Copy only when opted in.
     let derives = if config.copy {
         quote! { #[derive(Debug, Clone, Copy)] }
     } else {
         quote! { #[derive(Debug, Clone)] }
     };
+    // Payload structs additionally derive serde; the enum gets manual impls
+    // keyed by wire tag (see `serde_impls`).
+    let struct_derives = if serde {
+        quote! {
+            #derives
+            #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        }
+    } else {
+        derives.clone()
+    };
 
     let mut struct_defs = Vec::new();
     let mut enum_variants = Vec::new();
@@ -38,7 +58,7 @@ pub fn generate(config: &MessageEnumConfig) -> anyhow::Result<String> {
         if variant.fields.is_empty() {
             // Unit struct for empty variants
             struct_defs.push(quote! {
-                #derives
+                #struct_derives
                 #vis struct #struct_ident;
             });
         } else {
@@ -57,7 +77,7 @@ pub fn generate(config: &MessageEnumConfig) -> anyhow::Result<String> {
                 .collect::<Result<Vec<_>, _>>()?;
 
             struct_defs.push(quote! {
-                #derives
+                #struct_derives
                 #vis struct #struct_ident {
                     #(pub #field_idents: #field_types),*
                 }
@@ -92,10 +112,17 @@ pub fn generate(config: &MessageEnumConfig) -> anyhow::Result<String> {
         }
     };
 
+    let serde_block = if serde {
+        serde_impls(config)?
+    } else {
+        quote! {}
+    };
+
     let tokens = quote! {
         #(#struct_defs)*
         #enum_def
         #impl_block
+        #serde_block
     };
 
     let raw = tokens.to_string();
@@ -105,3 +132,106 @@ pub fn generate(config: &MessageEnumConfig) -> anyhow::Result<String> {
 
     Ok(format!("{}{}", HEADER, formatted))
 }
+
+/// Stable wire tag for a message variant: the 32-bit FNV-1a hash of its
+/// `message_name()`. Renaming a variant changes its tag; reordering does not.
+pub fn wire_tag(message_name: &str) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in message_name.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// `wire_tag()` plus `Serialize`/`Deserialize` impls that encode the enum as a
+/// `(wire_tag, payload)` tuple instead of serde's positional variant index.
+fn serde_impls(config: &MessageEnumConfig) -> anyhow::Result<proc_macro2::TokenStream> {
+    let enum_ident = format_ident!("{}", config.name);
+    let expecting = format!("a (wire_tag, payload) pair for {}", config.name);
+
+    let mut seen = std::collections::BTreeMap::new();
+    let mut tag_arms = Vec::new();
+    let mut ser_arms = Vec::new();
+    let mut de_arms = Vec::new();
+    for variant in &config.variants {
+        let tag = wire_tag(&variant.name);
+        if let Some(other) = seen.insert(tag, &variant.name) {
+            anyhow::bail!(
+                "message enum '{}': variants '{}' and '{}' share wire tag {:#010x}",
+                config.name,
+                other,
+                variant.name,
+                tag
+            );
+        }
+        let variant_ident = format_ident!("{}", variant.name);
+        tag_arms.push(quote! { #enum_ident::#variant_ident(..) => #tag });
+        ser_arms.push(quote! {
+            #enum_ident::#variant_ident(payload) => tuple.serialize_element(payload)?
+        });
+        de_arms.push(quote! {
+            #tag => seq
+                .next_element()?
+                .map(#enum_ident::#variant_ident)
+                .ok_or_else(|| ::serde::de::Error::invalid_length(1, &self))
+        });
+    }
+
+    Ok(quote! {
+        impl #enum_ident {
+            /// Stable per-variant wire tag (FNV-1a of `message_name()`).
+            pub const fn wire_tag(&self) -> u32 {
+                match self {
+                    #(#tag_arms,)*
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for #enum_ident {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&self.wire_tag())?;
+                match self {
+                    #(#ser_arms,)*
+                }
+                tuple.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for #enum_ident {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct WireVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for WireVisitor {
+                    type Value = #enum_ident;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(#expecting)
+                    }
+
+                    fn visit_seq<A: ::serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        let tag: u32 = seq
+                            .next_element()?
+                            .ok_or_else(|| ::serde::de::Error::invalid_length(0, &self))?;
+                        match tag {
+                            #(#de_arms,)*
+                            other => Err(::serde::de::Error::invalid_value(
+                                ::serde::de::Unexpected::Unsigned(other as u64),
+                                &self,
+                            )),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_tuple(2, WireVisitor)
+            }
+        }
+    })
+}
@@ -26,6 +26,14 @@ pub struct MessageEnumConfig {
     /// Defaults to `false` — only `Debug` and `Clone` are derived.
     #[serde(default)]
     pub copy: bool,
+    /// Serialization support to generate. Only `"serde"` is recognised: the
+    /// payload structs derive `Serialize`/`Deserialize`, and the enum gets a
+    /// `wire_tag()` method plus hand-written impls that encode it as a
+    /// `(wire_tag, payload)` pair. The serde items are gated on the message
+    /// crate's own `serde` feature, which should enable an optional `serde`
+    /// dependency (`default-features = false, features = ["derive"]`).
+    #[serde(default)]
+    pub serialize: Option<String>,
     pub variants: Vec<MessageVariantConfig>,
 }
 
@@ -91,7 +99,7 @@ pub struct TopologyConfig {
     pub exit: Vec<EntryExitConfig>,
     /// Raw `use` statements for the spec_skeleton module. These import the
     /// action functions referenced in transitions/entry/exit.
-    /// e.g. `["bloxide_child_management::{start_children, stop_all_children, handle_done_or_failed, ...}"]`
+    /// e.g. `["bloxide_child_management::{start_children, stop_all_children, handle_done, ...}"]`
     #[serde(default)]
     pub spec_imports: Vec<String>,
     /// Feature-gated raw `use` statements for the spec_skeleton module.
@@ -163,6 +171,11 @@ pub struct ContextConfig {
     /// Body of `on_init_entry` as a raw string (inserted verbatim).
     #[serde(default)]
     pub on_init: Option<String>,
+    /// Expression for `MachineSpec::stop_event` (inserted verbatim, `ctx` in
+    /// scope). When set, Stop dispatches this event and the actor reports
+    /// Stopped only once it reaches a terminal or error state.
+    #[serde(default)]
+    pub on_stop: Option<String>,
     /// Extra impl blocks emitted after the context struct, wrapped with the
     /// appropriate generics for each variant. Each entry is a raw impl body
     /// WITHOUT the `impl<...>` header — the codegen wraps it as:
@@ -447,10 +460,13 @@ pub struct SystemConfig {
 /// `[system]` table — runtime selection.
 #[derive(Debug, Deserialize, Clone)]
 pub struct SystemMeta {
-    /// Target runtime: `"tokio"`, `"embassy"`, or `"test"`.
+    /// Target runtime: `"tokio"`, `"embassy"`, `"std"`, or `"test"`.
     pub runtime: String,
     /// Optional system name (used as the binary name in generated output).
     pub name: Option<String>,
+    /// Tokio only: how long a graceful shutdown may take before remaining
+    /// tasks are aborted. Defaults to 5000 ms.
+    pub shutdown_timeout_ms: Option<u64>,
 }
 
 /// A bootstrap message to send to an actor after the supervisor starts.
@@ -537,6 +553,26 @@ pub struct InjectSource {
 /// A `[[supervision]]` entry — one supervisor group.
 #[derive(Debug, Deserialize, Clone)]
 pub struct SupervisionConfig {
+    /// Optional name. A named supervisor can be listed in another entry's
+    /// `children`, which makes it a nested supervisor: it is started and
+    /// restarted by its parent, and reports `Failed` to the parent when it
+    /// runs out of restarts.
+    ///
+    /// ```toml
+    /// [[supervision]]
+    /// name = "backend"
+    /// supervisor = "bloxide-supervisor"
+    /// strategy = "one_for_one"
+    /// children = ["db", "cache"]
+    ///
+    /// [[supervision]]
+    /// supervisor = "bloxide-supervisor"
+    /// strategy = "one_for_one"
+    /// children = ["backend", "frontend"]
+    /// [supervision.policies]
+    /// backend = { restart = { max = 3 } }
+    /// ```
+    pub name: Option<String>,
     /// Supervisor crate/spec name (e.g. `"bloxide-supervisor"`).
     pub supervisor: String,
     /// Restart strategy: `"one_for_one"`, `"one_for_all"`, or `"rest_for_one"`.
@@ -547,13 +583,59 @@ pub struct SupervisionConfig {
     ///
     /// ```toml
     /// [supervision.policies]
-    /// ping = { restart = { max = 1 } }
+    /// ping = { restart = { max = 3, within_ms = 5000 } }
     /// pong = { stop = true }
     /// ```
     #[serde(default)]
     pub policies: BTreeMap<String, ChildPolicyConfig>,
     /// Optional health-check interval in milliseconds.
     pub health_check_interval_ms: Option<u64>,
+    /// Optional health-check failure thresholds: consecutive unanswered
+    /// pings (`max_missed`) and consecutive pings that could not be
+    /// delivered (`max_undelivered`, 0 = never fail) before a child is
+    /// treated as failed. Both default to 1.
+    ///
+    /// ```toml
+    /// health_check_thresholds = { max_missed = 3, max_undelivered = 0 }
+    /// ```
+    pub health_check_thresholds: Option<HealthCheckThresholdsConfig>,
+    /// Optional group-wide restart intensity, counted across all children.
+    ///
+    /// ```toml
+    /// restart_intensity = { max = 10, within_ms = 60000 }
+    /// ```
+    pub restart_intensity: Option<RestartIntensityConfig>,
+    /// Optional per-child shutdown deadlines. A child that has not stopped
+    /// within `stop_ms` is aborted, then killed `abort_ms` later. Requires a
+    /// timer actor.
+    ///
+    /// ```toml
+    /// shutdown_timeouts = { stop_ms = 2000, abort_ms = 500 }
+    /// ```
+    pub shutdown_timeouts: Option<ShutdownTimeoutsConfig>,
+    /// Start children one at a time, in `children` order, each after the
+    /// previous one reported `Started`. Defaults to `"concurrent"`.
+    ///
+    /// ```toml
+    /// start_order = "sequential"
+    /// ```
+    pub start_order: Option<StartOrderConfig>,
+    /// Optional deadline for each child to report `Started`; a child that
+    /// misses it fails the group. Requires a timer actor.
+    pub start_timeout_ms: Option<u64>,
+    /// Optional number of lifecycle events and policy decisions the group
+    /// keeps in its journal. On Tokio the journal is printed to stderr when
+    /// the supervisor completes or escalates.
+    pub journal_capacity: Option<usize>,
+}
+
+/// `"concurrent"` starts every child whose dependencies are up at once,
+/// `"sequential"` one at a time.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartOrderConfig {
+    Concurrent,
+    Sequential,
 }
 
 /// A value in `[supervision.policies]` — restart or stop policy for a child.
@@ -563,6 +645,40 @@ pub struct ChildPolicyConfig {
     pub restart: Option<RestartPolicy>,
     /// When `true`, the supervisor stops the entire group when this child terminates.
     pub stop: Option<bool>,
+    /// Which exits are restarted (`RestartType`). Defaults to `"permanent"`.
+    ///
+    /// ```toml
+    /// worker = { restart = { max = 3 }, type = "transient" }
+    /// ```
+    #[serde(rename = "type")]
+    pub restart_type: Option<RestartTypeConfig>,
+    /// Set to `false` to exclude this child from health checks.
+    pub health_check: Option<bool>,
+    /// Ping this child at most once per `health_check_interval_ms` instead
+    /// of on every health-check tick (`Liveness::Every`).
+    ///
+    /// ```toml
+    /// logger = { stop = true, health_check_interval_ms = 10000 }
+    /// ```
+    pub health_check_interval_ms: Option<u64>,
+    /// Children of the same supervision entry that must have started
+    /// before this one is started (and restarted under `rest_for_one`).
+    ///
+    /// ```toml
+    /// api = { restart = { max = 3 }, depends_on = ["db"] }
+    /// ```
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+/// `"permanent"` restarts after `Done` and `Failed`, `"transient"` only
+/// after `Failed`, and `"temporary"` never.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartTypeConfig {
+    Permanent,
+    Transient,
+    Temporary,
 }
 
 /// Restart policy parameters.
@@ -570,4 +686,57 @@ pub struct ChildPolicyConfig {
 pub struct RestartPolicy {
     /// Maximum restart attempts before escalation.
     pub max: u32,
+    /// When set, only restarts within the last `within_ms` milliseconds
+    /// count towards `max` (`ChildPolicy::RestartWithin`). Otherwise `max`
+    /// is a lifetime budget.
+    pub within_ms: Option<u64>,
+    /// Optional delay before each restart. Requires a timer actor.
+    ///
+    /// ```toml
+    /// ping = { restart = { max = 5, backoff = { kind = "exponential", initial_ms = 100, max_ms = 5000, jitter_ms = 50 } } }
+    /// ```
+    pub backoff: Option<BackoffConfig>,
+}
+
+/// Restart backoff, tagged by `kind`. `jitter_ms` adds up to that many
+/// milliseconds of random delay and defaults to 0.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackoffConfig {
+    /// `{ kind = "fixed", delay_ms = 500 }`
+    Fixed {
+        delay_ms: u64,
+        #[serde(default)]
+        jitter_ms: u64,
+    },
+    /// `{ kind = "exponential", initial_ms = 100, max_ms = 5000 }` —
+    /// doubles per restart, capped at `max_ms`.
+    Exponential {
+        initial_ms: u64,
+        max_ms: u64,
+        #[serde(default)]
+        jitter_ms: u64,
+    },
+}
+
+/// Consecutive health-check misses that fail a child.
+#[derive(Debug, Deserialize, Clone)]
+pub struct HealthCheckThresholdsConfig {
+    pub max_missed: Option<u32>,
+    pub max_undelivered: Option<u32>,
+}
+
+/// How long shutdown waits at each level before escalating.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ShutdownTimeoutsConfig {
+    pub stop_ms: u64,
+    pub abort_ms: u64,
+}
+
+/// Group-wide restart intensity: at most `max` restarts within any
+/// `within_ms` window.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RestartIntensityConfig {
+    pub max: u32,
+    pub within_ms: u64,
 }
@@ -328,6 +328,20 @@ pub fn generate(
         }
     };
 
+    // on_stop expression — graceful Stop event
+    let stop_event_fn = if let Some(ref expr) = context.on_stop {
+        let expr: proc_macro2::TokenStream = expr
+            .parse()
+            .map_err(|e| anyhow::anyhow!("invalid on_stop expression '{}': {}", expr, e))?;
+        quote! {
+            fn stop_event(ctx: &Self::Ctx) -> Option<Self::Event> {
+                Some(#expr)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // ── Build imports ─────────────────────────────────────────────────────────
     let mut use_stmts = vec![quote! { use ::core::marker::PhantomData; }];
 
@@ -911,6 +925,8 @@ pub fn generate(
                 }
 
                 #on_init_fn
+
+                #stop_event_fn
             }
         };
 
@@ -1,12 +1,18 @@
 // Copyright 2025 Bloxide, all rights reserved
 //! Generate a complete binary `main.rs` from a `system.toml` wiring manifest.
 
-use crate::schema::{BloxConfig, SystemConfig};
+use crate::schema::{
+    BackoffConfig, BloxConfig, RestartTypeConfig, StartOrderConfig, SupervisionConfig, SystemConfig,
+};
 use quote::{format_ident, quote};
 use std::collections::{BTreeMap, BTreeSet};
 
 const HEADER: &str = "// Copyright 2025 Bloxide, all rights reserved\n// Auto-generated by bloxide-codegen. Do not edit manually.\n";
 
+/// Grace period for a Tokio system's shutdown when `system.toml` does not
+/// set `shutdown_timeout_ms`.
+const DEFAULT_SHUTDOWN_TIMEOUT_MS: u64 = 5000;
+
 fn crate_name(s: &str) -> String {
     s.replace("-", "_")
 }
@@ -62,6 +68,22 @@ fn collect_ctor_fields(
                     is_delegate: false,
                 });
             }
+            // Sub-fields are constructor params only with role "ctor".
+            for f in &u.fields {
+                if f.role.as_deref() != Some("ctor") {
+                    continue;
+                }
+                if let Some(feat) = &f.feature {
+                    if !enabled.map(|s| s.contains(feat)).unwrap_or(false) {
+                        continue;
+                    }
+                }
+                fields.push(CtorField {
+                    name: f.name.clone(),
+                    is_self_id: false,
+                    is_delegate: false,
+                });
+            }
         }
 
         // 2. behavior is LAST in the constructor (when delegatable uses exist).
@@ -135,6 +157,125 @@ fn primary_message(blox_config: &BloxConfig) -> Option<(String, String)> {
     }
 }
 
+/// Name a per-supervisor variable: `base` alone when the system has a single
+/// supervision entry, `base_<idx>` otherwise.
+fn supervisor_ident(config: &SystemConfig, idx: usize, base: &str) -> proc_macro2::Ident {
+    if config.supervision.len() == 1 {
+        format_ident!("{}", base)
+    } else {
+        format_ident!("{}_{}", base, idx)
+    }
+}
+
+/// Resolve the supervision tree: for every `[[supervision]]` entry, the
+/// index of the entry that lists it as a child (if any), plus an order in
+/// which each nested supervisor comes before its parent.
+fn supervision_tree(config: &SystemConfig) -> anyhow::Result<(Vec<Option<usize>>, Vec<usize>)> {
+    let actor_names: BTreeSet<&str> = config.actors.iter().map(|a| a.name.as_str()).collect();
+    let mut by_name = BTreeMap::new();
+    for (idx, sup) in config.supervision.iter().enumerate() {
+        if let Some(name) = &sup.name {
+            if actor_names.contains(name.as_str()) || name == "supervisor" {
+                anyhow::bail!("supervisor name '{}' is already taken", name);
+            }
+            if by_name.insert(name.as_str(), idx).is_some() {
+                anyhow::bail!("supervisor name '{}' is declared twice", name);
+            }
+        }
+    }
+
+    let mut parent = vec![None; config.supervision.len()];
+    for (idx, sup) in config.supervision.iter().enumerate() {
+        for child in &sup.children {
+            if let Some(&nested) = by_name.get(child.as_str()) {
+                if let Some(other) = parent[nested] {
+                    anyhow::bail!(
+                        "supervisor '{}' is a child of both supervision entries {} and {}",
+                        child,
+                        other,
+                        idx
+                    );
+                }
+                parent[nested] = Some(idx);
+            }
+        }
+    }
+
+    // Post-order walk from the roots. An entry that is never reached sits on
+    // a cycle.
+    fn visit(idx: usize, parent: &[Option<usize>], order: &mut Vec<usize>) {
+        for (nested, p) in parent.iter().enumerate() {
+            if *p == Some(idx) {
+                visit(nested, parent, order);
+            }
+        }
+        order.push(idx);
+    }
+    let mut order = Vec::new();
+    for (idx, p) in parent.iter().enumerate() {
+        if p.is_none() {
+            visit(idx, &parent, &mut order);
+        }
+    }
+    if order.len() != config.supervision.len() {
+        anyhow::bail!("supervision entries form a cycle");
+    }
+    Ok((parent, order))
+}
+
+/// Check a supervision entry's `depends_on` lists: every dependency is a
+/// sibling, and no child waits, directly or through others, on itself or,
+/// with sequential start, on a child that starts after it.
+fn validate_depends_on(sup: &SupervisionConfig) -> anyhow::Result<()> {
+    let sequential = sup.start_order == Some(StartOrderConfig::Sequential);
+    for (pos, child) in sup.children.iter().enumerate() {
+        let Some(policy) = sup.policies.get(child) else {
+            continue;
+        };
+        for dep in &policy.depends_on {
+            let Some(dep_pos) = sup.children.iter().position(|c| c == dep) else {
+                anyhow::bail!(
+                    "child '{}' depends on '{}', which is not a child of the same supervisor",
+                    child,
+                    dep
+                );
+            };
+            if sequential && dep_pos >= pos {
+                anyhow::bail!(
+                    "child '{}' depends on '{}', which starts after it in sequential order",
+                    child,
+                    dep
+                );
+            }
+        }
+    }
+
+    // Repeatedly take the children whose dependencies are all taken; any
+    // left over wait on each other.
+    let mut ready: BTreeSet<&str> = BTreeSet::new();
+    loop {
+        let before = ready.len();
+        for child in &sup.children {
+            let deps_ready = sup.policies.get(child).is_none_or(|policy| {
+                policy
+                    .depends_on
+                    .iter()
+                    .all(|dep| ready.contains(dep.as_str()))
+            });
+            if deps_ready {
+                ready.insert(child);
+            }
+        }
+        if ready.len() == before {
+            break;
+        }
+    }
+    if let Some(child) = sup.children.iter().find(|c| !ready.contains(c.as_str())) {
+        anyhow::bail!("child '{}' is part of a depends_on cycle", child);
+    }
+    Ok(())
+}
+
 fn validate(
     config: &SystemConfig,
     blox_configs: &BTreeMap<String, BloxConfig>,
@@ -167,6 +308,14 @@ fn validate(
                     // OK — implicit supervisor actor.
                     continue;
                 }
+                if config
+                    .supervision
+                    .iter()
+                    .any(|sup| sup.name.as_deref() == Some(src))
+                {
+                    // OK — named supervisor.
+                    continue;
+                }
                 if !actor_names.contains(src) {
                     anyhow::bail!(
                         "actor '{}' inject field '{}' references unknown actor '{}'",
@@ -229,35 +378,26 @@ pub fn generate(
 
     let is_tokio = config.system.runtime == "tokio";
     let is_embassy = config.system.runtime == "embassy";
-    if !is_tokio && !is_embassy {
+    let is_std = config.system.runtime == "std";
+    if !is_tokio && !is_embassy && !is_std {
         anyhow::bail!(
-            "unsupported system runtime '{}', expected 'tokio' or 'embassy'",
+            "unsupported system runtime '{}', expected 'tokio', 'embassy' or 'std'",
             config.system.runtime
         );
     }
+    // Tokio and std spawn tasks without a spawner handle.
+    let needs_spawner = is_embassy;
 
-    let runtime_crate_ident = format_ident!(
-        "{}",
-        if is_tokio {
-            "bloxide_tokio"
-        } else {
-            "bloxide_embassy"
-        }
-    );
-    let runtime_ident = format_ident!(
-        "{}",
-        if is_tokio {
-            "TokioRuntime"
-        } else {
-            "EmbassyRuntime"
-        }
-    );
-
-    let runtime_ident_str = if is_tokio {
-        "TokioRuntime".to_string()
+    let (runtime_crate, runtime_name) = if is_tokio {
+        ("bloxide_tokio", "TokioRuntime")
+    } else if is_std {
+        ("bloxide_std", "StdRuntime")
     } else {
-        "EmbassyRuntime".to_string()
+        ("bloxide_embassy", "EmbassyRuntime")
     };
+    let runtime_crate_ident = format_ident!("{}", runtime_crate);
+    let runtime_ident = format_ident!("{}", runtime_name);
+    let runtime_ident_str = runtime_name.to_string();
 
     let binary_name = config.system.name.as_deref().unwrap_or("main");
     let done_str = format!("{} complete", binary_name);
@@ -323,9 +463,11 @@ pub fn generate(
         }
     }
 
+    // Channels name their message types by path; only bootstrap sends need
+    // the type in scope.
     let mut message_imports: BTreeSet<(String, String)> = BTreeSet::new();
     for actor in &config.actors {
-        if actor.kind.as_deref() == Some("timer") {
+        if actor.kind.as_deref() == Some("timer") || actor.bootstrap.is_empty() {
             continue;
         }
         if let Some(blox_config) = blox_configs.get(&actor.blox) {
@@ -359,6 +501,10 @@ pub fn generate(
         .any(|a| a.kind.as_deref() == Some("timer"));
     if has_timer {
         if is_tokio {
+            timer_stmts.push(quote! {
+                let timer_ref = ::#runtime_crate_ident::spawn_timer!(8, system);
+            });
+        } else if !needs_spawner {
             timer_stmts.push(quote! {
                 let timer_ref = ::#runtime_crate_ident::spawn_timer!(8);
             });
@@ -497,10 +643,13 @@ pub fn generate(
     // - (supervisor, "notify")  → extracted notify_ref from ChildGroupBuilder
     let mut symbol_table: BTreeMap<(String, String), String> = BTreeMap::new();
     let mut supervisor_setup_stmts = Vec::new();
-    let mut supervisor_finish_stmts = Vec::new();
+    let mut finish_stmts_by_sup = Vec::new();
     let mut root_task_decls = Vec::new();
+    let (sup_parent, sup_order) = supervision_tree(config)?;
 
     for (idx, sup) in config.supervision.iter().enumerate() {
+        let nested = sup_parent[idx].is_some();
+        let mut supervisor_finish_stmts = Vec::new();
         let group_ident = if config.supervision.len() == 1 {
             format_ident!("group")
         } else {
@@ -546,45 +695,167 @@ pub fn generate(
             quote! { GroupShutdown::WhenAnyDone }
         };
 
+        let intensity = sup.restart_intensity.as_ref().map(|intensity| {
+            let max = intensity.max;
+            let within_ms = intensity.within_ms;
+            quote! { .with_restart_intensity(#max as usize, #within_ms) }
+        });
+        let shutdown_timeouts = match &sup.shutdown_timeouts {
+            None => None,
+            Some(_) if !has_timer => {
+                anyhow::bail!("shutdown_timeouts need a timer actor but the system declares none")
+            }
+            Some(timeouts) => {
+                let stop_ms = timeouts.stop_ms;
+                let abort_ms = timeouts.abort_ms;
+                Some(quote! { .with_shutdown_timeouts(#stop_ms, #abort_ms) })
+            }
+        };
+        validate_depends_on(sup)?;
+        let start_order = (sup.start_order == Some(StartOrderConfig::Sequential))
+            .then(|| quote! { .with_start_order(StartOrder::Sequential) });
+        let start_timeout = match sup.start_timeout_ms {
+            None => None,
+            Some(_) if !has_timer => {
+                anyhow::bail!("start_timeout_ms needs a timer actor but the system declares none")
+            }
+            Some(timeout_ms) => Some(quote! { .with_start_timeout(#timeout_ms) }),
+        };
+        let journal = sup.journal_capacity.map(|capacity| {
+            let sink = is_tokio.then(|| {
+                quote! { .with_journal_sink(::bloxide_tokio::journal::print_journal) }
+            });
+            quote! { .with_journal(#capacity) #sink }
+        });
+        let health_thresholds = sup.health_check_thresholds.as_ref().map(|thresholds| {
+            let max_missed = thresholds.max_missed.unwrap_or(1);
+            let max_undelivered = thresholds.max_undelivered.unwrap_or(1);
+            quote! { .with_health_thresholds(#max_missed, #max_undelivered) }
+        });
+
+        // Restart backoffs, shutdown and start deadlines need the supervisor
+        // to own a timer.
+        let needs_timer = sup.shutdown_timeouts.is_some()
+            || sup.start_timeout_ms.is_some()
+            || sup.policies.iter().any(|(child, policy)| {
+                sup.children.contains(child)
+                    && policy
+                        .restart
+                        .as_ref()
+                        .is_some_and(|restart| restart.backoff.is_some())
+            });
+
+        // The supervisor actor name comes from the supervision entry —
+        // we use "supervisor" as the canonical name (matching the system.toml
+        // `actor = "supervisor"` convention).
+        // A named supervisor is also reachable under its own name.
+        let mut sup_names = sup.name.iter().cloned().collect::<Vec<_>>();
+        if !nested {
+            sup_names.push("supervisor".to_string());
+        }
+
+        // The control ref is only extracted where something uses it: the
+        // timer, an actor it is injected into, or the Tokio system handle,
+        // which shuts root supervisors down through it.
+        let control_injected = config.actors.iter().any(|actor| {
+            actor.inject.values().any(|source| {
+                source.source == "actor"
+                    && source.field.as_deref() == Some("control")
+                    && source
+                        .actor
+                        .as_ref()
+                        .is_some_and(|name| sup_names.contains(name))
+            })
+        });
+        let control_ref_stmt = (needs_timer || control_injected || (is_tokio && !nested))
+            .then(|| quote! { let #control_ref_ident = #group_ident.control_ref(); });
+
         // Phase 1: create builder + extract control_ref and notify_ref.
         supervisor_setup_stmts.push(quote! {
-            let mut #group_ident = ChildGroupBuilder::new(#shutdown_strategy);
-            let #control_ref_ident = #group_ident.control_ref();
+            let mut #group_ident = ChildGroupBuilder::new(#shutdown_strategy) #intensity #shutdown_timeouts #health_thresholds #start_order #start_timeout #journal;
+            #control_ref_stmt
             let #notify_ref_ident = #group_ident.notify_ref();
         });
 
         // Register refs in symbol table.
-        // The supervisor actor name comes from the supervision entry —
-        // we use "supervisor" as the canonical name (matching the system.toml
-        // `actor = "supervisor"` convention).
-        let sup_name = "supervisor".to_string();
-        symbol_table.insert(
-            (sup_name.clone(), "control".to_string()),
-            control_ref_ident.to_string(),
-        );
-        symbol_table.insert(
-            (sup_name.clone(), "notify".to_string()),
-            notify_ref_ident.to_string(),
-        );
+        for sup_name in sup_names {
+            symbol_table.insert(
+                (sup_name.clone(), "control".to_string()),
+                control_ref_ident.to_string(),
+            );
+            symbol_table.insert(
+                (sup_name.clone(), "notify".to_string()),
+                notify_ref_ident.to_string(),
+            );
+        }
 
         // Phase 2: add children, finish, construct supervisor (after machines).
+        let mut child_id_idents = BTreeMap::new();
         for child_name in &sup.children {
-            let child_actor = config
-                .actors
+            let nested_idx = config
+                .supervision
                 .iter()
-                .find(|a| &a.name == child_name)
-                .ok_or_else(|| {
-                    anyhow::anyhow!("supervision child '{}' not declared", child_name)
-                })?;
-            let child_mbox_ident = format_ident!("{}_mbox", child_actor.name);
-            let child_id_ident = format_ident!("{}_id", child_actor.name);
-            let child_machine_ident = format_ident!("{}_machine", child_actor.name);
-            let child_task_ident = format_ident!("{}_task", child_actor.name);
+                .position(|s| s.name.as_ref() == Some(child_name));
+            let (child_mbox, child_id_ident, child_machine_ident, child_task_ident) =
+                if let Some(nested_idx) = nested_idx {
+                    // A nested supervisor: its mailboxes are the receivers
+                    // of its own group, finished before this one.
+                    let notify_rx = supervisor_ident(config, nested_idx, "sup_notify_rx");
+                    let control_rx = supervisor_ident(config, nested_idx, "sup_control_rx");
+                    (
+                        quote! { (#notify_rx, #control_rx) },
+                        supervisor_ident(config, nested_idx, "sup_id"),
+                        supervisor_ident(config, nested_idx, "sup_machine"),
+                        supervisor_ident(config, nested_idx, "supervisor_task"),
+                    )
+                } else {
+                    let child_actor = config
+                        .actors
+                        .iter()
+                        .find(|a| &a.name == child_name)
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("supervision child '{}' not declared", child_name)
+                        })?;
+                    let child_mbox_ident = format_ident!("{}_mbox", child_actor.name);
+                    (
+                        quote! { #child_mbox_ident },
+                        format_ident!("{}_id", child_actor.name),
+                        format_ident!("{}_machine", child_actor.name),
+                        format_ident!("{}_task", child_actor.name),
+                    )
+                };
 
             let policy = if let Some(policy_config) = sup.policies.get(child_name) {
                 if let Some(restart) = &policy_config.restart {
                     let max = restart.max;
-                    quote! { ChildPolicy::Restart { max: #max as usize } }
+                    let backoff = match &restart.backoff {
+                        None => quote! { Backoff::Immediate },
+                        Some(_) if !has_timer => anyhow::bail!(
+                            "child '{}' has a restart backoff but the system declares no timer actor",
+                            child_name
+                        ),
+                        Some(BackoffConfig::Fixed {
+                            delay_ms,
+                            jitter_ms,
+                        }) => quote! {
+                            Backoff::Fixed { delay_ms: #delay_ms, jitter_ms: #jitter_ms }
+                        },
+                        Some(BackoffConfig::Exponential {
+                            initial_ms,
+                            max_ms,
+                            jitter_ms,
+                        }) => quote! {
+                            Backoff::Exponential { initial_ms: #initial_ms, max_ms: #max_ms, jitter_ms: #jitter_ms }
+                        },
+                    };
+                    match restart.within_ms {
+                        Some(within_ms) => quote! {
+                            ChildPolicy::RestartWithin { max: #max as usize, within_ms: #within_ms, backoff: #backoff }
+                        },
+                        None => {
+                            quote! { ChildPolicy::Restart { max: #max as usize, backoff: #backoff } }
+                        }
+                    }
                 } else {
                     quote! { ChildPolicy::Stop }
                 }
@@ -593,10 +864,18 @@ pub fn generate(
             };
 
             if is_tokio {
+                supervisor_finish_stmts.push(quote! {
+                    system.track(::#runtime_crate_ident::spawn_child!(
+                        #group_ident,
+                        #child_task_ident(#child_machine_ident, #child_mbox, #child_id_ident),
+                        #policy
+                    ));
+                });
+            } else if !needs_spawner {
                 supervisor_finish_stmts.push(quote! {
                     ::#runtime_crate_ident::spawn_child!(
                         #group_ident,
-                        #child_task_ident(#child_machine_ident, #child_mbox_ident, #child_id_ident),
+                        #child_task_ident(#child_machine_ident, #child_mbox, #child_id_ident),
                         #policy
                     );
                 });
@@ -605,11 +884,65 @@ pub fn generate(
                     ::#runtime_crate_ident::spawn_child!(
                         spawner,
                         #group_ident,
-                        #child_task_ident(#child_machine_ident, #child_mbox_ident, #child_id_ident),
+                        #child_task_ident(#child_machine_ident, #child_mbox, #child_id_ident),
                         #policy
                     );
                 });
             }
+
+            // Children are added as permanent.
+            let restart_type = match sup.policies.get(child_name).and_then(|p| p.restart_type) {
+                None | Some(RestartTypeConfig::Permanent) => None,
+                Some(RestartTypeConfig::Transient) => Some(quote! { RestartType::Transient }),
+                Some(RestartTypeConfig::Temporary) => Some(quote! { RestartType::Temporary }),
+            };
+            if let Some(restart_type) = restart_type {
+                supervisor_finish_stmts.push(quote! {
+                    #group_ident.set_restart_type(#child_id_ident, #restart_type);
+                });
+            }
+
+            // Children are pinged on every health-check tick by default.
+            let policy = sup.policies.get(child_name);
+            let liveness = match (
+                policy.and_then(|p| p.health_check),
+                policy.and_then(|p| p.health_check_interval_ms),
+            ) {
+                (Some(false), Some(_)) => anyhow::bail!(
+                    "child '{}' sets both health_check = false and health_check_interval_ms",
+                    child_name
+                ),
+                (Some(false), None) => Some(quote! { Liveness::Disabled }),
+                (_, Some(interval_ms)) => {
+                    Some(quote! { Liveness::Every { interval_ms: #interval_ms } })
+                }
+                (_, None) => None,
+            };
+            if let Some(liveness) = liveness {
+                supervisor_finish_stmts.push(quote! {
+                    #group_ident.set_liveness(#child_id_ident, #liveness);
+                });
+            }
+            child_id_idents.insert(child_name.as_str(), child_id_ident);
+        }
+
+        // Dependencies may name children spawned later, so they are set once
+        // every child id exists.
+        for child_name in &sup.children {
+            let Some(policy) = sup.policies.get(child_name) else {
+                continue;
+            };
+            if policy.depends_on.is_empty() {
+                continue;
+            }
+            let child_id_ident = &child_id_idents[child_name.as_str()];
+            let dep_idents = policy
+                .depends_on
+                .iter()
+                .map(|dep| &child_id_idents[dep.as_str()]);
+            supervisor_finish_stmts.push(quote! {
+                #group_ident.set_depends_on(#child_id_ident, &[#(#dep_idents),*]);
+            });
         }
 
         supervisor_finish_stmts.push(quote! {
@@ -626,13 +959,29 @@ pub fn generate(
             syn::parse_str("::bloxide_supervisor::SupervisorEvent")
                 .expect("valid supervisor event path");
 
+        let supervisor_timer = needs_timer.then(|| {
+            quote! { .with_timer(timer_ref.clone(), #control_ref_ident.clone()) }
+        });
+
+        // A nested supervisor reports to its parent when it gives up, and is
+        // started, stopped and restarted by the parent like any other child.
+        if nested {
+            supervisor_finish_stmts.push(quote! {
+                let #sup_ctx_ident = #supervisor_ctx_path::new(#sup_id_ident, children, #notify_ref_ident) #supervisor_timer .escalate_to_parent();
+                let #sup_machine_ident = ::bloxide_core::StateMachine::<#supervisor_spec_path<#runtime_ident>>::new(#sup_ctx_ident);
+            });
+            root_task_decls.push(quote! {
+                ::#runtime_crate_ident::actor_task_supervised!(#task_ident, #supervisor_spec_path<#runtime_ident>);
+            });
+            finish_stmts_by_sup.push(supervisor_finish_stmts);
+            continue;
+        }
         supervisor_finish_stmts.push(quote! {
-            let #sup_ctx_ident = #supervisor_ctx_path::new(children, #sup_id_ident, #notify_ref_ident);
+            let #sup_ctx_ident = #supervisor_ctx_path::new(#sup_id_ident, children, #notify_ref_ident) #supervisor_timer;
             let mut #sup_machine_ident = ::bloxide_core::StateMachine::<#supervisor_spec_path<#runtime_ident>>::new(#sup_ctx_ident);
             #sup_machine_ident.dispatch(#supervisor_event_path::<#runtime_ident>::Lifecycle(LifecycleCommand::Start));
         });
-
-        if is_tokio {
+        if !needs_spawner {
             root_task_decls.push(quote! {
                 ::#runtime_crate_ident::root_task!(#task_ident, #supervisor_spec_path<#runtime_ident>);
             });
@@ -641,7 +990,13 @@ pub fn generate(
                 ::#runtime_crate_ident::root_task!(#task_ident, #supervisor_spec_path<#runtime_ident>, std::process::exit(0));
             });
         }
+        finish_stmts_by_sup.push(supervisor_finish_stmts);
     }
+    // Nested supervisors are finished before the parents that spawn them.
+    let supervisor_finish_stmts: Vec<_> = sup_order
+        .iter()
+        .flat_map(|&idx| finish_stmts_by_sup[idx].clone())
+        .collect();
 
     // ── Actor task declarations (file level) ──────────────────────────────
     let mut task_decls = Vec::new();
@@ -808,6 +1163,10 @@ pub fn generate(
     // ── Supervisor run statements ───────────────────────────────────────────
     let mut supervisor_run_stmts = Vec::new();
     for (idx, _sup) in config.supervision.iter().enumerate() {
+        if sup_parent[idx].is_some() {
+            // Spawned by its parent's group.
+            continue;
+        }
         let task_ident = if config.supervision.len() == 1 {
             format_ident!("supervisor_task")
         } else {
@@ -832,8 +1191,20 @@ pub fn generate(
         // Unified supervisor run — tuple mailboxes (child_rx, control_rx).
         // No feature gating, no SupervisorMailboxes, no spawn_rx.
         if is_tokio {
+            // Each root runs as its own task; the system handle stops them
+            // one at a time, last started first.
+            let control_ref_ident = format_ident!("sup_control_ref_{}", idx);
+            supervisor_run_stmts.push(quote! {
+                system.add_root(
+                    ::tokio::spawn(#task_ident(#sup_machine_ident, (#sup_notify_rx_ident, #sup_control_rx_ident))),
+                    #control_ref_ident,
+                    ::bloxide_supervisor::SupervisorControl::Shutdown,
+                    ::bloxide_supervisor::SupervisorControl::EscalateShutdown,
+                );
+            });
+        } else if is_std {
             supervisor_run_stmts.push(quote! {
-                #task_ident(#sup_machine_ident, (#sup_notify_rx_ident, #sup_control_rx_ident)).await;
+                #task_ident(#sup_machine_ident, (#sup_notify_rx_ident, #sup_control_rx_ident));
             });
         } else {
             supervisor_run_stmts.push(quote! {
@@ -890,17 +1261,45 @@ pub fn generate(
     }
 
     // ── Main function ───────────────────────────────────────────────────────
+    let log_init = quote! {
+        tracing_log::LogTracer::init().ok();
+        tracing_subscriber::fmt()
+            .with_env_filter(
+                tracing_subscriber::EnvFilter::try_from_default_env()
+                    .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+            )
+            .try_init()
+            .ok();
+    };
     let main_fn = if is_tokio {
+        let shutdown_timeout_ms = config
+            .system
+            .shutdown_timeout_ms
+            .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_MS);
         quote! {
-            async fn main() {
-                tracing_log::LogTracer::init().ok();
-                tracing_subscriber::fmt()
-                    .with_env_filter(
-                        tracing_subscriber::EnvFilter::try_from_default_env()
-                            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
-                    )
-                    .try_init()
-                    .ok();
+            async fn main() -> ::std::process::ExitCode {
+                #log_init
+
+                let mut system = ::#runtime_crate_ident::SystemHandle::new(
+                    ::std::time::Duration::from_millis(#shutdown_timeout_ms),
+                );
+                #(#timer_stmts)*
+                #(#channel_stmts)*
+                #(#supervisor_setup_stmts)*
+                #(#ctx_stmts)*
+                #(#machine_stmts)*
+                #(#supervisor_finish_stmts)*
+                #(#bootstrap_send_stmts)*
+                #(#supervisor_run_stmts)*
+                let report = system.run().await;
+                println!(#done_lit);
+                report.exit_code()
+            }
+        }
+    } else if is_std {
+        quote! {
+            fn main() {
+                #log_init
 
                 #(#timer_stmts)*
                 #(#channel_stmts)*
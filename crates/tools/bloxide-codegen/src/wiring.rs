@@ -208,7 +208,7 @@ pub fn generate(config: &WiringConfig, _crate_name: &str) -> anyhow::Result<Stri
                 let machine_ident = format_ident!("{}_machine", child_actor.name);
                 let task_ident = format_ident!("{}_task", child_actor.name);
                 let policy = if let Some(max) = child.restart_max {
-                    quote! { ::bloxide_child_management::ChildPolicy::Restart { max: #max } }
+                    quote! { ::bloxide_child_management::ChildPolicy::Restart { max: #max, backoff: ::bloxide_child_management::Backoff::Immediate } }
                 } else {
                     quote! { ::bloxide_child_management::ChildPolicy::Stop }
                 };
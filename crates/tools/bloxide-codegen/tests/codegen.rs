@@ -1,8 +1,11 @@
 // Copyright 2025 Bloxide, all rights reserved
 //! Integration tests for bloxide-codegen.
 
-use bloxide_codegen::schema::{BloxConfig, SystemConfig};
+use bloxide_codegen::schema::{
+    BackoffConfig, BloxConfig, RestartTypeConfig, StartOrderConfig, SystemConfig,
+};
 use bloxide_codegen::{generate_all, generate_from_toml};
+use std::collections::BTreeMap;
 
 #[test]
 fn test_parse_counter_messages_toml() {
@@ -140,6 +143,91 @@ name = "Resume"
     assert!(content.contains("PingPongMsg::Resume(..) => \"Resume\""));
 }
 
+#[test]
+fn test_generate_messages_serde() {
+    let toml = r#"
+[[messages]]
+name = "PingPongMsg"
+visibility = "pub"
+serialize = "serde"
+
+[[messages.variants]]
+name = "Ping"
+[[messages.variants.fields]]
+name = "round"
+ty = "u32"
+
+[[messages.variants]]
+name = "Resume"
+"#;
+
+    let config: BloxConfig = toml::from_str(toml).expect("parse failed");
+    assert_eq!(
+        config.messages.as_ref().unwrap()[0].serialize.as_deref(),
+        Some("serde")
+    );
+    let files = generate_all(&config, "ping-pong-messages").expect("generate failed");
+    let (_name, content) = files
+        .iter()
+        .find(|(n, _)| n == "messages_pingpongmsg.rs")
+        .expect("messages file missing");
+
+    // Payload structs derive serde behind the crate's `serde` feature.
+    assert!(content.contains(
+        "#[cfg_attr(feature = \"serde\", derive(::serde::Serialize, ::serde::Deserialize))]"
+    ));
+    // The enum is keyed by wire tag rather than serde's variant index.
+    assert!(content.contains("pub const fn wire_tag(&self) -> u32 {"));
+    let ping_tag = bloxide_codegen::message_wire_tag("Ping");
+    assert!(content.contains(&format!("PingPongMsg::Ping(..) => {ping_tag}u32")));
+    assert!(content.contains("impl ::serde::Serialize for PingPongMsg {"));
+    assert!(content.contains("impl<'de> ::serde::Deserialize<'de> for PingPongMsg {"));
+}
+
+#[test]
+fn test_message_wire_tag_is_stable() {
+    // FNV-1a 32 — these values are part of the wire format and must not change.
+    assert_eq!(bloxide_codegen::message_wire_tag(""), 0x811c_9dc5);
+    assert_eq!(bloxide_codegen::message_wire_tag("Ping"), 2142761129);
+    assert_eq!(bloxide_codegen::message_wire_tag("Pong"), 3787107799);
+}
+
+#[test]
+fn test_generate_messages_unknown_serializer_fails() {
+    let toml = r#"
+[[messages]]
+name = "CounterMsg"
+serialize = "bincode"
+
+[[messages.variants]]
+name = "Tick"
+"#;
+
+    let config: BloxConfig = toml::from_str(toml).expect("parse failed");
+    let err = generate_all(&config, "counter-messages").expect_err("should reject");
+    assert!(err.to_string().contains("bincode"));
+}
+
+#[test]
+fn test_generate_messages_without_serde_has_no_serde_items() {
+    let toml = r#"
+[[messages]]
+name = "CounterMsg"
+
+[[messages.variants]]
+name = "Tick"
+"#;
+
+    let config: BloxConfig = toml::from_str(toml).expect("parse failed");
+    let files = generate_all(&config, "counter-messages").expect("generate failed");
+    let (_name, content) = files
+        .iter()
+        .find(|(n, _)| n == "messages_countermsg.rs")
+        .expect("messages file missing");
+    assert!(!content.contains("serde"));
+    assert!(!content.contains("wire_tag"));
+}
+
 #[test]
 fn test_generate_counter_event() {
     let toml = r#"
@@ -852,6 +940,57 @@ terminal = true
     assert!(content.contains("fn on_init_entry"));
 }
 
+#[test]
+fn test_generate_spec_skeleton_on_stop() {
+    let toml = r#"
+[actor]
+name = "Drain"
+
+[event]
+name = "DrainEvent"
+
+[[event.mailboxes]]
+variant = "Msg"
+message = "DrainMsg"
+message_path = "drain_messages::DrainMsg"
+
+[context]
+name = "DrainCtx"
+on_stop = "DrainEvent::Msg(Envelope(ctx.self_id, DrainMsg::Flush))"
+
+[topology]
+
+[[topology.states]]
+name = "Draining"
+initial = true
+
+[[topology.states]]
+name = "Drained"
+terminal = true
+"#;
+
+    let config: BloxConfig = toml::from_str(toml).expect("parse failed");
+    let files = generate_all(&config, "drain-blox").expect("generate failed");
+    let content = &files
+        .iter()
+        .find(|(n, _)| n == "spec_skeleton.rs")
+        .expect("spec_skeleton.rs missing")
+        .1;
+
+    assert!(content.contains("fn stop_event"));
+    assert!(content.contains("DrainMsg::Flush"));
+
+    // Without `on_stop` the spec keeps the engine's immediate Stop
+    let config: BloxConfig = toml::from_str(&toml.replace("on_stop", "# on_stop")).unwrap();
+    let files = generate_all(&config, "drain-blox").expect("generate failed");
+    let content = &files
+        .iter()
+        .find(|(n, _)| n == "spec_skeleton.rs")
+        .unwrap()
+        .1;
+    assert!(!content.contains("fn stop_event"));
+}
+
 #[test]
 fn test_generate_spec_skeleton_ping() {
     let toml = r#"
@@ -1747,6 +1886,89 @@ health_check_interval_ms = 5000
     assert!(sup.policies.is_empty());
 }
 
+#[test]
+fn test_parse_system_toml_restart_intensity() {
+    // Per-child restart windows and a group-wide restart intensity.
+    let toml = r#"
+[system]
+runtime = "tokio"
+
+[[supervision]]
+supervisor = "bloxide-supervisor"
+strategy = "one_for_one"
+children = ["ping", "pong"]
+restart_intensity = { max = 10, within_ms = 60000 }
+
+  [supervision.policies]
+  ping = { restart = { max = 3, within_ms = 5000 } }
+  pong = { restart = { max = 1 } }
+"#;
+
+    let config: SystemConfig = toml::from_str(toml).expect("parse failed");
+    let sup = &config.supervision[0];
+    let intensity = sup.restart_intensity.as_ref().expect("restart_intensity");
+    assert_eq!((intensity.max, intensity.within_ms), (10, 60000));
+    let ping = sup.policies["ping"].restart.as_ref().expect("ping restart");
+    assert_eq!((ping.max, ping.within_ms), (3, Some(5000)));
+    let pong = sup.policies["pong"].restart.as_ref().expect("pong restart");
+    assert_eq!((pong.max, pong.within_ms), (1, None));
+}
+
+#[test]
+fn test_parse_system_toml_restart_backoff() {
+    // Restart backoff is tagged by `kind`; jitter_ms defaults to 0.
+    let toml = r#"
+[system]
+runtime = "tokio"
+
+[[supervision]]
+supervisor = "bloxide-supervisor"
+strategy = "one_for_one"
+children = ["ping", "pong"]
+
+  [supervision.policies]
+  ping = { restart = { max = 5, backoff = { kind = "exponential", initial_ms = 100, max_ms = 5000, jitter_ms = 50 } } }
+  pong = { restart = { max = 1, backoff = { kind = "fixed", delay_ms = 500 } } }
+"#;
+
+    let config: SystemConfig = toml::from_str(toml).expect("parse failed");
+    let policies = &config.supervision[0].policies;
+    let ping = policies["ping"].restart.as_ref().expect("ping restart");
+    assert!(matches!(
+        ping.backoff,
+        Some(BackoffConfig::Exponential {
+            initial_ms: 100,
+            max_ms: 5000,
+            jitter_ms: 50
+        })
+    ));
+    let pong = policies["pong"].restart.as_ref().expect("pong restart");
+    assert!(matches!(
+        pong.backoff,
+        Some(BackoffConfig::Fixed {
+            delay_ms: 500,
+            jitter_ms: 0
+        })
+    ));
+}
+
+#[test]
+fn test_parse_system_toml_shutdown_timeout() {
+    // shutdown_timeout_ms is optional and only consulted for Tokio.
+    let toml = r#"
+[system]
+runtime = "tokio"
+shutdown_timeout_ms = 2000
+"#;
+
+    let config: SystemConfig = toml::from_str(toml).expect("parse failed");
+    assert_eq!(config.system.shutdown_timeout_ms, Some(2000));
+
+    let config: SystemConfig =
+        toml::from_str("[system]\nruntime = \"tokio\"\n").expect("parse failed");
+    assert_eq!(config.system.shutdown_timeout_ms, None);
+}
+
 #[test]
 fn test_parse_system_toml_actor_no_behavior() {
     // Actors without a behavior field (e.g. timer service, or bloxes with
@@ -1981,7 +2203,8 @@ actor = "pong_actor"
 
     // Spawn calls
     assert!(content.contains("::bloxide_tokio::spawn_child!"));
-    assert!(content.contains("ChildPolicy::Restart { max : 3u32 }"));
+    assert!(content.contains("ChildPolicy::Restart { max : 3u32"));
+    assert!(content.contains("Backoff::Immediate"));
     assert!(content.contains("ChildPolicy::Stop"));
 
     // Supervisor wiring
@@ -2168,3 +2391,393 @@ name = "Active"
     assert!(content.contains("HasWorkers"));
     assert!(content.contains("on_init_entry"));
 }
+
+/// Load the ping and pong blox configs, keyed by crate name.
+fn ping_pong_blox_configs() -> BTreeMap<String, BloxConfig> {
+    let bloxes = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../bloxes");
+    let mut configs = BTreeMap::new();
+    for (crate_name, dir) in [("ping-blox", "ping"), ("pong-blox", "pong")] {
+        let content =
+            std::fs::read_to_string(bloxes.join(dir).join("blox.toml")).expect("read blox.toml");
+        configs.insert(
+            crate_name.to_string(),
+            toml::from_str(&content).expect("parse blox.toml"),
+        );
+    }
+    configs
+}
+
+const NESTED_SYSTEM_TOML: &str = r#"
+[system]
+runtime = "tokio"
+
+[[actors]]
+name = "timer"
+blox = "bloxide-timer"
+kind = "timer"
+
+[[actors]]
+name = "ping"
+blox = "ping-blox"
+behavior = "PingBehavior"
+behavior_impl = "ping-pong-impl"
+
+  [actors.inject]
+  self_ref = { source = "self" }
+  peer_ref = { source = "actor", actor = "pong" }
+  timer_ref = { source = "actor", actor = "timer" }
+
+[[actors]]
+name = "pong"
+blox = "pong-blox"
+
+  [actors.inject]
+  peer_ref = { source = "actor", actor = "ping" }
+
+[[supervision]]
+supervisor = "bloxide-supervisor"
+strategy = "one_for_one"
+children = ["ping", "pongs"]
+
+  [supervision.policies]
+  ping = { restart = { max = 1 } }
+  pongs = { restart = { max = 2 } }
+
+[[supervision]]
+name = "pongs"
+supervisor = "bloxide-supervisor"
+strategy = "one_for_one"
+children = ["pong"]
+
+  [supervision.policies]
+  pong = { restart = { max = 3 } }
+"#;
+
+#[test]
+fn test_generate_system_wiring_nested_supervisor() {
+    // A named supervision entry listed as a child is spawned by its parent's
+    // group, escalates to it, and is finished before the parent.
+    let config: SystemConfig = toml::from_str(NESTED_SYSTEM_TOML).expect("parse failed");
+    assert_eq!(config.supervision[1].name.as_deref(), Some("pongs"));
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+
+    assert!(main_rs.contains("actor_task_supervised!(\n    supervisor_task_1,"));
+    assert!(main_rs.contains("root_task!(\n    supervisor_task_0,"));
+    assert!(main_rs.contains(".escalate_to_parent()"));
+    let compact: String = main_rs.split_whitespace().collect();
+    assert!(compact.contains("SupervisorCtx::new(sup_id_1,children,sup_notify_ref_1,)"));
+    // Nothing uses the nested supervisor's control ref.
+    assert!(!main_rs.contains("sup_control_ref_1"));
+    assert_eq!(main_rs.matches("LifecycleCommand::Start").count(), 1);
+    assert_eq!(main_rs.matches("supervisor_task_1(").count(), 1);
+
+    let spawn_nested = main_rs
+        .find("group_0, supervisor_task_1(sup_machine_1, (sup_notify_rx_1,")
+        .expect("nested supervisor spawned as a child of the root group");
+    let nested_finished = main_rs
+        .find("group_1.finish()")
+        .expect("nested group finished");
+    assert!(nested_finished < spawn_nested);
+
+    // Only the root is registered with the system handle, which stops it
+    // and escalates it through its own supervisor.
+    assert_eq!(main_rs.matches("add_root(").count(), 1);
+    assert!(main_rs.contains("SupervisorControl::EscalateShutdown"));
+    assert!(main_rs.contains("system.run().await"));
+}
+
+/// Dependencies of the crate `generated_nested_system_compiles` builds; the
+/// same as the Tokio demo's, by path.
+const NESTED_SYSTEM_CARGO_TOML: &str = r#"
+[package]
+name = "nested-system"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[workspace]
+
+[dependencies]
+bloxide-core       = { path = "{root}/crates/bloxide-core", features = ["std"] }
+bloxide-tokio      = { path = "{root}/runtimes/bloxide-tokio" }
+bloxide-log        = { path = "{root}/crates/bloxide-log", features = ["log"] }
+bloxide-timer      = { path = "{root}/crates/bloxide-timer", features = ["std"] }
+bloxide-supervisor = { path = "{root}/crates/bloxide-supervisor", features = ["std"] }
+ping-blox          = { path = "{root}/crates/bloxes/ping" }
+pong-blox          = { path = "{root}/crates/bloxes/pong" }
+ping-pong-messages = { path = "{root}/crates/messages/ping-pong-messages" }
+ping-pong-actions  = { path = "{root}/crates/actions/ping-pong-actions" }
+ping-pong-impl     = { path = "{root}/crates/impl/ping-pong-impl" }
+tokio              = { version = "1", features = ["full"] }
+tracing            = "0.1"
+tracing-subscriber = { version = "0.3", features = ["env-filter"] }
+tracing-log        = "0.2"
+"#;
+
+#[test]
+fn test_generated_nested_system_compiles() {
+    // The string checks above cannot catch a call that does not type-check,
+    // so the nested system is built as a crate of its own, warnings denied.
+    let config: SystemConfig = toml::from_str(NESTED_SYSTEM_TOML).expect("parse failed");
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../..")
+        .canonicalize()
+        .expect("workspace root");
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("nested-system");
+    std::fs::create_dir_all(dir.join("src")).expect("create crate dir");
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        NESTED_SYSTEM_CARGO_TOML.replace("{root}", &root.display().to_string()),
+    )
+    .expect("write Cargo.toml");
+    std::fs::copy(root.join("Cargo.lock"), dir.join("Cargo.lock")).expect("copy Cargo.lock");
+    std::fs::write(dir.join("src/main.rs"), main_rs).expect("write main.rs");
+
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = std::process::Command::new(cargo)
+        .args(["check", "--offline", "--quiet"])
+        .current_dir(&dir)
+        .env("RUSTFLAGS", "-Dwarnings")
+        .output()
+        .expect("run cargo check");
+    assert!(
+        output.status.success(),
+        "generated nested system does not compile:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_generate_system_wiring_rejects_supervision_cycles() {
+    let mut config: SystemConfig = toml::from_str(NESTED_SYSTEM_TOML).expect("parse failed");
+    config.supervision[0].name = Some("root".to_string());
+    config.supervision[1].children.push("root".to_string());
+    let err = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect_err("cycle accepted");
+    assert!(err.to_string().contains("cycle"), "{err}");
+}
+
+#[test]
+fn test_generate_system_wiring_restart_types() {
+    // `type` sets a child's restart type after it is spawned; permanent is
+    // the default and emits nothing.
+    let toml = NESTED_SYSTEM_TOML
+        .replace(
+            "ping = { restart = { max = 1 } }",
+            "ping = { restart = { max = 1 }, type = \"transient\" }",
+        )
+        .replace(
+            "pong = { restart = { max = 3 } }",
+            "pong = { restart = { max = 3 }, type = \"permanent\" }",
+        );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert_eq!(
+        config.supervision[0].policies["ping"].restart_type,
+        Some(RestartTypeConfig::Transient)
+    );
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+    assert!(main_rs.contains("group_0.set_restart_type(ping_id, RestartType::Transient);"));
+    assert_eq!(main_rs.matches("set_restart_type").count(), 1);
+}
+
+#[test]
+fn test_generate_system_wiring_health_check_settings() {
+    // Thresholds go on the group builder; per-child settings become a
+    // `set_liveness` call after the child is spawned.
+    let toml = NESTED_SYSTEM_TOML
+        .replace(
+            "children = [\"pong\"]",
+            "children = [\"pong\"]\nhealth_check_thresholds = { max_missed = 3 }",
+        )
+        .replace(
+            "ping = { restart = { max = 1 } }",
+            "ping = { restart = { max = 1 }, health_check = false }",
+        )
+        .replace(
+            "pong = { restart = { max = 3 } }",
+            "pong = { restart = { max = 3 }, health_check_interval_ms = 10000 }",
+        );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert_eq!(
+        config.supervision[0].policies["ping"].health_check,
+        Some(false)
+    );
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+    assert!(main_rs.contains(".with_health_thresholds(3u32, 1u32)"));
+    assert_eq!(main_rs.matches(".with_health_thresholds(").count(), 1);
+    assert!(main_rs.contains("set_liveness(ping_id, Liveness::Disabled);"));
+    assert!(main_rs.contains("interval_ms: 10000u64"));
+    assert_eq!(main_rs.matches(".set_liveness(").count(), 2);
+
+    // Opting out and overriding the interval contradict each other.
+    let toml = NESTED_SYSTEM_TOML.replace(
+        "ping = { restart = { max = 1 } }",
+        "ping = { restart = { max = 1 }, health_check = false, health_check_interval_ms = 5 }",
+    );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert!(bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_generate_system_wiring_start_order_and_dependencies() {
+    // Start settings go on the group builder; dependencies are set once all
+    // children of the entry have ids.
+    let toml = NESTED_SYSTEM_TOML
+        .replace(
+            "children = [\"ping\", \"pongs\"]",
+            "children = [\"ping\", \"pongs\"]\nstart_order = \"sequential\"\nstart_timeout_ms = 3000",
+        )
+        .replace(
+            "pongs = { restart = { max = 2 } }",
+            "pongs = { restart = { max = 2 }, depends_on = [\"ping\"] }",
+        );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert_eq!(
+        config.supervision[0].start_order,
+        Some(StartOrderConfig::Sequential)
+    );
+    assert_eq!(config.supervision[0].policies["pongs"].depends_on, ["ping"]);
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+    assert!(main_rs.contains(".with_start_order(StartOrder::Sequential)"));
+    assert!(main_rs.contains(".with_start_timeout(3000u64)"));
+    assert!(main_rs.contains("group_0.set_depends_on(sup_id_1, &[ping_id]);"));
+    assert_eq!(main_rs.matches(".with_timer(").count(), 1);
+
+    // A sequential child cannot wait on one that starts after it, and
+    // dependencies cannot form a cycle.
+    for (start_order, ping, pongs) in [
+        ("sequential", "depends_on = [\"pongs\"]", ""),
+        (
+            "concurrent",
+            "depends_on = [\"pongs\"]",
+            ", depends_on = [\"ping\"]",
+        ),
+        ("concurrent", "depends_on = [\"pong\"]", ""),
+    ] {
+        let toml = NESTED_SYSTEM_TOML
+            .replace(
+                "children = [\"ping\", \"pongs\"]",
+                &format!("children = [\"ping\", \"pongs\"]\nstart_order = \"{start_order}\""),
+            )
+            .replace(
+                "ping = { restart = { max = 1 } }",
+                &format!("ping = {{ restart = {{ max = 1 }}, {ping} }}"),
+            )
+            .replace(
+                "pongs = { restart = { max = 2 } }",
+                &format!("pongs = {{ restart = {{ max = 2 }}{pongs} }}"),
+            );
+        let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+        assert!(bloxide_codegen::system_wiring::generate(
+            &config,
+            &ping_pong_blox_configs(),
+            &BTreeMap::new(),
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn test_generate_system_wiring_journal() {
+    // A journal capacity goes on the group; Tokio also prints it on exit.
+    let toml = NESTED_SYSTEM_TOML.replace(
+        "children = [\"pong\"]",
+        "children = [\"pong\"]\njournal_capacity = 64",
+    );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert_eq!(config.supervision[1].journal_capacity, Some(64));
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+    assert_eq!(main_rs.matches(".with_journal(64usize)").count(), 1);
+    assert!(main_rs.contains(".with_journal_sink(::bloxide_tokio::journal::print_journal)"));
+}
+
+#[test]
+fn test_generate_system_wiring_shutdown_timeouts() {
+    // Shutdown deadlines go on the group and give the supervisor a timer.
+    let toml = NESTED_SYSTEM_TOML.replace(
+        "children = [\"pong\"]",
+        "children = [\"pong\"]\nshutdown_timeouts = { stop_ms = 2000, abort_ms = 500 }",
+    );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    let timeouts = config.supervision[1]
+        .shutdown_timeouts
+        .as_ref()
+        .expect("shutdown_timeouts");
+    assert_eq!((timeouts.stop_ms, timeouts.abort_ms), (2000, 500));
+
+    let main_rs = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect("generate failed");
+    assert!(main_rs.contains(".with_shutdown_timeouts(2000u64, 500u64)"));
+    assert_eq!(main_rs.matches(".with_timer(").count(), 1);
+
+    // Deadlines that could never fire are rejected, not silently dropped.
+    // Ping's timer_ref is pointed at pong only so that injection resolves.
+    let toml = toml
+        .replace(
+            "[[actors]]\nname = \"timer\"\nblox = \"bloxide-timer\"\nkind = \"timer\"\n\n",
+            "",
+        )
+        .replace(
+            "timer_ref = { source = \"actor\", actor = \"timer\" }",
+            "timer_ref = { source = \"actor\", actor = \"pong\" }",
+        );
+    let config: SystemConfig = toml::from_str(&toml).expect("parse failed");
+    assert!(config.actors.iter().all(|actor| actor.name != "timer"));
+    let err = bloxide_codegen::system_wiring::generate(
+        &config,
+        &ping_pong_blox_configs(),
+        &BTreeMap::new(),
+    )
+    .expect_err("shutdown_timeouts without a timer must fail");
+    assert!(err.to_string().contains("need a timer"), "{err}");
+}
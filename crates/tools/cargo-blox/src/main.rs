@@ -104,14 +104,14 @@ enum BloxSubcommand {
     /// Scaffold a new binary (wiring) crate
     NewBinary {
         name: String,
-        /// Runtime to target (tokio or embassy)
+        /// Runtime to target (tokio, embassy or std)
         #[arg(long, default_value = "tokio")]
         runtime: String,
     },
     /// Scaffold all layers (messages, actions, blox, binary)
     NewAll {
         name: String,
-        /// Runtime to target (tokio or embassy)
+        /// Runtime to target (tokio, embassy or std)
         #[arg(long, default_value = "tokio")]
         runtime: String,
     },
@@ -28,6 +28,16 @@ use embassy_executor::Spawner;
 #[embassy_executor::main]
 async fn main(_spawner: Spawner) {{
 }}
+"#
+            ),
+        ),
+        "std" => (
+            "bloxide-std = { workspace = true }",
+            format!(
+                r#"// Copyright 2025 Bloxide, all rights reserved
+//! {name_camel} wiring binary — thread-per-actor std runtime.
+fn main() {{
+}}
 "#
             ),
         ),
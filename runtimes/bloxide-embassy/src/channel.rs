@@ -38,10 +38,23 @@ unsafe impl<M: Send + 'static> Sync for EmbassySender<M> {}
 /// Callers should not rely on stream termination as a shutdown signal.
 pub struct EmbassyStream<M: Send + 'static> {
     pub(crate) inner: DynamicReceiver<'static, Envelope<M>>,
+    /// Pool slot to hand back when this receiver is dropped. `None` for
+    /// channels from `StaticChannelCap::channel`.
+    #[cfg(feature = "dynamic")]
+    pub(crate) lease: Option<crate::spawn::SlotLease>,
 }
 
 impl<M: Send + 'static> Unpin for EmbassyStream<M> {}
 
+#[cfg(feature = "dynamic")]
+impl<M: Send + 'static> Drop for EmbassyStream<M> {
+    fn drop(&mut self) {
+        if let Some(lease) = self.lease.take() {
+            lease.release();
+        }
+    }
+}
+
 // SAFETY: Same reasoning as EmbassySender — the inner DynamicReceiver holds a
 // &'static reference to a Channel<CriticalSectionRawMutex, ..> created by
 // StaticChannelCap::channel().
@@ -9,7 +9,11 @@ extern crate std;
 use bloxide_core::{mailboxes::Mailboxes, spec::MachineSpec, StateMachine};
 use core::future::poll_fn;
 
-pub use bloxide_core::{run_actor, run_actor_auto_start, run_actor_to_completion};
+pub use bloxide_core::{
+    run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion,
+    run_actor_to_completion_batched, run_multiplexed, run_multiplexed_batched, ActorSlot,
+    BatchConfig, Multiplexed,
+};
 
 #[doc(hidden)]
 pub use bloxide_macros::channels as __channels_proc_macro;
@@ -19,12 +23,16 @@ pub use bloxide_macros::next_actor_id as __next_actor_id_proc_macro;
 pub mod channel;
 pub mod mailbox;
 pub mod prelude;
+#[cfg(feature = "dynamic")]
+pub mod spawn;
 pub mod supervision;
 pub mod timer;
 
 pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
 pub use channel::{EmbassySender, EmbassyStream, EmbassyTrySendError};
-pub use supervision::{run_supervised_actor, ChildGroupBuilder};
+pub use supervision::{
+    run_supervised_actor, run_supervised_actor_batched, ChildGroupBuilder, SupervisedSlot,
+};
 
 // ── EmbassyRuntime ────────────────────────────────────────────────────────────
 
@@ -58,6 +66,9 @@ macro_rules! channels {
 // ── actor_task! macro ─────────────────────────────────────────────────────────
 
 /// Generate an `#[embassy_executor::task]` wrapper for a bloxide actor.
+///
+/// Pass `batch = BatchConfig::new(n).with_yield_budget(b)` to drain up to `n`
+/// ready events per wake and yield to the executor every `b` events.
 #[macro_export]
 macro_rules! actor_task {
     ($name:ident, $spec:ty $(,)?) => {
@@ -71,11 +82,24 @@ macro_rules! actor_task {
             $crate::run_actor(machine, mailboxes).await;
         }
     };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        #[embassy_executor::task]
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::EmbassyRuntime,
+            >,
+        ) {
+            $crate::run_actor_batched(machine, mailboxes, $batch).await;
+        }
+    };
 }
 
 // ── actor_task_supervised! macro ──────────────────────────────────────────────
 
 /// Generate an `#[embassy_executor::task]` wrapper for a supervised bloxide actor.
+///
+/// Accepts the same optional `batch = ...` argument as [`actor_task!`].
 #[macro_export]
 macro_rules! actor_task_supervised {
     ($name:ident, $spec:ty $(,)?) => {
@@ -99,6 +123,28 @@ macro_rules! actor_task_supervised {
             .await;
         }
     };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        #[embassy_executor::task]
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            domain_mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::EmbassyRuntime,
+            >,
+            lifecycle_rx: $crate::EmbassyStream<$crate::LifecycleCommand>,
+            actor_id: ::bloxide_core::messaging::ActorId,
+            supervisor_notify: $crate::EmbassySender<$crate::ChildLifecycleEvent>,
+        ) {
+            $crate::supervision::run_supervised_actor_batched(
+                machine,
+                domain_mailboxes,
+                lifecycle_rx,
+                actor_id,
+                supervisor_notify,
+                $batch,
+            )
+            .await;
+        }
+    };
 }
 
 // ── root_task! macro ──────────────────────────────────────────────────────────
@@ -162,12 +208,16 @@ macro_rules! timer_task {
 // ── spawn_timer! macro ────────────────────────────────────────────────────────
 
 /// Spawn the timer service and return the `ActorRef<TimerCommand>` for it.
+///
+/// The service is also registered with [`timer::register_timer_service`], so
+/// supervised actors' timers are discarded when they stop or reset.
 #[macro_export]
 macro_rules! spawn_timer {
     ($spawner:expr, $task_fn:ident, $capacity:expr) => {{
         let ((timer_ref,), (timer_stream,)) =
             $crate::__channels_proc_macro!($crate::EmbassyRuntime; ::bloxide_timer::TimerCommand($capacity));
         $spawner.must_spawn($task_fn(timer_stream));
+        $crate::timer::register_timer_service(&timer_ref);
         timer_ref
     }};
 }
@@ -62,6 +62,8 @@ impl StaticChannelCap for EmbassyRuntime {
         };
         let stream = EmbassyStream {
             inner: ch.dyn_receiver(),
+            #[cfg(feature = "dynamic")]
+            lease: None,
         };
         (ActorRef::new(id, sender), stream)
     }
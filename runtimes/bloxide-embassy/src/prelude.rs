@@ -1,7 +1,8 @@
 // Copyright 2025 Bloxide, all rights reserved
 pub use crate::{
-    run_actor, run_actor_auto_start, run_root, run_supervised_actor, ChildGroupBuilder,
-    EmbassyRuntime, EmbassySender, EmbassyStream,
+    run_actor, run_actor_auto_start, run_actor_batched, run_multiplexed, run_root,
+    run_supervised_actor, ActorSlot, BatchConfig, ChildGroupBuilder, EmbassyRuntime, EmbassySender,
+    EmbassyStream, Multiplexed, SupervisedSlot,
 };
 /// Convenience re-exports for Embassy-based wiring sites.
 ///
@@ -12,7 +13,9 @@ pub use crate::{
 /// Supervisor types are NOT re-exported here — the runtime does not depend
 /// on `bloxide-supervisor`. Apps that use the supervisor import it directly:
 /// `use bloxide_supervisor::*;`
-pub use bloxide_child_management::{ChildGroup, ChildPolicy, GroupShutdown};
+pub use bloxide_child_management::{
+    Backoff, ChildGroup, ChildPolicy, GroupShutdown, Liveness, RestartType, StartOrder,
+};
 pub use bloxide_core::prelude::*;
 pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
 pub use embassy_executor::Spawner;
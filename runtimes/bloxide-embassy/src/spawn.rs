@@ -0,0 +1,409 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Bounded dynamic spawning for Embassy (`dynamic` feature).
+//!
+//! Embassy has no growable executor: every task lives in a slot of a pool
+//! declared at compile time. This module implements `SpawnCap` and
+//! `DynamicChannelCap` for [`EmbassyRuntime`] on top of such pools:
+//!
+//! - [`task_slots!`](crate::task_slots) declares `N` task slots. Each slot
+//!   runs one boxed actor future and is free again once that future returns.
+//! - [`channel_pool!`](crate::channel_pool) declares `SLOTS` channels of
+//!   capacity `CAP` for one message type and registers them. A channel slot
+//!   is taken by `channel` and handed back when its [`EmbassyStream`] is
+//!   dropped, which happens when the actor's task ends.
+//!
+//! Call [`init`] once at start-up with the executor's spawner and the task
+//! slots. When a pool is exhausted, `SpawnCap::try_spawn` and
+//! [`EmbassyRuntime::try_channel`] return a [`SpawnError`], which a spawn
+//! function passes back to `spawn_child`. The infallible trait methods
+//! panic instead.
+//!
+//! Senders are `Copy` and cannot be tracked, so a recycled channel can still
+//! be reached through a stale `ActorRef` to the actor that used it before.
+//! The slot is cleared when it is taken again, and managing bloxes drop a
+//! child's refs once it has terminated.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::cell::{Cell, RefCell};
+use core::future::Future;
+use core::pin::Pin;
+
+use bloxide_core::capability::DynamicChannelCap;
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use bloxide_spawn::SpawnCap;
+use embassy_executor::SendSpawner;
+use embassy_sync::blocking_mutex::{raw::CriticalSectionRawMutex, Mutex};
+use embassy_sync::channel::Channel;
+
+use crate::channel::{EmbassySender, EmbassyStream};
+use crate::EmbassyRuntime;
+
+pub use bloxide_spawn::SpawnError;
+
+#[doc(hidden)]
+pub use static_cell as __static_cell;
+
+/// An actor future, boxed so that one task pool can run any actor.
+pub type BoxedTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// First id returned by `alloc_actor_id`.
+///
+/// `channels!` and `next_actor_id!` count up from 1 at compile time, so
+/// runtime ids start in the upper half of the id space.
+pub const DYNAMIC_ID_BASE: ActorId = ActorId::MAX / 2 + 1;
+
+// ── Task slots ───────────────────────────────────────────────────────────────
+
+/// A pool of task slots, declared with [`task_slots!`](crate::task_slots).
+pub trait TaskSlots: Sync {
+    /// Run `task` in a free slot, or return [`SpawnError::NoTaskSlot`].
+    fn spawn(&self, spawner: SendSpawner, task: BoxedTask) -> Result<(), SpawnError>;
+}
+
+// ── Channel slots ────────────────────────────────────────────────────────────
+
+/// Channel storage for one message type, declared with
+/// [`channel_pool!`](crate::channel_pool).
+pub trait ChannelSlots<M: Send + 'static>: Sync {
+    /// Capacity of every channel in the pool.
+    fn capacity(&self) -> usize;
+
+    /// Take a free channel, or return `None` if all are in use.
+    fn acquire(&'static self) -> Option<(EmbassySender<M>, EmbassyStream<M>)>;
+}
+
+/// `SLOTS` channels of capacity `CAP` carrying `M`.
+pub struct ChannelPool<M: Send + 'static, const CAP: usize, const SLOTS: usize> {
+    channels: [Channel<CriticalSectionRawMutex, Envelope<M>, CAP>; SLOTS],
+    taken: Mutex<CriticalSectionRawMutex, Cell<[bool; SLOTS]>>,
+}
+
+impl<M: Send + 'static, const CAP: usize, const SLOTS: usize> ChannelPool<M, CAP, SLOTS> {
+    pub const fn new() -> Self {
+        Self {
+            channels: [const { Channel::new() }; SLOTS],
+            taken: Mutex::new(Cell::new([false; SLOTS])),
+        }
+    }
+
+    /// Number of channels not currently held by an actor.
+    pub fn free(&self) -> usize {
+        self.taken
+            .lock(|taken| taken.get().iter().filter(|t| !**t).count())
+    }
+}
+
+impl<M: Send + 'static, const CAP: usize, const SLOTS: usize> Default
+    for ChannelPool<M, CAP, SLOTS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Send + 'static, const CAP: usize, const SLOTS: usize> ChannelSlots<M>
+    for ChannelPool<M, CAP, SLOTS>
+{
+    fn capacity(&self) -> usize {
+        CAP
+    }
+
+    fn acquire(&'static self) -> Option<(EmbassySender<M>, EmbassyStream<M>)> {
+        let index = self.taken.lock(|taken| {
+            let mut slots = taken.get();
+            let index = slots.iter().position(|t| !*t)?;
+            slots[index] = true;
+            taken.set(slots);
+            Some(index)
+        })?;
+        let ch = &self.channels[index];
+        // Drop anything a stale sender delivered after the last release.
+        ch.clear();
+        let sender = EmbassySender {
+            inner: ch.dyn_sender(),
+        };
+        let stream = EmbassyStream {
+            inner: ch.dyn_receiver(),
+            lease: Some(SlotLease { pool: self, index }),
+        };
+        Some((sender, stream))
+    }
+}
+
+trait ReleaseSlot: Sync {
+    fn release(&self, index: usize);
+}
+
+impl<M: Send + 'static, const CAP: usize, const SLOTS: usize> ReleaseSlot
+    for ChannelPool<M, CAP, SLOTS>
+{
+    fn release(&self, index: usize) {
+        self.channels[index].clear();
+        self.taken.lock(|taken| {
+            let mut slots = taken.get();
+            slots[index] = false;
+            taken.set(slots);
+        });
+    }
+}
+
+/// A taken channel slot, handed back when its receiver is dropped.
+pub(crate) struct SlotLease {
+    pool: &'static dyn ReleaseSlot,
+    index: usize,
+}
+
+impl SlotLease {
+    pub(crate) fn release(self) {
+        self.pool.release(self.index);
+    }
+}
+
+// ── Registry ─────────────────────────────────────────────────────────────────
+
+struct Registry {
+    spawner: Option<SendSpawner>,
+    tasks: Option<&'static dyn TaskSlots>,
+    /// Each entry is a `&'static dyn ChannelSlots<M>` for some `M`.
+    channels: Vec<&'static (dyn Any + Send + Sync)>,
+    next_id: ActorId,
+}
+
+static REGISTRY: Mutex<CriticalSectionRawMutex, RefCell<Registry>> =
+    Mutex::new(RefCell::new(Registry {
+        spawner: None,
+        tasks: None,
+        channels: Vec::new(),
+        next_id: DYNAMIC_ID_BASE,
+    }));
+
+/// Enable dynamic spawning: tasks are spawned on `spawner` into `tasks`.
+///
+/// Call once at start-up, e.g. with `spawner.make_send()` and the result of
+/// [`task_slots!`](crate::task_slots). Until then `try_spawn` returns
+/// [`SpawnError::NoTaskSlot`].
+pub fn init(spawner: SendSpawner, tasks: &'static dyn TaskSlots) {
+    REGISTRY.lock(|registry| {
+        let mut registry = registry.borrow_mut();
+        registry.spawner = Some(spawner);
+        registry.tasks = Some(tasks);
+    });
+}
+
+/// Make `pool` available to `channel::<M>`.
+///
+/// [`channel_pool!`](crate::channel_pool) calls this. Several pools may be
+/// registered for one `M`; `channel` takes from the first one with a free
+/// slot and at least the requested capacity.
+pub fn register_channels<M: Send + 'static>(pool: &'static dyn ChannelSlots<M>) {
+    let entry: &'static (dyn Any + Send + Sync) = Box::leak(Box::new(pool));
+    REGISTRY.lock(|registry| registry.borrow_mut().channels.push(entry));
+}
+
+impl EmbassyRuntime {
+    /// Take a channel for `M` with at least `capacity` slots from the
+    /// registered pools, or return [`SpawnError::NoChannelSlot`].
+    pub fn try_channel<M: Send + 'static>(
+        id: ActorId,
+        capacity: usize,
+    ) -> Result<(ActorRef<M, Self>, EmbassyStream<M>), SpawnError> {
+        REGISTRY
+            .lock(|registry| {
+                registry
+                    .borrow()
+                    .channels
+                    .iter()
+                    .filter_map(|pool| pool.downcast_ref::<&'static dyn ChannelSlots<M>>())
+                    .filter(|pool| pool.capacity() >= capacity)
+                    .find_map(|pool| pool.acquire())
+            })
+            .map(|(sender, stream)| (ActorRef::new(id, sender), stream))
+            .ok_or(SpawnError::NoChannelSlot)
+    }
+}
+
+impl DynamicChannelCap for EmbassyRuntime {
+    fn alloc_actor_id() -> ActorId {
+        REGISTRY.lock(|registry| {
+            let mut registry = registry.borrow_mut();
+            let id = registry.next_id;
+            registry.next_id += 1;
+            id
+        })
+    }
+
+    /// Panics if no registered pool has a free channel; spawn functions use
+    /// [`EmbassyRuntime::try_channel`] instead.
+    fn channel<M: Send + 'static>(
+        id: ActorId,
+        capacity: usize,
+    ) -> (ActorRef<M, Self>, Self::Receiver<M>) {
+        match Self::try_channel(id, capacity) {
+            Ok(channel) => channel,
+            Err(err) => panic!("channel for actor {}: {}", id, err),
+        }
+    }
+}
+
+impl SpawnCap for EmbassyRuntime {
+    type TaskHandle = ();
+    type KillHandle = ();
+
+    fn spawn(future: impl Future<Output = ()> + Send + 'static) -> Self::TaskHandle {
+        if let Err(err) = Self::try_spawn(future) {
+            panic!("spawn: {}", err);
+        }
+    }
+
+    fn try_spawn(
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<Self::TaskHandle, SpawnError> {
+        let (spawner, tasks) = REGISTRY.lock(|registry| {
+            let registry = registry.borrow();
+            (registry.spawner, registry.tasks)
+        });
+        match (spawner, tasks) {
+            (Some(spawner), Some(tasks)) => tasks.spawn(spawner, Box::pin(future)),
+            _ => Err(SpawnError::NoTaskSlot),
+        }
+    }
+
+    fn kill_handle(_handle: Self::TaskHandle) -> Self::KillHandle {}
+
+    /// Embassy cannot cancel a task from outside; the abort mailbox is the
+    /// only way to end one.
+    fn kill(_handle: Self::KillHandle) {}
+}
+
+// ── Macros ───────────────────────────────────────────────────────────────────
+
+/// Declare `N` task slots for dynamically spawned actors.
+///
+/// Expands to an `#[embassy_executor::task(pool_size = N)]` runner and
+/// evaluates to the `&'static dyn TaskSlots` to pass to [`spawn::init`](crate::spawn::init).
+#[macro_export]
+macro_rules! task_slots {
+    ($n:expr) => {{
+        #[embassy_executor::task(pool_size = $n)]
+        async fn __bloxide_task_slot(task: $crate::spawn::BoxedTask) {
+            task.await
+        }
+
+        struct __BloxideTaskSlots;
+
+        impl $crate::spawn::TaskSlots for __BloxideTaskSlots {
+            fn spawn(
+                &self,
+                spawner: embassy_executor::SendSpawner,
+                task: $crate::spawn::BoxedTask,
+            ) -> ::core::result::Result<(), $crate::spawn::SpawnError> {
+                spawner
+                    .spawn(__bloxide_task_slot(task))
+                    .map_err(|_| $crate::spawn::SpawnError::NoTaskSlot)
+            }
+        }
+
+        static SLOTS: __BloxideTaskSlots = __BloxideTaskSlots;
+        &SLOTS as &'static dyn $crate::spawn::TaskSlots
+    }};
+}
+
+/// Declare and register `slots` channels of capacity `cap` for message `$msg`.
+///
+/// The storage is a `static_cell::ConstStaticCell`, so it is laid out at
+/// compile time and each invocation can only be evaluated once. Evaluates to
+/// the `&'static ChannelPool`.
+#[macro_export]
+macro_rules! channel_pool {
+    ($msg:ty, $cap:expr, $slots:expr) => {{
+        static POOL: $crate::spawn::__static_cell::ConstStaticCell<
+            $crate::spawn::ChannelPool<$msg, { $cap }, { $slots }>,
+        > = $crate::spawn::__static_cell::ConstStaticCell::new($crate::spawn::ChannelPool::new());
+        let pool: &'static $crate::spawn::ChannelPool<$msg, { $cap }, { $slots }> = POOL.take();
+        $crate::spawn::register_channels::<$msg>(pool);
+        pool
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn channel_slots_run_out_and_are_recycled_when_the_stream_drops() {
+        struct Msg;
+        let pool = crate::channel_pool!(Msg, 2, 2);
+
+        let (first_ref, first_rx) = EmbassyRuntime::try_channel::<Msg>(DYNAMIC_ID_BASE, 2).unwrap();
+        let (_second_ref, _second_rx) = EmbassyRuntime::try_channel::<Msg>(7, 1).unwrap();
+        assert_eq!(pool.free(), 0);
+        assert!(matches!(
+            EmbassyRuntime::try_channel::<Msg>(8, 1),
+            Err(SpawnError::NoChannelSlot)
+        ));
+
+        first_ref.try_send(1, Msg).unwrap();
+        drop(first_rx);
+        assert_eq!(pool.free(), 1);
+
+        let (_, reused_rx) = EmbassyRuntime::try_channel::<Msg>(9, 2).unwrap();
+        assert!(
+            reused_rx.inner.try_receive().is_err(),
+            "a recycled channel starts empty"
+        );
+    }
+
+    #[test]
+    fn channel_needs_a_registered_pool_with_enough_capacity() {
+        struct Small;
+        struct Unregistered;
+        crate::channel_pool!(Small, 2, 1);
+
+        assert!(matches!(
+            EmbassyRuntime::try_channel::<Small>(1, 4),
+            Err(SpawnError::NoChannelSlot)
+        ));
+        assert!(matches!(
+            EmbassyRuntime::try_channel::<Unregistered>(1, 1),
+            Err(SpawnError::NoChannelSlot)
+        ));
+        assert!(EmbassyRuntime::try_channel::<Small>(1, 2).is_ok());
+    }
+
+    #[test]
+    fn dynamic_ids_start_above_compile_time_ids() {
+        let a = EmbassyRuntime::alloc_actor_id();
+        let b = EmbassyRuntime::alloc_actor_id();
+        assert!(a >= DYNAMIC_ID_BASE);
+        assert!(b > a);
+    }
+
+    #[test]
+    fn try_spawn_reports_full_task_slots() {
+        struct OneSlot(AtomicUsize);
+        impl TaskSlots for OneSlot {
+            fn spawn(&self, _spawner: SendSpawner, _task: BoxedTask) -> Result<(), SpawnError> {
+                match self.0.fetch_add(1, Ordering::SeqCst) {
+                    0 => Ok(()),
+                    _ => Err(SpawnError::NoTaskSlot),
+                }
+            }
+        }
+        static SLOTS: OneSlot = OneSlot(AtomicUsize::new(0));
+        let executor: &'static embassy_executor::raw::Executor = Box::leak(Box::new(
+            embassy_executor::raw::Executor::new(core::ptr::null_mut()),
+        ));
+        // The macro must expand in a downstream-style context.
+        let _declared = crate::task_slots!(2);
+
+        init(executor.spawner().make_send(), &SLOTS);
+        assert_eq!(EmbassyRuntime::try_spawn(async {}), Ok(()));
+        assert_eq!(
+            EmbassyRuntime::try_spawn(async {}),
+            Err(SpawnError::NoTaskSlot)
+        );
+    }
+}
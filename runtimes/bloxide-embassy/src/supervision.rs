@@ -1,19 +1,23 @@
 // Copyright 2025 Bloxide, all rights reserved
-use bloxide_child_management::{ChildGroup, ChildPolicy, GroupShutdown};
+use bloxide_child_management::{AbortCommand, ChildGroup, ChildPolicy, GroupShutdown};
 use bloxide_core::{
-    capability::StaticChannelCap,
+    batch::{BatchConfig, YieldBudget},
+    capability::{ClockCap, StaticChannelCap},
     engine::{DispatchOutcome, StateMachine},
     lifecycle::{ChildLifecycleEvent, LifecycleCommand},
     mailboxes::Mailboxes,
     messaging::{ActorId, ActorRef, Envelope},
+    multiplex::{Multiplexed, SlotPoll},
     report_outcome,
     spec::MachineSpec,
 };
+use bloxide_timer::TimerMark;
 use core::future::poll_fn;
 use core::pin::Pin;
-use core::task::Poll;
+use core::task::{Context, Poll};
 use futures_core::Stream;
 
+use crate::timer::settle_timers;
 use crate::{EmbassyRuntime, EmbassySender, EmbassyStream};
 
 // ── Standalone supervised actor runner ───────────────────────────────────────
@@ -23,51 +27,94 @@ use crate::{EmbassyRuntime, EmbassySender, EmbassyStream};
 /// Polls lifecycle and domain mailboxes, dispatches events through the machine,
 /// and reports outcomes to the supervisor.
 pub async fn run_supervised_actor<S: MachineSpec + 'static>(
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<EmbassyRuntime>,
+    lifecycle_stream: EmbassyStream<LifecycleCommand>,
+    actor_id: ActorId,
+    supervisor_notify: EmbassySender<ChildLifecycleEvent>,
+) {
+    run_supervised_actor_batched(
+        machine,
+        domain_mailboxes,
+        lifecycle_stream,
+        actor_id,
+        supervisor_notify,
+        BatchConfig::UNBATCHED,
+    )
+    .await;
+}
+
+/// Like [`run_supervised_actor`], but dispatches up to `batch.max_batch` ready
+/// events per poll and yields to the executor whenever `batch.yield_budget`
+/// is spent.
+///
+/// The lifecycle stream is re-checked before every event in the batch, so a
+/// `Stop` or `Reset` still pre-empts queued domain messages.
+pub async fn run_supervised_actor_batched<S: MachineSpec + 'static>(
     mut machine: StateMachine<S>,
     mut domain_mailboxes: S::Mailboxes<EmbassyRuntime>,
     mut lifecycle_stream: EmbassyStream<LifecycleCommand>,
     actor_id: ActorId,
     supervisor_notify: EmbassySender<ChildLifecycleEvent>,
+    batch: BatchConfig,
 ) {
     enum LoopAction {
-        Continue,
+        Continue(usize),
         Stop,
     }
 
+    let mut budget = YieldBudget::new(&batch);
     loop {
         let action = poll_fn(|cx| {
-            // First check lifecycle stream (higher priority)
-            match Pin::new(&mut lifecycle_stream).poll_next(cx) {
-                Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
-                Poll::Ready(Some(Envelope(_, cmd))) => {
-                    let outcome = handle_lifecycle(&mut machine, cmd);
-                    report_outcome::<S, EmbassyRuntime>(&outcome, actor_id, &supervisor_notify);
-
-                    return match outcome {
-                        DispatchOutcome::Stopped => Poll::Ready(LoopAction::Stop),
-                        _ => Poll::Ready(LoopAction::Continue),
-                    };
+            let mut handled = 0;
+            while handled < batch.max_batch {
+                // First check lifecycle stream (higher priority)
+                match Pin::new(&mut lifecycle_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, cmd))) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = handle_lifecycle(&mut machine, cmd);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, EmbassyRuntime>(&outcome, actor_id, &supervisor_notify);
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                        continue;
+                    }
+                    Poll::Pending => {}
                 }
-                Poll::Pending => {}
-            }
 
-            // Then check domain mailboxes
-            match domain_mailboxes.poll_next(cx) {
-                Poll::Ready(Some(event)) => {
-                    let outcome = machine.dispatch(event);
-                    report_outcome::<S, EmbassyRuntime>(&outcome, actor_id, &supervisor_notify);
-                    Poll::Ready(LoopAction::Continue)
+                // Then check domain mailboxes
+                match domain_mailboxes.poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = machine.dispatch(event);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, EmbassyRuntime>(&outcome, actor_id, &supervisor_notify);
+                        // A graceful Stop completes on a domain event
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Pending => break,
                 }
-                Poll::Ready(None) => Poll::Ready(LoopAction::Stop),
-                Poll::Pending => Poll::Pending,
+            }
+            if handled == 0 {
+                Poll::Pending
+            } else {
+                Poll::Ready(LoopAction::Continue(handled))
             }
         })
         .await;
 
         match action {
-            LoopAction::Continue => {}
+            LoopAction::Continue(n) => budget.consume(n),
             LoopAction::Stop => break,
         }
+        budget.checkpoint().await;
     }
 }
 
@@ -81,6 +128,114 @@ fn handle_lifecycle<S: MachineSpec>(
     machine.handle_lifecycle(cmd)
 }
 
+// ── Multiplexed supervised actor ─────────────────────────────────────────────
+
+/// A supervised actor driven by a multiplexed task instead of its own task.
+///
+/// Each [`poll_dispatch`](Multiplexed::poll_dispatch) does what one iteration
+/// of [`run_supervised_actor`] does: lifecycle commands first, then the
+/// abort mailbox if one was attached with [`with_abort`](Self::with_abort),
+/// then domain mailboxes. Outcomes are
+/// reported to the supervisor and timers are settled the same way, so the
+/// supervisor cannot tell the two apart.
+pub struct SupervisedSlot<S: MachineSpec + 'static> {
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<EmbassyRuntime>,
+    lifecycle_stream: EmbassyStream<LifecycleCommand>,
+    abort_stream: Option<EmbassyStream<AbortCommand>>,
+    actor_id: ActorId,
+    supervisor_notify: EmbassySender<ChildLifecycleEvent>,
+    finished: bool,
+}
+
+impl<S: MachineSpec + 'static> SupervisedSlot<S> {
+    pub fn new(
+        machine: StateMachine<S>,
+        domain_mailboxes: S::Mailboxes<EmbassyRuntime>,
+        lifecycle_stream: EmbassyStream<LifecycleCommand>,
+        actor_id: ActorId,
+        supervisor_notify: EmbassySender<ChildLifecycleEvent>,
+    ) -> Self {
+        Self {
+            machine,
+            domain_mailboxes,
+            lifecycle_stream,
+            abort_stream: None,
+            actor_id,
+            supervisor_notify,
+            finished: false,
+        }
+    }
+
+    /// Attach the abort mailbox of a dynamically registered child.
+    pub fn with_abort(mut self, abort_stream: EmbassyStream<AbortCommand>) -> Self {
+        self.abort_stream = Some(abort_stream);
+        self
+    }
+
+    pub fn machine(&self) -> &StateMachine<S> {
+        &self.machine
+    }
+
+    fn report(&mut self, mark: TimerMark, outcome: &DispatchOutcome<S::State>) {
+        settle_timers(mark, outcome, self.actor_id);
+        report_outcome::<S, EmbassyRuntime>(outcome, self.actor_id, &self.supervisor_notify);
+    }
+
+    fn finish(&mut self) -> SlotPoll {
+        self.finished = true;
+        SlotPoll::Finished
+    }
+}
+
+impl<S: MachineSpec + 'static> Multiplexed for SupervisedSlot<S> {
+    fn poll_dispatch(&mut self, cx: &mut Context<'_>) -> SlotPoll {
+        if self.finished {
+            return SlotPoll::Finished;
+        }
+
+        match Pin::new(&mut self.lifecycle_stream).poll_next(cx) {
+            Poll::Ready(None) => return self.finish(),
+            Poll::Ready(Some(Envelope(_, cmd))) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = handle_lifecycle(&mut self.machine, cmd);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                return SlotPoll::Dispatched;
+            }
+            Poll::Pending => {}
+        }
+
+        if let Some(abort_stream) = self.abort_stream.as_mut() {
+            match Pin::new(abort_stream).poll_next(cx) {
+                Poll::Ready(None) => return self.finish(),
+                Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
+                    let mark = TimerMark::new(self.machine.current_state());
+                    self.report(mark, &DispatchOutcome::Aborted);
+                    return self.finish();
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        match self.domain_mailboxes.poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = self.machine.dispatch(event);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                SlotPoll::Dispatched
+            }
+            Poll::Ready(None) => self.finish(),
+            Poll::Pending => SlotPoll::Idle,
+        }
+    }
+}
+
 // ── ChildGroupBuilder ─────────────────────────────────────────────────────────
 //
 // Static-channel builder for Embassy. Generic over the control message type `Ctrl`
@@ -104,7 +259,7 @@ impl<Ctrl: Send + 'static> ChildGroupBuilder<Ctrl> {
             bloxide_macros::next_actor_id!(),
         );
         Self {
-            group: ChildGroup::new(shutdown),
+            group: ChildGroup::new(shutdown).with_clock(<EmbassyRuntime as ClockCap>::now_ms),
             notify_ref,
             notify_rx,
             control_ref,
@@ -112,6 +267,12 @@ impl<Ctrl: Send + 'static> ChildGroupBuilder<Ctrl> {
         }
     }
 
+    /// Limit the group to `max` restarts within any `within_ms` window.
+    pub fn with_restart_intensity(mut self, max: usize, within_ms: u64) -> Self {
+        self.group = self.group.with_restart_intensity(max, within_ms);
+        self
+    }
+
     pub fn add_child(
         &mut self,
         id: ActorId,
@@ -242,6 +403,38 @@ mod tests {
         }
     }
 
+    /// Backs the memory comparison in `spec/architecture/11-dynamic-actors.md`:
+    /// a slot against the future a per-actor task stores in its
+    /// `TaskStorage`, for an actor with a unit context and no domain
+    /// mailboxes. Run with `--nocapture` to see the sizes.
+    #[test]
+    fn slot_is_smaller_than_a_supervised_task() {
+        use super::{run_supervised_actor, SupervisedSlot};
+        use bloxide_core::engine::StateMachine;
+        use bloxide_core::multiplex::{run_multiplexed, Multiplexed};
+        use core::mem::{size_of, size_of_val};
+
+        let (_lc_ref, lc_rx) =
+            <EmbassyRuntime as StaticChannelCap>::channel::<LifecycleCommand, 4>(1);
+        let (notify_ref, _notify_rx) =
+            <EmbassyRuntime as StaticChannelCap>::channel::<ChildLifecycleEvent, 4>(2);
+        let task = run_supervised_actor(
+            StateMachine::<TestSpec>::new(()),
+            NoMailboxes,
+            lc_rx,
+            1,
+            notify_ref.sender(),
+        );
+
+        let slot = size_of::<SupervisedSlot<TestSpec>>();
+        let task = size_of_val(&task);
+        let mut slots: [&mut dyn Multiplexed; 0] = [];
+        let scheduler = size_of_val(&run_multiplexed(&mut slots));
+        std::println!("supervised task future: {task} B");
+        std::println!("supervised slot: {slot} B, scheduler future: {scheduler} B");
+        assert!(slot < task);
+    }
+
     #[test]
     fn started_terminal_reports_done_only() {
         let (notify_ref, notify_rx) =
@@ -1,13 +1,19 @@
 // Copyright 2025 Bloxide, all rights reserved
+use core::cell::RefCell;
 use core::future::poll_fn;
 use core::pin::Pin;
 
+use alloc::vec::Vec;
+
 use embassy_futures::select::{select, Either};
+use embassy_sync::blocking_mutex::{raw::CriticalSectionRawMutex, Mutex};
 use embassy_time::{Duration, Instant, Timer};
 use futures_core::Stream;
 
-use bloxide_core::messaging::Envelope;
-use bloxide_timer::{TimerCommand, TimerQueue, TimerService};
+use bloxide_core::capability::ClockCap;
+use bloxide_core::engine::DispatchOutcome;
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use bloxide_timer::{cancel_timers_for, TimerCommand, TimerMark, TimerQueue, TimerService};
 
 use crate::channel::EmbassyStream;
 use crate::EmbassyRuntime;
@@ -16,6 +22,40 @@ fn now_ms() -> u64 {
     Instant::now().as_millis()
 }
 
+static SUPERVISED_TIMERS: Mutex<
+    CriticalSectionRawMutex,
+    RefCell<Vec<ActorRef<TimerCommand, EmbassyRuntime>>>,
+> = Mutex::new(RefCell::new(Vec::new()));
+
+/// Register a timer service whose timers the supervised run loops discard
+/// when an actor stops or resets.
+///
+/// `spawn_timer!` registers the service it spawns. Call this only when the
+/// timer task is spawned by hand. Every registered service is asked to
+/// discard the actor's timers.
+pub fn register_timer_service(timer_ref: &ActorRef<TimerCommand, EmbassyRuntime>) {
+    SUPERVISED_TIMERS.lock(|registered| registered.borrow_mut().push(timer_ref.clone()));
+}
+
+/// Discard `owner`'s timers from before `mark` if `outcome` ended its
+/// incarnation.
+pub(crate) fn settle_timers<St>(mark: TimerMark, outcome: &DispatchOutcome<St>, owner: ActorId) {
+    if !mark.discards(outcome) {
+        return;
+    }
+    SUPERVISED_TIMERS.lock(|registered| {
+        for timer_ref in registered.borrow().iter() {
+            cancel_timers_for(timer_ref, owner, mark.before());
+        }
+    });
+}
+
+impl ClockCap for EmbassyRuntime {
+    fn now_ms() -> u64 {
+        now_ms()
+    }
+}
+
 impl TimerService for EmbassyRuntime {
     async fn run_timer_service(mut stream: EmbassyStream<TimerCommand>) {
         let mut queue = TimerQueue::new();
@@ -30,9 +70,9 @@ impl TimerService for EmbassyRuntime {
                     )
                     .await
                     {
-                        Either::First(Some(Envelope(_, cmd))) => {
+                        Either::First(Some(Envelope(from, cmd))) => {
                             let now = now_ms();
-                            if queue.handle_command(cmd, now) {
+                            if queue.handle_command(from, cmd, now) {
                                 for deliver in queue.drain_expired(now) {
                                     deliver();
                                 }
@@ -54,9 +94,9 @@ impl TimerService for EmbassyRuntime {
                 None => {
                     let poll_result = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
                     match poll_result {
-                        Some(Envelope(_, cmd)) => {
+                        Some(Envelope(from, cmd)) => {
                             let now = now_ms();
-                            if queue.handle_command(cmd, now) {
+                            if queue.handle_command(from, cmd, now) {
                                 for deliver in queue.drain_expired(now) {
                                     deliver();
                                 }
@@ -73,3 +113,17 @@ impl TimerService for EmbassyRuntime {
         }
     }
 }
+
+#[cfg(feature = "typed-timers")]
+impl bloxide_timer::TypedTimerService for EmbassyRuntime {
+    async fn run_typed_timer_service<M: Send + 'static, const N: usize>(
+        stream: EmbassyStream<bloxide_timer::TypedTimerCommand<M, Self>>,
+    ) {
+        bloxide_timer::typed::run_typed_timer_service::<M, Self, N, _, _>(
+            stream,
+            now_ms,
+            |deadline_ms| Timer::at(Instant::from_millis(deadline_ms)),
+        )
+        .await
+    }
+}
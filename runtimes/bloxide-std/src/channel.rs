@@ -0,0 +1,218 @@
+// Copyright 2025 Bloxide, all rights reserved
+use bloxide_core::messaging::Envelope;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, SyncSender, TryRecvError, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// ── Wakers ────────────────────────────────────────────────────────────────────
+
+/// Wakers shared by both halves of a channel.
+///
+/// `std::sync::mpsc` has no async hooks, so a receiver that finds the queue
+/// empty parks its waker here, and senders waiting for capacity park theirs.
+#[derive(Default)]
+pub(crate) struct Wakers {
+    rx: Mutex<Option<Waker>>,
+    tx: Mutex<Vec<Waker>>,
+    /// Set when the receiver is dropped.
+    closed: AtomicBool,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl Wakers {
+    /// Whether the receiver has been dropped.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    fn wake_rx(&self) {
+        if let Some(waker) = lock(&self.rx).take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_tx(&self) {
+        for waker in lock(&self.tx).drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+// ── StdSender ─────────────────────────────────────────────────────────────────
+
+/// A clonable sender handle backed by a bounded `std::sync::mpsc::SyncSender`.
+pub struct StdSender<M: Send + 'static> {
+    // Always `Some` until drop, which takes it so the receiver observes the
+    // disconnect before it is woken.
+    pub(crate) inner: Option<SyncSender<Envelope<M>>>,
+    pub(crate) wakers: Arc<Wakers>,
+}
+
+impl<M: Send + 'static> Clone for StdSender<M> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            wakers: Arc::clone(&self.wakers),
+        }
+    }
+}
+
+impl<M: Send + 'static> Drop for StdSender<M> {
+    fn drop(&mut self) {
+        drop(self.inner.take());
+        self.wakers.wake_rx();
+    }
+}
+
+impl<M: Send + 'static> StdSender<M> {
+    fn sender(&self) -> &SyncSender<Envelope<M>> {
+        self.inner.as_ref().expect("sender used after drop")
+    }
+
+    pub(crate) fn try_send(&self, envelope: Envelope<M>) -> Result<(), StdTrySendError> {
+        match self.sender().try_send(envelope) {
+            Ok(()) => {
+                self.wakers.wake_rx();
+                Ok(())
+            }
+            Err(_) => Err(StdTrySendError),
+        }
+    }
+
+    pub(crate) async fn send(&self, envelope: Envelope<M>) -> Result<(), StdSendError> {
+        let mut pending = Some(envelope);
+        core::future::poll_fn(|cx| {
+            let envelope = pending.take().expect("send polled after completion");
+            let envelope = match self.sender().try_send(envelope) {
+                Ok(()) => {
+                    self.wakers.wake_rx();
+                    return Poll::Ready(Ok(()));
+                }
+                Err(TrySendError::Disconnected(_)) => return Poll::Ready(Err(StdSendError)),
+                Err(TrySendError::Full(envelope)) => envelope,
+            };
+            // Register before retrying so a slot freed in between is not missed.
+            lock(&self.wakers.tx).push(cx.waker().clone());
+            match self.sender().try_send(envelope) {
+                Ok(()) => {
+                    self.wakers.wake_rx();
+                    Poll::Ready(Ok(()))
+                }
+                Err(TrySendError::Disconnected(_)) => Poll::Ready(Err(StdSendError)),
+                Err(TrySendError::Full(envelope)) => {
+                    pending = Some(envelope);
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+// ── StdStream ─────────────────────────────────────────────────────────────────
+
+/// The receiver half plus a `Stream` adapter.
+///
+/// Yields `None` once every sender has been dropped and the queue is drained,
+/// so the timer service and supervision loops can shut down gracefully.
+pub struct StdStream<M: Send + 'static> {
+    pub(crate) inner: Receiver<Envelope<M>>,
+    pub(crate) wakers: Arc<Wakers>,
+}
+
+impl<M: Send + 'static> Unpin for StdStream<M> {}
+
+impl<M: Send + 'static> StdStream<M> {
+    /// Take the next queued envelope without waiting.
+    pub(crate) fn try_recv(&mut self) -> Result<Envelope<M>, TryRecvError> {
+        let received = self.inner.try_recv();
+        if received.is_ok() {
+            self.wakers.wake_tx();
+        }
+        received
+    }
+}
+
+impl<M: Send + 'static> Drop for StdStream<M> {
+    fn drop(&mut self) {
+        // Senders blocked on a full queue must see the disconnect.
+        self.wakers.closed.store(true, Ordering::Release);
+        self.wakers.wake_tx();
+    }
+}
+
+impl<M: Send + 'static> Stream for StdStream<M> {
+    type Item = Envelope<M>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.try_recv() {
+            Ok(envelope) => return Poll::Ready(Some(envelope)),
+            Err(TryRecvError::Disconnected) => return Poll::Ready(None),
+            Err(TryRecvError::Empty) => {}
+        }
+        // Register before retrying so a send in between is not missed.
+        *lock(&self.wakers.rx) = Some(cx.waker().clone());
+        match self.try_recv() {
+            Ok(envelope) => Poll::Ready(Some(envelope)),
+            Err(TryRecvError::Disconnected) => Poll::Ready(None),
+            Err(TryRecvError::Empty) => Poll::Pending,
+        }
+    }
+}
+
+// ── Error types ───────────────────────────────────────────────────────────────
+
+#[derive(Debug)]
+pub struct StdSendError;
+
+#[derive(Debug)]
+pub struct StdTrySendError;
+
+#[cfg(test)]
+mod tests {
+    use crate::{block_on, StdRuntime};
+    use bloxide_core::{capability::DynamicChannelCap, messaging::Envelope};
+    use core::future::poll_fn;
+    use core::pin::Pin;
+    use futures_core::Stream;
+    use std::thread;
+
+    /// `send` waits for capacity instead of failing, and the receiver on
+    /// another thread sees every message in order.
+    #[test]
+    fn send_waits_for_capacity_across_threads() {
+        let (tx, mut rx) = <StdRuntime as DynamicChannelCap>::channel::<u32>(1, 2);
+        let producer = thread::spawn(move || {
+            block_on(async {
+                for i in 0..32 {
+                    tx.send(1, i).await.expect("receiver alive");
+                }
+            });
+        });
+
+        let received: Vec<u32> = block_on(async {
+            let mut received = Vec::new();
+            while let Some(Envelope(_, i)) = poll_fn(|cx| Pin::new(&mut rx).poll_next(cx)).await {
+                received.push(i);
+            }
+            received
+        });
+        producer.join().expect("producer thread");
+        assert_eq!(received, (0..32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn try_send_fails_when_full() {
+        let (tx, _rx) = <StdRuntime as DynamicChannelCap>::channel::<u32>(1, 1);
+        assert!(tx.try_send(1, 0).is_ok());
+        assert!(tx.try_send(1, 1).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! A minimal single-future executor that drives each actor on its own OS
+//! thread.
+//!
+//! The thread parks while its future is pending. A waker unparks it; a
+//! [`sleep_until`] deadline bounds the park so timers fire without a
+//! separate reactor.
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::task::Wake;
+use std::thread::{self, Thread};
+use std::time::Instant;
+
+// ── Signal ────────────────────────────────────────────────────────────────────
+
+/// Wake and kill flags for one executor thread.
+pub(crate) struct Signal {
+    woken: AtomicBool,
+    killed: AtomicBool,
+    thread: OnceLock<Thread>,
+}
+
+impl Signal {
+    pub(crate) fn new() -> Self {
+        Self {
+            woken: AtomicBool::new(true),
+            killed: AtomicBool::new(false),
+            thread: OnceLock::new(),
+        }
+    }
+
+    /// Ask the executor to drop its future at the next wake-up.
+    pub(crate) fn kill(&self) {
+        self.killed.store(true, Ordering::Release);
+        self.notify();
+    }
+
+    fn notify(&self) {
+        self.woken.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.get() {
+            thread.unpark();
+        }
+    }
+}
+
+impl Wake for Signal {
+    fn wake(self: Arc<Self>) {
+        self.notify();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.notify();
+    }
+}
+
+// ── Deadlines ─────────────────────────────────────────────────────────────────
+
+thread_local! {
+    /// Earliest deadline registered by a pending [`Sleep`] during the
+    /// current poll.
+    static WAKE_AT: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Future that completes once `deadline` has passed.
+///
+/// Only meaningful on a thread driven by [`block_on`]: it asks the executor to
+/// stop parking at `deadline` rather than registering with a timer wheel.
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    Sleep { deadline }
+}
+
+/// Future returned by [`sleep_until`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct Sleep {
+    deadline: Instant,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: core::pin::Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        WAKE_AT.with(|at| {
+            let earliest = match at.get() {
+                Some(existing) if existing <= self.deadline => existing,
+                _ => self.deadline,
+            };
+            at.set(Some(earliest));
+        });
+        Poll::Pending
+    }
+}
+
+// ── block_on ──────────────────────────────────────────────────────────────────
+
+/// Run `future` to completion on the current thread.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let signal = Arc::new(Signal::new());
+    run(future, &signal).expect("block_on future is never killed")
+}
+
+/// Drive `future` until it completes or `signal` is killed.
+pub(crate) fn run<F: Future>(future: F, signal: &Arc<Signal>) -> Option<F::Output> {
+    let _ = signal.thread.set(thread::current());
+    let waker = Waker::from(Arc::clone(signal));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if signal.killed.load(Ordering::Acquire) {
+            return None;
+        }
+        if signal.woken.swap(false, Ordering::AcqRel) {
+            WAKE_AT.with(|at| at.set(None));
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return Some(output);
+            }
+            continue;
+        }
+        match WAKE_AT.with(Cell::get) {
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    signal.woken.store(true, Ordering::Release);
+                } else {
+                    thread::park_timeout(deadline - now);
+                }
+            }
+            None => thread::park(),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Thread-per-actor runtime for plain `std`, without an async executor
+//! dependency.
+//!
+//! Every actor, and the timer service, runs on its own OS thread driven by a
+//! minimal [`block_on`] executor that parks the thread while the actor's
+//! mailboxes are empty. Channels are bounded `std::sync::mpsc` sync channels.
+//!
+//! The macro surface mirrors `bloxide-tokio`: actor task macros still generate
+//! `async fn`s, and [`spawn_child!`] / [`spawn_timer!`] start them on a new
+//! thread. [`root_task!`] generates a blocking `fn` instead, so the program's
+//! top-level supervisor runs on the calling (usually the main) thread.
+use bloxide_core::{mailboxes::Mailboxes, spec::MachineSpec, StateMachine};
+use core::future::poll_fn;
+
+pub use bloxide_core::{
+    run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion,
+    run_actor_to_completion_batched, run_multiplexed, run_multiplexed_batched, ActorSlot,
+    BatchConfig, Multiplexed,
+};
+pub use bloxide_spawn::SpawnCap;
+
+#[doc(hidden)]
+pub use bloxide_macros::dyn_channels as __dyn_channels_proc_macro;
+#[doc(hidden)]
+pub use bloxide_macros::next_actor_id as __next_actor_id_proc_macro;
+
+pub mod channel;
+pub mod executor;
+pub mod mailbox;
+pub mod prelude;
+pub mod spawn;
+pub mod supervision;
+pub mod timer;
+
+pub use bloxide_child_management::ChildGroupBuilder;
+pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
+pub use channel::{StdSender, StdStream, StdTrySendError};
+pub use executor::block_on;
+pub use spawn::{spawn, StdKillHandle, StdTask};
+pub use supervision::{
+    run_supervised_actor, run_supervised_actor_batched, run_supervised_actor_with_abort,
+    run_supervised_actor_with_abort_batched, GenericChildGroupBuilder, SupervisedSlot,
+};
+
+// ── StdRuntime ────────────────────────────────────────────────────────────────
+
+/// The thread-per-actor runtime capability handle (zero-sized type).
+#[derive(Clone, Copy)]
+pub struct StdRuntime;
+
+// ── next_actor_id! macro ──────────────────────────────────────────────────────
+
+/// Allocate a compile-time actor ID from the same counter used by `channels!`.
+#[macro_export]
+macro_rules! next_actor_id {
+    () => {
+        $crate::__next_actor_id_proc_macro!()
+    };
+}
+
+// ── channels! macro ───────────────────────────────────────────────────────────
+
+/// Create all channels for an actor in one call using bounded std channels.
+///
+/// Takes a comma-separated list of `MessageType(capacity)` pairs and returns
+/// `(refs_tuple, mailboxes_tuple)`.
+#[macro_export]
+macro_rules! channels {
+    ($($tt:tt)*) => {
+        $crate::__dyn_channels_proc_macro!($crate::StdRuntime; $($tt)*)
+    };
+}
+
+// ── actor_task! macro ─────────────────────────────────────────────────────────
+
+/// Generate an async wrapper for an unsupervised bloxide actor.
+///
+/// Start it on its own thread with [`spawn()`]. Pass
+/// `batch = BatchConfig::new(n)` to drain up to `n` ready events per wake via
+/// [`run_actor_batched`].
+#[macro_export]
+macro_rules! actor_task {
+    ($name:ident, $spec:ty $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<$crate::StdRuntime>,
+        ) {
+            $crate::run_actor(machine, mailboxes).await;
+        }
+    };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<$crate::StdRuntime>,
+        ) {
+            $crate::run_actor_batched(machine, mailboxes, $batch).await;
+        }
+    };
+}
+
+// ── actor_task_supervised! macro ──────────────────────────────────────────────
+
+/// Generate an async wrapper for a supervised bloxide actor.
+///
+/// Accepts the same optional `batch = ...` argument as [`actor_task!`].
+#[macro_export]
+macro_rules! actor_task_supervised {
+    ($name:ident, $spec:ty $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            domain_mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::StdRuntime,
+            >,
+            lifecycle_rx: $crate::StdStream<$crate::LifecycleCommand>,
+            actor_id: ::bloxide_core::messaging::ActorId,
+            supervisor_notify: $crate::StdSender<$crate::ChildLifecycleEvent>,
+        ) {
+            $crate::supervision::run_supervised_actor(
+                machine,
+                domain_mailboxes,
+                lifecycle_rx,
+                actor_id,
+                supervisor_notify,
+            )
+            .await;
+        }
+    };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            domain_mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::StdRuntime,
+            >,
+            lifecycle_rx: $crate::StdStream<$crate::LifecycleCommand>,
+            actor_id: ::bloxide_core::messaging::ActorId,
+            supervisor_notify: $crate::StdSender<$crate::ChildLifecycleEvent>,
+        ) {
+            $crate::supervision::run_supervised_actor_batched(
+                machine,
+                domain_mailboxes,
+                lifecycle_rx,
+                actor_id,
+                supervisor_notify,
+                $batch,
+            )
+            .await;
+        }
+    };
+}
+
+// ── root_task! macro ──────────────────────────────────────────────────────────
+
+/// Generate a blocking wrapper for a top-level supervisor or root actor.
+///
+/// The generated `fn` runs [`run_root`] on the calling thread and returns
+/// once the root stops.
+#[macro_export]
+macro_rules! root_task {
+    ($name:ident, $spec:ty, $on_done:expr $(,)?) => {
+        fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<$crate::StdRuntime>,
+        ) {
+            $crate::block_on($crate::run_root(machine, mailboxes));
+            $on_done
+        }
+    };
+    ($name:ident, $spec:ty $(,)?) => {
+        fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<$crate::StdRuntime>,
+        ) {
+            $crate::block_on($crate::run_root(machine, mailboxes));
+        }
+    };
+}
+
+// ── spawn_timer! macro ────────────────────────────────────────────────────────
+
+/// Spawn the timer service and return the `ActorRef<TimerCommand>` for it.
+///
+/// The service runs on its own thread until it receives a
+/// `TimerCommand::Shutdown` message or every `timer_ref` is dropped. It is
+/// also registered with [`timer::register_timer_service`], so supervised
+/// actors' timers are discarded when they stop, reset or abort; the ref the
+/// registry keeps does not count towards keeping the service running.
+#[macro_export]
+macro_rules! spawn_timer {
+    ($capacity:expr) => {{
+        let ((timer_ref,), (timer_stream,)) =
+            $crate::__dyn_channels_proc_macro!($crate::StdRuntime; ::bloxide_timer::TimerCommand($capacity));
+        $crate::spawn(
+            <$crate::StdRuntime as ::bloxide_timer::TimerService>::run_timer_service(
+                timer_stream,
+            ),
+        );
+        $crate::timer::register_timer_service(&timer_ref);
+        timer_ref
+    }};
+}
+
+// ── spawn_child! macro ────────────────────────────────────────────────────────
+
+/// Spawn a supervised child actor on its own thread.
+///
+/// Creates the per-child lifecycle channel, registers the child in the
+/// `ChildGroupBuilder`, and spawns the task with lifecycle arguments injected.
+/// Like the Tokio version, there is no `spawner` parameter.
+#[macro_export]
+macro_rules! spawn_child {
+    ($builder:expr, $task_fn:ident($machine:expr, $mbox:expr, $id:expr), $policy:expr) => {{
+        let (lc_rx, sup_notify) = $builder.add_child($id, $policy);
+        let _handle = $crate::spawn($task_fn($machine, $mbox, lc_rx, $id, sup_notify));
+    }};
+}
+
+// ── Actor run loop ────────────────────────────────────────────────────────────
+
+/// Run the program's top-level supervisor.
+///
+/// Like `run_actor`, dispatches events from `mailboxes` to `machine` in
+/// run-to-completion order. When `DispatchOutcome::Stopped`,
+/// `DispatchOutcome::Aborted`, or `DispatchOutcome::Done` (terminal state
+/// reached) is observed, the function returns so the caller can terminate.
+pub async fn run_root<S, M>(mut machine: StateMachine<S>, mut mailboxes: M)
+where
+    S: MachineSpec + 'static,
+    M: Mailboxes<S::Event>,
+{
+    use bloxide_core::engine::DispatchOutcome;
+    loop {
+        let event = match poll_fn(|cx| mailboxes.poll_next(cx)).await {
+            Some(event) => event,
+            None => return,
+        };
+        match machine.dispatch(event) {
+            DispatchOutcome::Stopped | DispatchOutcome::Aborted | DispatchOutcome::Done(_) => {
+                return
+            }
+            _ => {}
+        }
+    }
+}
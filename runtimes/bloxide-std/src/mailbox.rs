@@ -0,0 +1,78 @@
+// Copyright 2025 Bloxide, all rights reserved
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+
+use bloxide_core::{
+    capability::{BloxRuntime, DynamicChannelCap},
+    messaging::{ActorId, ActorRef, Envelope},
+};
+use bloxide_spawn::Kill;
+
+use crate::{
+    channel::{StdSendError, StdSender, StdStream, StdTrySendError, Wakers},
+    StdRuntime,
+};
+
+// ── Actor ID allocation ───────────────────────────────────────────────────────
+
+static NEXT_STD_ID: AtomicUsize = AtomicUsize::new(1);
+
+fn alloc_std_id() -> ActorId {
+    NEXT_STD_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+// ── BloxRuntime impl ──────────────────────────────────────────────────────────
+
+impl BloxRuntime for StdRuntime {
+    type SendError = StdSendError;
+    type TrySendError = StdTrySendError;
+    type Sender<M: Send + 'static> = StdSender<M>;
+    type Receiver<M: Send + 'static> = StdStream<M>;
+    type Stream<M: Send + 'static> = StdStream<M>;
+    type Kill = Kill;
+
+    fn to_stream<M: Send + 'static>(rx: Self::Receiver<M>) -> Self::Stream<M> {
+        rx
+    }
+
+    async fn send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::SendError> {
+        sender.send(envelope).await
+    }
+
+    fn try_send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::TrySendError> {
+        sender.try_send(envelope)
+    }
+}
+
+// ── DynamicChannelCap impl ────────────────────────────────────────────────────
+
+impl DynamicChannelCap for StdRuntime {
+    fn alloc_actor_id() -> ActorId {
+        alloc_std_id()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero: a zero-capacity `sync_channel` is a
+    /// rendezvous channel, on which `try_send` never succeeds.
+    fn channel<M: Send + 'static>(
+        id: ActorId,
+        capacity: usize,
+    ) -> (ActorRef<M, Self>, Self::Receiver<M>) {
+        assert!(capacity > 0, "channel capacity must be at least 1");
+        let (tx, rx) = mpsc::sync_channel::<Envelope<M>>(capacity);
+        let wakers = Arc::new(Wakers::default());
+        let sender = StdSender {
+            inner: Some(tx),
+            wakers: Arc::clone(&wakers),
+        };
+        let stream = StdStream { inner: rx, wakers };
+        (ActorRef::new(id, sender), stream)
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright 2025 Bloxide, all rights reserved
+/// Convenience re-exports for std wiring sites.
+///
+/// Re-exports everything from `bloxide_core::prelude` plus the std runtime
+/// types. A single `use bloxide_std::prelude::*;` covers all framework types
+/// needed in a wiring binary.
+///
+/// Supervisor types are NOT re-exported here — the runtime does not depend
+/// on `bloxide-supervisor`. Apps that use the supervisor import it directly:
+/// `use bloxide_supervisor::*;`
+pub use crate::{
+    block_on, run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion,
+    run_multiplexed, run_root, run_supervised_actor_with_abort, ActorSlot, BatchConfig,
+    GenericChildGroupBuilder, Multiplexed, SpawnCap, StdRuntime, StdSender, StdStream,
+    SupervisedSlot,
+};
+pub use bloxide_child_management::{
+    Backoff, ChildGroup, ChildGroupBuilder, ChildPolicy, GroupShutdown, Liveness, RestartType,
+    StartOrder,
+};
+pub use bloxide_core::prelude::*;
+pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
@@ -0,0 +1,69 @@
+// Copyright 2025 Bloxide, all rights reserved
+use core::future::Future;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use crate::executor::{self, Signal};
+use crate::StdRuntime;
+
+/// Handle to an actor thread started by [`spawn()`].
+pub struct StdTask {
+    join: JoinHandle<()>,
+    signal: Arc<Signal>,
+}
+
+impl StdTask {
+    /// Block until the thread's future completes or is killed.
+    pub fn join(self) {
+        let _ = self.join.join();
+    }
+
+    /// Returns `true` once the thread has exited.
+    pub fn is_finished(&self) -> bool {
+        self.join.is_finished()
+    }
+}
+
+/// Clonable handle that stops an actor thread.
+///
+/// Killing is cooperative: the thread drops its future the next time it is
+/// woken, which [`StdKillHandle::kill`] forces. A handler that never returns
+/// cannot be interrupted.
+#[derive(Clone)]
+pub struct StdKillHandle(Arc<Signal>);
+
+impl StdKillHandle {
+    pub fn kill(&self) {
+        self.0.kill();
+    }
+}
+
+/// Run `future` on a new OS thread with its own executor.
+pub fn spawn(future: impl Future<Output = ()> + Send + 'static) -> StdTask {
+    let signal = Arc::new(Signal::new());
+    let thread_signal = Arc::clone(&signal);
+    let join = thread::Builder::new()
+        .name("bloxide-actor".into())
+        .spawn(move || {
+            executor::run(future, &thread_signal);
+        })
+        .expect("failed to spawn actor thread");
+    StdTask { join, signal }
+}
+
+impl bloxide_spawn::SpawnCap for StdRuntime {
+    type TaskHandle = StdTask;
+    type KillHandle = StdKillHandle;
+
+    fn spawn(future: impl Future<Output = ()> + Send + 'static) -> Self::TaskHandle {
+        spawn(future)
+    }
+
+    fn kill_handle(handle: Self::TaskHandle) -> Self::KillHandle {
+        StdKillHandle(handle.signal)
+    }
+
+    fn kill(handle: Self::KillHandle) {
+        handle.kill();
+    }
+}
@@ -0,0 +1,689 @@
+// Copyright 2025 Bloxide, all rights reserved
+use bloxide_child_management::AbortCommand;
+use bloxide_core::{
+    batch::{BatchConfig, YieldBudget},
+    engine::{DispatchOutcome, StateMachine},
+    lifecycle::{ChildLifecycleEvent, LifecycleCommand},
+    mailboxes::Mailboxes,
+    messaging::{ActorId, Envelope},
+    multiplex::{Multiplexed, SlotPoll},
+    report_outcome,
+    spec::MachineSpec,
+};
+use bloxide_timer::TimerMark;
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+
+use crate::timer::settle_timers;
+use crate::{StdRuntime, StdSender, StdStream};
+
+// ── Standalone supervised actor runner ───────────────────────────────────────
+
+/// Run a supervised actor on a dedicated thread.
+///
+/// Polls lifecycle and domain mailboxes, dispatches events through the machine,
+/// and reports outcomes to the supervisor.
+pub async fn run_supervised_actor<S: MachineSpec + 'static>(
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<StdRuntime>,
+    lifecycle_stream: StdStream<LifecycleCommand>,
+    actor_id: ActorId,
+    supervisor_notify: StdSender<ChildLifecycleEvent>,
+) {
+    run_supervised_actor_batched(
+        machine,
+        domain_mailboxes,
+        lifecycle_stream,
+        actor_id,
+        supervisor_notify,
+        BatchConfig::UNBATCHED,
+    )
+    .await;
+}
+
+/// Like [`run_supervised_actor`], but dispatches up to `batch.max_batch` ready
+/// events per poll and yields to the executor whenever `batch.yield_budget`
+/// is spent.
+///
+/// The lifecycle stream is re-checked before every event in the batch, so a
+/// `Stop` or `Reset` still pre-empts queued domain messages.
+pub async fn run_supervised_actor_batched<S: MachineSpec + 'static>(
+    mut machine: StateMachine<S>,
+    mut domain_mailboxes: S::Mailboxes<StdRuntime>,
+    mut lifecycle_stream: StdStream<LifecycleCommand>,
+    actor_id: ActorId,
+    supervisor_notify: StdSender<ChildLifecycleEvent>,
+    batch: BatchConfig,
+) {
+    enum LoopAction {
+        Continue(usize),
+        Stop,
+    }
+
+    let mut budget = YieldBudget::new(&batch);
+    loop {
+        let action = poll_fn(|cx| {
+            let mut handled = 0;
+            while handled < batch.max_batch {
+                // First check lifecycle stream (higher priority)
+                match Pin::new(&mut lifecycle_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, cmd))) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = handle_lifecycle(&mut machine, cmd);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, StdRuntime>(&outcome, actor_id, &supervisor_notify);
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                        continue;
+                    }
+                    Poll::Pending => {}
+                }
+
+                // Then check domain mailboxes
+                match domain_mailboxes.poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = machine.dispatch(event);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, StdRuntime>(&outcome, actor_id, &supervisor_notify);
+                        // A graceful Stop completes on a domain event
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Pending => break,
+                }
+            }
+            if handled == 0 {
+                Poll::Pending
+            } else {
+                Poll::Ready(LoopAction::Continue(handled))
+            }
+        })
+        .await;
+
+        match action {
+            LoopAction::Continue(n) => budget.consume(n),
+            LoopAction::Stop => break,
+        }
+        budget.checkpoint().await;
+    }
+}
+
+/// Handle lifecycle command by delegating to engine's lifecycle handler.
+///
+/// This ensures state transitions fire their `on_entry`/`on_exit` callbacks.
+fn handle_lifecycle<S: MachineSpec>(
+    machine: &mut StateMachine<S>,
+    cmd: LifecycleCommand,
+) -> DispatchOutcome<S::State> {
+    machine.handle_lifecycle(cmd)
+}
+
+// ── Abort-aware supervised actor runner ──────────────────────────────────────
+
+/// Run a supervised actor with abort mailbox support.
+///
+/// This wraps [`run_supervised_actor`] with an additional abort mailbox.
+/// When an `AbortCommand::Abort` is received, the actor self-terminates
+/// immediately (breaks out of the loop, drops the future). No callbacks
+/// fire — abort is cooperative but immediate.
+///
+/// The abort mailbox is polled between the lifecycle stream and domain
+/// mailboxes, so an abort command is serviced before any pending domain
+/// messages.
+pub async fn run_supervised_actor_with_abort<S: MachineSpec + 'static>(
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<StdRuntime>,
+    lifecycle_stream: StdStream<LifecycleCommand>,
+    abort_stream: StdStream<AbortCommand>,
+    actor_id: ActorId,
+    supervisor_notify: StdSender<ChildLifecycleEvent>,
+) {
+    run_supervised_actor_with_abort_batched(
+        machine,
+        domain_mailboxes,
+        lifecycle_stream,
+        abort_stream,
+        actor_id,
+        supervisor_notify,
+        BatchConfig::UNBATCHED,
+    )
+    .await;
+}
+
+/// Batched variant of [`run_supervised_actor_with_abort`].
+///
+/// See [`run_supervised_actor_batched`] for the batching semantics. The abort
+/// mailbox is re-checked before every domain event in the batch.
+pub async fn run_supervised_actor_with_abort_batched<S: MachineSpec + 'static>(
+    mut machine: StateMachine<S>,
+    mut domain_mailboxes: S::Mailboxes<StdRuntime>,
+    mut lifecycle_stream: StdStream<LifecycleCommand>,
+    mut abort_stream: StdStream<AbortCommand>,
+    actor_id: ActorId,
+    supervisor_notify: StdSender<ChildLifecycleEvent>,
+    batch: BatchConfig,
+) {
+    enum LoopAction {
+        Continue(usize),
+        Stop,
+    }
+
+    let mut budget = YieldBudget::new(&batch);
+    loop {
+        let action = poll_fn(|cx| {
+            let mut handled = 0;
+            while handled < batch.max_batch {
+                // First check lifecycle stream (higher priority)
+                match Pin::new(&mut lifecycle_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, cmd))) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = handle_lifecycle(&mut machine, cmd);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, StdRuntime>(&outcome, actor_id, &supervisor_notify);
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                        continue;
+                    }
+                    Poll::Pending => {}
+                }
+
+                // Then check abort mailbox (high priority — abort should be
+                // serviced before domain messages so a stuck actor can be
+                // terminated promptly when it next yields to the select loop).
+                match Pin::new(&mut abort_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
+                        // Self-termination: report Aborted, then break out of the
+                        // loop and return. No lifecycle callback fires — abort
+                        // is cooperative but immediate.
+                        settle_timers(
+                            TimerMark::new(machine.current_state()),
+                            &DispatchOutcome::<S::State>::Aborted,
+                            actor_id,
+                        );
+                        report_outcome::<S, StdRuntime>(
+                            &DispatchOutcome::Aborted,
+                            actor_id,
+                            &supervisor_notify,
+                        );
+                        return Poll::Ready(LoopAction::Stop);
+                    }
+                    Poll::Pending => {}
+                }
+
+                // Then check domain mailboxes
+                match domain_mailboxes.poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = machine.dispatch(event);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, StdRuntime>(&outcome, actor_id, &supervisor_notify);
+                        // A graceful Stop completes on a domain event
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Pending => break,
+                }
+            }
+            if handled == 0 {
+                Poll::Pending
+            } else {
+                Poll::Ready(LoopAction::Continue(handled))
+            }
+        })
+        .await;
+
+        match action {
+            LoopAction::Continue(n) => budget.consume(n),
+            LoopAction::Stop => break,
+        }
+        budget.checkpoint().await;
+    }
+}
+
+// ── Multiplexed supervised actor ─────────────────────────────────────────────
+
+/// A supervised actor driven by a multiplexed task instead of its own task.
+///
+/// Each [`poll_dispatch`](Multiplexed::poll_dispatch) does what one iteration
+/// of [`run_supervised_actor_with_abort`] does: lifecycle commands first,
+/// then the abort mailbox if one was attached with
+/// [`with_abort`](Self::with_abort), then domain mailboxes. Outcomes are
+/// reported to the supervisor and timers are settled the same way, so the
+/// supervisor cannot tell the two apart.
+pub struct SupervisedSlot<S: MachineSpec + 'static> {
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<StdRuntime>,
+    lifecycle_stream: StdStream<LifecycleCommand>,
+    abort_stream: Option<StdStream<AbortCommand>>,
+    actor_id: ActorId,
+    supervisor_notify: StdSender<ChildLifecycleEvent>,
+    finished: bool,
+}
+
+impl<S: MachineSpec + 'static> SupervisedSlot<S> {
+    pub fn new(
+        machine: StateMachine<S>,
+        domain_mailboxes: S::Mailboxes<StdRuntime>,
+        lifecycle_stream: StdStream<LifecycleCommand>,
+        actor_id: ActorId,
+        supervisor_notify: StdSender<ChildLifecycleEvent>,
+    ) -> Self {
+        Self {
+            machine,
+            domain_mailboxes,
+            lifecycle_stream,
+            abort_stream: None,
+            actor_id,
+            supervisor_notify,
+            finished: false,
+        }
+    }
+
+    /// Attach the abort mailbox of a dynamically registered child.
+    pub fn with_abort(mut self, abort_stream: StdStream<AbortCommand>) -> Self {
+        self.abort_stream = Some(abort_stream);
+        self
+    }
+
+    pub fn machine(&self) -> &StateMachine<S> {
+        &self.machine
+    }
+
+    fn report(&mut self, mark: TimerMark, outcome: &DispatchOutcome<S::State>) {
+        settle_timers(mark, outcome, self.actor_id);
+        report_outcome::<S, StdRuntime>(outcome, self.actor_id, &self.supervisor_notify);
+    }
+
+    fn finish(&mut self) -> SlotPoll {
+        self.finished = true;
+        SlotPoll::Finished
+    }
+}
+
+impl<S: MachineSpec + 'static> Multiplexed for SupervisedSlot<S> {
+    fn poll_dispatch(&mut self, cx: &mut Context<'_>) -> SlotPoll {
+        if self.finished {
+            return SlotPoll::Finished;
+        }
+
+        match Pin::new(&mut self.lifecycle_stream).poll_next(cx) {
+            Poll::Ready(None) => return self.finish(),
+            Poll::Ready(Some(Envelope(_, cmd))) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = handle_lifecycle(&mut self.machine, cmd);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                return SlotPoll::Dispatched;
+            }
+            Poll::Pending => {}
+        }
+
+        if let Some(abort_stream) = self.abort_stream.as_mut() {
+            match Pin::new(abort_stream).poll_next(cx) {
+                Poll::Ready(None) => return self.finish(),
+                Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
+                    let mark = TimerMark::new(self.machine.current_state());
+                    self.report(mark, &DispatchOutcome::Aborted);
+                    return self.finish();
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        match self.domain_mailboxes.poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = self.machine.dispatch(event);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                SlotPoll::Dispatched
+            }
+            Poll::Ready(None) => self.finish(),
+            Poll::Pending => SlotPoll::Idle,
+        }
+    }
+}
+
+// ── ChildGroupBuilder ─────────────────────────────────────────────────────────
+//
+// The concrete builder is now `bloxide_child_management::ChildGroupBuilder<R, Ctrl>`.
+// We re-export it with the supervisor's control type baked in so existing code
+// that writes `ChildGroupBuilder` (without generic args) keeps working.
+//
+// The app chooses the control type — the runtime does NOT know about
+// `SupervisorControl`. Users who want a different managing blox can use
+// `bloxide_child_management::ChildGroupBuilder<StdRuntime, MyCtrl>` directly.
+
+pub use bloxide_child_management::ChildGroupBuilder as GenericChildGroupBuilder;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_on;
+    use bloxide_core::{
+        capability::{BloxRuntime, DynamicChannelCap},
+        event_tag::{EventTag, LifecycleEvent},
+        mailboxes::NoMailboxes,
+        spec::{MachineSpec, StateFns},
+        topology::StateTopology,
+    };
+    use std::thread;
+    use std::time::Duration;
+
+    // ── Minimal test MachineSpec for supervision loop tests ─────────────────────
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum TestState {
+        Running,
+        // TODO: wire this up — `Done` is the terminal state required by the
+        // `MachineSpec` impl (`is_terminal`, `HANDLER_TABLE`) but no test in
+        // this module transitions to it yet.
+        #[allow(dead_code)]
+        Done,
+    }
+
+    impl StateTopology for TestState {
+        const STATE_COUNT: usize = 2;
+
+        fn parent(self) -> Option<Self> {
+            None
+        }
+
+        fn is_leaf(self) -> bool {
+            true
+        }
+
+        fn path(self) -> &'static [Self] {
+            match self {
+                TestState::Running => &[TestState::Running],
+                TestState::Done => &[TestState::Done],
+            }
+        }
+
+        fn as_index(self) -> usize {
+            match self {
+                TestState::Running => 0,
+                TestState::Done => 1,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct TestEvent;
+    impl EventTag for TestEvent {
+        fn event_tag(&self) -> u8 {
+            0
+        }
+    }
+    impl LifecycleEvent for TestEvent {
+        fn as_lifecycle_command(&self) -> Option<LifecycleCommand> {
+            None
+        }
+    }
+
+    struct TestSpec;
+
+    const RUNNING_FNS: StateFns<TestSpec> = StateFns {
+        on_entry: &[],
+        on_exit: &[],
+        transitions: &[],
+    };
+    const DONE_FNS: StateFns<TestSpec> = StateFns {
+        on_entry: &[],
+        on_exit: &[],
+        transitions: &[],
+    };
+
+    impl MachineSpec for TestSpec {
+        type State = TestState;
+        type Event = TestEvent;
+        type Ctx = ();
+        type Mailboxes<R: BloxRuntime> = NoMailboxes;
+
+        const HANDLER_TABLE: &'static [&'static StateFns<Self>] = &[&RUNNING_FNS, &DONE_FNS];
+
+        fn initial_state() -> Self::State {
+            TestState::Running
+        }
+
+        fn is_terminal(state: &Self::State) -> bool {
+            matches!(state, TestState::Done)
+        }
+    }
+
+    /// Integration test: the supervisor's ripcord path kills an unresponsive
+    /// child thread.
+    ///
+    /// The child awaits a future that never completes and never polls its
+    /// abort mailbox. `ChildPolicy::Kill` must still make the thread drop
+    /// that future, observed through a `Drop` guard.
+    #[test]
+    fn ripcord_kills_unresponsive_child() {
+        use bloxide_child_management::{ChildGroup, ChildPolicy, GroupShutdown};
+        use bloxide_spawn::SpawnCap;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let child_id = <StdRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (lifecycle_ref, _lifecycle_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+        let (abort_ref, _abort_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<AbortCommand>(child_id, 4);
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let dropped_clone = dropped.clone();
+
+        struct DropGuard(Arc<AtomicBool>);
+        impl Drop for DropGuard {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let handle = <StdRuntime as SpawnCap>::spawn(async move {
+            let _guard = DropGuard(dropped_clone);
+            core::future::pending::<()>().await;
+        });
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(!dropped.load(Ordering::SeqCst), "task should be running");
+
+        let kill_handle = <StdRuntime as SpawnCap>::kill_handle(handle);
+        let mut group = ChildGroup::<StdRuntime>::new(GroupShutdown::WhenAnyDone);
+        group.add_dynamic(
+            child_id,
+            lifecycle_ref,
+            abort_ref,
+            kill_handle,
+            ChildPolicy::Kill,
+        );
+
+        let (notify_ref, _notify_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+        group.handle_failed(child_id, 42, &notify_ref);
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(
+            dropped.load(Ordering::SeqCst),
+            "task should have been dropped (killed by ripcord)"
+        );
+    }
+
+    /// A supervised actor on its own thread reports lifecycle outcomes back
+    /// to the supervisor, and a `Stop` in the middle of a batch ends the
+    /// loop immediately.
+    #[test]
+    fn batched_supervised_actor_stops_mid_batch() {
+        let child_id = <StdRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (lifecycle_ref, lifecycle_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+        let (notify_ref, mut notify_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        let machine = StateMachine::<TestSpec>::new(());
+        let task = crate::spawn(run_supervised_actor_batched(
+            machine,
+            NoMailboxes,
+            lifecycle_rx,
+            child_id,
+            notify_ref.sender(),
+            BatchConfig::new(8).with_yield_budget(2),
+        ));
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Ping,
+            LifecycleCommand::Stop,
+            LifecycleCommand::Ping,
+        ] {
+            lifecycle_ref
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+        task.join();
+
+        let mut events = Vec::new();
+        while let Ok(Envelope(_, ev)) = notify_rx.try_recv() {
+            events.push(ev);
+        }
+        assert_eq!(
+            events,
+            vec![
+                ChildLifecycleEvent::Started { child_id },
+                ChildLifecycleEvent::Alive { child_id },
+                ChildLifecycleEvent::Stopped { child_id },
+            ]
+        );
+    }
+
+    /// A reset or stop makes the loop ask the registered timer services to
+    /// drop the actor's earlier timers; starting from `Init` does not.
+    #[test]
+    fn reset_and_stop_cancel_the_actors_timers() {
+        use bloxide_timer::TimerCommand;
+
+        let child_id = <StdRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (timer_ref, mut timer_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<TimerCommand>(0, 16);
+        crate::timer::register_timer_service(&timer_ref);
+        let (lifecycle_ref, lifecycle_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+        let (notify_ref, _notify_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Reset,
+            LifecycleCommand::Stop,
+        ] {
+            lifecycle_ref
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+
+        let machine = StateMachine::<TestSpec>::new(());
+        block_on(run_supervised_actor(
+            machine,
+            NoMailboxes,
+            lifecycle_rx,
+            child_id,
+            notify_ref.sender(),
+        ));
+
+        let mut cancels = 0;
+        while let Ok(Envelope(from, cmd)) = timer_rx.try_recv() {
+            if from == child_id {
+                assert!(
+                    matches!(cmd, TimerCommand::CancelAllFor { owner, .. } if owner == child_id)
+                );
+                cancels += 1;
+            }
+        }
+        assert_eq!(cancels, 2);
+    }
+
+    /// Two supervised actors on one multiplexed task report to the same
+    /// supervisor as if each had its own task: `Stop` ends one slot, an abort
+    /// command ends the other, and the task returns once both have finished.
+    #[test]
+    fn supervised_slots_share_one_task() {
+        let a_id = <StdRuntime as DynamicChannelCap>::alloc_actor_id();
+        let b_id = <StdRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (a_lifecycle, a_lifecycle_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(a_id, 4);
+        let (b_lifecycle, b_lifecycle_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(b_id, 4);
+        let (b_abort, b_abort_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<AbortCommand>(b_id, 4);
+        let (notify_ref, mut notify_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Ping,
+            LifecycleCommand::Stop,
+        ] {
+            a_lifecycle
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+        b_lifecycle
+            .try_send(42, LifecycleCommand::Start)
+            .expect("queue lifecycle command");
+        b_abort
+            .try_send(42, AbortCommand::Abort { child_id: b_id })
+            .expect("queue abort command");
+
+        let mut a = SupervisedSlot::new(
+            StateMachine::<TestSpec>::new(()),
+            NoMailboxes,
+            a_lifecycle_rx,
+            a_id,
+            notify_ref.sender(),
+        );
+        let mut b = SupervisedSlot::new(
+            StateMachine::<TestSpec>::new(()),
+            NoMailboxes,
+            b_lifecycle_rx,
+            b_id,
+            notify_ref.sender(),
+        )
+        .with_abort(b_abort_rx);
+        block_on(bloxide_core::run_multiplexed(&mut [&mut a, &mut b]));
+
+        let mut events = Vec::new();
+        while let Ok(Envelope(_, ev)) = notify_rx.try_recv() {
+            events.push(ev);
+        }
+        assert_eq!(
+            events,
+            vec![
+                ChildLifecycleEvent::Started { child_id: a_id },
+                ChildLifecycleEvent::Started { child_id: b_id },
+                ChildLifecycleEvent::Aborted { child_id: b_id },
+                ChildLifecycleEvent::Alive { child_id: a_id },
+                ChildLifecycleEvent::Stopped { child_id: a_id },
+            ]
+        );
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2025 Bloxide, all rights reserved
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use core::task::Poll;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use bloxide_core::capability::ClockCap;
+use bloxide_core::engine::DispatchOutcome;
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use bloxide_timer::{cancel_timers_for, TimerCommand, TimerMark, TimerQueue, TimerService};
+use futures_core::Stream;
+
+use crate::channel::{StdStream, Wakers};
+use crate::executor::sleep_until;
+use crate::StdRuntime;
+
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+fn now_ms() -> u64 {
+    epoch().elapsed().as_millis() as u64
+}
+
+/// A registered timer service. The channel's wakers are kept alongside the
+/// ref so the service can tell whether only the registry still holds it.
+struct Registered {
+    timer_ref: ActorRef<TimerCommand, StdRuntime>,
+    wakers: Arc<Wakers>,
+}
+
+static SUPERVISED_TIMERS: Mutex<Vec<Registered>> = Mutex::new(Vec::new());
+
+/// Register a timer service whose timers the supervised run loops discard
+/// when an actor stops, resets or aborts.
+///
+/// `spawn_timer!` registers the service it spawns. Call this only when the
+/// service thread is started by hand. Every registered service is asked to
+/// discard the actor's timers; a service whose thread has ended is
+/// forgotten. The registry's ref does not keep the service running: once
+/// every other `timer_ref` is dropped, the service leaves the registry and
+/// ends.
+pub fn register_timer_service(timer_ref: &ActorRef<TimerCommand, StdRuntime>) {
+    let wakers = Arc::clone(&timer_ref.sender().wakers);
+    SUPERVISED_TIMERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(Registered {
+            timer_ref: timer_ref.clone(),
+            wakers,
+        });
+}
+
+/// Remove the service behind `wakers` from the registry if the registry
+/// holds its only senders; `true` if it was removed.
+///
+/// Besides the receiver, each registered entry holds two references to the
+/// wakers: its ref's sender and its own copy.
+fn unregister_if_unused(wakers: &Arc<Wakers>) -> bool {
+    let mut registered = SUPERVISED_TIMERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let entries = registered
+        .iter()
+        .filter(|r| Arc::ptr_eq(&r.wakers, wakers))
+        .count();
+    if entries == 0 || Arc::strong_count(wakers) > 1 + 2 * entries {
+        return false;
+    }
+    registered.retain(|r| !Arc::ptr_eq(&r.wakers, wakers));
+    true
+}
+
+/// Discard `owner`'s timers from before `mark` if `outcome` ended its
+/// incarnation.
+pub(crate) fn settle_timers<St>(mark: TimerMark, outcome: &DispatchOutcome<St>, owner: ActorId) {
+    if !mark.discards(outcome) {
+        return;
+    }
+    let mut registered = SUPERVISED_TIMERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.retain(|r| !r.wakers.is_closed());
+    for r in registered.iter() {
+        cancel_timers_for(&r.timer_ref, owner, mark.before());
+    }
+}
+
+enum Wake {
+    Command(Option<Envelope<TimerCommand>>),
+    Deadline,
+}
+
+impl ClockCap for StdRuntime {
+    fn now_ms() -> u64 {
+        now_ms()
+    }
+}
+
+impl TimerService for StdRuntime {
+    async fn run_timer_service(mut stream: StdStream<TimerCommand>) {
+        let mut queue = TimerQueue::new();
+        loop {
+            let mut sleep = queue
+                .next_deadline()
+                .map(|deadline_ms| sleep_until(epoch() + Duration::from_millis(deadline_ms)));
+            let wake = poll_fn(|cx| {
+                // Commands first, so a cancel beats a deadline that expires
+                // in the same wake-up.
+                if let Poll::Ready(maybe_env) = Pin::new(&mut stream).poll_next(cx) {
+                    return Poll::Ready(Wake::Command(maybe_env));
+                }
+                // Dropping a sender wakes the service, so it notices when
+                // only the registry is left.
+                if unregister_if_unused(&stream.wakers) {
+                    return Poll::Ready(Wake::Command(None));
+                }
+                match sleep.as_mut() {
+                    Some(sleep) => Pin::new(sleep).poll(cx).map(|()| Wake::Deadline),
+                    None => Poll::Pending,
+                }
+            })
+            .await;
+
+            match wake {
+                Wake::Command(Some(Envelope(from, cmd))) => {
+                    let now = now_ms();
+                    let shutdown = queue.handle_command(from, cmd, now);
+                    for deliver in queue.drain_expired(now) {
+                        deliver();
+                    }
+                    if shutdown {
+                        return;
+                    }
+                }
+                // Every timer_ref outside the registry dropped — shut down
+                // gracefully.
+                Wake::Command(None) => return,
+                Wake::Deadline => {
+                    for deliver in queue.drain_expired(now_ms()) {
+                        deliver();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bloxide_core::capability::DynamicChannelCap;
+    use bloxide_timer::{next_timer_id, TimerSchedule};
+    use std::sync::Arc;
+
+    /// The service thread parks until the earliest deadline and then
+    /// delivers, in deadline order.
+    #[test]
+    fn timer_service_delivers_in_deadline_order() {
+        let (timer_ref, timer_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<TimerCommand>(0, 8);
+        let service = crate::spawn(StdRuntime::run_timer_service(timer_rx));
+        let (target, mut target_rx) = <StdRuntime as DynamicChannelCap>::channel::<u32>(1, 8);
+
+        let started = Instant::now();
+        for (after_ms, event) in [(40, 2), (10, 1)] {
+            let cmd = <TimerCommand as TimerSchedule<u32, StdRuntime>>::schedule(
+                1,
+                next_timer_id(),
+                after_ms,
+                &target,
+                event,
+            );
+            timer_ref.try_send(1, cmd).expect("timer channel has room");
+        }
+
+        let fired: Vec<u32> = crate::block_on(async {
+            let mut fired = Vec::new();
+            while fired.len() < 2 {
+                if let Some(Envelope(_, event)) =
+                    poll_fn(|cx| Pin::new(&mut target_rx).poll_next(cx)).await
+                {
+                    fired.push(event);
+                }
+            }
+            fired
+        });
+        assert_eq!(fired, vec![1, 2]);
+        assert!(started.elapsed() >= Duration::from_millis(40));
+
+        drop(timer_ref);
+        service.join();
+    }
+
+    /// Every registered service is told to discard the actor's timers, and
+    /// a service that has ended is dropped from the set.
+    #[test]
+    fn settle_timers_reaches_every_registered_service() {
+        use bloxide_core::engine::MachineState;
+
+        let owner = StdRuntime::alloc_actor_id();
+        let (first_ref, mut first_rx) = StdRuntime::channel::<TimerCommand>(0, 16);
+        let (second_ref, mut second_rx) = StdRuntime::channel::<TimerCommand>(0, 16);
+        let (ended_ref, ended_rx) = StdRuntime::channel::<TimerCommand>(0, 16);
+        register_timer_service(&first_ref);
+        register_timer_service(&second_ref);
+        register_timer_service(&ended_ref);
+        drop(ended_rx);
+
+        let mark = TimerMark::new(MachineState::State(1u8));
+        settle_timers(mark, &DispatchOutcome::<u8>::Stopped, owner);
+
+        for rx in [&mut first_rx, &mut second_rx] {
+            let mut cancels = 0;
+            while let Ok(Envelope(_, cmd)) = rx.try_recv() {
+                if matches!(cmd, TimerCommand::CancelAllFor { owner: o, .. } if o == owner) {
+                    cancels += 1;
+                }
+            }
+            assert_eq!(cancels, 1);
+        }
+        let registered = SUPERVISED_TIMERS.lock().unwrap();
+        assert!(!registered
+            .iter()
+            .any(|r| Arc::ptr_eq(&r.wakers, &ended_ref.sender().wakers)));
+    }
+
+    /// A registered service ends once every `timer_ref` but the registry's
+    /// is dropped, and leaves the registry.
+    #[test]
+    fn registered_service_ends_when_its_refs_are_dropped() {
+        let (timer_ref, timer_rx) =
+            <StdRuntime as DynamicChannelCap>::channel::<TimerCommand>(0, 8);
+        register_timer_service(&timer_ref);
+        let wakers = Arc::downgrade(&timer_ref.sender().wakers);
+        let service = crate::spawn(StdRuntime::run_timer_service(timer_rx));
+        let copy = timer_ref.clone();
+
+        drop(timer_ref);
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!service.is_finished());
+
+        drop(copy);
+        service.join();
+        let registered = SUPERVISED_TIMERS.lock().unwrap();
+        assert!(!registered
+            .iter()
+            .any(|r| Arc::as_ptr(&r.wakers) == wakers.as_ptr()));
+    }
+}
@@ -5,13 +5,24 @@
 //! (from `bloxide-spawn`) so it can be used as the `R` type parameter in unit
 //! tests without an Embassy or Tokio executor.
 //!
+//! [`NoKillTestRuntime`] shares `TestRuntime`'s channels but has no kill
+//! capability, like Embassy, for code that must behave differently there.
+//!
+//! [`sim`] adds [`SimRuntime`]: a seeded, single-threaded simulator that
+//! chooses message delivery order and injects drops, delays and duplicates,
+//! so concurrency bugs between actors reproduce from a seed.
+//!
 //! Timer simulation is intentionally not part of `TestRuntime` itself.
-//! Tests that use timers should pair `TestRuntime` with `bloxide_timer::test_utils`
-//! instead.
+//! Tests that use timers should pair `TestRuntime` with `bloxide_timer::test_utils`,
+//! which also provides `TestRuntime`'s `TimerService` impl.
 
 extern crate alloc;
 
-use bloxide_core::capability::{BloxRuntime, DynamicChannelCap};
+pub mod sim;
+
+pub use sim::{FaultConfig, SimRuntime, Simulation};
+
+use bloxide_core::capability::{BloxRuntime, ClockCap, DynamicChannelCap, NoKill};
 use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
 use bloxide_spawn::{Kill, SpawnCap};
 
@@ -195,18 +206,94 @@ impl DynamicChannelCap for TestRuntime {
         id: ActorId,
         _capacity: usize,
     ) -> (ActorRef<M, Self>, Self::Receiver<M>) {
-        let queue: Queue<M> = Arc::new(Mutex::new(VecDeque::new()));
-        let waker: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
-        let sender = TestSender {
-            queue: Arc::clone(&queue),
-            full: Arc::new(std::sync::atomic::AtomicBool::new(false)),
-            waker: Arc::clone(&waker),
-        };
-        let receiver = TestReceiver { queue, waker };
+        let (sender, receiver) = test_channel();
         (ActorRef::new(id, sender), receiver)
     }
 }
 
+fn test_channel<M: Send + 'static>() -> (TestSender<M>, TestReceiver<M>) {
+    let queue: Queue<M> = Arc::new(Mutex::new(VecDeque::new()));
+    let waker: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
+    let sender = TestSender {
+        queue: Arc::clone(&queue),
+        full: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        waker: Arc::clone(&waker),
+    };
+    let receiver = TestReceiver { queue, waker };
+    (sender, receiver)
+}
+
+// ── NoKillTestRuntime ────────────────────────────────────────────────────
+
+/// `TestRuntime` without a kill capability (`Kill = NoKill`), as on
+/// Embassy. Channels behave exactly as `TestRuntime`'s.
+#[derive(Clone, Default)]
+pub struct NoKillTestRuntime;
+
+impl BloxRuntime for NoKillTestRuntime {
+    type SendError = TestSendError;
+    type TrySendError = TestTrySendError;
+    type Sender<M: Send + 'static> = TestSender<M>;
+    type Receiver<M: Send + 'static> = TestReceiver<M>;
+    type Stream<M: Send + 'static> = TestReceiver<M>;
+    type Kill = NoKill;
+
+    fn to_stream<M: Send + 'static>(rx: Self::Receiver<M>) -> Self::Stream<M> {
+        rx
+    }
+
+    async fn send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::SendError> {
+        TestRuntime::send_via(sender, envelope).await
+    }
+
+    fn try_send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::TrySendError> {
+        TestRuntime::try_send_via(sender, envelope)
+    }
+}
+
+impl DynamicChannelCap for NoKillTestRuntime {
+    fn alloc_actor_id() -> ActorId {
+        alloc_test_id()
+    }
+
+    fn channel<M: Send + 'static>(
+        id: ActorId,
+        _capacity: usize,
+    ) -> (ActorRef<M, Self>, Self::Receiver<M>) {
+        let (sender, receiver) = test_channel();
+        (ActorRef::new(id, sender), receiver)
+    }
+}
+
+// ── ClockCap ─────────────────────────────────────────────────────────────
+
+thread_local! {
+    static NOW_MS: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+impl TestRuntime {
+    /// Move this thread's manual clock forward by `delta_ms`.
+    ///
+    /// The clock starts at 0 and only moves when a test advances it. It is
+    /// independent of `bloxide_timer::test_utils::VirtualClock`, which drives
+    /// timers; tests that need both advance both.
+    pub fn advance_clock(delta_ms: u64) {
+        NOW_MS.with(|now| now.set(now.get().saturating_add(delta_ms)));
+    }
+}
+
+impl ClockCap for TestRuntime {
+    fn now_ms() -> u64 {
+        NOW_MS.with(std::cell::Cell::get)
+    }
+}
+
 // ── SpawnCap ─────────────────────────────────────────────────────────────
 
 use alloc::boxed::Box;
@@ -254,24 +341,37 @@ pub fn spawned_count() -> usize {
 // not directly constructed or read in the waker tests yet.
 #[allow(dead_code)]
 mod waker_tests {
-    use bloxide_core::actor::run_actor_to_completion;
+    use bloxide_core::actor::{run_actor_to_completion, run_actor_to_completion_batched};
+    use bloxide_core::batch::{poll_batch, BatchConfig, BatchStep};
     use bloxide_core::capability::{BloxRuntime, DynamicChannelCap};
     use bloxide_core::engine::StateMachine;
     use bloxide_core::event_tag::{EventTag, LifecycleEvent};
     use bloxide_core::lifecycle::LifecycleCommand;
-    use bloxide_core::messaging::Envelope;
+    use bloxide_core::messaging::{ActorRef, Envelope};
+    use bloxide_core::multiplex::{
+        run_multiplexed, run_multiplexed_batched, ActorSlot, Multiplexed, SlotPoll,
+    };
     use bloxide_core::spec::MachineSpec;
     use bloxide_core::topology::{LeafState, StateTopology};
     use bloxide_core::transition::{ActionResult, Guard, TransitionRule};
+    use core::future::Future;
+    use std::cell::RefCell;
     use std::marker::PhantomData;
+    use std::rc::Rc;
     use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use std::sync::Arc;
-    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Wake, Waker};
 
-    use crate::TestRuntime;
+    use crate::{SimRuntime, TestRuntime};
 
     /// Minimal block_on that only re-polls when woken.
     fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        block_on_counting(future).0
+    }
+
+    /// Like `block_on`, but also returns how many times the future returned
+    /// `Poll::Pending` before completing.
+    fn block_on_counting<F: core::future::Future>(future: F) -> (F::Output, usize) {
         struct WakeFlag {
             woken: AtomicBool,
         }
@@ -303,10 +403,12 @@ mod waker_tests {
         let waker = unsafe { Waker::from_raw(raw) };
         let mut cx = Context::from_waker(&waker);
         let mut future = std::pin::pin!(future);
+        let mut pending = 0;
         loop {
             match future.as_mut().poll(&mut cx) {
-                Poll::Ready(v) => return v,
+                Poll::Ready(v) => return (v, pending),
                 Poll::Pending => {
+                    pending += 1;
                     while !flag.woken.swap(false, Ordering::SeqCst) {
                         std::thread::yield_now();
                     }
@@ -480,6 +582,193 @@ mod waker_tests {
         handle.join().unwrap();
         assert_eq!(processed.load(Ordering::SeqCst), 5);
     }
+
+    fn preloaded_machine(
+        count: u32,
+    ) -> (
+        StateMachine<WSpec<TestRuntime>>,
+        <TestRuntime as BloxRuntime>::Stream<u32>,
+        Arc<AtomicU32>,
+    ) {
+        let id = TestRuntime::alloc_actor_id();
+        let (sender_ref, receiver) = TestRuntime::channel::<u32>(id, 16);
+        for i in 0..count {
+            sender_ref.try_send(0, i).unwrap();
+        }
+        let processed = Arc::new(AtomicU32::new(0));
+        let ctx = WCtx {
+            processed: processed.clone(),
+            threshold: count,
+        };
+        let mut machine = StateMachine::<WSpec<TestRuntime>>::new(ctx);
+        machine.handle_lifecycle(LifecycleCommand::Start);
+        (machine, TestRuntime::to_stream(receiver), processed)
+    }
+
+    #[test]
+    fn poll_batch_drains_at_most_max_batch() {
+        let (_machine, stream, _processed) = preloaded_machine(5);
+        let mut mailboxes = (stream,);
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut seen = Vec::new();
+
+        let mut step = |cx: &mut Context<'_>, seen: &mut Vec<u32>| {
+            poll_batch::<WEvent, _, _>(&mut mailboxes, cx, 3, |ev| {
+                if let WEvent::Msg(n) = ev {
+                    seen.push(n);
+                }
+                false
+            })
+        };
+
+        assert_eq!(
+            step(&mut cx, &mut seen),
+            Poll::Ready(BatchStep::Dispatched(3))
+        );
+        assert_eq!(
+            step(&mut cx, &mut seen),
+            Poll::Ready(BatchStep::Dispatched(2))
+        );
+        assert_eq!(step(&mut cx, &mut seen), Poll::Pending);
+        assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn batched_run_without_budget_never_yields() {
+        let (machine, stream, processed) = preloaded_machine(6);
+        let ((), pending) = block_on_counting(run_actor_to_completion_batched(
+            machine,
+            (stream,),
+            BatchConfig::new(4),
+        ));
+        assert_eq!(processed.load(Ordering::SeqCst), 6);
+        assert_eq!(pending, 0);
+    }
+
+    #[test]
+    fn batched_run_yields_when_budget_spent() {
+        let (machine, stream, processed) = preloaded_machine(6);
+        let batch = BatchConfig::new(2).with_yield_budget(2);
+        let ((), pending) =
+            block_on_counting(run_actor_to_completion_batched(machine, (stream,), batch));
+        assert_eq!(processed.load(Ordering::SeqCst), 6);
+        // Yields after events 2 and 4; event 6 completes the actor.
+        assert_eq!(pending, 2);
+    }
+
+    #[test]
+    fn zero_batch_and_budget_are_clamped_to_one() {
+        let batch = BatchConfig::new(0).with_yield_budget(0);
+        assert_eq!(batch.max_batch, 1);
+        assert_eq!(batch.yield_budget, Some(1));
+        assert_eq!(BatchConfig::default(), BatchConfig::UNBATCHED);
+    }
+
+    /// Records its id once per dispatch, then finishes after `remaining`.
+    struct RecordingSlot {
+        id: u8,
+        remaining: u32,
+        log: Rc<RefCell<Vec<u8>>>,
+    }
+
+    impl Multiplexed for RecordingSlot {
+        fn poll_dispatch(&mut self, _cx: &mut Context<'_>) -> SlotPoll {
+            if self.remaining == 0 {
+                return SlotPoll::Finished;
+            }
+            self.remaining -= 1;
+            self.log.borrow_mut().push(self.id);
+            SlotPoll::Dispatched
+        }
+    }
+
+    fn recording_slots(counts: &[u32]) -> (Vec<RecordingSlot>, Rc<RefCell<Vec<u8>>>) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let slots = counts
+            .iter()
+            .enumerate()
+            .map(|(id, &remaining)| RecordingSlot {
+                id: id as u8,
+                remaining,
+                log: log.clone(),
+            })
+            .collect();
+        (slots, log)
+    }
+
+    #[test]
+    fn multiplexed_rounds_interleave_and_rotate() {
+        let (mut slots, log) = recording_slots(&[2, 1, 2]);
+        let mut actors: Vec<&mut RecordingSlot> = slots.iter_mut().collect();
+        let ((), pending) = block_on_counting(run_multiplexed(&mut actors));
+        // One event per slot per round; each round starts one slot later.
+        assert_eq!(*log.borrow(), vec![0, 1, 2, 2, 0]);
+        assert_eq!(pending, 0);
+    }
+
+    #[test]
+    fn multiplexed_batch_drains_each_slot_up_to_max_batch() {
+        let (mut slots, log) = recording_slots(&[3, 3]);
+        let mut actors: Vec<&mut RecordingSlot> = slots.iter_mut().collect();
+        block_on(run_multiplexed_batched(&mut actors, BatchConfig::new(2)));
+        assert_eq!(*log.borrow(), vec![0, 0, 1, 1, 1, 0]);
+    }
+
+    #[derive(Default)]
+    struct CountingWaker(AtomicU32);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    type TestSlot<R> = ActorSlot<WSpec<R>, (<TestRuntime as BloxRuntime>::Stream<u32>,)>;
+
+    #[test]
+    fn multiplexed_actor_slots_share_one_task() {
+        fn slot<R: BloxRuntime>(
+            threshold: u32,
+        ) -> (TestSlot<R>, ActorRef<u32, TestRuntime>, Arc<AtomicU32>) {
+            let id = TestRuntime::alloc_actor_id();
+            let (sender_ref, receiver) = TestRuntime::channel::<u32>(id, 16);
+            let processed = Arc::new(AtomicU32::new(0));
+            let ctx = WCtx {
+                processed: processed.clone(),
+                threshold,
+            };
+            let mut machine = StateMachine::<WSpec<R>>::new(ctx);
+            machine.handle_lifecycle(LifecycleCommand::Start);
+            let mailboxes = (TestRuntime::to_stream(receiver),);
+            (ActorSlot::new(machine, mailboxes), sender_ref, processed)
+        }
+
+        // Two machine specs with different types behind one scheduler.
+        let (mut a, a_tx, a_processed) = slot::<TestRuntime>(10);
+        let (mut b, b_tx, b_processed) = slot::<SimRuntime>(10);
+        for i in 0..3 {
+            a_tx.try_send(0, i).unwrap();
+        }
+        b_tx.try_send(0, 7).unwrap();
+
+        let wakes = Arc::new(CountingWaker::default());
+        let waker = Waker::from(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut actors: [&mut dyn Multiplexed; 2] = [&mut a, &mut b];
+        let mut run = std::pin::pin!(run_multiplexed(&mut actors));
+
+        // Drains everything ready, then waits with a waker registered.
+        assert!(run.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(a_processed.load(Ordering::SeqCst), 3);
+        assert_eq!(b_processed.load(Ordering::SeqCst), 1);
+
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+        b_tx.try_send(0, 8).unwrap();
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+        assert!(run.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(b_processed.load(Ordering::SeqCst), 2);
+    }
 }
 
 // ── Lifecycle dispatch tests ──────────────────────────────────────────────
@@ -0,0 +1,845 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Deterministic simulation: a seeded, single-threaded executor for exploring
+//! message interleavings.
+//!
+//! A [`Simulation`] owns every task spawned on [`SimRuntime`] and every
+//! channel created with it. Sends do not reach the receiver directly; they
+//! are held "in transit" and released one at a time; they count against the
+//! mailbox's capacity, so `try_send` fails and `send` waits while it is
+//! full. Each [`step`](Simulation::step):
+//!
+//! 1. polls every task that was woken since the previous step, in spawn
+//!    order;
+//! 2. picks one mailbox whose oldest in-transit message is due, using the
+//!    seeded RNG, and delivers that message;
+//! 3. advances the step counter, which is the simulation's only notion of
+//!    time.
+//!
+//! Fault injection is configured with [`FaultConfig`]: messages can be
+//! dropped, delayed by a random number of steps, or duplicated (for mailboxes
+//! opted in with [`Simulation::allow_duplicates`], since duplication needs
+//! `M: Clone`). All decisions come from one RNG seeded by
+//! [`Simulation::new`], so a failing seed replays identically; compare
+//! [`trace`](Simulation::trace)s to confirm.
+//!
+//! ```ignore
+//! // Doc test ignored: illustrative
+//! for seed in 0..100 {
+//!     let mut sim = Simulation::new(seed, FaultConfig::NONE.with_drop(50));
+//!     let ((ping_ref,), ping_mbox) = /* SimRuntime channels */;
+//!     sim.spawn(run_actor(ping_machine, ping_mbox));
+//!     sim.run_until_idle(10_000).unwrap_or_else(|_| panic!("seed {seed} livelocked"));
+//!     assert!(invariant_holds(), "failed with seed {seed}");
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use std::cell::RefCell;
+use std::sync::{Mutex, MutexGuard};
+use std::task::{Context, Poll, Wake, Waker};
+
+use bloxide_core::capability::{BloxRuntime, DynamicChannelCap};
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use bloxide_spawn::{Kill, SpawnCap};
+use futures_core::Stream;
+
+use crate::{TestSendError, TestTrySendError};
+
+fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ── FaultConfig ──────────────────────────────────────────────────────────
+
+/// Message faults injected by a [`Simulation`]. Rates are per mille (out of
+/// 1000) and are rolled independently for every send.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultConfig {
+    /// Chance a sent message is discarded.
+    pub drop_per_mille: u16,
+    /// Chance a sent message is delivered twice. Only applies to mailboxes
+    /// registered with [`Simulation::allow_duplicates`].
+    pub duplicate_per_mille: u16,
+    /// Upper bound, in steps, of the random delay added to each message.
+    pub max_delay: u64,
+}
+
+impl FaultConfig {
+    /// Reliable delivery: no drops, duplicates or delays.
+    pub const NONE: Self = Self {
+        drop_per_mille: 0,
+        duplicate_per_mille: 0,
+        max_delay: 0,
+    };
+
+    pub const fn with_drop(mut self, per_mille: u16) -> Self {
+        self.drop_per_mille = per_mille;
+        self
+    }
+
+    pub const fn with_duplicate(mut self, per_mille: u16) -> Self {
+        self.duplicate_per_mille = per_mille;
+        self
+    }
+
+    pub const fn with_max_delay(mut self, steps: u64) -> Self {
+        self.max_delay = steps;
+        self
+    }
+}
+
+impl Default for FaultConfig {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+// ── Trace ────────────────────────────────────────────────────────────────
+
+/// What happened to a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceKind {
+    /// Handed to the receiving mailbox.
+    Delivered,
+    /// Discarded by fault injection at send time.
+    Dropped,
+    /// A second copy was queued by fault injection at send time.
+    Duplicated,
+}
+
+/// One entry in a simulation's message log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub step: u64,
+    pub kind: TraceKind,
+    pub from: ActorId,
+    pub to: ActorId,
+}
+
+/// [`Simulation::run_until_idle`] hit its step limit with work remaining.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepLimitReached {
+    pub steps: u64,
+}
+
+// ── RNG ──────────────────────────────────────────────────────────────────
+
+/// SplitMix64: tiny, fast, and stable across platforms and releases, which
+/// is what seed replay needs.
+struct SimRng(u64);
+
+impl SimRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`. `n` must be non-zero.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn chance(&mut self, per_mille: u16) -> bool {
+        per_mille > 0 && self.below(1000) < u64::from(per_mille)
+    }
+}
+
+// ── World ────────────────────────────────────────────────────────────────
+
+type Task = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// State shared by the simulation, its channels and its wakers.
+struct World {
+    rng: SimRng,
+    faults: FaultConfig,
+    step: u64,
+    channels: Vec<Arc<dyn Mailbox>>,
+    trace: Vec<TraceEvent>,
+    spawned: Vec<(usize, Task)>,
+    killed: BTreeSet<usize>,
+    next_task: usize,
+    next_actor: ActorId,
+}
+
+/// Task IDs woken since they were last polled. Kept outside [`World`] so a
+/// waker can fire while the world is locked.
+type WokenSet = Arc<Mutex<BTreeSet<usize>>>;
+
+struct Shared {
+    world: Mutex<World>,
+    woken: WokenSet,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Shared>>> = const { RefCell::new(None) };
+}
+
+fn current() -> Arc<Shared> {
+    CURRENT
+        .with(|c| c.borrow().clone())
+        .expect("SimRuntime used outside of a live Simulation on this thread")
+}
+
+struct TaskWaker {
+    id: usize,
+    woken: WokenSet,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        lock(&self.woken).insert(self.id);
+    }
+}
+
+/// Type-erased view of a channel, used to pick and deliver due messages.
+trait Mailbox: Send + Sync {
+    /// Whether the oldest in-transit message is due at `step`.
+    fn has_due(&self, step: u64) -> bool;
+    fn has_in_transit(&self) -> bool;
+    /// Move the oldest in-transit message to the receiver and wake it.
+    /// Returns the sender's ID.
+    fn deliver(&self) -> ActorId;
+    fn id(&self) -> ActorId;
+}
+
+// ── Channels ─────────────────────────────────────────────────────────────
+
+struct ChannelState<M> {
+    in_transit: VecDeque<(u64, Envelope<M>)>,
+    ready: VecDeque<Envelope<M>>,
+    waker: Option<Waker>,
+    /// Senders parked in `send` until the receiver frees a slot.
+    blocked: Vec<Waker>,
+    duplicate: Option<fn(&M) -> M>,
+}
+
+struct Channel<M> {
+    id: ActorId,
+    capacity: usize,
+    state: Mutex<ChannelState<M>>,
+}
+
+impl<M: Send + 'static> Mailbox for Channel<M> {
+    fn has_due(&self, step: u64) -> bool {
+        lock(&self.state)
+            .in_transit
+            .front()
+            .is_some_and(|(due, _)| *due <= step)
+    }
+
+    fn has_in_transit(&self) -> bool {
+        !lock(&self.state).in_transit.is_empty()
+    }
+
+    fn deliver(&self) -> ActorId {
+        let mut state = lock(&self.state);
+        let (_, envelope) = state
+            .in_transit
+            .pop_front()
+            .expect("deliver called without a due message");
+        let from = envelope.0;
+        state.ready.push_back(envelope);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        from
+    }
+
+    fn id(&self) -> ActorId {
+        self.id
+    }
+}
+
+/// Send half of a [`SimRuntime`] channel.
+pub struct SimSender<M: Send + 'static> {
+    channel: Arc<Channel<M>>,
+    shared: Arc<Shared>,
+}
+
+impl<M: Send + 'static> Clone for SimSender<M> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: Arc::clone(&self.channel),
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<M: Send + 'static> SimSender<M> {
+    /// Queue `envelope` in transit, applying fault injection.
+    fn enqueue(&self, envelope: Envelope<M>) {
+        let mut world = lock(&self.shared.world);
+        let faults = world.faults;
+        let (from, to, step) = (envelope.0, self.channel.id, world.step);
+
+        if world.rng.chance(faults.drop_per_mille) {
+            world.trace.push(TraceEvent {
+                step,
+                kind: TraceKind::Dropped,
+                from,
+                to,
+            });
+            return;
+        }
+
+        let mut state = lock(&self.channel.state);
+        let due = |rng: &mut SimRng| {
+            step + match faults.max_delay {
+                0 => 0,
+                max => rng.below(max + 1),
+            }
+        };
+        let copy = state
+            .duplicate
+            .filter(|_| world.rng.chance(faults.duplicate_per_mille))
+            .map(|dup| Envelope(from, dup(&envelope.1)));
+        let first_due = due(&mut world.rng);
+        state.in_transit.push_back((first_due, envelope));
+        if let Some(copy) = copy {
+            let copy_due = due(&mut world.rng).max(first_due);
+            state.in_transit.push_back((copy_due, copy));
+            world.trace.push(TraceEvent {
+                step,
+                kind: TraceKind::Duplicated,
+                from,
+                to,
+            });
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        let state = lock(&self.channel.state);
+        state.in_transit.len() + state.ready.len() >= self.channel.capacity
+    }
+
+    /// Ready once the mailbox has room; otherwise park `cx`'s waker until the
+    /// receiver takes a message.
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = lock(&self.channel.state);
+        if state.in_transit.len() + state.ready.len() < self.channel.capacity {
+            return Poll::Ready(());
+        }
+        state.blocked.push(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// Receive half of a [`SimRuntime`] channel.
+pub struct SimReceiver<M: Send + 'static> {
+    channel: Arc<Channel<M>>,
+}
+
+impl<M: Send + 'static> Stream for SimReceiver<M> {
+    type Item = Envelope<M>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = lock(&self.channel.state);
+        match state.ready.pop_front() {
+            Some(envelope) => {
+                for waker in state.blocked.drain(..) {
+                    waker.wake();
+                }
+                Poll::Ready(Some(envelope))
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// ── SimRuntime ───────────────────────────────────────────────────────────
+
+/// Runtime whose channels and tasks belong to the [`Simulation`] live on
+/// the current thread.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SimRuntime;
+
+impl BloxRuntime for SimRuntime {
+    type SendError = TestSendError;
+    type TrySendError = TestTrySendError;
+    type Sender<M: Send + 'static> = SimSender<M>;
+    type Receiver<M: Send + 'static> = SimReceiver<M>;
+    type Stream<M: Send + 'static> = SimReceiver<M>;
+    type Kill = Kill;
+
+    fn to_stream<M: Send + 'static>(rx: Self::Receiver<M>) -> Self::Stream<M> {
+        rx
+    }
+
+    async fn send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::SendError> {
+        poll_fn(|cx| sender.poll_ready(cx)).await;
+        sender.enqueue(envelope);
+        Ok(())
+    }
+
+    fn try_send_via<M: Send + 'static>(
+        sender: &Self::Sender<M>,
+        envelope: Envelope<M>,
+    ) -> Result<(), Self::TrySendError> {
+        if sender.is_full() {
+            return Err(TestTrySendError);
+        }
+        sender.enqueue(envelope);
+        Ok(())
+    }
+}
+
+impl DynamicChannelCap for SimRuntime {
+    /// IDs are allocated per simulation so traces replay identically.
+    fn alloc_actor_id() -> ActorId {
+        let shared = current();
+        let mut world = lock(&shared.world);
+        let id = world.next_actor;
+        world.next_actor += 1;
+        id
+    }
+
+    fn channel<M: Send + 'static>(
+        id: ActorId,
+        capacity: usize,
+    ) -> (ActorRef<M, Self>, Self::Receiver<M>) {
+        let shared = current();
+        let channel = Arc::new(Channel {
+            id,
+            capacity: capacity.max(1),
+            state: Mutex::new(ChannelState {
+                in_transit: VecDeque::new(),
+                ready: VecDeque::new(),
+                waker: None,
+                blocked: Vec::new(),
+                duplicate: None,
+            }),
+        });
+        lock(&shared.world)
+            .channels
+            .push(Arc::clone(&channel) as Arc<dyn Mailbox>);
+        let sender = SimSender {
+            channel: Arc::clone(&channel),
+            shared,
+        };
+        (ActorRef::new(id, sender), SimReceiver { channel })
+    }
+}
+
+impl SpawnCap for SimRuntime {
+    type TaskHandle = usize;
+    type KillHandle = usize;
+
+    fn spawn(future: impl Future<Output = ()> + Send + 'static) -> Self::TaskHandle {
+        spawn_on(&current(), Box::pin(future))
+    }
+
+    fn kill_handle(handle: Self::TaskHandle) -> Self::KillHandle {
+        handle
+    }
+
+    fn kill(handle: Self::KillHandle) {
+        let shared = current();
+        lock(&shared.world).killed.insert(handle);
+        lock(&shared.woken).remove(&handle);
+    }
+}
+
+fn spawn_on(shared: &Shared, task: Task) -> usize {
+    let id = {
+        let mut world = lock(&shared.world);
+        let id = world.next_task;
+        world.next_task += 1;
+        world.spawned.push((id, task));
+        id
+    };
+    lock(&shared.woken).insert(id);
+    id
+}
+
+// ── Simulation ───────────────────────────────────────────────────────────
+
+/// A deterministic world for [`SimRuntime`] actors. See the
+/// [module docs](self).
+///
+/// Creating a `Simulation` makes it current for the thread until it is
+/// dropped; only one may be live per thread.
+pub struct Simulation {
+    shared: Arc<Shared>,
+    seed: u64,
+    tasks: Vec<(usize, Task, Waker)>,
+}
+
+impl Simulation {
+    pub fn new(seed: u64, faults: FaultConfig) -> Self {
+        let shared = Arc::new(Shared {
+            world: Mutex::new(World {
+                rng: SimRng(seed),
+                faults,
+                step: 0,
+                channels: Vec::new(),
+                trace: Vec::new(),
+                spawned: Vec::new(),
+                killed: BTreeSet::new(),
+                next_task: 0,
+                next_actor: 1,
+            }),
+            woken: Arc::new(Mutex::new(BTreeSet::new())),
+        });
+        CURRENT.with(|c| {
+            let mut current = c.borrow_mut();
+            assert!(
+                current.is_none(),
+                "a Simulation is already live on this thread"
+            );
+            *current = Some(Arc::clone(&shared));
+        });
+        Self {
+            shared,
+            seed,
+            tasks: Vec::new(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Steps taken so far.
+    pub fn now(&self) -> u64 {
+        lock(&self.shared.world).step
+    }
+
+    /// Spawn a task. Same as `SimRuntime::spawn` while this simulation is
+    /// current.
+    pub fn spawn(&mut self, future: impl Future<Output = ()> + Send + 'static) -> usize {
+        spawn_on(&self.shared, Box::pin(future))
+    }
+
+    /// Let fault injection duplicate messages sent to `target`.
+    pub fn allow_duplicates<M: Clone + Send + 'static>(&self, target: &ActorRef<M, SimRuntime>) {
+        lock(&target.sender().channel.state).duplicate = Some(M::clone);
+    }
+
+    /// The message log so far.
+    pub fn trace(&self) -> Vec<TraceEvent> {
+        lock(&self.shared.world).trace.clone()
+    }
+
+    /// Number of tasks that have not yet completed or been killed.
+    pub fn live_tasks(&self) -> usize {
+        self.tasks.len() + lock(&self.shared.world).spawned.len()
+    }
+
+    /// Run one step. Returns `false` when the simulation is idle: no task is
+    /// woken and no message is in transit.
+    pub fn step(&mut self) -> bool {
+        self.adopt_spawned();
+        let woken = core::mem::take(&mut *lock(&self.shared.woken));
+        for id in &woken {
+            self.poll_task(*id);
+        }
+        let delivered = self.deliver_one();
+
+        let mut world = lock(&self.shared.world);
+        world.step += 1;
+        let in_transit = world.channels.iter().any(|c| c.has_in_transit());
+        let pending = !world.spawned.is_empty() || !lock(&self.shared.woken).is_empty();
+        !woken.is_empty() || delivered || in_transit || pending
+    }
+
+    /// Step until idle, or fail after `max_steps`.
+    pub fn run_until_idle(&mut self, max_steps: u64) -> Result<u64, StepLimitReached> {
+        for taken in 0..max_steps {
+            if !self.step() {
+                return Ok(taken + 1);
+            }
+        }
+        Err(StepLimitReached { steps: max_steps })
+    }
+
+    fn adopt_spawned(&mut self) {
+        let mut world = lock(&self.shared.world);
+        let spawned = core::mem::take(&mut world.spawned);
+        for (id, task) in spawned {
+            if world.killed.contains(&id) {
+                continue;
+            }
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id,
+                woken: Arc::clone(&self.shared.woken),
+            }));
+            self.tasks.push((id, task, waker));
+        }
+        let killed = core::mem::take(&mut world.killed);
+        self.tasks.retain(|(id, _, _)| !killed.contains(id));
+    }
+
+    fn poll_task(&mut self, id: usize) {
+        let Some(index) = self.tasks.iter().position(|(t, _, _)| *t == id) else {
+            return;
+        };
+        let (_, task, waker) = &mut self.tasks[index];
+        let mut cx = Context::from_waker(waker);
+        if task.as_mut().poll(&mut cx).is_ready() {
+            drop(self.tasks.remove(index));
+        }
+    }
+
+    fn deliver_one(&mut self) -> bool {
+        let mut world = lock(&self.shared.world);
+        let step = world.step;
+        let due: Vec<Arc<dyn Mailbox>> = world
+            .channels
+            .iter()
+            .filter(|c| c.has_due(step))
+            .cloned()
+            .collect();
+        if due.is_empty() {
+            return false;
+        }
+        let pick = &due[world.rng.below(due.len() as u64) as usize];
+        let from = pick.deliver();
+        let to = pick.id();
+        world.trace.push(TraceEvent {
+            step,
+            kind: TraceKind::Delivered,
+            from,
+            to,
+        });
+        true
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        // Drop tasks first: their destructors may touch the world.
+        self.tasks.clear();
+        lock(&self.shared.world).spawned.clear();
+        CURRENT.with(|c| c.borrow_mut().take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    type Log = Arc<Mutex<Vec<u32>>>;
+
+    async fn recv<M: Send + 'static>(rx: &mut SimReceiver<M>) -> Option<M> {
+        poll_fn(|cx| Pin::new(&mut *rx).poll_next(cx))
+            .await
+            .map(|Envelope(_, msg)| msg)
+    }
+
+    /// Two producers each send three numbers through their own relay to a
+    /// collector. The RNG decides which relay's mailbox delivers first, so
+    /// the collector's order depends on the seed.
+    fn relay_scenario(sim: &mut Simulation) -> Log {
+        let log: Log = Arc::default();
+        let (collector, mut collector_rx) =
+            SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 16);
+        for base in [0u32, 100] {
+            let relay_id = SimRuntime::alloc_actor_id();
+            let (relay, mut relay_rx) = SimRuntime::channel::<u32>(relay_id, 16);
+            let out = collector.clone();
+            sim.spawn(async move {
+                while let Some(n) = recv(&mut relay_rx).await {
+                    out.send(relay_id, n).await.unwrap();
+                }
+            });
+            sim.spawn(async move {
+                for n in base..base + 3 {
+                    relay.send(0, n).await.unwrap();
+                }
+            });
+        }
+        let sink = Arc::clone(&log);
+        sim.spawn(async move {
+            while let Some(n) = recv(&mut collector_rx).await {
+                sink.lock().unwrap().push(n);
+            }
+        });
+        log
+    }
+
+    fn run_relay(seed: u64, faults: FaultConfig) -> (Vec<u32>, Vec<TraceEvent>) {
+        let mut sim = Simulation::new(seed, faults);
+        let log = relay_scenario(&mut sim);
+        sim.run_until_idle(1_000).unwrap();
+        let order = log.lock().unwrap().clone();
+        (order, sim.trace())
+    }
+
+    #[test]
+    fn same_seed_replays_identically() {
+        let faults = FaultConfig::NONE.with_max_delay(3).with_drop(100);
+        for seed in 0..10 {
+            assert_eq!(
+                run_relay(seed, faults),
+                run_relay(seed, faults),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn seeds_explore_different_interleavings() {
+        let mut orders = Vec::new();
+        for seed in 0..20 {
+            let (order, _) = run_relay(seed, FaultConfig::NONE);
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, [0, 1, 2, 100, 101, 102], "seed {seed}");
+            if !orders.contains(&order) {
+                orders.push(order);
+            }
+        }
+        assert!(orders.len() > 1, "every seed produced the same order");
+    }
+
+    #[test]
+    fn per_mailbox_order_is_preserved() {
+        for seed in 0..20 {
+            let (order, _) = run_relay(seed, FaultConfig::NONE.with_max_delay(4));
+            let low: Vec<_> = order.iter().copied().filter(|n| *n < 100).collect();
+            let high: Vec<_> = order.iter().copied().filter(|n| *n >= 100).collect();
+            assert_eq!(low, [0, 1, 2], "seed {seed}");
+            assert_eq!(high, [100, 101, 102], "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn dropped_messages_never_arrive() {
+        let (order, trace) = run_relay(7, FaultConfig::NONE.with_drop(1000));
+        assert!(order.is_empty());
+        assert_eq!(trace.len(), 6);
+        assert!(trace.iter().all(|e| e.kind == TraceKind::Dropped));
+    }
+
+    #[test]
+    fn duplicates_only_hit_opted_in_mailboxes() {
+        let mut sim = Simulation::new(1, FaultConfig::NONE.with_duplicate(1000));
+        let (plain, mut plain_rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 8);
+        let (dup, mut dup_rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 8);
+        sim.allow_duplicates(&dup);
+
+        plain.try_send(0, 1).unwrap();
+        dup.try_send(0, 2).unwrap();
+        let log: Log = Arc::default();
+        let sink = Arc::clone(&log);
+        sim.spawn(async move {
+            while let Some(n) = recv(&mut plain_rx).await {
+                sink.lock().unwrap().push(n);
+            }
+        });
+        let sink = Arc::clone(&log);
+        sim.spawn(async move {
+            while let Some(n) = recv(&mut dup_rx).await {
+                sink.lock().unwrap().push(n);
+            }
+        });
+        sim.run_until_idle(100).unwrap();
+
+        let mut got = log.lock().unwrap().clone();
+        got.sort_unstable();
+        assert_eq!(got, [1, 2, 2]);
+    }
+
+    #[test]
+    fn delayed_messages_arrive_later() {
+        let mut sim = Simulation::new(3, FaultConfig::NONE.with_max_delay(10));
+        let (target, _rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 64);
+        for n in 0..32 {
+            target.try_send(0, n).unwrap();
+        }
+        sim.run_until_idle(1_000).unwrap();
+        let trace = sim.trace();
+        assert_eq!(trace.len(), 32);
+        assert!(
+            trace.iter().any(|e| e.step > 31),
+            "some message was held back"
+        );
+    }
+
+    #[test]
+    fn try_send_respects_capacity() {
+        let _sim = Simulation::new(0, FaultConfig::NONE);
+        let (target, _rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 2);
+        target.try_send(0, 1).unwrap();
+        target.try_send(0, 2).unwrap();
+        assert_eq!(target.try_send(0, 3), Err(TestTrySendError));
+    }
+
+    #[test]
+    fn send_waits_for_capacity() {
+        let mut sim = Simulation::new(0, FaultConfig::NONE);
+        let (target, mut rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 1);
+        let sent: Log = Arc::default();
+        let sink = Arc::clone(&sent);
+        sim.spawn(async move {
+            for n in 0..3 {
+                target.send(0, n).await.unwrap();
+                sink.lock().unwrap().push(n);
+            }
+        });
+        sim.run_until_idle(10).unwrap();
+        assert_eq!(*sent.lock().unwrap(), [0]);
+
+        let log: Log = Arc::default();
+        let got = Arc::clone(&log);
+        sim.spawn(async move {
+            while let Some(n) = recv(&mut rx).await {
+                got.lock().unwrap().push(n);
+            }
+        });
+        sim.run_until_idle(100).unwrap();
+        assert_eq!(*sent.lock().unwrap(), [0, 1, 2]);
+        assert_eq!(*log.lock().unwrap(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn killed_task_is_never_polled_again() {
+        let mut sim = Simulation::new(0, FaultConfig::NONE);
+        let (target, mut rx) = SimRuntime::channel::<u32>(SimRuntime::alloc_actor_id(), 8);
+        let log: Log = Arc::default();
+        let sink = Arc::clone(&log);
+        let handle = SimRuntime::spawn(async move {
+            while let Some(n) = recv(&mut rx).await {
+                sink.lock().unwrap().push(n);
+            }
+        });
+        sim.run_until_idle(10).unwrap();
+        assert_eq!(sim.live_tasks(), 1);
+
+        SimRuntime::kill(SimRuntime::kill_handle(handle));
+        target.try_send(0, 1).unwrap();
+        sim.run_until_idle(10).unwrap();
+        assert!(log.lock().unwrap().is_empty());
+        assert_eq!(sim.live_tasks(), 0);
+    }
+
+    #[test]
+    fn busy_task_hits_step_limit() {
+        let mut sim = Simulation::new(0, FaultConfig::NONE);
+        sim.spawn(async {
+            loop {
+                bloxide_core::batch::yield_now().await;
+            }
+        });
+        assert_eq!(sim.run_until_idle(50), Err(StepLimitReached { steps: 50 }));
+    }
+}
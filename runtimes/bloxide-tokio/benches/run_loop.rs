@@ -0,0 +1,168 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Throughput of the unbatched run loop versus `run_actor_batched`.
+//!
+//! Run with `cargo bench -p bloxide-tokio --bench run_loop`. A producer task
+//! pushes `MESSAGES` events through a bounded channel while the actor drains
+//! them; each configuration reports messages per second on a current-thread
+//! runtime.
+
+use std::time::{Duration, Instant};
+
+use bloxide_core::{
+    capability::{BloxRuntime, DynamicChannelCap},
+    event_tag::{EventTag, LifecycleEvent},
+    lifecycle::LifecycleCommand,
+    messaging::Envelope,
+    spec::{MachineSpec, StateFns},
+    topology::{LeafState, StateTopology},
+    transition::{ActionResult, Guard, TransitionRule},
+    StateMachine,
+};
+use bloxide_tokio::{run_actor_to_completion, run_actor_to_completion_batched, BatchConfig};
+use bloxide_tokio::{TokioRuntime, TokioStream};
+
+const MESSAGES: u64 = 1_000_000;
+const CAPACITY: usize = 1024;
+const ROUNDS: usize = 5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BenchState {
+    Running,
+    Done,
+}
+
+impl StateTopology for BenchState {
+    const STATE_COUNT: usize = 2;
+    fn parent(self) -> Option<Self> {
+        None
+    }
+    fn is_leaf(self) -> bool {
+        true
+    }
+    fn path(self) -> &'static [Self] {
+        match self {
+            BenchState::Running => &[BenchState::Running],
+            BenchState::Done => &[BenchState::Done],
+        }
+    }
+    fn as_index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Tick;
+
+impl EventTag for Tick {
+    fn event_tag(&self) -> u8 {
+        0
+    }
+}
+
+impl LifecycleEvent for Tick {
+    fn as_lifecycle_command(&self) -> Option<LifecycleCommand> {
+        None
+    }
+}
+
+impl From<Envelope<u64>> for Tick {
+    fn from(_: Envelope<u64>) -> Self {
+        Tick
+    }
+}
+
+struct BenchCtx {
+    seen: u64,
+}
+
+struct BenchSpec;
+
+impl MachineSpec for BenchSpec {
+    type State = BenchState;
+    type Event = Tick;
+    type Ctx = BenchCtx;
+    type Mailboxes<R: BloxRuntime> = (R::Stream<u64>,);
+
+    const HANDLER_TABLE: &'static [&'static StateFns<Self>] = &[
+        &StateFns {
+            on_entry: &[],
+            on_exit: &[],
+            transitions: &[TransitionRule {
+                event_tag: 0,
+                matches: |_| true,
+                actions: &[|ctx, _| {
+                    ctx.seen += 1;
+                    ActionResult::Ok
+                }],
+                guard: |ctx, _, _| {
+                    if ctx.seen >= MESSAGES {
+                        Guard::Transition(LeafState::new(BenchState::Done))
+                    } else {
+                        Guard::Stay
+                    }
+                },
+            }],
+        },
+        &StateFns {
+            on_entry: &[],
+            on_exit: &[],
+            transitions: &[],
+        },
+    ];
+
+    fn initial_state() -> BenchState {
+        BenchState::Running
+    }
+
+    fn is_terminal(state: &BenchState) -> bool {
+        matches!(state, BenchState::Done)
+    }
+}
+
+async fn run_once(batch: Option<BatchConfig>) -> Duration {
+    let id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+    let (actor_ref, rx): (_, TokioStream<u64>) =
+        <TokioRuntime as DynamicChannelCap>::channel::<u64>(id, CAPACITY);
+    let mut machine = StateMachine::<BenchSpec>::new(BenchCtx { seen: 0 });
+    machine.handle_lifecycle(LifecycleCommand::Start);
+
+    let start = Instant::now();
+    let producer = tokio::spawn(async move {
+        for n in 0..MESSAGES {
+            actor_ref.send(0, n).await.expect("actor alive");
+        }
+    });
+    match batch {
+        None => run_actor_to_completion(machine, (rx,)).await,
+        Some(batch) => run_actor_to_completion_batched(machine, (rx,), batch).await,
+    }
+    let elapsed = start.elapsed();
+    producer.await.expect("producer");
+    elapsed
+}
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("tokio runtime");
+
+    let configs: [(&str, Option<BatchConfig>); 5] = [
+        ("unbatched", None),
+        ("batch=16", Some(BatchConfig::new(16))),
+        ("batch=64", Some(BatchConfig::new(64))),
+        ("batch=256", Some(BatchConfig::new(256))),
+        (
+            "batch=64,budget=256",
+            Some(BatchConfig::new(64).with_yield_budget(256)),
+        ),
+    ];
+
+    for (name, batch) in configs {
+        let best = (0..ROUNDS)
+            .map(|_| rt.block_on(run_once(batch)))
+            .min()
+            .expect("at least one round");
+        let rate = MESSAGES as f64 / best.as_secs_f64();
+        println!("{name:<22} {best:>12.2?}  {rate:>14.0} msg/s");
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Structured output for a `ChildGroup` journal.
+//!
+//! Each entry becomes one logfmt line, e.g.
+//!
+//! ```text
+//! supervisor=3 seq=12 at_ms=1500 kind=decision decision=reset child=4 reason=failed
+//! ```
+//!
+//! Install [`print_journal`] with `ChildGroup::with_journal_sink` to dump the
+//! journal to stderr when the group shuts down.
+
+use bloxide_child_management::{Decision, DecisionReason, Journal, JournalEntry, JournalRecord};
+use bloxide_core::{
+    lifecycle::{ChildLifecycleEvent, ShutdownEscalation},
+    messaging::ActorId,
+};
+use std::io::{self, Write};
+
+/// Write `journal` as logfmt lines, oldest entry first. Evicted entries are
+/// reported on a leading `kind=dropped` line.
+pub fn write_journal(
+    out: &mut impl Write,
+    supervisor_id: ActorId,
+    journal: &Journal,
+) -> io::Result<()> {
+    let dropped = journal.dropped();
+    if dropped > 0 {
+        writeln!(
+            out,
+            "supervisor={supervisor_id} kind=dropped count={dropped}"
+        )?;
+    }
+    for entry in journal.iter() {
+        write_entry(out, supervisor_id, entry)?;
+    }
+    Ok(())
+}
+
+/// A `JournalSink` that writes the journal to stderr.
+pub fn print_journal(supervisor_id: ActorId, journal: &Journal) {
+    let mut err = io::stderr().lock();
+    // Nowhere left to report a failed write to stderr.
+    let _ = write_journal(&mut err, supervisor_id, journal);
+}
+
+fn write_entry(
+    out: &mut impl Write,
+    supervisor_id: ActorId,
+    entry: &JournalEntry,
+) -> io::Result<()> {
+    write!(
+        out,
+        "supervisor={supervisor_id} seq={} at_ms={}",
+        entry.seq, entry.at_ms
+    )?;
+    match entry.record {
+        JournalRecord::Event(event) => {
+            let (name, child_id) = match event {
+                ChildLifecycleEvent::Started { child_id } => ("started", child_id),
+                ChildLifecycleEvent::Done { child_id } => ("done", child_id),
+                ChildLifecycleEvent::Failed { child_id } => ("failed", child_id),
+                ChildLifecycleEvent::Stopped { child_id } => ("stopped", child_id),
+                ChildLifecycleEvent::Aborted { child_id } => ("aborted", child_id),
+                ChildLifecycleEvent::Killed { child_id } => ("killed", child_id),
+                ChildLifecycleEvent::Alive { child_id } => ("alive", child_id),
+                ChildLifecycleEvent::ShutdownEscalated { child_id, .. } => {
+                    ("shutdown_escalated", child_id)
+                }
+            };
+            write!(out, " kind=event event={name} child={child_id}")?;
+            if let ChildLifecycleEvent::ShutdownEscalated { to, .. } = event {
+                let to = match to {
+                    ShutdownEscalation::Abort => "abort",
+                    ShutdownEscalation::Kill => "kill",
+                    ShutdownEscalation::Abandon => "abandon",
+                };
+                write!(out, " to={to}")?;
+            }
+        }
+        JournalRecord::Decision {
+            child_id,
+            decision,
+            reason,
+        } => {
+            let name = match decision {
+                Decision::Reset => "reset",
+                Decision::BackOff { .. } => "back_off",
+                Decision::Stop => "stop",
+                Decision::Abort => "abort",
+                Decision::Kill => "kill",
+                Decision::Abandon => "abandon",
+                Decision::GiveUp => "give_up",
+                Decision::Delete => "delete",
+                Decision::Shutdown => "shutdown",
+            };
+            write!(out, " kind=decision decision={name} child={child_id}")?;
+            if let Decision::BackOff { delay_ms } = decision {
+                write!(out, " delay_ms={delay_ms}")?;
+            }
+            let reason_name = match reason {
+                DecisionReason::Done => "done",
+                DecisionReason::Failed => "failed",
+                DecisionReason::MissedAlive => "missed_alive",
+                DecisionReason::UndeliveredPings => "undelivered_pings",
+                DecisionReason::Sibling { .. } => "sibling",
+                DecisionReason::BackoffElapsed => "backoff_elapsed",
+                DecisionReason::RestartsExhausted => "restarts_exhausted",
+                DecisionReason::IntensityExceeded => "intensity_exceeded",
+                DecisionReason::NotRestartable => "not_restartable",
+                DecisionReason::GroupStopping => "group_stopping",
+                DecisionReason::ShutdownDeadline => "shutdown_deadline",
+                DecisionReason::StartTimeout => "start_timeout",
+                DecisionReason::TaskEnded => "task_ended",
+                DecisionReason::Operator => "operator",
+            };
+            write!(out, " reason={reason_name}")?;
+            if let DecisionReason::Sibling { failed } = reason {
+                write!(out, " failed={failed}")?;
+            }
+        }
+    }
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokioRuntime;
+    use bloxide_child_management::{ChildGroup, ChildPolicy, GroupShutdown};
+    use bloxide_core::capability::DynamicChannelCap;
+
+    #[test]
+    fn write_journal_emits_one_logfmt_line_per_entry() {
+        let (lifecycle_ref, _lifecycle_rx) = TokioRuntime::channel(4, 4);
+        let mut group = ChildGroup::<TokioRuntime>::new(GroupShutdown::WhenAllDone)
+            .with_journal(2)
+            .with_clock(|| 1500);
+        group.add(4, lifecycle_ref, ChildPolicy::Stop);
+        group.journal_event(ChildLifecycleEvent::Started { child_id: 4 });
+        group.stop_all(3);
+        group.journal_event(ChildLifecycleEvent::Stopped { child_id: 4 });
+
+        let mut out = Vec::new();
+        write_journal(&mut out, 3, group.journal()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "supervisor=3 kind=dropped count=1\n\
+             supervisor=3 seq=1 at_ms=1500 kind=decision decision=stop child=4 reason=group_stopping\n\
+             supervisor=3 seq=2 at_ms=1500 kind=event event=stopped child=4\n"
+        );
+    }
+}
@@ -2,7 +2,11 @@
 use bloxide_core::{mailboxes::Mailboxes, spec::MachineSpec, StateMachine};
 use core::future::poll_fn;
 
-pub use bloxide_core::{run_actor, run_actor_auto_start, run_actor_to_completion};
+pub use bloxide_core::{
+    run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion,
+    run_actor_to_completion_batched, run_multiplexed, run_multiplexed_batched, ActorSlot,
+    BatchConfig, Multiplexed,
+};
 pub use bloxide_spawn::SpawnCap;
 
 #[doc(hidden)]
@@ -11,8 +15,10 @@ pub use bloxide_macros::dyn_channels as __dyn_channels_proc_macro;
 pub use bloxide_macros::next_actor_id as __next_actor_id_proc_macro;
 
 pub mod channel;
+pub mod journal;
 pub mod mailbox;
 pub mod prelude;
+pub mod shutdown;
 pub mod spawn;
 pub mod supervision;
 pub mod timer;
@@ -20,8 +26,10 @@ pub mod timer;
 pub use bloxide_child_management::ChildGroupBuilder;
 pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
 pub use channel::{TokioSender, TokioStream, TokioTrySendError};
+pub use shutdown::{shutdown_signal, ShutdownCause, ShutdownReport, SystemHandle};
 pub use supervision::{
-    run_supervised_actor, run_supervised_actor_with_abort, GenericChildGroupBuilder,
+    run_supervised_actor, run_supervised_actor_batched, run_supervised_actor_with_abort,
+    run_supervised_actor_with_abort_batched, GenericChildGroupBuilder, SupervisedSlot,
 };
 
 // ── TokioRuntime ──────────────────────────────────────────────────────────────
@@ -56,6 +64,9 @@ macro_rules! channels {
 // ── actor_task! macro ─────────────────────────────────────────────────────────
 
 /// Generate an async wrapper for an unsupervised bloxide actor.
+///
+/// Pass `batch = BatchConfig::new(n)` to drain up to `n` ready events per
+/// wake via [`run_actor_batched`].
 #[macro_export]
 macro_rules! actor_task {
     ($name:ident, $spec:ty $(,)?) => {
@@ -68,11 +79,23 @@ macro_rules! actor_task {
             $crate::run_actor(machine, mailboxes).await;
         }
     };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::TokioRuntime,
+            >,
+        ) {
+            $crate::run_actor_batched(machine, mailboxes, $batch).await;
+        }
+    };
 }
 
 // ── actor_task_supervised! macro ──────────────────────────────────────────────
 
 /// Generate an async wrapper for a supervised bloxide actor.
+///
+/// Accepts the same optional `batch = ...` argument as [`actor_task!`].
 #[macro_export]
 macro_rules! actor_task_supervised {
     ($name:ident, $spec:ty $(,)?) => {
@@ -95,6 +118,27 @@ macro_rules! actor_task_supervised {
             .await;
         }
     };
+    ($name:ident, $spec:ty, batch = $batch:expr $(,)?) => {
+        async fn $name(
+            machine: ::bloxide_core::StateMachine<$spec>,
+            domain_mailboxes: <$spec as ::bloxide_core::spec::MachineSpec>::Mailboxes<
+                $crate::TokioRuntime,
+            >,
+            lifecycle_rx: $crate::TokioStream<$crate::LifecycleCommand>,
+            actor_id: ::bloxide_core::messaging::ActorId,
+            supervisor_notify: $crate::TokioSender<$crate::ChildLifecycleEvent>,
+        ) {
+            $crate::supervision::run_supervised_actor_batched(
+                machine,
+                domain_mailboxes,
+                lifecycle_rx,
+                actor_id,
+                supervisor_notify,
+                $batch,
+            )
+            .await;
+        }
+    };
 }
 
 // ── root_task! macro ──────────────────────────────────────────────────────────
@@ -130,7 +174,12 @@ macro_rules! root_task {
 /// Spawn the timer service and return the `ActorRef<TimerCommand>` for it.
 ///
 /// The timer task is spawned as a Tokio task and runs until it receives a
-/// `TimerCommand::Shutdown` message.
+/// `TimerCommand::Shutdown` message. The service is also registered with
+/// [`timer::register_timer_service`], so supervised actors' timers are
+/// discarded when they stop, reset or abort.
+///
+/// `spawn_timer!(capacity, system)` also hands the task to a
+/// [`SystemHandle`], which shuts the service down and joins it on exit.
 #[macro_export]
 macro_rules! spawn_timer {
     ($capacity:expr) => {{
@@ -141,6 +190,19 @@ macro_rules! spawn_timer {
                 timer_stream,
             ),
         );
+        $crate::timer::register_timer_service(&timer_ref);
+        timer_ref
+    }};
+    ($capacity:expr, $system:expr) => {{
+        let ((timer_ref,), (timer_stream,)) =
+            $crate::__dyn_channels_proc_macro!($crate::TokioRuntime; ::bloxide_timer::TimerCommand($capacity));
+        let task = tokio::spawn(
+            <$crate::TokioRuntime as ::bloxide_timer::TimerService>::run_timer_service(
+                timer_stream,
+            ),
+        );
+        $crate::timer::register_timer_service(&timer_ref);
+        $system.track_timer(timer_ref.clone(), task);
         timer_ref
     }};
 }
@@ -153,12 +215,13 @@ macro_rules! spawn_timer {
 /// `ChildGroupBuilder`, and spawns the task with lifecycle arguments injected.
 ///
 /// Unlike the Embassy version, there is no `spawner` parameter — Tokio tasks
-/// are spawned directly via `tokio::spawn`.
+/// are spawned directly via `tokio::spawn`. Evaluates to the task's
+/// `JoinHandle`, e.g. for [`SystemHandle::track`].
 #[macro_export]
 macro_rules! spawn_child {
     ($builder:expr, $task_fn:ident($machine:expr, $mbox:expr, $id:expr), $policy:expr) => {{
         let (lc_rx, sup_notify) = $builder.add_child($id, $policy);
-        let _handle = tokio::spawn($task_fn($machine, $mbox, lc_rx, $id, sup_notify));
+        tokio::spawn($task_fn($machine, $mbox, lc_rx, $id, sup_notify))
     }};
 }
 
@@ -9,10 +9,13 @@
 /// on `bloxide-supervisor`. Apps that use the supervisor import it directly:
 /// `use bloxide_supervisor::*;`
 pub use crate::{
-    run_actor, run_actor_auto_start, run_actor_to_completion, run_root,
-    run_supervised_actor_with_abort, GenericChildGroupBuilder, SpawnCap, TokioRuntime, TokioSender,
-    TokioStream,
+    run_actor, run_actor_auto_start, run_actor_batched, run_actor_to_completion, run_multiplexed,
+    run_root, run_supervised_actor_with_abort, ActorSlot, BatchConfig, GenericChildGroupBuilder,
+    Multiplexed, SpawnCap, SupervisedSlot, TokioRuntime, TokioSender, TokioStream,
+};
+pub use bloxide_child_management::{
+    Backoff, ChildGroup, ChildGroupBuilder, ChildPolicy, GroupShutdown, Liveness, RestartType,
+    StartOrder,
 };
-pub use bloxide_child_management::{ChildGroup, ChildGroupBuilder, ChildPolicy, GroupShutdown};
 pub use bloxide_core::prelude::*;
 pub use bloxide_core::{ChildLifecycleEvent, LifecycleCommand};
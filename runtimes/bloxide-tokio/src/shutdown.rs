@@ -0,0 +1,634 @@
+// Copyright 2025 Bloxide, all rights reserved
+//! Coordinated, system-wide graceful shutdown for Tokio wiring binaries.
+//!
+//! A [`SystemHandle`] collects what a generated `main` starts (the root
+//! supervisors' tasks and control refs, the timer service and every spawned
+//! task) and tears it down in a fixed order:
+//!
+//! 1. On SIGINT/SIGTERM, the root supervisors are stopped one at a time,
+//!    last registered first. Each is sent its stop message — for the
+//!    standard supervisor `SupervisorControl::Shutdown`, which stops its
+//!    children in reverse start order — and the next root is only stopped
+//!    once its task has returned. A root that is still running halfway
+//!    through the deadline is sent its escalate message
+//!    (`SupervisorControl::EscalateShutdown`, which aborts the children
+//!    still stopping), again three quarters of the way through (which kills
+//!    them), and its task is aborted with [`JoinHandle::abort`] once the
+//!    deadline passes.
+//! 2. Once every root has returned, the tracked mailboxes are drained: the
+//!    handle waits until each is empty or closed. The messages actors send
+//!    while stopping, such as the timer cancellations of their exit
+//!    actions, are still delivered, and the timer services have room for
+//!    the next step.
+//! 3. Each registered timer service is sent `TimerCommand::Shutdown`.
+//! 4. Every tracked task is joined.
+//!
+//! If the roots all return on their own, shutdown starts at step 2. Each
+//! root in step 1, the drain in step 2 and the join in step 4 get their own
+//! deadline; a second
+//! signal ends the grace period early, and whatever is still running is
+//! then aborted at once. The outcome is counted in the [`ShutdownReport`].
+use bloxide_core::{
+    capability::DynamicChannelCap,
+    messaging::{ActorId, ActorRef},
+};
+use bloxide_timer::TimerCommand;
+use core::future::Future;
+use core::pin::Pin;
+use std::process::ExitCode;
+use std::time::Duration;
+use tokio::task::{JoinError, JoinHandle};
+use tokio::time::{sleep_until, Instant};
+
+use crate::TokioRuntime;
+
+/// What a root supervisor is sent during shutdown.
+#[derive(Clone, Copy)]
+enum RootSignal {
+    Stop,
+    Escalate,
+}
+
+type SignalFn = Box<dyn Fn(ActorId, RootSignal) + Send>;
+
+/// Number of messages waiting in a tracked mailbox; `0` once it is closed.
+type QueuedFn = Box<dyn Fn() -> usize + Send>;
+
+/// A root supervisor registered with [`SystemHandle::add_root`].
+struct Root {
+    task: JoinHandle<()>,
+    signal: SignalFn,
+    joined: Option<Result<(), JoinError>>,
+}
+
+impl Root {
+    /// Await the task unless it has already been joined.
+    async fn join(&mut self) {
+        if self.joined.is_none() {
+            self.joined = Some((&mut self.task).await);
+        }
+    }
+}
+
+/// Owns the shutdown sequence of a Tokio system.
+pub struct SystemHandle {
+    id: ActorId,
+    deadline: Duration,
+    roots: Vec<Root>,
+    mailboxes: Vec<QueuedFn>,
+    timers: Vec<ActorRef<TimerCommand, TokioRuntime>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl SystemHandle {
+    /// Create a handle that gives each root supervisor, and then the
+    /// remaining tasks, `deadline` to stop before aborting them.
+    pub fn new(deadline: Duration) -> Self {
+        Self {
+            id: <TokioRuntime as DynamicChannelCap>::alloc_actor_id(),
+            deadline,
+            roots: Vec::new(),
+            mailboxes: Vec::new(),
+            timers: Vec::new(),
+            tasks: Vec::new(),
+        }
+    }
+
+    /// The actor id stop, escalate and timer messages are sent from.
+    pub fn id(&self) -> ActorId {
+        self.id
+    }
+
+    /// Join `task` during shutdown, aborting it if the deadline expires.
+    pub fn track(&mut self, task: JoinHandle<()>) {
+        self.tasks.push(task);
+    }
+
+    /// Drain `mailbox` once every root has returned: shutdown goes on only
+    /// when it is empty or closed, or the deadline passes.
+    ///
+    /// Only a weak handle is kept, so tracking does not keep the mailbox
+    /// open.
+    pub fn track_mailbox<M: Send + 'static>(&mut self, mailbox: &ActorRef<M, TokioRuntime>) {
+        let weak = mailbox.sender().inner.downgrade();
+        self.mailboxes.push(Box::new(move || match weak.upgrade() {
+            Some(tx) if !tx.is_closed() => tx.max_capacity() - tx.capacity(),
+            _ => 0,
+        }));
+    }
+
+    /// Track the timer service task and its mailbox, and send it
+    /// `TimerCommand::Shutdown` once the mailbox has been drained.
+    pub fn track_timer(
+        &mut self,
+        timer_ref: ActorRef<TimerCommand, TokioRuntime>,
+        task: JoinHandle<()>,
+    ) {
+        self.track_mailbox(&timer_ref);
+        self.timers.push(timer_ref);
+        self.tasks.push(task);
+    }
+
+    /// Register a root supervisor running as `task`.
+    ///
+    /// On a shutdown signal `control` is sent `stop`, and `escalate` if the
+    /// root misses its deadline (see the [module docs](self)). Roots are
+    /// stopped in reverse registration order, so register them in the order
+    /// they were started.
+    pub fn add_root<M: Clone + Send + 'static>(
+        &mut self,
+        task: JoinHandle<()>,
+        control: ActorRef<M, TokioRuntime>,
+        stop: M,
+        escalate: M,
+    ) {
+        let signal = move |from: ActorId, signal: RootSignal| {
+            let message = match signal {
+                RootSignal::Stop => stop.clone(),
+                RootSignal::Escalate => escalate.clone(),
+            };
+            if control.try_send(from, message).is_err() {
+                bloxide_log::blox_log_warn!(
+                    from,
+                    "failed to send shutdown to actor {} (channel full or closed)",
+                    control.id()
+                );
+            }
+        };
+        self.roots.push(Root {
+            task,
+            signal: Box::new(signal),
+            joined: None,
+        });
+    }
+
+    /// Run until every root returns or SIGINT/SIGTERM arrives, then shut the
+    /// system down.
+    pub async fn run(self) -> ShutdownReport {
+        self.drive(shutdown_signal(), async { shutdown_signal().await })
+            .await
+    }
+
+    /// Like [`run`](Self::run), but shutdown begins when `trigger` completes
+    /// instead of on a signal.
+    pub async fn run_until(self, trigger: impl Future<Output = ()>) -> ShutdownReport {
+        self.drive(trigger, core::future::pending()).await
+    }
+
+    async fn drive(
+        self,
+        trigger: impl Future<Output = ()>,
+        force: impl Future<Output = ()>,
+    ) -> ShutdownReport {
+        let Self {
+            id,
+            deadline,
+            mut roots,
+            mailboxes,
+            timers,
+            tasks,
+        } = self;
+
+        let cause = tokio::select! {
+            biased;
+            () = async {
+                for root in roots.iter_mut() {
+                    root.join().await;
+                }
+            } => ShutdownCause::Completed,
+            () = trigger => ShutdownCause::Signal,
+        };
+        let mut report = ShutdownReport::new(cause);
+        let mut grace = Grace::new(force);
+
+        if cause == ShutdownCause::Signal {
+            bloxide_log::blox_log_info!(id, "shutdown requested, stopping supervisors");
+            for root in roots.iter_mut().rev() {
+                stop_root(id, root, deadline, &mut grace, &mut report).await;
+            }
+        }
+        for root in &roots {
+            if matches!(&root.joined, Some(Err(err)) if err.is_panic()) {
+                report.panicked += 1;
+            }
+        }
+
+        let drain_deadline = Instant::now() + deadline;
+        let drained = grace
+            .wait(drain_deadline, async {
+                while mailboxes.iter().any(|queued| queued() > 0) {
+                    tokio::time::sleep(DRAIN_POLL).await;
+                }
+            })
+            .await;
+        if drained.is_none() {
+            report.undrained = mailboxes.iter().filter(|queued| queued() > 0).count();
+            bloxide_log::blox_log_warn!(
+                id,
+                "{} mailbox(es) still held messages at the shutdown deadline",
+                report.undrained
+            );
+        }
+
+        for timer_ref in &timers {
+            if timer_ref.try_send(id, TimerCommand::Shutdown).is_err() {
+                bloxide_log::blox_log_warn!(
+                    id,
+                    "failed to send Shutdown to timer service {}",
+                    timer_ref.id()
+                );
+            }
+        }
+
+        let tasks_deadline = Instant::now() + deadline;
+        for mut task in tasks {
+            let joined = if task.is_finished() {
+                Some((&mut task).await)
+            } else {
+                grace.wait(tasks_deadline, &mut task).await
+            };
+            match joined {
+                Some(Ok(())) => report.finished += 1,
+                Some(Err(err)) if err.is_panic() => report.panicked += 1,
+                Some(Err(_)) => report.cancelled += 1,
+                None => {
+                    task.abort();
+                    let _ = task.await;
+                    report.killed += 1;
+                }
+            }
+        }
+
+        if report.killed > 0 {
+            bloxide_log::blox_log_warn!(
+                id,
+                "killed {} task(s) still running at the shutdown deadline",
+                report.killed
+            );
+        }
+        report
+    }
+}
+
+/// How often a drain re-checks the tracked mailboxes.
+const DRAIN_POLL: Duration = Duration::from_millis(1);
+
+/// Stop one root: send it its stop message and wait for its task, escalating
+/// halfway and three quarters of the way through `deadline`, and aborting
+/// the task once it has passed.
+async fn stop_root<F: Future<Output = ()>>(
+    id: ActorId,
+    root: &mut Root,
+    deadline: Duration,
+    grace: &mut Grace<F>,
+    report: &mut ShutdownReport,
+) {
+    if root.joined.is_some() {
+        return;
+    }
+    let start = Instant::now();
+    let steps = [
+        (RootSignal::Stop, deadline / 2),
+        (RootSignal::Escalate, deadline * 3 / 4),
+        (RootSignal::Escalate, deadline),
+    ];
+    for (i, (signal, until)) in steps.into_iter().enumerate() {
+        if i == 1 {
+            bloxide_log::blox_log_warn!(id, "root did not stop in time, escalating its shutdown");
+            report.escalated += 1;
+        }
+        (root.signal)(id, signal);
+        if let Some(joined) = grace.wait(start + until, &mut root.task).await {
+            root.joined = Some(joined);
+            return;
+        }
+    }
+    bloxide_log::blox_log_warn!(id, "root did not stop before the deadline, aborting it");
+    root.task.abort();
+    root.joined = Some((&mut root.task).await);
+    report.root_stopped = false;
+}
+
+/// The grace period: each wait ends at its deadline, and every wait ends at
+/// once after `force` completes.
+struct Grace<F> {
+    force: Pin<Box<F>>,
+    expired: bool,
+}
+
+impl<F: Future<Output = ()>> Grace<F> {
+    fn new(force: F) -> Self {
+        Self {
+            force: Box::pin(force),
+            expired: false,
+        }
+    }
+
+    /// Await `work` until `deadline`; `None` if it did not finish in time or
+    /// the grace period was ended by `force`.
+    async fn wait<T>(&mut self, deadline: Instant, work: impl Future<Output = T>) -> Option<T> {
+        if self.expired {
+            return None;
+        }
+        tokio::select! {
+            biased;
+            out = work => Some(out),
+            () = sleep_until(deadline) => None,
+            () = self.force.as_mut() => {
+                self.expired = true;
+                None
+            }
+        }
+    }
+}
+
+/// Completes on the first SIGINT (Ctrl-C) or, on Unix, SIGTERM.
+pub async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Why the system shut down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownCause {
+    /// Every root returned on its own.
+    Completed,
+    /// A shutdown signal (or the `run_until` trigger) arrived first.
+    Signal,
+}
+
+/// Outcome of [`SystemHandle::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub cause: ShutdownCause,
+    /// `false` if a root had to be aborted at its deadline.
+    pub root_stopped: bool,
+    /// Roots whose shutdown had to be escalated to abort or kill children.
+    pub escalated: usize,
+    /// Tracked mailboxes that still held messages when the drain deadline
+    /// passed.
+    pub undrained: usize,
+    /// Tracked tasks that returned normally.
+    pub finished: usize,
+    /// Roots and tracked tasks that panicked.
+    pub panicked: usize,
+    /// Tracked tasks aborted before the final join, e.g. by a `Kill` policy
+    /// or an escalated shutdown.
+    pub cancelled: usize,
+    /// Tracked tasks aborted because the deadline expired.
+    pub killed: usize,
+}
+
+impl ShutdownReport {
+    fn new(cause: ShutdownCause) -> Self {
+        Self {
+            cause,
+            root_stopped: true,
+            escalated: 0,
+            undrained: 0,
+            finished: 0,
+            panicked: 0,
+            cancelled: 0,
+            killed: 0,
+        }
+    }
+
+    /// `true` if everything stopped on its own before the deadline.
+    pub fn is_graceful(&self) -> bool {
+        self.root_stopped
+            && self.escalated == 0
+            && self.undrained == 0
+            && self.killed == 0
+            && self.panicked == 0
+    }
+
+    /// Process exit code summarising the shutdown.
+    ///
+    /// | Code | Meaning |
+    /// |------|---------|
+    /// | 0 | Graceful: everything stopped before the deadline |
+    /// | 1 | A root or a tracked task panicked |
+    /// | 2 | A deadline expired: a shutdown was escalated, a mailbox left undrained or a task aborted |
+    pub fn exit_code(&self) -> ExitCode {
+        if self.panicked > 0 {
+            ExitCode::from(1)
+        } else if !self.is_graceful() {
+            ExitCode::from(2)
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bloxide_core::messaging::Envelope;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    const DEADLINE: Duration = Duration::from_millis(100);
+
+    const STOP: u8 = 0;
+    const ESCALATE: u8 = 1;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    /// A root that logs each control message it receives and returns after
+    /// `returns_after` of them (never, if `None`).
+    fn spawn_root(
+        system: &mut SystemHandle,
+        name: &'static str,
+        log: &Log,
+        returns_after: Option<usize>,
+    ) {
+        let (control_ref, mut control_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<u8>(1, 4);
+        let log = log.clone();
+        let task = tokio::spawn(async move {
+            let mut received = 0;
+            while let Some(Envelope(_, message)) = control_rx.inner.recv().await {
+                let what = if message == STOP { "stop" } else { "escalate" };
+                log.lock().unwrap().push(format!("{name}: {what}"));
+                received += 1;
+                if Some(received) == returns_after {
+                    // Wind down for a while before returning.
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    log.lock().unwrap().push(format!("{name}: stopped"));
+                    return;
+                }
+            }
+        });
+        system.add_root(task, control_ref, STOP, ESCALATE);
+    }
+
+    fn entries(log: &Log) -> Vec<String> {
+        log.lock().unwrap().clone()
+    }
+
+    /// Roots that return on their own skip the stop messages, and the timer
+    /// service is flushed and every task joined.
+    #[tokio::test]
+    async fn completed_roots_flush_timers_and_join_tasks() {
+        let (timer_ref, mut timer_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<TimerCommand>(1, 4);
+        let (stop_ref, mut stop_rx) = <TokioRuntime as DynamicChannelCap>::channel::<u8>(2, 4);
+
+        let mut system = SystemHandle::new(DEADLINE);
+        system.add_root(tokio::spawn(async {}), stop_ref, STOP, ESCALATE);
+        let timer_task = tokio::spawn(async move {
+            let Some(Envelope(_, TimerCommand::Shutdown)) = timer_rx.inner.recv().await else {
+                panic!("expected TimerCommand::Shutdown");
+            };
+        });
+        system.track_timer(timer_ref, timer_task);
+        system.track(tokio::spawn(async {}));
+
+        let report = system.run_until(core::future::pending()).await;
+
+        assert_eq!(report.cause, ShutdownCause::Completed);
+        assert_eq!(report.finished, 2);
+        assert!(report.is_graceful());
+        assert_eq!(report.exit_code(), ExitCode::SUCCESS);
+        assert!(stop_rx.inner.try_recv().is_err());
+    }
+
+    /// On a signal the roots are stopped last-registered-first, and the next
+    /// root is only sent its stop once the previous one has returned.
+    #[tokio::test]
+    async fn signal_stops_roots_one_at_a_time_in_reverse_order() {
+        let log = Log::default();
+        let mut system = SystemHandle::new(DEADLINE);
+        spawn_root(&mut system, "first", &log, Some(1));
+        spawn_root(&mut system, "second", &log, Some(1));
+
+        let report = system.run_until(async {}).await;
+
+        assert_eq!(
+            entries(&log),
+            [
+                "second: stop",
+                "second: stopped",
+                "first: stop",
+                "first: stopped"
+            ]
+        );
+        assert_eq!(report.cause, ShutdownCause::Signal);
+        assert!(report.is_graceful());
+        assert_eq!(report.exit_code(), ExitCode::SUCCESS);
+    }
+
+    /// A root that misses its stop window is escalated through its own
+    /// supervisor first, and only aborted once the whole deadline passed;
+    /// the next root is still stopped afterwards.
+    #[tokio::test]
+    async fn late_root_is_escalated_before_it_is_aborted() {
+        let log = Log::default();
+        let mut system = SystemHandle::new(DEADLINE);
+        spawn_root(&mut system, "first", &log, Some(2));
+        spawn_root(&mut system, "hung", &log, None);
+
+        let report = system.run_until(async {}).await;
+
+        assert_eq!(
+            entries(&log),
+            [
+                "hung: stop",
+                "hung: escalate",
+                "hung: escalate",
+                "first: stop",
+                "first: escalate",
+                "first: stopped"
+            ]
+        );
+        assert!(!report.root_stopped);
+        assert_eq!(report.escalated, 2);
+        assert_eq!(report.exit_code(), ExitCode::from(2));
+    }
+
+    /// The timer service's mailbox is drained before it is sent
+    /// `TimerCommand::Shutdown`, so a full mailbox does not lose the flush,
+    /// and a closed mailbox does not hold shutdown up.
+    #[tokio::test]
+    async fn mailboxes_are_drained_before_timers_are_flushed() {
+        let (timer_ref, mut timer_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<TimerCommand>(1, 1);
+        let (closed_ref, closed_rx) = <TokioRuntime as DynamicChannelCap>::channel::<u8>(2, 4);
+        closed_ref.try_send(0, STOP).unwrap();
+        drop(closed_rx);
+
+        let mut system = SystemHandle::new(DEADLINE);
+        system.add_root(tokio::spawn(async {}), closed_ref.clone(), STOP, ESCALATE);
+        system.track_mailbox(&closed_ref);
+        // An exit action's cancellation, still queued as the roots return.
+        let id = bloxide_timer::next_timer_id();
+        timer_ref.try_send(0, TimerCommand::Cancel { id }).unwrap();
+        let timer_task = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            let Some(Envelope(_, TimerCommand::Cancel { .. })) = timer_rx.inner.recv().await else {
+                panic!("expected TimerCommand::Cancel");
+            };
+            let Some(Envelope(_, TimerCommand::Shutdown)) = timer_rx.inner.recv().await else {
+                panic!("expected TimerCommand::Shutdown");
+            };
+        });
+        system.track_timer(timer_ref, timer_task);
+
+        let report = system.run_until(core::future::pending()).await;
+
+        assert_eq!(report.undrained, 0);
+        assert_eq!(report.finished, 1);
+        assert!(report.is_graceful());
+    }
+
+    /// Tasks that ignore shutdown are aborted at the deadline, and the exit
+    /// code reports the escalation.
+    #[tokio::test]
+    async fn deadline_escalates_to_abort() {
+        struct DropFlag(Arc<AtomicBool>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = DropFlag(dropped.clone());
+        let mut system = SystemHandle::new(DEADLINE);
+        system.track(tokio::spawn(async move {
+            let _flag = flag;
+            core::future::pending::<()>().await;
+        }));
+        system.track(tokio::spawn(async { panic!("child panicked") }));
+
+        let report = system.run_until(async {}).await;
+
+        assert_eq!(report.killed, 1);
+        assert_eq!(report.panicked, 1);
+        assert!(dropped.load(Ordering::SeqCst));
+        assert_eq!(report.exit_code(), ExitCode::from(1));
+
+        let escalated = ShutdownReport {
+            panicked: 0,
+            ..report
+        };
+        assert_eq!(escalated.exit_code(), ExitCode::from(2));
+    }
+}
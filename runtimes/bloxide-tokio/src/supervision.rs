@@ -1,18 +1,22 @@
 // Copyright 2025 Bloxide, all rights reserved
 use bloxide_child_management::AbortCommand;
 use bloxide_core::{
+    batch::{BatchConfig, YieldBudget},
     engine::{DispatchOutcome, StateMachine},
     lifecycle::{ChildLifecycleEvent, LifecycleCommand},
     mailboxes::Mailboxes,
     messaging::{ActorId, Envelope},
+    multiplex::{Multiplexed, SlotPoll},
     report_outcome,
     spec::MachineSpec,
 };
+use bloxide_timer::TimerMark;
 use core::future::poll_fn;
 use core::pin::Pin;
-use core::task::Poll;
+use core::task::{Context, Poll};
 use futures_core::Stream;
 
+use crate::timer::settle_timers;
 use crate::{TokioRuntime, TokioSender, TokioStream};
 
 // ── Standalone supervised actor runner ───────────────────────────────────────
@@ -22,51 +26,94 @@ use crate::{TokioRuntime, TokioSender, TokioStream};
 /// Polls lifecycle and domain mailboxes, dispatches events through the machine,
 /// and reports outcomes to the supervisor.
 pub async fn run_supervised_actor<S: MachineSpec + 'static>(
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<TokioRuntime>,
+    lifecycle_stream: TokioStream<LifecycleCommand>,
+    actor_id: ActorId,
+    supervisor_notify: TokioSender<ChildLifecycleEvent>,
+) {
+    run_supervised_actor_batched(
+        machine,
+        domain_mailboxes,
+        lifecycle_stream,
+        actor_id,
+        supervisor_notify,
+        BatchConfig::UNBATCHED,
+    )
+    .await;
+}
+
+/// Like [`run_supervised_actor`], but dispatches up to `batch.max_batch` ready
+/// events per poll and yields to the executor whenever `batch.yield_budget`
+/// is spent.
+///
+/// The lifecycle stream is re-checked before every event in the batch, so a
+/// `Stop` or `Reset` still pre-empts queued domain messages.
+pub async fn run_supervised_actor_batched<S: MachineSpec + 'static>(
     mut machine: StateMachine<S>,
     mut domain_mailboxes: S::Mailboxes<TokioRuntime>,
     mut lifecycle_stream: TokioStream<LifecycleCommand>,
     actor_id: ActorId,
     supervisor_notify: TokioSender<ChildLifecycleEvent>,
+    batch: BatchConfig,
 ) {
     enum LoopAction {
-        Continue,
+        Continue(usize),
         Stop,
     }
 
+    let mut budget = YieldBudget::new(&batch);
     loop {
         let action = poll_fn(|cx| {
-            // First check lifecycle stream (higher priority)
-            match Pin::new(&mut lifecycle_stream).poll_next(cx) {
-                Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
-                Poll::Ready(Some(Envelope(_, cmd))) => {
-                    let outcome = handle_lifecycle(&mut machine, cmd);
-                    report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
-
-                    return match outcome {
-                        DispatchOutcome::Stopped => Poll::Ready(LoopAction::Stop),
-                        _ => Poll::Ready(LoopAction::Continue),
-                    };
+            let mut handled = 0;
+            while handled < batch.max_batch {
+                // First check lifecycle stream (higher priority)
+                match Pin::new(&mut lifecycle_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, cmd))) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = handle_lifecycle(&mut machine, cmd);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                        continue;
+                    }
+                    Poll::Pending => {}
                 }
-                Poll::Pending => {}
-            }
 
-            // Then check domain mailboxes
-            match domain_mailboxes.poll_next(cx) {
-                Poll::Ready(Some(event)) => {
-                    let outcome = machine.dispatch(event);
-                    report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
-                    Poll::Ready(LoopAction::Continue)
+                // Then check domain mailboxes
+                match domain_mailboxes.poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = machine.dispatch(event);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
+                        // A graceful Stop completes on a domain event
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Pending => break,
                 }
-                Poll::Ready(None) => Poll::Ready(LoopAction::Stop),
-                Poll::Pending => Poll::Pending,
+            }
+            if handled == 0 {
+                Poll::Pending
+            } else {
+                Poll::Ready(LoopAction::Continue(handled))
             }
         })
         .await;
 
         match action {
-            LoopAction::Continue => {}
+            LoopAction::Continue(n) => budget.consume(n),
             LoopAction::Stop => break,
         }
+        budget.checkpoint().await;
     }
 }
 
@@ -99,70 +146,221 @@ pub async fn run_supervised_actor_with_abort<S: MachineSpec + 'static>(
     abort_stream: TokioStream<AbortCommand>,
     actor_id: ActorId,
     supervisor_notify: TokioSender<ChildLifecycleEvent>,
+) {
+    run_supervised_actor_with_abort_batched(
+        machine,
+        domain_mailboxes,
+        lifecycle_stream,
+        abort_stream,
+        actor_id,
+        supervisor_notify,
+        BatchConfig::UNBATCHED,
+    )
+    .await;
+}
+
+/// Batched variant of [`run_supervised_actor_with_abort`].
+///
+/// See [`run_supervised_actor_batched`] for the batching semantics. The abort
+/// mailbox is re-checked before every domain event in the batch.
+pub async fn run_supervised_actor_with_abort_batched<S: MachineSpec + 'static>(
+    mut machine: StateMachine<S>,
+    mut domain_mailboxes: S::Mailboxes<TokioRuntime>,
+    mut lifecycle_stream: TokioStream<LifecycleCommand>,
+    mut abort_stream: TokioStream<AbortCommand>,
+    actor_id: ActorId,
+    supervisor_notify: TokioSender<ChildLifecycleEvent>,
+    batch: BatchConfig,
 ) {
     enum LoopAction {
-        Continue,
+        Continue(usize),
         Stop,
     }
 
-    let mut machine = machine;
-    let mut domain_mailboxes = domain_mailboxes;
-    let mut lifecycle_stream = lifecycle_stream;
-    let mut abort_stream = abort_stream;
-
+    let mut budget = YieldBudget::new(&batch);
     loop {
         let action = poll_fn(|cx| {
-            // First check lifecycle stream (higher priority)
-            match Pin::new(&mut lifecycle_stream).poll_next(cx) {
-                Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
-                Poll::Ready(Some(Envelope(_, cmd))) => {
-                    let outcome = handle_lifecycle(&mut machine, cmd);
-                    report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
-
-                    return match outcome {
-                        DispatchOutcome::Stopped => Poll::Ready(LoopAction::Stop),
-                        _ => Poll::Ready(LoopAction::Continue),
-                    };
+            let mut handled = 0;
+            while handled < batch.max_batch {
+                // First check lifecycle stream (higher priority)
+                match Pin::new(&mut lifecycle_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, cmd))) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = handle_lifecycle(&mut machine, cmd);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                        continue;
+                    }
+                    Poll::Pending => {}
                 }
-                Poll::Pending => {}
-            }
 
-            // Then check abort mailbox (high priority — abort should be
-            // serviced before domain messages so a stuck actor can be
-            // terminated promptly when it next yields to the select loop).
-            match Pin::new(&mut abort_stream).poll_next(cx) {
-                Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
-                Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
-                    // Self-termination: report Aborted, then break out of the
-                    // loop and return. No lifecycle callback fires — abort
-                    // is cooperative but immediate.
-                    report_outcome::<S, TokioRuntime>(
-                        &DispatchOutcome::Aborted,
-                        actor_id,
-                        &supervisor_notify,
-                    );
-                    return Poll::Ready(LoopAction::Stop);
+                // Then check abort mailbox (high priority — abort should be
+                // serviced before domain messages so a stuck actor can be
+                // terminated promptly when it next yields to the select loop).
+                match Pin::new(&mut abort_stream).poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
+                        // Self-termination: report Aborted, then break out of the
+                        // loop and return. No lifecycle callback fires — abort
+                        // is cooperative but immediate.
+                        settle_timers(
+                            TimerMark::new(machine.current_state()),
+                            &DispatchOutcome::<S::State>::Aborted,
+                            actor_id,
+                        );
+                        report_outcome::<S, TokioRuntime>(
+                            &DispatchOutcome::Aborted,
+                            actor_id,
+                            &supervisor_notify,
+                        );
+                        return Poll::Ready(LoopAction::Stop);
+                    }
+                    Poll::Pending => {}
                 }
-                Poll::Pending => {}
-            }
 
-            // Then check domain mailboxes
-            match domain_mailboxes.poll_next(cx) {
-                Poll::Ready(Some(event)) => {
-                    let outcome = machine.dispatch(event);
-                    report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
-                    Poll::Ready(LoopAction::Continue)
+                // Then check domain mailboxes
+                match domain_mailboxes.poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        let mark = TimerMark::new(machine.current_state());
+                        let outcome = machine.dispatch(event);
+                        settle_timers(mark, &outcome, actor_id);
+                        report_outcome::<S, TokioRuntime>(&outcome, actor_id, &supervisor_notify);
+                        // A graceful Stop completes on a domain event
+                        if let DispatchOutcome::Stopped = outcome {
+                            return Poll::Ready(LoopAction::Stop);
+                        }
+                        handled += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(LoopAction::Stop),
+                    Poll::Pending => break,
                 }
-                Poll::Ready(None) => Poll::Ready(LoopAction::Stop),
-                Poll::Pending => Poll::Pending,
+            }
+            if handled == 0 {
+                Poll::Pending
+            } else {
+                Poll::Ready(LoopAction::Continue(handled))
             }
         })
         .await;
 
         match action {
-            LoopAction::Continue => {}
+            LoopAction::Continue(n) => budget.consume(n),
             LoopAction::Stop => break,
         }
+        budget.checkpoint().await;
+    }
+}
+
+// ── Multiplexed supervised actor ─────────────────────────────────────────────
+
+/// A supervised actor driven by a multiplexed task instead of its own task.
+///
+/// Each [`poll_dispatch`](Multiplexed::poll_dispatch) does what one iteration
+/// of [`run_supervised_actor_with_abort`] does: lifecycle commands first,
+/// then the abort mailbox if one was attached with
+/// [`with_abort`](Self::with_abort), then domain mailboxes. Outcomes are
+/// reported to the supervisor and timers are settled the same way, so the
+/// supervisor cannot tell the two apart.
+pub struct SupervisedSlot<S: MachineSpec + 'static> {
+    machine: StateMachine<S>,
+    domain_mailboxes: S::Mailboxes<TokioRuntime>,
+    lifecycle_stream: TokioStream<LifecycleCommand>,
+    abort_stream: Option<TokioStream<AbortCommand>>,
+    actor_id: ActorId,
+    supervisor_notify: TokioSender<ChildLifecycleEvent>,
+    finished: bool,
+}
+
+impl<S: MachineSpec + 'static> SupervisedSlot<S> {
+    pub fn new(
+        machine: StateMachine<S>,
+        domain_mailboxes: S::Mailboxes<TokioRuntime>,
+        lifecycle_stream: TokioStream<LifecycleCommand>,
+        actor_id: ActorId,
+        supervisor_notify: TokioSender<ChildLifecycleEvent>,
+    ) -> Self {
+        Self {
+            machine,
+            domain_mailboxes,
+            lifecycle_stream,
+            abort_stream: None,
+            actor_id,
+            supervisor_notify,
+            finished: false,
+        }
+    }
+
+    /// Attach the abort mailbox of a dynamically registered child.
+    pub fn with_abort(mut self, abort_stream: TokioStream<AbortCommand>) -> Self {
+        self.abort_stream = Some(abort_stream);
+        self
+    }
+
+    pub fn machine(&self) -> &StateMachine<S> {
+        &self.machine
+    }
+
+    fn report(&mut self, mark: TimerMark, outcome: &DispatchOutcome<S::State>) {
+        settle_timers(mark, outcome, self.actor_id);
+        report_outcome::<S, TokioRuntime>(outcome, self.actor_id, &self.supervisor_notify);
+    }
+
+    fn finish(&mut self) -> SlotPoll {
+        self.finished = true;
+        SlotPoll::Finished
+    }
+}
+
+impl<S: MachineSpec + 'static> Multiplexed for SupervisedSlot<S> {
+    fn poll_dispatch(&mut self, cx: &mut Context<'_>) -> SlotPoll {
+        if self.finished {
+            return SlotPoll::Finished;
+        }
+
+        match Pin::new(&mut self.lifecycle_stream).poll_next(cx) {
+            Poll::Ready(None) => return self.finish(),
+            Poll::Ready(Some(Envelope(_, cmd))) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = handle_lifecycle(&mut self.machine, cmd);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                return SlotPoll::Dispatched;
+            }
+            Poll::Pending => {}
+        }
+
+        if let Some(abort_stream) = self.abort_stream.as_mut() {
+            match Pin::new(abort_stream).poll_next(cx) {
+                Poll::Ready(None) => return self.finish(),
+                Poll::Ready(Some(Envelope(_, AbortCommand::Abort { .. }))) => {
+                    let mark = TimerMark::new(self.machine.current_state());
+                    self.report(mark, &DispatchOutcome::Aborted);
+                    return self.finish();
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        match self.domain_mailboxes.poll_next(cx) {
+            Poll::Ready(Some(event)) => {
+                let mark = TimerMark::new(self.machine.current_state());
+                let outcome = self.machine.dispatch(event);
+                self.report(mark, &outcome);
+                if let DispatchOutcome::Stopped = outcome {
+                    return self.finish();
+                }
+                SlotPoll::Dispatched
+            }
+            Poll::Ready(None) => self.finish(),
+            Poll::Pending => SlotPoll::Idle,
+        }
     }
 }
 
@@ -357,7 +555,7 @@ mod tests {
     ///   2. Create a `ChildGroup<TokioRuntime>` with `ChildPolicy::Kill`
     ///   3. Register the child via `add_dynamic` with the real `KillHandle`
     ///      and `abort_ref`
-    ///   4. Call `handle_done_or_failed` — this calls `R::Kill::kill(handle)`
+    ///   4. Call `handle_failed` — this calls `R::Kill::kill(handle)`
     ///      which calls `SpawnCap::kill(handle)` which calls
     ///      `AbortHandle::abort()`
     ///   5. Verify the task was actually killed via a `Drop` guard that sets
@@ -415,7 +613,7 @@ mod tests {
             <TokioRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
 
         // Fire the Kill policy — this calls R::Kill::kill(kill_handle)
-        group.handle_done_or_failed(child_id, 42, &notify_ref);
+        group.handle_failed(child_id, 42, &notify_ref);
 
         // Wait for the kill to take effect.
         sleep(Duration::from_millis(50)).await;
@@ -424,4 +622,276 @@ mod tests {
             "task should have been dropped (killed by ripcord)"
         );
     }
+
+    /// A child terminated with `Stop` ends its task, so the group refuses to
+    /// restart it and `clear_counters` leaves it permanently done. Deleting
+    /// it and registering a freshly spawned task under the same id brings
+    /// it back.
+    #[tokio::test]
+    async fn terminated_child_runs_again_only_once_respawned() {
+        use bloxide_child_management::{
+            Backoff, ChildAction, ChildGroup, ChildPhase, ChildPolicy, GroupShutdown, Termination,
+        };
+
+        fn spawn_child(
+            child_id: ActorId,
+            notify: &crate::TokioSender<ChildLifecycleEvent>,
+        ) -> (
+            bloxide_core::messaging::ActorRef<LifecycleCommand, TokioRuntime>,
+            tokio::task::JoinHandle<()>,
+        ) {
+            let (lifecycle_ref, lifecycle_rx) =
+                <TokioRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+            let task = tokio::spawn(run_supervised_actor(
+                StateMachine::<TestSpec>::new(()),
+                NoMailboxes,
+                lifecycle_rx,
+                child_id,
+                notify.clone(),
+            ));
+            (lifecycle_ref, task)
+        }
+
+        async fn next_event(rx: &mut TokioStream<ChildLifecycleEvent>) -> ChildLifecycleEvent {
+            tokio::time::timeout(Duration::from_secs(1), rx.inner.recv())
+                .await
+                .expect("child should report")
+                .expect("notify channel open")
+                .1
+        }
+
+        let child_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (notify_ref, mut notify_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+        let notify = notify_ref.sender();
+        let policy = ChildPolicy::Restart {
+            max: 1,
+            backoff: Backoff::Immediate,
+        };
+
+        let (lifecycle_ref, task) = spawn_child(child_id, &notify);
+        let mut group = ChildGroup::<TokioRuntime>::new(GroupShutdown::WhenAnyDone);
+        group.add(child_id, lifecycle_ref, policy);
+        group.start_all(42);
+        assert_eq!(
+            next_event(&mut notify_rx).await,
+            ChildLifecycleEvent::Started { child_id }
+        );
+        group.handle_started(child_id);
+
+        assert_eq!(
+            group.terminate_child(child_id, Termination::Stop, 42, &notify_ref),
+            Some(ChildAction::Continue)
+        );
+        // Still stopping: a restart now would reach a task about to end.
+        assert!(!group.restart_child(child_id, 42));
+        assert_eq!(
+            next_event(&mut notify_rx).await,
+            ChildLifecycleEvent::Stopped { child_id }
+        );
+        group.record_stopped(child_id);
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .expect("task should end once the child stops")
+            .expect("task should not panic");
+
+        assert!(!group.restart_child(child_id, 42));
+        assert_eq!(group.phase(child_id), Some(ChildPhase::Stopped));
+        assert!(group.all_stopped());
+        group.clear_counters();
+        group.start_all(42);
+        assert_eq!(group.phase(child_id), Some(ChildPhase::PermanentlyDone));
+        assert!(group.all_stopped());
+
+        assert!(group.delete_child(child_id));
+        let (lifecycle_ref, _task) = spawn_child(child_id, &notify);
+        group.add(child_id, lifecycle_ref.clone(), policy);
+        group.start_child(child_id, 42);
+        assert_eq!(
+            next_event(&mut notify_rx).await,
+            ChildLifecycleEvent::Started { child_id }
+        );
+        group.handle_started(child_id);
+        assert_eq!(group.phase(child_id), Some(ChildPhase::Running));
+        assert!(!group.all_stopped());
+
+        lifecycle_ref
+            .try_send(42, LifecycleCommand::Ping)
+            .expect("queue ping");
+        assert_eq!(
+            next_event(&mut notify_rx).await,
+            ChildLifecycleEvent::Alive { child_id }
+        );
+    }
+
+    /// Lifecycle commands queued together are handled in one batch, and a
+    /// `Stop` in the middle of the batch ends the loop immediately.
+    #[tokio::test]
+    async fn batched_supervised_actor_stops_mid_batch() {
+        let child_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (lifecycle_ref, lifecycle_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+        let (notify_ref, mut notify_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Ping,
+            LifecycleCommand::Stop,
+            LifecycleCommand::Ping,
+        ] {
+            lifecycle_ref
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+
+        let machine = StateMachine::<TestSpec>::new(());
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            run_supervised_actor_batched(
+                machine,
+                NoMailboxes,
+                lifecycle_rx,
+                child_id,
+                notify_ref.sender(),
+                BatchConfig::new(8).with_yield_budget(2),
+            ),
+        )
+        .await
+        .expect("loop should exit on Stop");
+
+        let mut events = Vec::new();
+        while let Ok(Envelope(_, ev)) = notify_rx.inner.try_recv() {
+            events.push(ev);
+        }
+        assert_eq!(
+            events,
+            vec![
+                ChildLifecycleEvent::Started { child_id },
+                ChildLifecycleEvent::Alive { child_id },
+                ChildLifecycleEvent::Stopped { child_id },
+            ]
+        );
+    }
+
+    /// A reset or stop makes the loop ask the registered timer services to
+    /// drop the actor's earlier timers; starting from `Init` does not.
+    #[tokio::test]
+    async fn reset_and_stop_cancel_the_actors_timers() {
+        use bloxide_timer::TimerCommand;
+
+        let child_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (timer_ref, mut timer_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<TimerCommand>(0, 16);
+        crate::timer::register_timer_service(&timer_ref);
+        let (lifecycle_ref, lifecycle_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(child_id, 4);
+        let (notify_ref, _notify_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Reset,
+            LifecycleCommand::Stop,
+        ] {
+            lifecycle_ref
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+
+        let machine = StateMachine::<TestSpec>::new(());
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            run_supervised_actor(
+                machine,
+                NoMailboxes,
+                lifecycle_rx,
+                child_id,
+                notify_ref.sender(),
+            ),
+        )
+        .await
+        .expect("loop should exit on Stop");
+
+        let mut cancels = 0;
+        while let Ok(Envelope(from, cmd)) = timer_rx.inner.try_recv() {
+            if from == child_id {
+                assert!(
+                    matches!(cmd, TimerCommand::CancelAllFor { owner, .. } if owner == child_id)
+                );
+                cancels += 1;
+            }
+        }
+        assert_eq!(cancels, 2);
+    }
+
+    /// Two supervised actors on one multiplexed task report to the same
+    /// supervisor as if each had its own task: `Stop` ends one slot, an abort
+    /// command ends the other, and the task returns once both have finished.
+    #[tokio::test]
+    async fn supervised_slots_share_one_task() {
+        let a_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let b_id = <TokioRuntime as DynamicChannelCap>::alloc_actor_id();
+        let (a_lifecycle, a_lifecycle_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(a_id, 4);
+        let (b_lifecycle, b_lifecycle_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<LifecycleCommand>(b_id, 4);
+        let (b_abort, b_abort_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<AbortCommand>(b_id, 4);
+        let (notify_ref, mut notify_rx) =
+            <TokioRuntime as DynamicChannelCap>::channel::<ChildLifecycleEvent>(42, 16);
+
+        for cmd in [
+            LifecycleCommand::Start,
+            LifecycleCommand::Ping,
+            LifecycleCommand::Stop,
+        ] {
+            a_lifecycle
+                .try_send(42, cmd)
+                .expect("queue lifecycle command");
+        }
+        b_lifecycle
+            .try_send(42, LifecycleCommand::Start)
+            .expect("queue lifecycle command");
+        b_abort
+            .try_send(42, AbortCommand::Abort { child_id: b_id })
+            .expect("queue abort command");
+
+        let mut a = SupervisedSlot::new(
+            StateMachine::<TestSpec>::new(()),
+            NoMailboxes,
+            a_lifecycle_rx,
+            a_id,
+            notify_ref.sender(),
+        );
+        let mut b = SupervisedSlot::new(
+            StateMachine::<TestSpec>::new(()),
+            NoMailboxes,
+            b_lifecycle_rx,
+            b_id,
+            notify_ref.sender(),
+        )
+        .with_abort(b_abort_rx);
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            bloxide_core::run_multiplexed(&mut [&mut a, &mut b]),
+        )
+        .await
+        .expect("task should end once both slots finish");
+
+        let mut events = Vec::new();
+        while let Ok(Envelope(_, ev)) = notify_rx.inner.try_recv() {
+            events.push(ev);
+        }
+        assert_eq!(
+            events,
+            vec![
+                ChildLifecycleEvent::Started { child_id: a_id },
+                ChildLifecycleEvent::Started { child_id: b_id },
+                ChildLifecycleEvent::Aborted { child_id: b_id },
+                ChildLifecycleEvent::Alive { child_id: a_id },
+                ChildLifecycleEvent::Stopped { child_id: a_id },
+            ]
+        );
+    }
 }
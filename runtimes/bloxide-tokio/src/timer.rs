@@ -1,9 +1,12 @@
 // Copyright 2025 Bloxide, all rights reserved
 use core::future::poll_fn;
 use core::pin::Pin;
+use std::sync::Mutex;
 
-use bloxide_core::messaging::Envelope;
-use bloxide_timer::{TimerCommand, TimerQueue, TimerService};
+use bloxide_core::capability::ClockCap;
+use bloxide_core::engine::DispatchOutcome;
+use bloxide_core::messaging::{ActorId, ActorRef, Envelope};
+use bloxide_timer::{cancel_timers_for, TimerCommand, TimerMark, TimerQueue, TimerService};
 use futures_core::Stream;
 use tokio::time::{sleep_until, Duration, Instant};
 
@@ -19,6 +22,42 @@ fn now_ms() -> u64 {
     epoch.elapsed().as_millis() as u64
 }
 
+static SUPERVISED_TIMERS: Mutex<Vec<ActorRef<TimerCommand, TokioRuntime>>> = Mutex::new(Vec::new());
+
+/// Register a timer service whose timers the supervised run loops discard
+/// when an actor stops, resets or aborts.
+///
+/// `spawn_timer!` registers the service it spawns. Call this only when the
+/// service task is started by hand. Every registered service is asked to
+/// discard the actor's timers; a service whose task has ended is forgotten.
+pub fn register_timer_service(timer_ref: &ActorRef<TimerCommand, TokioRuntime>) {
+    SUPERVISED_TIMERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(timer_ref.clone());
+}
+
+/// Discard `owner`'s timers from before `mark` if `outcome` ended its
+/// incarnation.
+pub(crate) fn settle_timers<St>(mark: TimerMark, outcome: &DispatchOutcome<St>, owner: ActorId) {
+    if !mark.discards(outcome) {
+        return;
+    }
+    let mut registered = SUPERVISED_TIMERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.retain(|timer_ref| !timer_ref.sender().inner.is_closed());
+    for timer_ref in registered.iter() {
+        cancel_timers_for(timer_ref, owner, mark.before());
+    }
+}
+
+impl ClockCap for TokioRuntime {
+    fn now_ms() -> u64 {
+        now_ms()
+    }
+}
+
 impl TimerService for TokioRuntime {
     async fn run_timer_service(mut stream: TokioStream<TimerCommand>) {
         let mut queue = TimerQueue::new();
@@ -33,9 +72,9 @@ impl TimerService for TokioRuntime {
                         biased;
                         maybe_env = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)) => {
                             match maybe_env {
-                                Some(Envelope(_, cmd)) => {
+                                Some(Envelope(from, cmd)) => {
                                     let now = now_ms();
-                                    if queue.handle_command(cmd, now) {
+                                    if queue.handle_command(from, cmd, now) {
                                         for deliver in queue.drain_expired(now) {
                                             deliver();
                                         }
@@ -60,9 +99,9 @@ impl TimerService for TokioRuntime {
                 None => {
                     let maybe_env = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
                     match maybe_env {
-                        Some(Envelope(_, cmd)) => {
+                        Some(Envelope(from, cmd)) => {
                             let now = now_ms();
-                            if queue.handle_command(cmd, now) {
+                            if queue.handle_command(from, cmd, now) {
                                 for deliver in queue.drain_expired(now) {
                                     deliver();
                                 }
@@ -80,3 +119,45 @@ impl TimerService for TokioRuntime {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bloxide_core::capability::DynamicChannelCap;
+    use bloxide_core::engine::MachineState;
+
+    fn cancels_for(rx: &mut crate::TokioStream<TimerCommand>, owner: ActorId) -> usize {
+        let mut count = 0;
+        while let Ok(Envelope(_, cmd)) = rx.inner.try_recv() {
+            if matches!(cmd, TimerCommand::CancelAllFor { owner: o, .. } if o == owner) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Every registered service is told to discard the actor's timers, and
+    /// a service that has ended is dropped from the set.
+    #[test]
+    fn settle_timers_reaches_every_registered_service() {
+        let owner = TokioRuntime::alloc_actor_id();
+        let (first_ref, mut first_rx) = TokioRuntime::channel::<TimerCommand>(0, 16);
+        let (second_ref, mut second_rx) = TokioRuntime::channel::<TimerCommand>(0, 16);
+        let (ended_ref, ended_rx) = TokioRuntime::channel::<TimerCommand>(0, 16);
+        register_timer_service(&first_ref);
+        register_timer_service(&second_ref);
+        register_timer_service(&ended_ref);
+        drop(ended_rx);
+
+        let mark = TimerMark::new(MachineState::State(1u8));
+        settle_timers(mark, &DispatchOutcome::<u8>::Stopped, owner);
+
+        assert_eq!(cancels_for(&mut first_rx, owner), 1);
+        assert_eq!(cancels_for(&mut second_rx, owner), 1);
+        let ended = ended_ref.sender().inner;
+        let registered = SUPERVISED_TIMERS.lock().unwrap();
+        assert!(!registered
+            .iter()
+            .any(|r| std::sync::Arc::ptr_eq(&r.sender().inner, &ended)));
+    }
+}
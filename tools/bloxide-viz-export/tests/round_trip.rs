@@ -444,6 +444,7 @@ fn test_full_round_trip_no_data_loss() {
                     h.state == trans.state
                         && h.event == full_event
                         && h.actions == trans.actions
+                        && h.guard.branches.is_empty() == trans.guards.is_empty()
                         && matches!(h.source, bloxide_viz_export::model::HandlerSource::Explicit)
                 });
 